@@ -0,0 +1,8 @@
+# German translations of the localization example
+-brand = Bevy
+
+title = Willkommen bei { -brand }!
+presses = Du hast { $count } Mal die Leertaste gedrückt.
+instructions =
+    Drücke die Leertaste zum Zählen
+    und L, um die Sprache zu wechseln.
@@ -0,0 +1,8 @@
+# English translations of the localization example
+-brand = Bevy
+
+title = Welcome to { -brand }!
+presses = You pressed space { $count } times.
+instructions =
+    Press space to count,
+    and L to switch the language.
@@ -0,0 +1,165 @@
+use crate::{PropertyCurve, VariableCurve};
+use bevy_type_registry::TypeUuid;
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+
+/// The path from an animated entity to one of its descendants. Each part of the path is a label
+/// (see [Labels](bevy_core::Labels)) of a child of the previous entity. An empty path refers to
+/// the animated entity itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct EntityPath {
+    pub parts: Vec<Cow<'static, str>>,
+}
+
+impl EntityPath {
+    pub fn new<T: Into<Cow<'static, str>>>(parts: impl IntoIterator<Item = T>) -> Self {
+        EntityPath {
+            parts: parts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Keyframe curves for the entities of a hierarchy, played by an
+/// [AnimationPlayer](crate::AnimationPlayer). Clips can also contain named events, which are sent
+/// as [AnimationEvent](crate::AnimationEvent)s when playback reaches their timestamp.
+#[derive(Debug, Clone, Default, TypeUuid)]
+#[uuid = "3d6b3f5e-0d2c-4f4a-9a57-8f1b7c0e6a21"]
+pub struct AnimationClip {
+    curves: HashMap<EntityPath, Vec<VariableCurve>>,
+    property_curves: HashMap<EntityPath, Vec<PropertyCurve>>,
+    /// Sorted by timestamp
+    events: Vec<(f32, Cow<'static, str>)>,
+    duration: f32,
+}
+
+impl AnimationClip {
+    /// Adds a curve that animates the entity at `path`
+    pub fn add_curve(&mut self, path: EntityPath, curve: VariableCurve) -> &mut Self {
+        self.duration = self.duration.max(curve.duration());
+        self.curves.entry(path).or_default().push(curve);
+        self
+    }
+
+    pub fn curves(&self) -> impl Iterator<Item = (&EntityPath, &[VariableCurve])> {
+        self.curves
+            .iter()
+            .map(|(path, curves)| (path, curves.as_slice()))
+    }
+
+    /// Adds a curve that animates a field of a component or asset of the entity at `path`
+    pub fn add_property_curve(&mut self, path: EntityPath, curve: PropertyCurve) -> &mut Self {
+        self.duration = self.duration.max(curve.duration());
+        self.property_curves.entry(path).or_default().push(curve);
+        self
+    }
+
+    pub fn property_curves(&self) -> impl Iterator<Item = (&EntityPath, &[PropertyCurve])> {
+        self.property_curves
+            .iter()
+            .map(|(path, curves)| (path, curves.as_slice()))
+    }
+
+    /// Adds an event that is sent when playback reaches `time`, such as a footstep
+    pub fn add_event(&mut self, time: f32, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.duration = self.duration.max(time);
+        let index = self
+            .events
+            .iter()
+            .position(|(event_time, _)| *event_time > time)
+            .unwrap_or_else(|| self.events.len());
+        self.events.insert(index, (time, name.into()));
+        self
+    }
+
+    /// The events of the clip and their timestamps, in the order they are played
+    pub fn events(&self) -> impl Iterator<Item = (f32, &str)> {
+        self.events
+            .iter()
+            .map(|(time, name)| (*time, name.as_ref()))
+    }
+
+    /// The timestamp of the last keyframe or event, in seconds
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Returns the events that are reached when playback moves from `start` to `end` seconds, in
+    /// the order they are reached. `end` may be outside of the clip, in which case playback either
+    /// stops at the clip's bounds or wraps around them if `repeat` is true. The event at `start`
+    /// is only included if `include_start` is true.
+    pub(crate) fn crossed_events(
+        &self,
+        start: f32,
+        end: f32,
+        repeat: bool,
+        include_start: bool,
+    ) -> Vec<&str> {
+        if self.events.is_empty() || (start == end && !include_start) {
+            return Vec::new();
+        }
+        let forward = end >= start;
+        let is_crossed = |time: f32| {
+            let after_start = if forward { time > start } else { time < start };
+            let before_end = if forward { time <= end } else { time >= end };
+            (after_start || (include_start && time == start)) && before_end
+        };
+
+        let mut crossed = Vec::new();
+        if repeat && self.duration > 0.0 {
+            let first_loop = (start.min(end) / self.duration).floor() as i64;
+            let last_loop = (start.max(end) / self.duration).floor() as i64;
+            for repetition in first_loop..=last_loop {
+                let offset = repetition as f32 * self.duration;
+                for (time, name) in self.events.iter() {
+                    if is_crossed(time + offset) {
+                        crossed.push((time + offset, name.as_ref()));
+                    }
+                }
+            }
+        } else {
+            for (time, name) in self.events.iter() {
+                if is_crossed(*time) {
+                    crossed.push((*time, name.as_ref()));
+                }
+            }
+        }
+        if !forward {
+            crossed.reverse();
+        }
+        crossed.into_iter().map(|(_, name)| name).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnimationClip;
+
+    #[test]
+    fn finds_crossed_events() {
+        let mut clip = AnimationClip::default();
+        clip.add_event(1.0, "step")
+            .add_event(0.0, "start")
+            .add_event(2.0, "end");
+        assert_eq!(clip.duration(), 2.0);
+
+        assert_eq!(clip.crossed_events(0.0, 0.5, false, true), vec!["start"]);
+        assert_eq!(
+            clip.crossed_events(0.0, 0.5, false, false),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            clip.crossed_events(0.5, 3.0, false, false),
+            vec!["step", "end"]
+        );
+        // playback wraps around the end of repeating clips
+        assert_eq!(
+            clip.crossed_events(1.5, 4.5, true, false),
+            vec!["end", "start", "step", "end", "start"]
+        );
+        // and plays events in reverse when playing backwards
+        assert_eq!(
+            clip.crossed_events(1.5, -0.5, true, false),
+            vec!["step", "start", "end"]
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use crate::AnimationClip;
+use bevy_asset::Handle;
+use bevy_type_registry::TypeUuid;
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+
+/// A state machine of animation clips. When an [AnimationPlayer](crate::AnimationPlayer) plays a
+/// graph, it starts in the first state that was added and cross-fades to other states when the
+/// conditions of their transitions are met. Conditions check parameters that gameplay code sets
+/// on the player, for example with [AnimationPlayer::set_float](crate::AnimationPlayer::set_float).
+#[derive(Debug, Clone, Default, TypeUuid)]
+#[uuid = "1b0f3b4e-7d3c-4c8e-a2b5-6a3f0e9d7c41"]
+pub struct AnimationGraph {
+    states: Vec<(Cow<'static, str>, AnimationState)>,
+    transitions: Vec<AnimationTransition>,
+}
+
+impl AnimationGraph {
+    /// Adds a state. The first state that is added is the state the graph starts in.
+    pub fn add_state(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        state: AnimationState,
+    ) -> &mut Self {
+        self.states.push((name.into(), state));
+        self
+    }
+
+    /// Adds a transition from the state `from` to the state `to` that happens when `condition` is
+    /// met, cross-fading between their clips over `duration` seconds. Transitions are checked in
+    /// the order they were added.
+    pub fn add_transition(
+        &mut self,
+        from: impl Into<Cow<'static, str>>,
+        to: impl Into<Cow<'static, str>>,
+        condition: Condition,
+        duration: f32,
+    ) -> &mut Self {
+        self.transitions.push(AnimationTransition {
+            from: Some(from.into()),
+            to: to.into(),
+            condition,
+            duration,
+        });
+        self
+    }
+
+    /// Adds a transition from every other state to the state `to`, such as a "hit" reaction
+    pub fn add_transition_from_any(
+        &mut self,
+        to: impl Into<Cow<'static, str>>,
+        condition: Condition,
+        duration: f32,
+    ) -> &mut Self {
+        self.transitions.push(AnimationTransition {
+            from: None,
+            to: to.into(),
+            condition,
+            duration,
+        });
+        self
+    }
+
+    pub fn state(&self, name: &str) -> Option<&AnimationState> {
+        self.states
+            .iter()
+            .find(|(state_name, _)| state_name == name)
+            .map(|(_, state)| state)
+    }
+
+    /// The name of the state the graph starts in
+    pub fn entry_state(&self) -> Option<&str> {
+        self.states.first().map(|(name, _)| name.as_ref())
+    }
+
+    /// Returns the first transition out of the state `current` whose condition is met
+    pub(crate) fn find_transition(
+        &self,
+        current: &str,
+        parameters: &AnimationParameters,
+        clip_finished: bool,
+    ) -> Option<&AnimationTransition> {
+        self.transitions.iter().find(|transition| {
+            let leaves_current = match &transition.from {
+                Some(from) => from == current,
+                None => transition.to != current,
+            };
+            leaves_current && transition.condition.is_met(parameters, clip_finished)
+        })
+    }
+}
+
+/// A state of an [AnimationGraph], which plays a clip
+#[derive(Debug, Clone)]
+pub struct AnimationState {
+    pub clip: Handle<AnimationClip>,
+    /// Whether the clip plays in a loop while the graph is in this state
+    pub repeat: bool,
+    pub speed: f32,
+}
+
+impl AnimationState {
+    /// A state that plays `clip` in a loop
+    pub fn new(clip: Handle<AnimationClip>) -> Self {
+        AnimationState {
+            clip,
+            repeat: true,
+            speed: 1.0,
+        }
+    }
+
+    /// Plays the clip once, so [Condition::Finished] can move on to another state
+    pub fn once(mut self) -> Self {
+        self.repeat = false;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AnimationTransition {
+    /// `None` for transitions from any state
+    pub(crate) from: Option<Cow<'static, str>>,
+    pub(crate) to: Cow<'static, str>,
+    pub(crate) condition: Condition,
+    pub(crate) duration: f32,
+}
+
+/// When a transition of an [AnimationGraph] happens. Parameters that haven't been set are `false`
+/// or `0.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// The transition happens as soon as it is checked
+    Always,
+    /// The bool parameter is true
+    Bool(Cow<'static, str>),
+    /// The float parameter is greater than the value
+    Greater(Cow<'static, str>, f32),
+    /// The float parameter is less than the value
+    Less(Cow<'static, str>, f32),
+    /// The trigger parameter has been set. The trigger is reset when the transition happens.
+    Trigger(Cow<'static, str>),
+    /// The clip of the current state has reached its end. Repeating clips never finish.
+    Finished,
+    Not(Box<Condition>),
+    /// All of the conditions are met
+    All(Vec<Condition>),
+    /// Any of the conditions is met
+    Any(Vec<Condition>),
+}
+
+impl Condition {
+    pub fn is_met(&self, parameters: &AnimationParameters, clip_finished: bool) -> bool {
+        match self {
+            Condition::Always => true,
+            Condition::Bool(name) => parameters.bool(name),
+            Condition::Greater(name, value) => parameters.float(name) > *value,
+            Condition::Less(name, value) => parameters.float(name) < *value,
+            Condition::Trigger(name) => parameters.is_triggered(name),
+            Condition::Finished => clip_finished,
+            Condition::Not(condition) => !condition.is_met(parameters, clip_finished),
+            Condition::All(conditions) => conditions
+                .iter()
+                .all(|condition| condition.is_met(parameters, clip_finished)),
+            Condition::Any(conditions) => conditions
+                .iter()
+                .any(|condition| condition.is_met(parameters, clip_finished)),
+        }
+    }
+
+    /// Resets the triggers this condition checks
+    pub(crate) fn consume_triggers(&self, parameters: &mut AnimationParameters) {
+        match self {
+            Condition::Trigger(name) => parameters.reset_trigger(name),
+            Condition::Not(condition) => condition.consume_triggers(parameters),
+            Condition::All(conditions) | Condition::Any(conditions) => {
+                for condition in conditions {
+                    condition.consume_triggers(parameters);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The values that the conditions of an [AnimationGraph] check
+#[derive(Debug, Clone, Default)]
+pub struct AnimationParameters {
+    bools: HashMap<Cow<'static, str>, bool>,
+    floats: HashMap<Cow<'static, str>, f32>,
+    triggers: HashMap<Cow<'static, str>, bool>,
+}
+
+impl AnimationParameters {
+    pub fn set_bool(&mut self, name: impl Into<Cow<'static, str>>, value: bool) {
+        self.bools.insert(name.into(), value);
+    }
+
+    pub fn bool(&self, name: &str) -> bool {
+        self.bools.get(name).copied().unwrap_or(false)
+    }
+
+    pub fn set_float(&mut self, name: impl Into<Cow<'static, str>>, value: f32) {
+        self.floats.insert(name.into(), value);
+    }
+
+    pub fn float(&self, name: &str) -> f32 {
+        self.floats.get(name).copied().unwrap_or(0.0)
+    }
+
+    /// Sets a trigger, which stays set until a transition that checks it happens
+    pub fn set_trigger(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.triggers.insert(name.into(), true);
+    }
+
+    pub fn is_triggered(&self, name: &str) -> bool {
+        self.triggers.get(name).copied().unwrap_or(false)
+    }
+
+    pub fn reset_trigger(&mut self, name: &str) {
+        if let Some(triggered) = self.triggers.get_mut(name) {
+            *triggered = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnimationParameters, Condition};
+
+    #[test]
+    fn conditions_check_parameters() {
+        let mut parameters = AnimationParameters::default();
+        parameters.set_bool("grounded", true);
+        parameters.set_float("speed", 2.0);
+
+        assert!(Condition::Always.is_met(&parameters, false));
+        assert!(Condition::Bool("grounded".into()).is_met(&parameters, false));
+        assert!(!Condition::Bool("crouching".into()).is_met(&parameters, false));
+        assert!(Condition::Greater("speed".into(), 1.0).is_met(&parameters, false));
+        assert!(!Condition::Less("speed".into(), 1.0).is_met(&parameters, false));
+        // unset floats are 0
+        assert!(Condition::Less("height".into(), 1.0).is_met(&parameters, false));
+        assert!(!Condition::Finished.is_met(&parameters, false));
+        assert!(Condition::Finished.is_met(&parameters, true));
+
+        let not_grounded = Condition::Not(Box::new(Condition::Bool("grounded".into())));
+        assert!(!not_grounded.is_met(&parameters, false));
+        let moving_on_ground = Condition::All(vec![
+            Condition::Bool("grounded".into()),
+            Condition::Greater("speed".into(), 1.0),
+        ]);
+        assert!(moving_on_ground.is_met(&parameters, false));
+        let finished_or_falling = Condition::Any(vec![Condition::Finished, not_grounded]);
+        assert!(!finished_or_falling.is_met(&parameters, false));
+        assert!(finished_or_falling.is_met(&parameters, true));
+        assert!(!Condition::Any(Vec::new()).is_met(&parameters, false));
+        assert!(Condition::All(Vec::new()).is_met(&parameters, false));
+    }
+
+    #[test]
+    fn consume_triggers_resets_nested_triggers() {
+        let mut parameters = AnimationParameters::default();
+        parameters.set_trigger("jump");
+        parameters.set_trigger("hit");
+        parameters.set_trigger("dodge");
+
+        let jump = Condition::Trigger("jump".into());
+        assert!(jump.is_met(&parameters, false));
+        jump.consume_triggers(&mut parameters);
+        assert!(!jump.is_met(&parameters, false));
+        assert!(parameters.is_triggered("hit"));
+
+        let hit_or_dodge = Condition::Any(vec![
+            Condition::Trigger("hit".into()),
+            Condition::Not(Box::new(Condition::Trigger("dodge".into()))),
+        ]);
+        assert!(hit_or_dodge.is_met(&parameters, false));
+        hit_or_dodge.consume_triggers(&mut parameters);
+        assert!(!parameters.is_triggered("hit"));
+        assert!(!parameters.is_triggered("dodge"));
+        // with both triggers reset, only the negated branch is met
+        assert!(hit_or_dodge.is_met(&parameters, false));
+    }
+}
@@ -0,0 +1,593 @@
+use crate::{
+    blend::TransformBlend, AnimationClip, AnimationGraph, AnimationParameters, EntityPath,
+};
+use bevy_app::Events;
+use bevy_asset::{Assets, Handle};
+use bevy_core::{Labels, Time};
+use bevy_ecs::{Entity, Query, Res, ResMut};
+use bevy_transform::prelude::{Children, Transform};
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+
+/// Plays [AnimationClip]s on the entity it is added to and the entity's descendants.
+///
+/// Several clips can play at once. Their weights are relative: each animated property becomes
+/// the weighted average of the clips that animate it. When the weights of those clips add up to
+/// less than 1, the rest is taken from the bind pose, which is the [Transform] the entity had
+/// before it was first animated. Use [AnimationPlayer::cross_fade] to
+/// smoothly transition from the playing clips to another one, or [AnimationPlayer::play_graph]
+/// to let an [AnimationGraph] choose the clips.
+#[derive(Debug)]
+pub struct AnimationPlayer {
+    /// The playing clips, the most recently started one last
+    clips: Vec<PlayingClip>,
+    paused: bool,
+    repeat: bool,
+    speed: f32,
+    graph: Option<Handle<AnimationGraph>>,
+    /// The current state of the graph, `None` until the graph has loaded
+    graph_state: Option<Cow<'static, str>>,
+    parameters: AnimationParameters,
+    /// The transforms of the animated entities before they were first animated
+    bind_poses: HashMap<Entity, Transform>,
+}
+
+#[derive(Debug)]
+struct PlayingClip {
+    clip: Handle<AnimationClip>,
+    elapsed: f32,
+    weight: f32,
+    fade: Option<Fade>,
+    /// Whether the clip hasn't been advanced yet, in which case events at its start are sent
+    starting: bool,
+}
+
+/// Changes the weight of a clip over time
+#[derive(Debug)]
+struct Fade {
+    target_weight: f32,
+    /// The change in weight per second
+    rate: f32,
+}
+
+impl PlayingClip {
+    fn new(clip: Handle<AnimationClip>, weight: f32) -> Self {
+        PlayingClip {
+            clip,
+            elapsed: 0.0,
+            weight,
+            fade: None,
+            starting: true,
+        }
+    }
+
+    fn fade_to(&mut self, target_weight: f32, duration: f32) {
+        self.fade = Some(Fade {
+            target_weight,
+            rate: (target_weight - self.weight).abs() / duration,
+        });
+    }
+
+    /// Returns true if the clip has reached the end it plays towards, which is its start when
+    /// `speed` is negative
+    fn reached_end(&self, clip: &AnimationClip, speed: f32) -> bool {
+        if speed < 0.0 {
+            self.elapsed <= 0.0
+        } else {
+            self.elapsed >= clip.duration()
+        }
+    }
+}
+
+impl Default for AnimationPlayer {
+    fn default() -> Self {
+        AnimationPlayer {
+            clips: Vec::new(),
+            paused: false,
+            repeat: false,
+            speed: 1.0,
+            graph: None,
+            graph_state: None,
+            parameters: Default::default(),
+            bind_poses: Default::default(),
+        }
+    }
+}
+
+impl AnimationPlayer {
+    /// Stops all playing clips and starts playing `clip` from the beginning
+    pub fn play(&mut self, clip: Handle<AnimationClip>) -> &mut Self {
+        self.clips.clear();
+        self.clips.push(PlayingClip::new(clip, 1.0));
+        self.paused = false;
+        self
+    }
+
+    /// Fades in `clip` while fading out every other playing clip over `duration` seconds. The
+    /// clip starts from the beginning unless it is already playing, and becomes the most recently
+    /// started clip either way. Faded out clips are stopped.
+    pub fn cross_fade(&mut self, clip: Handle<AnimationClip>, duration: f32) -> &mut Self {
+        if duration <= 0.0 {
+            return self.play(clip);
+        }
+        let playing = match self.clips.iter().position(|playing| playing.clip == clip) {
+            Some(index) => self.clips.remove(index),
+            None => PlayingClip::new(clip.clone(), 0.0),
+        };
+        self.clips.push(playing);
+        for playing in self.clips.iter_mut() {
+            let target_weight = if playing.clip == clip { 1.0 } else { 0.0 };
+            playing.fade_to(target_weight, duration);
+        }
+        self.paused = false;
+        self
+    }
+
+    /// Plays `clip` with the given weight alongside the other playing clips. The clip starts from
+    /// the beginning unless it is already playing. Clips with a weight of 0 are stopped.
+    pub fn set_weight(&mut self, clip: Handle<AnimationClip>, weight: f32) -> &mut Self {
+        match self.clips.iter_mut().find(|playing| playing.clip == clip) {
+            Some(playing) => {
+                playing.weight = weight;
+                playing.fade = None;
+            }
+            None => self.clips.push(PlayingClip::new(clip, weight)),
+        }
+        self
+    }
+
+    /// Returns the weight of `clip`, or `None` if it isn't playing
+    pub fn weight(&self, clip: &Handle<AnimationClip>) -> Option<f32> {
+        self.clips
+            .iter()
+            .find(|playing| playing.clip == *clip)
+            .map(|playing| playing.weight)
+    }
+
+    /// Stops playing `clip`
+    pub fn stop(&mut self, clip: &Handle<AnimationClip>) -> &mut Self {
+        self.clips.retain(|playing| playing.clip != *clip);
+        self
+    }
+
+    /// Plays the clips in a loop instead of stopping at their end
+    pub fn repeat(&mut self) -> &mut Self {
+        self.repeat = true;
+        self
+    }
+
+    /// Stops looping the clips, so they stop at their end
+    pub fn stop_repeating(&mut self) -> &mut Self {
+        self.repeat = false;
+        self
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns true if the clips aren't repeating and have all reached their end, or their start
+    /// when playing backwards
+    pub fn is_finished(&self, clips: &Assets<AnimationClip>) -> bool {
+        !self.repeat
+            && self.clips.iter().all(|playing| {
+                clips
+                    .get(&playing.clip)
+                    .map_or(false, |clip| playing.reached_end(clip, self.speed))
+            })
+    }
+
+    /// The most recently started clip
+    pub fn clip(&self) -> Option<&Handle<AnimationClip>> {
+        self.clips.last().map(|playing| &playing.clip)
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the playback speed, where 1.0 is the clips' own speed. Negative speeds play the clips
+    /// backwards.
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        self.speed = speed;
+        self
+    }
+
+    /// The time into the most recently started clip, in seconds
+    pub fn elapsed(&self) -> f32 {
+        self.clips.last().map_or(0.0, |playing| playing.elapsed)
+    }
+
+    /// Jumps to `elapsed` seconds into every playing clip
+    pub fn seek_to(&mut self, elapsed: f32) -> &mut Self {
+        for playing in self.clips.iter_mut() {
+            playing.elapsed = elapsed;
+        }
+        self
+    }
+
+    /// The playing clips with their elapsed time and weight
+    pub(crate) fn playing_clips(&self) -> impl Iterator<Item = (&Handle<AnimationClip>, f32, f32)> {
+        self.clips
+            .iter()
+            .map(|playing| (&playing.clip, playing.elapsed, playing.weight))
+    }
+
+    /// Plays the clips of `graph`'s states, starting with its entry state once the graph has
+    /// loaded. The graph moves between its states when the conditions of its transitions are met,
+    /// and sets the repetition and speed of the player to those of the current state.
+    pub fn play_graph(&mut self, graph: Handle<AnimationGraph>) -> &mut Self {
+        self.clips.clear();
+        self.graph = Some(graph);
+        self.graph_state = None;
+        self.paused = false;
+        self
+    }
+
+    /// Stops following the graph. The clips that are playing keep playing.
+    pub fn stop_graph(&mut self) -> &mut Self {
+        self.graph = None;
+        self.graph_state = None;
+        self
+    }
+
+    pub fn graph(&self) -> Option<&Handle<AnimationGraph>> {
+        self.graph.as_ref()
+    }
+
+    /// The name of the current state of the graph
+    pub fn graph_state(&self) -> Option<&str> {
+        self.graph_state.as_deref()
+    }
+
+    /// The parameters that the graph's conditions check
+    pub fn parameters(&self) -> &AnimationParameters {
+        &self.parameters
+    }
+
+    pub fn parameters_mut(&mut self) -> &mut AnimationParameters {
+        &mut self.parameters
+    }
+
+    pub fn set_bool(&mut self, name: impl Into<Cow<'static, str>>, value: bool) -> &mut Self {
+        self.parameters.set_bool(name, value);
+        self
+    }
+
+    pub fn set_float(&mut self, name: impl Into<Cow<'static, str>>, value: f32) -> &mut Self {
+        self.parameters.set_float(name, value);
+        self
+    }
+
+    pub fn set_trigger(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.parameters.set_trigger(name);
+        self
+    }
+
+    /// Enters the graph's entry state, or follows the first transition out of the current state
+    /// whose condition is met. `clip_finished` is whether the current state's clip has ended.
+    fn update_graph(&mut self, graph: &AnimationGraph, clip_finished: bool) {
+        let (next, duration) = match &self.graph_state {
+            None => match graph.entry_state() {
+                Some(entry) => (entry, 0.0),
+                None => return,
+            },
+            Some(current) => {
+                match graph.find_transition(current, &self.parameters, clip_finished) {
+                    Some(transition) => {
+                        transition.condition.consume_triggers(&mut self.parameters);
+                        (transition.to.as_ref(), transition.duration)
+                    }
+                    None => return,
+                }
+            }
+        };
+        let state = match graph.state(next) {
+            Some(state) => state,
+            None => return,
+        };
+        self.graph_state = Some(Cow::Owned(next.to_string()));
+        self.repeat = state.repeat;
+        self.speed = state.speed;
+        // states always start their clip from the beginning, even if it is still fading out
+        if let Some(playing) = self
+            .clips
+            .iter_mut()
+            .find(|playing| playing.clip == state.clip)
+        {
+            playing.elapsed = 0.0;
+            playing.starting = true;
+        }
+        self.cross_fade(state.clip.clone(), duration);
+    }
+
+    /// Returns true if the clip of the graph's current state doesn't repeat and has reached its end
+    fn is_graph_clip_finished(
+        &self,
+        graph: &AnimationGraph,
+        clips: &Assets<AnimationClip>,
+    ) -> bool {
+        let state = match self
+            .graph_state
+            .as_ref()
+            .and_then(|current| graph.state(current))
+        {
+            Some(state) => state,
+            None => return false,
+        };
+        !state.repeat
+            && self
+                .clips
+                .iter()
+                .find(|playing| playing.clip == state.clip)
+                .map_or(false, |playing| {
+                    clips
+                        .get(&playing.clip)
+                        .map_or(false, |clip| playing.reached_end(clip, self.speed))
+                })
+    }
+
+    /// Advances fades by `delta` seconds and stops clips that have faded out
+    fn update_weights(&mut self, delta: f32) {
+        for playing in self.clips.iter_mut() {
+            if let Some(fade) = &playing.fade {
+                let step = fade.rate * delta;
+                if (fade.target_weight - playing.weight).abs() <= step {
+                    playing.weight = fade.target_weight;
+                    playing.fade = None;
+                } else if fade.target_weight > playing.weight {
+                    playing.weight += step;
+                } else {
+                    playing.weight -= step;
+                }
+            }
+        }
+        self.clips
+            .retain(|playing| playing.weight > 0.0 || playing.fade.is_some());
+    }
+
+    /// Advances each clip by `delta` seconds of playback, wrapping or clamping it to its duration.
+    /// Calls `send_event` for each clip event that is reached.
+    fn update_elapsed(
+        &mut self,
+        delta: f32,
+        clips: &Assets<AnimationClip>,
+        mut send_event: impl FnMut(&PlayingClip, &str),
+    ) {
+        let repeat = self.repeat;
+        for playing in self.clips.iter_mut() {
+            let clip = match clips.get(&playing.clip) {
+                Some(clip) => clip,
+                None => continue,
+            };
+            let duration = clip.duration();
+            let elapsed = playing.elapsed + delta;
+            if delta != 0.0 {
+                for event in clip.crossed_events(playing.elapsed, elapsed, repeat, playing.starting)
+                {
+                    send_event(playing, event);
+                }
+                playing.starting = false;
+            }
+            playing.elapsed = if repeat && duration > 0.0 {
+                elapsed.rem_euclid(duration)
+            } else {
+                elapsed.max(0.0).min(duration)
+            };
+        }
+    }
+}
+
+/// Sent when an [AnimationPlayer] reaches an event of one of its clips, see
+/// [AnimationClip::add_event]
+#[derive(Debug, Clone)]
+pub struct AnimationEvent {
+    /// The entity with the [AnimationPlayer]
+    pub entity: Entity,
+    pub clip: Handle<AnimationClip>,
+    pub name: String,
+    /// The weight of the clip when the event was reached. Events of clips that are fading out
+    /// have a weight below 1.
+    pub weight: f32,
+}
+
+/// Advances each [AnimationPlayer], follows the transitions of the graphs they play and applies
+/// the blend of its clips to the transforms of the animated entities
+pub fn animation_player_system(
+    time: Res<Time>,
+    clips: Res<Assets<AnimationClip>>,
+    graphs: Res<Assets<AnimationGraph>>,
+    mut animation_events: ResMut<Events<AnimationEvent>>,
+    mut players: Query<(Entity, &mut AnimationPlayer)>,
+    children: Query<&Children>,
+    labels: Query<&Labels>,
+    mut transforms: Query<&mut Transform>,
+) {
+    let mut blends = HashMap::<Entity, TransformBlend>::default();
+    for (entity, mut player) in players.iter_mut() {
+        if let Some(graph) = player.graph.as_ref().and_then(|graph| graphs.get(graph)) {
+            let clip_finished = player.is_graph_clip_finished(graph, &clips);
+            player.update_graph(graph, clip_finished);
+        }
+
+        let delta = if player.paused {
+            // still clamps clips that were seeked while paused
+            0.0
+        } else {
+            player.update_weights(time.delta_seconds);
+            time.delta_seconds * player.speed
+        };
+        player.update_elapsed(delta, &clips, |playing, name| {
+            animation_events.send(AnimationEvent {
+                entity,
+                clip: playing.clip.clone_weak(),
+                name: name.to_string(),
+                weight: playing.weight,
+            })
+        });
+
+        blends.clear();
+        for playing in player.clips.iter() {
+            let clip = match clips.get(&playing.clip) {
+                Some(clip) => clip,
+                None => continue,
+            };
+            if playing.weight <= 0.0 {
+                continue;
+            }
+            for (path, curves) in clip.curves() {
+                let target = match find_target(entity, path, &children, &labels) {
+                    Some(target) => target,
+                    None => continue,
+                };
+                let blend = blends.entry(target).or_default();
+                for curve in curves {
+                    if let Some(value) = curve.sample(playing.elapsed) {
+                        blend.add(value, playing.weight);
+                    }
+                }
+            }
+        }
+
+        for (target, blend) in blends.iter_mut() {
+            if let Ok(mut transform) = transforms.get_mut(*target) {
+                let bind_pose = player.bind_poses.entry(*target).or_insert(*transform);
+                blend.fill_with_bind_pose(bind_pose);
+                blend.apply(&mut transform);
+            }
+        }
+    }
+}
+
+/// Follows `path` from `root` through the labels of each entity's children
+pub(crate) fn find_target(
+    root: Entity,
+    path: &EntityPath,
+    children: &Query<&Children>,
+    labels: &Query<&Labels>,
+) -> Option<Entity> {
+    let mut current = root;
+    for part in path.parts.iter() {
+        current = children.get(current).ok()?.iter().copied().find(|child| {
+            labels
+                .get(*child)
+                .map_or(false, |labels| labels.contains(part.clone()))
+        })?;
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnimationState, Condition, Keyframes, VariableCurve};
+    use bevy_asset::HandleId;
+    use bevy_math::Vec3;
+
+    #[test]
+    fn cross_fade_moves_weight_to_new_clip() {
+        let walk = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let run = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let mut player = AnimationPlayer::default();
+        player.play(walk.clone()).cross_fade(run.clone(), 1.0);
+        assert_eq!(player.weight(&walk), Some(1.0));
+        assert_eq!(player.weight(&run), Some(0.0));
+
+        player.update_weights(0.25);
+        assert_eq!(player.weight(&walk), Some(0.75));
+        assert_eq!(player.weight(&run), Some(0.25));
+
+        player.update_weights(1.0);
+        assert_eq!(player.weight(&walk), None);
+        assert_eq!(player.weight(&run), Some(1.0));
+        assert_eq!(player.clip(), Some(&run));
+    }
+
+    #[test]
+    fn cross_fade_back_makes_clip_current() {
+        let walk = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let run = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let mut player = AnimationPlayer::default();
+        player.play(walk.clone()).cross_fade(run.clone(), 1.0);
+        player.update_weights(0.5);
+
+        // walk is still fading out, so it is faded back in instead of being restarted
+        player.cross_fade(walk.clone(), 1.0);
+        assert_eq!(player.clip(), Some(&walk));
+        assert_eq!(player.weight(&walk), Some(0.5));
+
+        player.update_weights(1.0);
+        assert_eq!(player.weight(&run), None);
+        assert_eq!(player.clip(), Some(&walk));
+    }
+
+    #[test]
+    fn graph_follows_transitions() {
+        let idle = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let run = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let jump = Handle::<AnimationClip>::weak(HandleId::random::<AnimationClip>());
+        let mut graph = AnimationGraph::default();
+        graph
+            .add_state("idle", AnimationState::new(idle.clone()))
+            .add_state("run", AnimationState::new(run.clone()).with_speed(2.0))
+            .add_state("jump", AnimationState::new(jump.clone()).once())
+            .add_transition("idle", "run", Condition::Greater("speed".into(), 0.5), 0.25)
+            .add_transition("run", "idle", Condition::Less("speed".into(), 0.5), 0.25)
+            .add_transition("jump", "idle", Condition::Finished, 0.0)
+            .add_transition_from_any("jump", Condition::Trigger("jump".into()), 0.0);
+
+        let mut player = AnimationPlayer::default();
+        player.update_graph(&graph, false);
+        assert_eq!(player.graph_state(), Some("idle"));
+        assert_eq!(player.clip(), Some(&idle));
+
+        player.set_float("speed", 1.0);
+        player.update_graph(&graph, false);
+        assert_eq!(player.graph_state(), Some("run"));
+        assert_eq!(player.speed(), 2.0);
+        assert_eq!(player.weight(&idle), Some(1.0));
+        assert_eq!(player.weight(&run), Some(0.0));
+
+        player.set_trigger("jump");
+        player.update_graph(&graph, false);
+        assert_eq!(player.graph_state(), Some("jump"));
+        assert_eq!(player.clip(), Some(&jump));
+        // the trigger was consumed, so the graph stays in its state until the clip finishes
+        player.update_graph(&graph, false);
+        assert_eq!(player.graph_state(), Some("jump"));
+        player.update_graph(&graph, true);
+        assert_eq!(player.graph_state(), Some("idle"));
+    }
+
+    #[test]
+    fn clips_played_backwards_end_at_their_start() {
+        let mut clip = AnimationClip::default();
+        clip.add_curve(
+            EntityPath::default(),
+            VariableCurve::new(
+                vec![0.0, 2.0],
+                Keyframes::Translation(vec![Vec3::zero(), Vec3::one()]),
+            ),
+        );
+        let mut playing = PlayingClip::new(Handle::weak(HandleId::random::<AnimationClip>()), 1.0);
+        playing.elapsed = 1.0;
+        assert!(!playing.reached_end(&clip, 1.0));
+        assert!(!playing.reached_end(&clip, -1.0));
+
+        playing.elapsed = 2.0;
+        assert!(playing.reached_end(&clip, 1.0));
+        assert!(!playing.reached_end(&clip, -1.0));
+
+        playing.elapsed = 0.0;
+        assert!(!playing.reached_end(&clip, 1.0));
+        assert!(playing.reached_end(&clip, -1.0));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{property_curve::apply_property, KeyframeValue, PropertyCurve};
+use bevy_math::{Quat, Vec3, Vec4};
+use bevy_property::{Properties, Property};
+use bevy_transform::prelude::Transform;
+use std::borrow::Cow;
+
+/// Accumulates weighted [KeyframeValue]s of several clips for one entity. Weights are relative:
+/// each property becomes the weighted average of the values added for it.
+#[derive(Debug, Default)]
+pub(crate) struct TransformBlend {
+    translation: Option<(Vec3, f32)>,
+    rotation: Option<(Vec4, f32)>,
+    scale: Option<(Vec3, f32)>,
+}
+
+impl TransformBlend {
+    pub(crate) fn add(&mut self, value: KeyframeValue, weight: f32) {
+        match value {
+            KeyframeValue::Translation(translation) => {
+                let (sum, total) = self.translation.get_or_insert((Vec3::zero(), 0.0));
+                *sum += translation * weight;
+                *total += weight;
+            }
+            KeyframeValue::Rotation(rotation) => {
+                let (sum, total) = self.rotation.get_or_insert((Vec4::zero(), 0.0));
+                let mut rotation = Vec4::from(rotation);
+                // q and -q are the same rotation, so pick the one closest to the rotations that
+                // were already added to blend along the shortest path
+                if sum.dot(rotation) < 0.0 {
+                    rotation = -rotation;
+                }
+                *sum += rotation * weight;
+                *total += weight;
+            }
+            KeyframeValue::Scale(scale) => {
+                let (sum, total) = self.scale.get_or_insert((Vec3::zero(), 0.0));
+                *sum += scale * weight;
+                *total += weight;
+            }
+        }
+    }
+
+    /// Makes up the weight that is missing from each property whose weights add up to less than 1
+    /// with the value of that property in `bind_pose`, so a clip that fades out blends towards the
+    /// bind pose instead of the other clips' values
+    pub(crate) fn fill_with_bind_pose(&mut self, bind_pose: &Transform) {
+        if let Some((_, total)) = self.translation {
+            if total < 1.0 {
+                self.add(
+                    KeyframeValue::Translation(bind_pose.translation),
+                    1.0 - total,
+                );
+            }
+        }
+        if let Some((_, total)) = self.rotation {
+            if total < 1.0 {
+                self.add(KeyframeValue::Rotation(bind_pose.rotation), 1.0 - total);
+            }
+        }
+        if let Some((_, total)) = self.scale {
+            if total < 1.0 {
+                self.add(KeyframeValue::Scale(bind_pose.scale), 1.0 - total);
+            }
+        }
+    }
+
+    /// Sets each property that had values added to the weighted average of those values
+    pub(crate) fn apply(&self, transform: &mut Transform) {
+        if let Some((sum, total)) = self.translation {
+            if total > 0.0 {
+                transform.translation = sum / total;
+            }
+        }
+        if let Some((sum, total)) = self.rotation {
+            if total > 0.0 && sum.length_squared() > 0.0 {
+                transform.rotation = Quat::from(sum.normalize());
+            }
+        }
+        if let Some((sum, total)) = self.scale {
+            if total > 0.0 {
+                transform.scale = sum / total;
+            }
+        }
+    }
+}
+
+/// Accumulates weighted values of [PropertyCurve]s for one component or asset. Like
+/// [TransformBlend], each field becomes the weighted average of the values added for it.
+#[derive(Default)]
+pub(crate) struct PropertyBlend {
+    values: Vec<(Cow<'static, str>, Box<dyn Property>, f32)>,
+}
+
+impl PropertyBlend {
+    pub(crate) fn add(&mut self, curve: &PropertyCurve, value: Box<dyn Property>, weight: f32) {
+        match self
+            .values
+            .iter_mut()
+            .find(|(property, _, _)| property == curve.property())
+        {
+            Some((_, blended, total)) => {
+                *total += weight;
+                if *total > 0.0 {
+                    // moving towards each value by its share of the total weight keeps the result
+                    // the weighted average of all values added so far
+                    *blended = curve.interpolate(&**blended, &*value, weight / *total);
+                }
+            }
+            None => self
+                .values
+                .push((curve.property().to_string().into(), value, weight)),
+        }
+    }
+
+    /// Sets each field that had values added to the weighted average of those values
+    pub(crate) fn apply(self, target: &mut dyn Properties) {
+        for (property, value, _) in self.values {
+            apply_property(target, &property, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blends_weighted_values() {
+        let mut blend = TransformBlend::default();
+        blend.add(KeyframeValue::Translation(Vec3::zero()), 3.0);
+        blend.add(KeyframeValue::Translation(Vec3::new(4.0, 0.0, 0.0)), 1.0);
+        blend.add(KeyframeValue::Rotation(Quat::identity()), 0.5);
+        // the same rotation as the identity, but with a flipped sign
+        blend.add(
+            KeyframeValue::Rotation(Quat::from_xyzw(0.0, 0.0, 0.0, -1.0)),
+            0.5,
+        );
+
+        let mut transform = Transform::from_scale(Vec3::splat(2.0));
+        blend.apply(&mut transform);
+        assert_eq!(transform.translation, Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(transform.rotation, Quat::identity());
+        // properties without values are left alone
+        assert_eq!(transform.scale, Vec3::splat(2.0));
+    }
+
+    #[test]
+    fn fills_missing_weight_with_bind_pose() {
+        let mut blend = TransformBlend::default();
+        blend.add(KeyframeValue::Translation(Vec3::new(4.0, 0.0, 0.0)), 0.25);
+        blend.fill_with_bind_pose(&Transform::from_translation(Vec3::zero()));
+
+        let mut transform = Transform::identity();
+        blend.apply(&mut transform);
+        assert_eq!(transform.translation, Vec3::new(1.0, 0.0, 0.0));
+    }
+}
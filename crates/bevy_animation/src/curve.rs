@@ -0,0 +1,184 @@
+pub use bevy_math::Interpolate;
+use bevy_math::{Quat, Vec3};
+use bevy_transform::prelude::Transform;
+
+/// How the value of a curve between two keyframes is computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// The value of the previous keyframe is used until the next keyframe is reached
+    Step,
+    /// The value changes linearly from one keyframe to the next. Rotations are interpolated along
+    /// the shortest path.
+    Linear,
+}
+
+impl Default for Interpolation {
+    fn default() -> Self {
+        Interpolation::Linear
+    }
+}
+
+/// The values of a curve at each of its keyframes
+#[derive(Debug, Clone)]
+pub enum Keyframes {
+    Translation(Vec<Vec3>),
+    Rotation(Vec<Quat>),
+    Scale(Vec<Vec3>),
+}
+
+impl Keyframes {
+    pub fn len(&self) -> usize {
+        match self {
+            Keyframes::Translation(keyframes) => keyframes.len(),
+            Keyframes::Rotation(keyframes) => keyframes.len(),
+            Keyframes::Scale(keyframes) => keyframes.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The value of a [VariableCurve] at some point in time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyframeValue {
+    Translation(Vec3),
+    Rotation(Quat),
+    Scale(Vec3),
+}
+
+impl KeyframeValue {
+    pub fn apply(self, transform: &mut Transform) {
+        match self {
+            KeyframeValue::Translation(translation) => transform.translation = translation,
+            KeyframeValue::Rotation(rotation) => transform.rotation = rotation,
+            KeyframeValue::Scale(scale) => transform.scale = scale,
+        }
+    }
+}
+
+/// Animates one property of an entity using keyframes. The timestamps are in seconds from the
+/// start of the clip and must be increasing. Before the first keyframe and after the last one,
+/// the curve keeps the value of that keyframe.
+#[derive(Debug, Clone)]
+pub struct VariableCurve {
+    pub keyframe_timestamps: Vec<f32>,
+    pub keyframes: Keyframes,
+    pub interpolation: Interpolation,
+}
+
+impl VariableCurve {
+    pub fn new(keyframe_timestamps: Vec<f32>, keyframes: Keyframes) -> Self {
+        assert_eq!(
+            keyframe_timestamps.len(),
+            keyframes.len(),
+            "Each keyframe needs exactly one timestamp"
+        );
+        VariableCurve {
+            keyframe_timestamps,
+            keyframes,
+            interpolation: Interpolation::default(),
+        }
+    }
+
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// The timestamp of the last keyframe
+    pub fn duration(&self) -> f32 {
+        self.keyframe_timestamps.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the value of the curve at `time`, or `None` if the curve has no keyframes
+    pub fn sample(&self, time: f32) -> Option<KeyframeValue> {
+        match &self.keyframes {
+            Keyframes::Translation(keyframes) => self
+                .sample_keyframes(keyframes, time)
+                .map(KeyframeValue::Translation),
+            Keyframes::Rotation(keyframes) => self
+                .sample_keyframes(keyframes, time)
+                .map(KeyframeValue::Rotation),
+            Keyframes::Scale(keyframes) => self
+                .sample_keyframes(keyframes, time)
+                .map(KeyframeValue::Scale),
+        }
+    }
+
+    /// Sets the animated property of `transform` to the value of the curve at `time`
+    pub fn apply(&self, time: f32, transform: &mut Transform) {
+        if let Some(value) = self.sample(time) {
+            value.apply(transform);
+        }
+    }
+
+    fn sample_keyframes<T: Interpolate>(&self, keyframes: &[T], time: f32) -> Option<T> {
+        let (previous, next, t) =
+            keyframe_span(&self.keyframe_timestamps, time, self.interpolation)?;
+        Some(if previous == next {
+            keyframes[previous]
+        } else {
+            keyframes[previous].interpolate(keyframes[next], t)
+        })
+    }
+}
+
+/// Finds the keyframes that the value of a curve at `time` is interpolated between, and how far
+/// `time` is from the first to the second one. Both keyframes are the same one if `time` is
+/// outside of the curve or the curve steps between its keyframes. Returns `None` if the curve
+/// has no keyframes.
+pub(crate) fn keyframe_span(
+    keyframe_timestamps: &[f32],
+    time: f32,
+    interpolation: Interpolation,
+) -> Option<(usize, usize, f32)> {
+    let last = keyframe_timestamps.len().checked_sub(1)?;
+    let next = match keyframe_timestamps
+        .iter()
+        .position(|timestamp| *timestamp > time)
+    {
+        Some(0) => return Some((0, 0, 0.0)),
+        Some(next) => next,
+        None => return Some((last, last, 0.0)),
+    };
+    let previous = next - 1;
+    match interpolation {
+        Interpolation::Step => Some((previous, previous, 0.0)),
+        Interpolation::Linear => {
+            let start = keyframe_timestamps[previous];
+            let end = keyframe_timestamps[next];
+            Some((previous, next, (time - start) / (end - start)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_between_keyframes() {
+        let curve = VariableCurve::new(
+            vec![1.0, 2.0, 4.0],
+            Keyframes::Translation(vec![Vec3::zero(), Vec3::unit_x(), Vec3::unit_y()]),
+        );
+        let mut transform = Transform::default();
+
+        curve.apply(0.0, &mut transform);
+        assert_eq!(transform.translation, Vec3::zero());
+        curve.apply(1.5, &mut transform);
+        assert_eq!(transform.translation, Vec3::new(0.5, 0.0, 0.0));
+        curve.apply(3.0, &mut transform);
+        assert_eq!(transform.translation, Vec3::new(0.5, 0.5, 0.0));
+        curve.apply(5.0, &mut transform);
+        assert_eq!(transform.translation, Vec3::unit_y());
+
+        let curve = curve.with_interpolation(Interpolation::Step);
+        curve.apply(1.5, &mut transform);
+        assert_eq!(transform.translation, Vec3::zero());
+        curve.apply(2.0, &mut transform);
+        assert_eq!(transform.translation, Vec3::unit_x());
+    }
+}
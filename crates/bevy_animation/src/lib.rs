@@ -0,0 +1,45 @@
+mod animation_clip;
+mod animation_graph;
+mod animation_player;
+mod blend;
+mod curve;
+mod property_animation;
+mod property_curve;
+
+pub use animation_clip::*;
+pub use animation_graph::*;
+pub use animation_player::*;
+pub use curve::*;
+pub use property_animation::*;
+pub use property_curve::*;
+
+pub mod prelude {
+    pub use crate::{
+        AddPropertyAnimation, AnimationClip, AnimationEvent, AnimationGraph, AnimationPlayer,
+        AnimationState, Condition, EntityPath, Keyframes, PropertyCurve, VariableCurve,
+    };
+}
+
+use bevy_app::prelude::*;
+use bevy_asset::AddAsset;
+
+/// The names of animation stages in an App Schedule
+pub mod stage {
+    /// Stage where animations are applied. It runs after the UPDATE stage, so animations can be
+    /// controlled from UPDATE systems, and before transforms are propagated in POST_UPDATE.
+    pub const ANIMATE: &str = "animate";
+}
+
+/// Adds keyframe animation to an App
+#[derive(Default)]
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.add_stage_after(bevy_app::stage::UPDATE, stage::ANIMATE)
+            .add_asset::<AnimationClip>()
+            .add_asset::<AnimationGraph>()
+            .add_event::<AnimationEvent>()
+            .add_system_to_stage(stage::ANIMATE, animation_player_system);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    animation_player::find_target, blend::PropertyBlend, stage, AnimationClip, AnimationPlayer,
+};
+use bevy_app::AppBuilder;
+use bevy_asset::{Asset, Assets, Handle};
+use bevy_core::Labels;
+use bevy_ecs::{Component, Entity, Query, Res, ResMut};
+use bevy_property::Properties;
+use bevy_transform::prelude::Children;
+use bevy_utils::HashMap;
+
+/// Adds systems that apply the [PropertyCurve](crate::PropertyCurve)s of playing clips to a type.
+/// Requires the [AnimationPlugin](crate::AnimationPlugin).
+pub trait AddPropertyAnimation {
+    /// Animates the fields of `T` components of the animated entities
+    fn add_component_animation<T>(&mut self) -> &mut Self
+    where
+        T: Component + Properties;
+    /// Animates the fields of `T` assets whose handles are components of the animated entities.
+    /// Assets are shared, so this changes the asset for every entity that uses it.
+    fn add_asset_animation<T>(&mut self) -> &mut Self
+    where
+        T: Asset + Properties;
+}
+
+impl AddPropertyAnimation for AppBuilder {
+    fn add_component_animation<T>(&mut self) -> &mut Self
+    where
+        T: Component + Properties,
+    {
+        self.add_system_to_stage(stage::ANIMATE, component_animation_system::<T>)
+    }
+
+    fn add_asset_animation<T>(&mut self) -> &mut Self
+    where
+        T: Asset + Properties,
+    {
+        self.add_system_to_stage(stage::ANIMATE, asset_animation_system::<T>)
+    }
+}
+
+/// Applies the blend of the [PropertyCurve](crate::PropertyCurve)s for `T` of each
+/// [AnimationPlayer]'s clips to the `T` components of the animated entities
+pub fn component_animation_system<T: Component + Properties>(
+    clips: Res<Assets<AnimationClip>>,
+    players: Query<(Entity, &AnimationPlayer)>,
+    children: Query<&Children>,
+    labels: Query<&Labels>,
+    mut components: Query<&mut T>,
+) {
+    for (target, blend) in blend_property_curves::<T>(&clips, &players, &children, &labels) {
+        if let Ok(mut component) = components.get_mut(target) {
+            blend.apply(&mut *component);
+        }
+    }
+}
+
+/// Applies the blend of the [PropertyCurve](crate::PropertyCurve)s for `T` of each
+/// [AnimationPlayer]'s clips to the `T` assets of the animated entities
+pub fn asset_animation_system<T: Asset + Properties>(
+    clips: Res<Assets<AnimationClip>>,
+    mut assets: ResMut<Assets<T>>,
+    players: Query<(Entity, &AnimationPlayer)>,
+    children: Query<&Children>,
+    labels: Query<&Labels>,
+    handles: Query<&Handle<T>>,
+) {
+    for (target, blend) in blend_property_curves::<T>(&clips, &players, &children, &labels) {
+        if let Some(asset) = handles
+            .get(target)
+            .ok()
+            .and_then(|handle| assets.get_mut(handle))
+        {
+            blend.apply(asset);
+        }
+    }
+}
+
+/// Samples the curves for `T` of each player's clips at the clips' elapsed time and blends them by
+/// the clips' weights
+fn blend_property_curves<T: 'static>(
+    clips: &Assets<AnimationClip>,
+    players: &Query<(Entity, &AnimationPlayer)>,
+    children: &Query<&Children>,
+    labels: &Query<&Labels>,
+) -> HashMap<Entity, PropertyBlend> {
+    let mut blends = HashMap::<Entity, PropertyBlend>::default();
+    for (entity, player) in players.iter() {
+        for (clip, elapsed, weight) in player.playing_clips() {
+            let clip = match clips.get(clip) {
+                Some(clip) => clip,
+                None => continue,
+            };
+            if weight <= 0.0 {
+                continue;
+            }
+            for (path, curves) in clip.property_curves() {
+                if !curves.iter().any(|curve| curve.animates::<T>()) {
+                    continue;
+                }
+                let target = match find_target(entity, path, children, labels) {
+                    Some(target) => target,
+                    None => continue,
+                };
+                let blend = blends.entry(target).or_default();
+                for curve in curves.iter().filter(|curve| curve.animates::<T>()) {
+                    if let Some(value) = curve.sample(elapsed) {
+                        blend.add(curve, value, weight);
+                    }
+                }
+            }
+        }
+    }
+    blends
+}
@@ -0,0 +1,215 @@
+use crate::{curve::keyframe_span, Interpolate, Interpolation};
+use bevy_property::{DynamicProperties, Properties, Property};
+use std::{any::TypeId, borrow::Cow, fmt};
+
+type InterpolateFn = fn(&dyn Property, &dyn Property, f32) -> Box<dyn Property>;
+
+/// Animates a field of a component or asset using keyframes, for example the color of a light or
+/// a material. Like [VariableCurve](crate::VariableCurve)s, the timestamps are in seconds from the
+/// start of the clip and must be increasing.
+///
+/// The animated type has to be registered with
+/// [AddPropertyAnimation](crate::AddPropertyAnimation).
+pub struct PropertyCurve {
+    property: Cow<'static, str>,
+    target_type: TypeId,
+    target_type_name: &'static str,
+    keyframe_timestamps: Vec<f32>,
+    keyframes: Vec<Box<dyn Property>>,
+    interpolation: Interpolation,
+    interpolate: InterpolateFn,
+}
+
+impl PropertyCurve {
+    /// Creates a curve that animates the field of `T` named `property`. Fields of nested structs
+    /// that derive [Properties] are separated by dots, for example `"shadow.color"`. The curve is
+    /// ignored if `T` has no such field or the field isn't a `V`.
+    pub fn new<T: Properties, V: Property + Interpolate>(
+        property: impl Into<Cow<'static, str>>,
+        keyframe_timestamps: Vec<f32>,
+        keyframes: Vec<V>,
+    ) -> Self {
+        assert_eq!(
+            keyframe_timestamps.len(),
+            keyframes.len(),
+            "Each keyframe needs exactly one timestamp"
+        );
+        PropertyCurve {
+            property: property.into(),
+            target_type: TypeId::of::<T>(),
+            target_type_name: std::any::type_name::<T>(),
+            keyframe_timestamps,
+            keyframes: keyframes
+                .into_iter()
+                .map(|keyframe| Box::new(keyframe) as Box<dyn Property>)
+                .collect(),
+            interpolation: Interpolation::default(),
+            interpolate: interpolate_property::<V>,
+        }
+    }
+
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// The path of the animated field
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// Returns true if the curve animates a field of `T`
+    pub fn animates<T: 'static>(&self) -> bool {
+        self.target_type == TypeId::of::<T>()
+    }
+
+    /// The timestamp of the last keyframe
+    pub fn duration(&self) -> f32 {
+        self.keyframe_timestamps.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the value of the curve at `time`, or `None` if the curve has no keyframes
+    pub fn sample(&self, time: f32) -> Option<Box<dyn Property>> {
+        let (previous, next, t) =
+            keyframe_span(&self.keyframe_timestamps, time, self.interpolation)?;
+        Some(if previous == next {
+            self.keyframes[previous].clone_prop()
+        } else {
+            self.interpolate(&*self.keyframes[previous], &*self.keyframes[next], t)
+        })
+    }
+
+    /// Interpolates between two values of the animated field
+    pub(crate) fn interpolate(
+        &self,
+        from: &dyn Property,
+        to: &dyn Property,
+        t: f32,
+    ) -> Box<dyn Property> {
+        (self.interpolate)(from, to, t)
+    }
+}
+
+impl Clone for PropertyCurve {
+    fn clone(&self) -> Self {
+        PropertyCurve {
+            property: self.property.clone(),
+            target_type: self.target_type,
+            target_type_name: self.target_type_name,
+            keyframe_timestamps: self.keyframe_timestamps.clone(),
+            keyframes: self
+                .keyframes
+                .iter()
+                .map(|keyframe| keyframe.clone_prop())
+                .collect(),
+            interpolation: self.interpolation,
+            interpolate: self.interpolate,
+        }
+    }
+}
+
+impl fmt::Debug for PropertyCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PropertyCurve")
+            .field("property", &self.property)
+            .field("target_type", &self.target_type_name)
+            .field("keyframe_timestamps", &self.keyframe_timestamps)
+            .field("interpolation", &self.interpolation)
+            .finish()
+    }
+}
+
+fn interpolate_property<V: Property + Interpolate>(
+    from: &dyn Property,
+    to: &dyn Property,
+    t: f32,
+) -> Box<dyn Property> {
+    let from = from.any().downcast_ref::<V>().unwrap();
+    let to = to.any().downcast_ref::<V>().unwrap();
+    Box::new(from.interpolate(*to, t))
+}
+
+/// Sets the field at the dotted `path` of `target` to `value`. Returns false and leaves `target`
+/// unchanged if there is no such field or it has a different type than `value`.
+pub(crate) fn apply_property(
+    target: &mut dyn Properties,
+    path: &str,
+    value: Box<dyn Property>,
+) -> bool {
+    let mut parts = path.split('.');
+    let first = parts.next().unwrap();
+    let nested = parts.collect::<Vec<_>>();
+
+    // applying a value of the wrong type panics, so check the field before changing it
+    let mut field = match target.prop(first) {
+        Some(field) => field,
+        None => return false,
+    };
+    for part in nested.iter() {
+        field = match field.as_properties().and_then(|field| field.prop(part)) {
+            Some(field) => field,
+            None => return false,
+        };
+    }
+    if field.any().type_id() != value.any().type_id() {
+        return false;
+    }
+
+    // nested fields are applied through maps that only contain the path to the field
+    let mut value = value;
+    for part in nested.iter().rev() {
+        let mut properties = DynamicProperties::map();
+        properties.set_box(part, value);
+        value = Box::new(properties);
+    }
+    target.prop_mut(first).unwrap().apply(&*value);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec3;
+
+    #[derive(Properties, Default)]
+    struct Inner {
+        value: f32,
+    }
+
+    #[derive(Properties, Default)]
+    struct Outer {
+        position: Vec3,
+        inner: Inner,
+    }
+
+    #[test]
+    fn applies_nested_properties() {
+        let curve = PropertyCurve::new::<Outer, f32>("inner.value", vec![0.0, 2.0], vec![0.0, 4.0]);
+        assert!(curve.animates::<Outer>());
+        assert!(!curve.animates::<Inner>());
+
+        let mut outer = Outer::default();
+        assert!(apply_property(
+            &mut outer,
+            curve.property(),
+            curve.sample(0.5).unwrap()
+        ));
+        assert_eq!(outer.inner.value, 1.0);
+
+        assert!(apply_property(
+            &mut outer,
+            "position",
+            Box::new(Vec3::unit_x())
+        ));
+        assert_eq!(outer.position, Vec3::unit_x());
+
+        // missing fields and values of the wrong type are ignored
+        assert!(!apply_property(
+            &mut outer,
+            "inner.missing",
+            Box::new(1.0f32)
+        ));
+        assert!(!apply_property(&mut outer, "position", Box::new(1.0f32)));
+        assert_eq!(outer.position, Vec3::unit_x());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::app_builder::AppBuilder;
+use crate::{app_builder::AppBuilder, sub_app::SubApp};
 use bevy_ecs::{ParallelExecutor, Resources, Schedule, World};
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
@@ -9,6 +9,9 @@ use bevy_utils::tracing::info_span;
 /// App store the ECS World, Resources, Schedule, and Executor. They also store the "run" function of the App, which
 /// by default executes the App schedule once. Apps are constructed using the builder pattern.
 ///
+/// Runners call [App::initialize] before the first update, which runs the startup schedule, and
+/// [App::shutdown] after the last update, which runs the shutdown schedule.
+///
 /// ## Example
 /// Here is a simple "Hello World" Bevy app:
 /// ```
@@ -33,6 +36,9 @@ pub struct App {
     pub executor: ParallelExecutor,
     pub startup_schedule: Schedule,
     pub startup_executor: ParallelExecutor,
+    pub shutdown_schedule: Schedule,
+    pub shutdown_executor: ParallelExecutor,
+    pub sub_apps: Vec<SubApp>,
 }
 
 impl Default for App {
@@ -44,6 +50,9 @@ impl Default for App {
             executor: Default::default(),
             startup_schedule: Default::default(),
             startup_executor: ParallelExecutor::without_tracker_clears(),
+            shutdown_schedule: Default::default(),
+            shutdown_executor: Default::default(),
+            sub_apps: Default::default(),
             runner: Box::new(run_once),
         }
     }
@@ -52,6 +61,7 @@ impl Default for App {
 fn run_once(mut app: App) {
     app.initialize();
     app.update();
+    app.shutdown();
 }
 
 impl App {
@@ -64,6 +74,24 @@ impl App {
             .initialize(&mut self.world, &mut self.resources);
         self.executor
             .run(&mut self.schedule, &mut self.world, &mut self.resources);
+
+        for sub_app in self.sub_apps.iter_mut() {
+            sub_app.update(&mut self.world, &mut self.resources);
+        }
+    }
+
+    pub fn sub_app(&self, label: &str) -> Option<&App> {
+        self.sub_apps
+            .iter()
+            .find(|sub_app| sub_app.label == label)
+            .map(|sub_app| &sub_app.app)
+    }
+
+    pub fn sub_app_mut(&mut self, label: &str) -> Option<&mut App> {
+        self.sub_apps
+            .iter_mut()
+            .find(|sub_app| sub_app.label == label)
+            .map(|sub_app| &mut sub_app.app)
     }
 
     pub fn initialize(&mut self) {
@@ -79,6 +107,31 @@ impl App {
             &mut self.world,
             &mut self.resources,
         );
+
+        for sub_app in self.sub_apps.iter_mut() {
+            sub_app.initialize(&self.resources);
+        }
+    }
+
+    /// Runs the shutdown schedule. Runners call this once, after the app's last update, so
+    /// shutdown systems can flush saves and release devices before the app is dropped.
+    pub fn shutdown(&mut self) {
+        #[cfg(feature = "trace")]
+        let shutdown_schedule_span = info_span!("shutdown_schedule");
+        #[cfg(feature = "trace")]
+        let _shutdown_schedule_guard = shutdown_schedule_span.enter();
+        self.shutdown_schedule
+            .initialize(&mut self.world, &mut self.resources);
+        self.shutdown_executor.initialize(&mut self.resources);
+        self.shutdown_executor.run(
+            &mut self.shutdown_schedule,
+            &mut self.world,
+            &mut self.resources,
+        );
+
+        for sub_app in self.sub_apps.iter_mut() {
+            sub_app.app.shutdown();
+        }
     }
 
     pub fn run(mut self) {
@@ -94,6 +147,7 @@ impl App {
     }
 }
 
-/// An event that indicates the app should exit. This will fully exit the app process.
+/// An event that indicates the app should exit. Runners stop updating the app once this is sent and
+/// run the app's shutdown systems before returning.
 #[derive(Debug, Clone)]
 pub struct AppExit;
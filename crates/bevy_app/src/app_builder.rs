@@ -2,7 +2,9 @@ use crate::{
     app::{App, AppExit},
     event::Events,
     plugin::Plugin,
-    stage, startup_stage, PluginGroup, PluginGroupBuilder,
+    shutdown_stage, stage, startup_stage,
+    sub_app::SubApp,
+    PluginGroup, PluginGroupBuilder,
 };
 use bevy_ecs::{FromResources, IntoSystem, Resources, System, World};
 use bevy_utils::tracing::debug;
@@ -95,6 +97,33 @@ impl AppBuilder {
         self
     }
 
+    pub fn add_shutdown_stage(&mut self, stage_name: &'static str) -> &mut Self {
+        self.app.shutdown_schedule.add_stage(stage_name);
+        self
+    }
+
+    pub fn add_shutdown_stage_after(
+        &mut self,
+        target: &'static str,
+        stage_name: &'static str,
+    ) -> &mut Self {
+        self.app
+            .shutdown_schedule
+            .add_stage_after(target, stage_name);
+        self
+    }
+
+    pub fn add_shutdown_stage_before(
+        &mut self,
+        target: &'static str,
+        stage_name: &'static str,
+    ) -> &mut Self {
+        self.app
+            .shutdown_schedule
+            .add_stage_before(target, stage_name);
+        self
+    }
+
     pub fn add_system<S, Params, IntoS>(&mut self, system: IntoS) -> &mut Self
     where
         S: System<Input = (), Output = ()>,
@@ -129,14 +158,45 @@ impl AppBuilder {
         self
     }
 
+    pub fn add_shutdown_system_to_stage<S, Params, IntoS>(
+        &mut self,
+        stage_name: &'static str,
+        system: IntoS,
+    ) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.app
+            .shutdown_schedule
+            .add_system_to_stage(stage_name, system);
+        self
+    }
+
+    /// Adds a system that runs once when the app shuts down, after its last update
+    pub fn add_shutdown_system<S, Params, IntoS>(&mut self, system: IntoS) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.app
+            .shutdown_schedule
+            .add_system_to_stage(shutdown_stage::SHUTDOWN, system);
+        self
+    }
+
     pub fn add_default_stages(&mut self) -> &mut Self {
         self.add_startup_stage(startup_stage::PRE_STARTUP)
             .add_startup_stage(startup_stage::STARTUP)
             .add_startup_stage(startup_stage::POST_STARTUP)
+            .add_shutdown_stage(shutdown_stage::PRE_SHUTDOWN)
+            .add_shutdown_stage(shutdown_stage::SHUTDOWN)
+            .add_shutdown_stage(shutdown_stage::POST_SHUTDOWN)
             .add_stage(stage::FIRST)
             .add_stage(stage::PRE_EVENT)
             .add_stage(stage::EVENT)
             .add_stage(stage::PRE_UPDATE)
+            .add_stage(stage::FIXED_UPDATE)
             .add_stage(stage::UPDATE)
             .add_stage(stage::POST_UPDATE)
             .add_stage(stage::LAST)
@@ -215,6 +275,24 @@ impl AppBuilder {
         self
     }
 
+    /// Adds a [SubApp] that is updated after this app. Before each update, `extract` copies the data
+    /// the sub app needs from this app's world and resources into the sub app. Adding a sub app
+    /// with the label of an existing sub app replaces it.
+    pub fn add_sub_app(
+        &mut self,
+        label: &'static str,
+        app: App,
+        extract: impl Fn(&mut World, &mut Resources, &mut App) + 'static,
+    ) -> &mut Self {
+        self.app.sub_apps.retain(|sub_app| sub_app.label != label);
+        self.app.sub_apps.push(SubApp::new(label, app, extract));
+        self
+    }
+
+    pub fn sub_app_mut(&mut self, label: &str) -> Option<&mut App> {
+        self.app.sub_app_mut(label)
+    }
+
     pub fn set_runner(&mut self, run_fn: impl Fn(App) + 'static) -> &mut Self {
         self.app.runner = Box::new(run_fn);
         self
@@ -0,0 +1,88 @@
+use bevy_utils::{HashMap, HashSet};
+use std::borrow::Cow;
+
+/// Optional engine subsystems, such as audio output, gamepad input or asset hot reloading, that can
+/// be turned on and off when the app starts.
+///
+/// Every capability is enabled unless it has been disabled, either by the app or with a
+/// `--disable-<name>` command line flag (see [Capabilities::from_args]). Plugins check their
+/// capability when they are added and fall back to doing nothing when it is disabled. If a plugin's
+/// backend is missing (for example because there is no audio device), the plugin marks its
+/// capability as unavailable instead of panicking.
+///
+/// To take effect, this resource must be inserted before the plugins are added.
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    requested: HashMap<Cow<'static, str>, bool>,
+    unavailable: HashSet<Cow<'static, str>>,
+}
+
+impl Capabilities {
+    /// Reads `--enable-<name>` and `--disable-<name>` flags from the given command line arguments,
+    /// ignoring all other arguments. Later flags override earlier ones.
+    pub fn from_args<T: AsRef<str>>(args: impl IntoIterator<Item = T>) -> Self {
+        let mut capabilities = Capabilities::default();
+        for arg in args {
+            let arg = arg.as_ref();
+            if let Some(name) = arg.strip_prefix("--enable-") {
+                capabilities.enable(name.to_string());
+            } else if let Some(name) = arg.strip_prefix("--disable-") {
+                capabilities.disable(name.to_string());
+            }
+        }
+        capabilities
+    }
+
+    pub fn enable(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.requested.insert(name.into(), true);
+        self
+    }
+
+    pub fn disable(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.requested.insert(name.into(), false);
+        self
+    }
+
+    /// Records that the capability can't be used on this machine, e.g. because its device is missing
+    pub fn set_unavailable(&mut self, name: impl Into<Cow<'static, str>>) -> &mut Self {
+        self.unavailable.insert(name.into());
+        self
+    }
+
+    /// Returns `false` if the capability has been disabled
+    pub fn is_requested(&self, name: &str) -> bool {
+        self.requested.get(name).copied().unwrap_or(true)
+    }
+
+    /// Returns `false` if the capability's backend has been marked as unavailable
+    pub fn is_available(&self, name: &str) -> bool {
+        !self.unavailable.contains(name)
+    }
+
+    /// Returns `true` if the capability hasn't been disabled and its backend is available
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.is_requested(name) && self.is_available(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Capabilities;
+
+    #[test]
+    fn capabilities_from_args() {
+        let mut capabilities = Capabilities::from_args(vec![
+            "game",
+            "--disable-audio",
+            "--disable-gamepad",
+            "--enable-gamepad",
+        ]);
+        assert!(!capabilities.is_enabled("audio"));
+        assert!(capabilities.is_enabled("gamepad"));
+        assert!(capabilities.is_enabled("asset_hot_reload"));
+
+        capabilities.set_unavailable("gamepad");
+        assert!(capabilities.is_requested("gamepad"));
+        assert!(!capabilities.is_enabled("gamepad"));
+    }
+}
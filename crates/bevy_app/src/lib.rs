@@ -1,3 +1,5 @@
+/// The names of the default App shutdown stages
+pub mod shutdown_stage;
 /// The names of the default App stages
 pub mod stage;
 /// The names of the default App startup stages
@@ -5,18 +7,22 @@ pub mod startup_stage;
 
 mod app;
 mod app_builder;
+mod capabilities;
 mod event;
 mod plugin;
 mod plugin_group;
 mod schedule_runner;
+mod sub_app;
 
 pub use app::*;
 pub use app_builder::*;
 pub use bevy_derive::DynamicPlugin;
+pub use capabilities::*;
 pub use event::*;
 pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
+pub use sub_app::*;
 
 pub mod prelude {
     pub use crate::{
@@ -11,6 +11,8 @@ struct PluginEntry {
     enabled: bool,
 }
 
+/// Collects the plugins of a [PluginGroup] in the order they will be built. Plugins can be
+/// disabled, replaced or inserted at a specific position before the group is added to an App.
 #[derive(Default)]
 pub struct PluginGroupBuilder {
     plugins: HashMap<TypeId, PluginEntry>,
@@ -18,10 +20,27 @@ pub struct PluginGroupBuilder {
 }
 
 impl PluginGroupBuilder {
-    pub fn add<T: Plugin>(&mut self, plugin: T) -> &mut Self {
-        self.order.push(TypeId::of::<T>());
+    fn index_of<Target: Plugin>(&self) -> usize {
+        self.order
+            .iter()
+            .position(|ty| *ty == TypeId::of::<Target>())
+            .unwrap_or_else(|| panic!("Plugin does not exist: {}", std::any::type_name::<Target>()))
+    }
+
+    /// Inserts `plugin` at `index`. If the group already contains a plugin of the same type, it is
+    /// removed first so that each plugin is only built once.
+    fn insert_at<T: Plugin>(&mut self, index: usize, plugin: T) -> &mut Self {
+        let ty = TypeId::of::<T>();
+        let mut index = index;
+        if let Some(existing_index) = self.order.iter().position(|existing| *existing == ty) {
+            self.order.remove(existing_index);
+            if existing_index < index {
+                index -= 1;
+            }
+        }
+        self.order.insert(index, ty);
         self.plugins.insert(
-            TypeId::of::<T>(),
+            ty,
             PluginEntry {
                 plugin: Box::new(plugin),
                 enabled: true,
@@ -30,48 +49,42 @@ impl PluginGroupBuilder {
         self
     }
 
+    /// Adds `plugin` to the end of the group
+    pub fn add<T: Plugin>(&mut self, plugin: T) -> &mut Self {
+        self.insert_at(self.order.len(), plugin)
+    }
+
+    /// Adds `plugin` right before the `Target` plugin
     pub fn add_before<Target: Plugin, T: Plugin>(&mut self, plugin: T) -> &mut Self {
-        let target_index = self
-            .order
-            .iter()
-            .enumerate()
-            .find(|(_i, ty)| **ty == TypeId::of::<Target>())
-            .map(|(i, _)| i)
-            .unwrap_or_else(|| {
-                panic!("Plugin does not exist: {}", std::any::type_name::<Target>())
-            });
-        self.order.insert(target_index, TypeId::of::<T>());
-        self.plugins.insert(
-            TypeId::of::<T>(),
-            PluginEntry {
-                plugin: Box::new(plugin),
-                enabled: true,
-            },
-        );
-        self
+        let target_index = self.index_of::<Target>();
+        self.insert_at(target_index, plugin)
     }
 
+    /// Adds `plugin` right after the `Target` plugin
     pub fn add_after<Target: Plugin, T: Plugin>(&mut self, plugin: T) -> &mut Self {
-        let target_index = self
-            .order
-            .iter()
-            .enumerate()
-            .find(|(_i, ty)| **ty == TypeId::of::<Target>())
-            .map(|(i, _)| i)
-            .unwrap_or_else(|| {
-                panic!("Plugin does not exist: {}", std::any::type_name::<Target>())
-            });
-        self.order.insert(target_index + 1, TypeId::of::<T>());
-        self.plugins.insert(
-            TypeId::of::<T>(),
-            PluginEntry {
-                plugin: Box::new(plugin),
-                enabled: true,
-            },
-        );
+        let target_index = self.index_of::<Target>();
+        self.insert_at(target_index + 1, plugin)
+    }
+
+    /// Replaces the group's plugin of type `T` with `plugin`, keeping its position in the group.
+    /// This is how a plugin in the group can be configured differently.
+    pub fn set<T: Plugin>(&mut self, plugin: T) -> &mut Self {
+        let mut plugin_entry = self
+            .plugins
+            .get_mut(&TypeId::of::<T>())
+            .unwrap_or_else(|| panic!("Plugin does not exist: {}", std::any::type_name::<T>()));
+        plugin_entry.plugin = Box::new(plugin);
         self
     }
 
+    /// Removes the `Target` plugin and adds `plugin` in its place
+    pub fn replace<Target: Plugin, T: Plugin>(&mut self, plugin: T) -> &mut Self {
+        let target_index = self.index_of::<Target>();
+        self.order.remove(target_index);
+        self.plugins.remove(&TypeId::of::<Target>());
+        self.insert_at(target_index, plugin)
+    }
+
     pub fn enable<T: Plugin>(&mut self) -> &mut Self {
         let mut plugin_entry = self
             .plugins
@@ -101,3 +114,84 @@ impl PluginGroupBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PluginGroupBuilder;
+    use crate::{AppBuilder, Plugin};
+
+    struct PluginA;
+    impl Plugin for PluginA {
+        fn build(&self, _app: &mut AppBuilder) {}
+    }
+
+    struct PluginB;
+    impl Plugin for PluginB {
+        fn build(&self, _app: &mut AppBuilder) {}
+    }
+
+    struct PluginC;
+    impl Plugin for PluginC {
+        fn build(&self, _app: &mut AppBuilder) {}
+    }
+
+    fn names(group: &PluginGroupBuilder) -> Vec<&str> {
+        group
+            .order
+            .iter()
+            .map(|ty| group.plugins[ty].plugin.name())
+            .collect()
+    }
+
+    #[test]
+    fn insert_before_and_after() {
+        let mut group = PluginGroupBuilder::default();
+        group
+            .add(PluginA)
+            .add_before::<PluginA, _>(PluginB)
+            .add_after::<PluginA, _>(PluginC);
+        assert_eq!(
+            names(&group),
+            vec![
+                std::any::type_name::<PluginB>(),
+                std::any::type_name::<PluginA>(),
+                std::any::type_name::<PluginC>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn readding_moves_plugin() {
+        let mut group = PluginGroupBuilder::default();
+        group
+            .add(PluginA)
+            .add(PluginB)
+            .add(PluginC)
+            .add_after::<PluginC, _>(PluginA);
+        assert_eq!(
+            names(&group),
+            vec![
+                std::any::type_name::<PluginB>(),
+                std::any::type_name::<PluginC>(),
+                std::any::type_name::<PluginA>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn replace_keeps_position() {
+        let mut group = PluginGroupBuilder::default();
+        group
+            .add(PluginA)
+            .add(PluginB)
+            .replace::<PluginA, _>(PluginC);
+        assert_eq!(
+            names(&group),
+            vec![
+                std::any::type_name::<PluginC>(),
+                std::any::type_name::<PluginB>(),
+            ]
+        );
+        assert_eq!(group.plugins.len(), 2);
+    }
+}
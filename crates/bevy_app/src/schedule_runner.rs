@@ -14,7 +14,11 @@ use wasm_bindgen::{prelude::*, JsCast};
 /// Determines the method used to run an [App]'s `Schedule`
 #[derive(Copy, Clone, Debug)]
 pub enum RunMode {
+    /// Updates the app until an [AppExit] event is sent. If `wait` is set, the runner sleeps
+    /// between updates so that a new update starts at most once every `wait`. Otherwise the app
+    /// updates as fast as possible, which keeps a CPU core busy.
     Loop { wait: Option<Duration> },
+    /// Updates the app a single time, which is useful for tools and tests
     Once,
 }
 
@@ -24,7 +28,7 @@ impl Default for RunMode {
     }
 }
 
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Debug, Default)]
 pub struct ScheduleRunnerSettings {
     pub run_mode: RunMode,
 }
@@ -43,17 +47,44 @@ impl ScheduleRunnerSettings {
             },
         }
     }
+
+    /// Loops at (at most) the given number of updates per second
+    pub fn run_loop_at_rate(updates_per_second: f64) -> Self {
+        Self::run_loop(Duration::from_secs_f64(1.0 / updates_per_second))
+    }
 }
 
 /// Configures an App to run its [Schedule](bevy_ecs::Schedule) according to a given [RunMode]
+///
+/// A [ScheduleRunnerSettings] resource inserted before this plugin is added takes precedence over
+/// `run_mode`.
 #[derive(Default)]
-pub struct ScheduleRunnerPlugin {}
+pub struct ScheduleRunnerPlugin {
+    pub run_mode: RunMode,
+}
+
+impl ScheduleRunnerPlugin {
+    pub fn run_once() -> Self {
+        ScheduleRunnerPlugin {
+            run_mode: ScheduleRunnerSettings::run_once().run_mode,
+        }
+    }
+
+    /// Loops at (at most) the given number of updates per second
+    pub fn run_loop_at_rate(updates_per_second: f64) -> Self {
+        ScheduleRunnerPlugin {
+            run_mode: ScheduleRunnerSettings::run_loop_at_rate(updates_per_second).run_mode,
+        }
+    }
+}
 
 impl Plugin for ScheduleRunnerPlugin {
     fn build(&self, app: &mut AppBuilder) {
         let settings = app
             .resources_mut()
-            .get_or_insert_with(ScheduleRunnerSettings::default)
+            .get_or_insert_with(|| ScheduleRunnerSettings {
+                run_mode: self.run_mode,
+            })
             .to_owned();
         app.set_runner(move |mut app: App| {
             app.initialize();
@@ -62,13 +93,13 @@ impl Plugin for ScheduleRunnerPlugin {
             match settings.run_mode {
                 RunMode::Once => {
                     app.update();
+                    app.shutdown();
                 }
                 RunMode::Loop { wait } => {
+                    let mut next_update = Instant::now();
                     let mut tick = move |app: &mut App,
                                          wait: Option<Duration>|
                           -> Result<Option<Duration>, AppExit> {
-                        let start_time = Instant::now();
-
                         if let Some(app_exit_events) = app.resources.get_mut::<Events<AppExit>>() {
                             if let Some(exit) = app_exit_event_reader.latest(&app_exit_events) {
                                 return Err(exit.clone());
@@ -83,13 +114,16 @@ impl Plugin for ScheduleRunnerPlugin {
                             }
                         }
 
-                        let end_time = Instant::now();
-
                         if let Some(wait) = wait {
-                            let exe_time = end_time - start_time;
-                            if exe_time < wait {
-                                return Ok(Some(wait - exe_time));
+                            // pace updates against a fixed schedule, so time lost to oversleeping
+                            // is made up by a shorter sleep on the next update
+                            next_update += wait;
+                            let now = Instant::now();
+                            if next_update > now {
+                                return Ok(Some(next_update - now));
                             }
+                            // don't rush through updates to catch up after falling behind
+                            next_update = now;
                         }
 
                         Ok(None)
@@ -102,6 +136,7 @@ impl Plugin for ScheduleRunnerPlugin {
                                 std::thread::sleep(delay);
                             }
                         }
+                        app.shutdown();
                     }
 
                     #[cfg(target_arch = "wasm32")]
@@ -128,7 +163,7 @@ impl Plugin for ScheduleRunnerPlugin {
                                 Ok(delay) => {
                                     set_timeout(f.borrow().as_ref().unwrap(), delay.unwrap_or(asap))
                                 }
-                                Err(_) => {}
+                                Err(_) => app.shutdown(),
                             }
                         };
                         *g.borrow_mut() = Some(Closure::wrap(Box::new(c) as Box<dyn FnMut()>));
@@ -139,3 +174,68 @@ impl Plugin for ScheduleRunnerPlugin {
         });
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::{ScheduleRunnerPlugin, ScheduleRunnerSettings};
+    use crate::{app::AppExit, event::Events, App};
+    use bevy_ecs::{Local, Res, ResMut};
+    use bevy_utils::{Duration, Instant};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Clone, Default)]
+    struct UpdateCount(Arc<AtomicUsize>);
+
+    #[derive(Clone, Default)]
+    struct ShutdownCount(Arc<AtomicUsize>);
+
+    fn count_updates(count: Res<UpdateCount>) {
+        count.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn count_shutdowns(count: Res<ShutdownCount>) {
+        count.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn exit_after_three_updates(mut updates: Local<usize>, mut exit: ResMut<Events<AppExit>>) {
+        *updates += 1;
+        if *updates == 3 {
+            exit.send(AppExit);
+        }
+    }
+
+    #[test]
+    fn run_once_updates_once() {
+        let count = UpdateCount::default();
+        App::build()
+            .add_resource(ScheduleRunnerSettings::run_once())
+            .add_resource(count.clone())
+            .add_plugin(ScheduleRunnerPlugin::default())
+            .add_system(count_updates)
+            .run();
+        assert_eq!(count.0.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn run_loop_paces_updates_until_exit() {
+        let count = UpdateCount::default();
+        let shutdowns = ShutdownCount::default();
+        let start = Instant::now();
+        App::build()
+            .add_resource(ScheduleRunnerSettings::run_loop_at_rate(100.0))
+            .add_resource(count.clone())
+            .add_resource(shutdowns.clone())
+            .add_plugin(ScheduleRunnerPlugin::default())
+            .add_system(count_updates)
+            .add_system(exit_after_three_updates)
+            .add_shutdown_system(count_shutdowns)
+            .run();
+        assert_eq!(count.0.load(Ordering::Relaxed), 3);
+        assert_eq!(shutdowns.0.load(Ordering::Relaxed), 1);
+        // the runner sleeps after the first two updates and exits before sleeping after the third
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+}
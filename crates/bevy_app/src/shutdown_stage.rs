@@ -0,0 +1,8 @@
+/// Name of app stage that runs once before the shutdown stage
+pub const PRE_SHUTDOWN: &str = "pre_shutdown";
+
+/// Name of app stage that runs once when an app shuts down
+pub const SHUTDOWN: &str = "shutdown";
+
+/// Name of app stage that runs once after the shutdown stage
+pub const POST_SHUTDOWN: &str = "post_shutdown";
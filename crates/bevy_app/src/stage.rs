@@ -10,6 +10,10 @@ pub const EVENT: &str = "events";
 /// Name of app stage responsible for performing setup before an update. Runs before UPDATE.
 pub const PRE_UPDATE: &str = "pre_update";
 
+/// Name of app stage that runs systems at a fixed rate, independent of the frame rate. Runs after
+/// PRE_UPDATE and before UPDATE.
+pub const FIXED_UPDATE: &str = "fixed_update";
+
 /// Name of app stage responsible for doing most app logic. Systems should be registered here by default.
 pub const UPDATE: &str = "update";
 
@@ -0,0 +1,112 @@
+use crate::app::App;
+use bevy_ecs::{Resources, World};
+use bevy_tasks::ComputeTaskPool;
+#[cfg(feature = "trace")]
+use bevy_utils::tracing::info_span;
+
+/// An [App] that runs alongside its parent app, with its own [World], [Resources] and
+/// [Schedule](bevy_ecs::Schedule).
+///
+/// Sub apps are updated after their parent app's schedule has run. Before each update, the sub
+/// app's extract function copies the data it needs out of the parent app's world and resources.
+/// Because the sub app never reads the parent's world directly, its schedule can later be run
+/// while the parent app prepares the next frame (e.g. for pipelined rendering).
+pub struct SubApp {
+    pub label: &'static str,
+    pub app: App,
+    extract: Box<dyn Fn(&mut World, &mut Resources, &mut App)>,
+}
+
+impl SubApp {
+    pub fn new(
+        label: &'static str,
+        app: App,
+        extract: impl Fn(&mut World, &mut Resources, &mut App) + 'static,
+    ) -> Self {
+        SubApp {
+            label,
+            app,
+            extract: Box::new(extract),
+        }
+    }
+
+    /// Runs the sub app's startup schedule. The sub app shares its parent's compute task pool.
+    pub fn initialize(&mut self, resources: &Resources) {
+        if !self.app.resources.contains::<ComputeTaskPool>() {
+            if let Some(compute_pool) = resources.get_cloned::<ComputeTaskPool>() {
+                self.app.resources.insert(compute_pool);
+            }
+        }
+        self.app.initialize();
+    }
+
+    /// Extracts data from the parent app's `world` and `resources`, then updates the sub app
+    pub fn update(&mut self, world: &mut World, resources: &mut Resources) {
+        #[cfg(feature = "trace")]
+        let sub_app_span = info_span!("sub_app", name = self.label);
+        #[cfg(feature = "trace")]
+        let _sub_app_guard = sub_app_span.enter();
+        {
+            #[cfg(feature = "trace")]
+            let extract_span = info_span!("extract");
+            #[cfg(feature = "trace")]
+            let _extract_guard = extract_span.enter();
+            (self.extract)(world, resources, &mut self.app);
+        }
+        self.app.update();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, AppBuilder};
+    use bevy_ecs::{Res, ResMut, Resources, World};
+    use std::sync::{Arc, Mutex};
+
+    struct FrameCount(u32);
+
+    #[derive(Clone, Default)]
+    struct ExtractedFrames(Arc<Mutex<Vec<u32>>>);
+
+    fn count_frames(mut frames: ResMut<FrameCount>) {
+        frames.0 += 1;
+    }
+
+    fn record_frame(frame: Res<FrameCount>, extracted: Res<ExtractedFrames>) {
+        extracted.0.lock().unwrap().push(frame.0);
+    }
+
+    fn extract_frame_count(_world: &mut World, resources: &mut Resources, sub_app: &mut App) {
+        let frame = resources.get::<FrameCount>().unwrap().0;
+        sub_app.resources.insert(FrameCount(frame));
+    }
+
+    #[test]
+    fn sub_app_updates_with_extracted_data() {
+        let extracted = ExtractedFrames::default();
+        let mut sub_app = AppBuilder::default();
+        sub_app
+            .add_resource(FrameCount(0))
+            .add_resource(extracted.clone())
+            .add_system(record_frame);
+
+        let mut app = App::build();
+        app.add_resource(FrameCount(0))
+            .add_system(count_frames)
+            .add_sub_app("sub", sub_app.app, extract_frame_count);
+        let mut app = std::mem::take(&mut app.app);
+
+        app.initialize();
+        app.update();
+        app.update();
+
+        assert_eq!(*extracted.0.lock().unwrap(), vec![1, 2]);
+        // the sub app keeps its own world and resources
+        assert!(app
+            .sub_app("sub")
+            .unwrap()
+            .resources
+            .contains::<ExtractedFrames>());
+        assert!(!app.resources.contains::<ExtractedFrames>());
+    }
+}
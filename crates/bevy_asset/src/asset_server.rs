@@ -1,16 +1,25 @@
 use crate::{
     path::{AssetPath, AssetPathId, SourcePathId},
     Asset, AssetIo, AssetIoError, AssetLifecycle, AssetLifecycleChannel, AssetLifecycleEvent,
-    AssetLoader, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext, LoadState,
-    RefChange, RefChangeChannel, SourceInfo, SourceMeta,
+    AssetLoader, AssetResult, Assets, Handle, HandleId, HandleUntyped, LabelId, LoadContext,
+    LoadState, RefChange, RefChangeChannel, SourceInfo, SourceMeta,
 };
 use anyhow::Result;
 use bevy_ecs::Res;
 use bevy_tasks::TaskPool;
+#[cfg(feature = "trace")]
+use bevy_utils::tracing::{info_span, Instrument};
 use bevy_utils::HashMap;
 use crossbeam_channel::TryRecvError;
-use parking_lot::RwLock;
-use std::{collections::hash_map::Entry, path::Path, sync::Arc};
+use parking_lot::{RwLock, RwLockWriteGuard};
+use std::{
+    collections::{hash_map::Entry, VecDeque},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -44,6 +53,8 @@ pub struct AssetServerInternal {
     extension_to_loader_index: RwLock<HashMap<String, usize>>,
     handle_to_path: Arc<RwLock<HashMap<HandleId, AssetPath<'static>>>>,
     task_pool: TaskPool,
+    decode_task_pool: TaskPool,
+    hot_reload_enabled: AtomicBool,
 }
 
 /// Loads assets from the filesystem on background threads
@@ -61,6 +72,16 @@ impl Clone for AssetServer {
 
 impl AssetServer {
     pub fn new<T: AssetIo>(source_io: T, task_pool: TaskPool) -> Self {
+        Self::with_decode_task_pool(source_io, task_pool.clone(), task_pool)
+    }
+
+    /// Creates an AssetServer that reads asset bytes on `task_pool` and runs [AssetLoader]s on
+    /// `decode_task_pool`, so CPU heavy decoding doesn't hold up IO
+    pub fn with_decode_task_pool<T: AssetIo>(
+        source_io: T,
+        task_pool: TaskPool,
+        decode_task_pool: TaskPool,
+    ) -> Self {
         AssetServer {
             server: Arc::new(AssetServerInternal {
                 loaders: Default::default(),
@@ -70,6 +91,8 @@ impl AssetServer {
                 handle_to_path: Default::default(),
                 asset_lifecycles: Default::default(),
                 task_pool,
+                decode_task_pool,
+                hot_reload_enabled: AtomicBool::new(true),
                 asset_io: Box::new(source_io),
             }),
         }
@@ -98,11 +121,22 @@ impl AssetServer {
         loaders.push(Arc::new(Box::new(loader)));
     }
 
+    /// Reloads assets when their files change. Does nothing when the
+    /// [HOT_RELOAD_CAPABILITY](crate::HOT_RELOAD_CAPABILITY) is disabled.
     pub fn watch_for_changes(&self) -> Result<(), AssetServerError> {
+        if !self.server.hot_reload_enabled.load(Ordering::Relaxed) {
+            return Ok(());
+        }
         self.server.asset_io.watch_for_changes()?;
         Ok(())
     }
 
+    pub(crate) fn disable_hot_reload(&self) {
+        self.server
+            .hot_reload_enabled
+            .store(false, Ordering::Relaxed);
+    }
+
     pub fn get_handle<T: Asset, I: Into<HandleId>>(&self, id: I) -> Handle<T> {
         let sender = self.server.asset_ref_counter.channel.sender.clone();
         Handle::strong(id.into(), sender)
@@ -220,8 +254,15 @@ impl AssetServer {
             source_info.version
         };
 
-        // load the asset bytes
-        let bytes = self.server.asset_io.load_path(asset_path.path()).await?;
+        // load the asset bytes on the io task pool. the rest of the load runs on the decode task pool
+        let bytes = {
+            let server = self.clone();
+            let path = asset_path.path().to_owned();
+            self.server
+                .task_pool
+                .spawn(async move { server.server.asset_io.load_path(&path).await })
+                .await?
+        };
 
         // load the asset source using the corresponding AssetLoader
         let mut load_context = LoadContext::new(
@@ -289,12 +330,14 @@ impl AssetServer {
         let asset_path: AssetPath<'a> = path.into();
         let server = self.clone();
         let owned_path = asset_path.to_owned();
-        self.server
-            .task_pool
-            .spawn(async move {
-                server.load_async(owned_path, force).await.unwrap();
-            })
-            .detach();
+        #[cfg(feature = "trace")]
+        let load_span = info_span!("asset_load", path = ?owned_path.path());
+        let load = async move {
+            server.load_async(owned_path, force).await.unwrap();
+        };
+        #[cfg(feature = "trace")]
+        let load = load.instrument(load_span);
+        self.server.decode_task_pool.spawn(load).detach();
         asset_path.into()
     }
 
@@ -389,7 +432,15 @@ impl AssetServer {
         }
     }
 
-    pub(crate) fn update_asset_storage<T: Asset>(&self, assets: &mut Assets<T>) {
+    /// Applies loaded and freed assets to `assets`. When `max_created` is set, at most that many
+    /// loaded assets are added and the rest are kept in `deferred` for the next call. Freed assets
+    /// are always removed right away.
+    pub(crate) fn update_asset_storage<T: Asset>(
+        &self,
+        assets: &mut Assets<T>,
+        deferred: &mut VecDeque<AssetResult<T>>,
+        max_created: Option<usize>,
+    ) {
         let asset_lifecycles = self.server.asset_lifecycles.read();
         let asset_lifecycle = asset_lifecycles.get(&T::TYPE_UUID).unwrap();
         let mut asset_sources_guard = None;
@@ -397,26 +448,32 @@ impl AssetServer {
             .downcast_ref::<AssetLifecycleChannel<T>>()
             .unwrap();
 
+        let mut created = 0;
+        let can_create = |created: usize| max_created.map_or(true, |max| created < max);
+        // assets deferred by earlier calls are added first, in the order they were loaded
+        while can_create(created) {
+            match deferred.pop_front() {
+                Some(result) => {
+                    self.create_asset(assets, &mut asset_sources_guard, result);
+                    created += 1;
+                }
+                None => break,
+            }
+        }
+
         loop {
             match channel.receiver.try_recv() {
                 Ok(AssetLifecycleEvent::Create(result)) => {
-                    // update SourceInfo if this asset was loaded from an AssetPath
-                    if let HandleId::AssetPathId(id) = result.id {
-                        let asset_sources = asset_sources_guard
-                            .get_or_insert_with(|| self.server.asset_sources.write());
-                        if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
-                            if source_info.version == result.version {
-                                source_info.committed_assets.insert(id.label_id());
-                                if source_info.is_loaded() {
-                                    source_info.load_state = LoadState::Loaded;
-                                }
-                            }
-                        }
+                    if deferred.is_empty() && can_create(created) {
+                        self.create_asset(assets, &mut asset_sources_guard, result);
+                        created += 1;
+                    } else {
+                        deferred.push_back(result);
                     }
-
-                    assets.set(result.id, result.asset);
                 }
                 Ok(AssetLifecycleEvent::Free(handle_id)) => {
+                    // assets that are freed before they were added are never added
+                    deferred.retain(|result| result.id != handle_id);
                     if let HandleId::AssetPathId(id) = handle_id {
                         let asset_sources = asset_sources_guard
                             .get_or_insert_with(|| self.server.asset_sources.write());
@@ -436,6 +493,29 @@ impl AssetServer {
             }
         }
     }
+
+    fn create_asset<'a, T: Asset>(
+        &'a self,
+        assets: &mut Assets<T>,
+        asset_sources_guard: &mut Option<RwLockWriteGuard<'a, HashMap<SourcePathId, SourceInfo>>>,
+        result: AssetResult<T>,
+    ) {
+        // update SourceInfo if this asset was loaded from an AssetPath
+        if let HandleId::AssetPathId(id) = result.id {
+            let asset_sources =
+                asset_sources_guard.get_or_insert_with(|| self.server.asset_sources.write());
+            if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
+                if source_info.version == result.version {
+                    source_info.committed_assets.insert(id.label_id());
+                    if source_info.is_loaded() {
+                        source_info.load_state = LoadState::Loaded;
+                    }
+                }
+            }
+        }
+
+        assets.set(result.id, result.asset);
+    }
 }
 
 pub fn free_unused_assets_system(asset_server: Res<AssetServer>) {
@@ -2,8 +2,11 @@ use crate::{AssetIo, AssetIoError};
 use anyhow::Result;
 use bevy_ecs::bevy_utils::BoxedFuture;
 use js_sys::Uint8Array;
-use std::path::{Path, PathBuf};
-use wasm_bindgen::JsCast;
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::Response;
 
@@ -26,9 +29,22 @@ impl AssetIo for WasmAssetIo {
             let window = web_sys::window().unwrap();
             let resp_value = JsFuture::from(window.fetch_with_str(path.to_str().unwrap()))
                 .await
-                .unwrap();
+                .map_err(js_error)?;
             let resp: Response = resp_value.dyn_into().unwrap();
-            let data = JsFuture::from(resp.array_buffer().unwrap()).await.unwrap();
+            if !resp.ok() {
+                return Err(if resp.status() == 404 {
+                    AssetIoError::NotFound(path)
+                } else {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("fetching {:?} failed with status {}", path, resp.status()),
+                    )
+                    .into()
+                });
+            }
+            let data = JsFuture::from(resp.array_buffer().map_err(js_error)?)
+                .await
+                .map_err(js_error)?;
             let bytes = Uint8Array::new(&data).to_vec();
             Ok(bytes)
         })
@@ -53,3 +69,7 @@ impl AssetIo for WasmAssetIo {
         self.root_path.join(path).is_dir()
     }
 }
+
+fn js_error(error: JsValue) -> AssetIoError {
+    io::Error::new(io::ErrorKind::Other, format!("{:?}", error)).into()
+}
@@ -13,7 +13,7 @@ mod path;
 
 pub use asset_server::*;
 pub use assets::*;
-use bevy_tasks::IoTaskPool;
+use bevy_tasks::{AsyncComputeTaskPool, IoTaskPool};
 pub use handle::*;
 pub use info::*;
 pub use io::*;
@@ -33,19 +33,48 @@ pub mod prelude {
 use bevy_app::{prelude::Plugin, AppBuilder};
 use bevy_type_registry::RegisterType;
 
+/// The name of the asset hot reloading [Capabilities](bevy_app::Capabilities). When it is disabled,
+/// [AssetServer::watch_for_changes] still succeeds but doesn't watch the asset folder, so changed
+/// assets are not reloaded.
+pub const HOT_RELOAD_CAPABILITY: &str = "asset_hot_reload";
+
 /// Adds support for Assets to an App. Assets are typed collections with change tracking, which are added as App Resources.
 /// Examples of assets: textures, sounds, 3d models, maps, scenes
-#[derive(Default)]
-pub struct AssetPlugin;
+///
+/// An [AssetServerSettings] resource inserted before this plugin is added takes precedence over the
+/// plugin's settings.
+pub struct AssetPlugin {
+    /// The folder assets are loaded from, relative to the app's root
+    pub asset_folder: String,
+    /// The maximum number of loaded assets of each type that are added to their [Assets] collection
+    /// per frame. `None` adds every loaded asset as soon as it is ready. Must not be `Some(0)`.
+    pub max_created_assets_per_frame: Option<usize>,
+}
+
+impl Default for AssetPlugin {
+    fn default() -> Self {
+        let settings = AssetServerSettings::default();
+        Self {
+            asset_folder: settings.asset_folder,
+            max_created_assets_per_frame: settings.max_created_assets_per_frame,
+        }
+    }
+}
 
 pub struct AssetServerSettings {
     pub asset_folder: String,
+    /// Limits how many loaded assets of each type are added to their [Assets] collection per frame.
+    /// Assets over the limit stay queued until the next frame, which spreads the cost of preparing
+    /// many newly loaded assets (such as uploading textures to the GPU) over several frames.
+    /// Freed assets don't count towards the limit. The limit must not be `Some(0)`.
+    pub max_created_assets_per_frame: Option<usize>,
 }
 
 impl Default for AssetServerSettings {
     fn default() -> Self {
         Self {
             asset_folder: "assets".to_string(),
+            max_created_assets_per_frame: None,
         }
     }
 }
@@ -58,11 +87,28 @@ impl Plugin for AssetPlugin {
             .expect("IoTaskPool resource not found")
             .0
             .clone();
+        // loaders decode on the async compute pool so large assets don't tie up the io threads
+        let decode_task_pool = app
+            .resources()
+            .get::<AsyncComputeTaskPool>()
+            .map_or_else(|| task_pool.clone(), |pool| pool.0.clone());
 
+        let hot_reload_enabled = app
+            .resources_mut()
+            .get_or_insert_with(bevy_app::Capabilities::default)
+            .is_enabled(HOT_RELOAD_CAPABILITY);
         let asset_server = {
             let settings = app
                 .resources_mut()
-                .get_or_insert_with(AssetServerSettings::default);
+                .get_or_insert_with(|| AssetServerSettings {
+                    asset_folder: self.asset_folder.clone(),
+                    max_created_assets_per_frame: self.max_created_assets_per_frame,
+                });
+            assert_ne!(
+                settings.max_created_assets_per_frame,
+                Some(0),
+                "max_created_assets_per_frame must be at least 1, or None to add loaded assets right away"
+            );
 
             #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
             let source = FileAssetIo::new(&settings.asset_folder);
@@ -70,8 +116,11 @@ impl Plugin for AssetPlugin {
             let source = WasmAssetIo::new(&settings.asset_folder);
             #[cfg(target_os = "android")]
             let source = AndroidAssetIo::new(&settings.asset_folder);
-            AssetServer::new(source, task_pool)
+            AssetServer::with_decode_task_pool(source, task_pool, decode_task_pool)
         };
+        if !hot_reload_enabled {
+            asset_server.disable_hot_reload();
+        }
 
         app.add_stage_before(bevy_app::stage::PRE_UPDATE, stage::LOAD_ASSETS)
             .add_stage_after(bevy_app::stage::POST_UPDATE, stage::ASSET_EVENTS)
@@ -86,6 +135,8 @@ impl Plugin for AssetPlugin {
             feature = "filesystem_watcher",
             all(not(target_arch = "wasm32"), not(target_os = "android"))
         ))]
-        app.add_system_to_stage(stage::LOAD_ASSETS, io::filesystem_watcher_system);
+        if hot_reload_enabled {
+            app.add_system_to_stage(stage::LOAD_ASSETS, io::filesystem_watcher_system);
+        }
     }
 }
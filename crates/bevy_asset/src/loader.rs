@@ -1,14 +1,14 @@
 use crate::{
-    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, Assets, Handle, HandleId,
-    RefChangeChannel,
+    path::AssetPath, AssetIo, AssetIoError, AssetMeta, AssetServer, AssetServerSettings, Assets,
+    Handle, HandleId, RefChangeChannel,
 };
 use anyhow::Result;
-use bevy_ecs::{Res, ResMut, Resource};
+use bevy_ecs::{Local, Res, ResMut, Resource};
 use bevy_type_registry::{TypeUuid, TypeUuidDynamic};
 use bevy_utils::{BoxedFuture, HashMap};
 use crossbeam_channel::{Receiver, Sender};
 use downcast_rs::{impl_downcast, Downcast};
-use std::path::Path;
+use std::{collections::VecDeque, path::Path};
 
 /// A loader for an asset source
 pub trait AssetLoader: Send + Sync + 'static {
@@ -168,10 +168,17 @@ impl<T: Resource> Default for AssetLifecycleChannel<T> {
     }
 }
 
-/// Updates the [Assets] collection according to the changes queued up by [AssetServer].
+/// Updates the [Assets] collection according to the changes queued up by [AssetServer], adding at
+/// most [AssetServerSettings::max_created_assets_per_frame] loaded assets.
 pub fn update_asset_storage_system<T: Asset + AssetDynamic>(
+    mut deferred: Local<VecDeque<AssetResult<T>>>,
     asset_server: Res<AssetServer>,
+    settings: Res<AssetServerSettings>,
     mut assets: ResMut<Assets<T>>,
 ) {
-    asset_server.update_asset_storage(&mut assets);
+    asset_server.update_asset_storage(
+        &mut assets,
+        &mut deferred,
+        settings.max_created_assets_per_frame,
+    );
 }
@@ -19,13 +19,23 @@ where
     P: Decodable,
 {
     fn default() -> Self {
-        let (stream, stream_handle) = OutputStream::try_default().unwrap();
+        Self::try_default().expect("No audio device found")
+    }
+}
 
-        Self {
+impl<P> AudioOutput<P>
+where
+    P: Decodable,
+{
+    /// Opens the default audio device, returning `None` if there isn't one
+    pub fn try_default() -> Option<Self> {
+        let (stream, stream_handle) = OutputStream::try_default().ok()?;
+
+        Some(Self {
             _stream: stream,
             stream_handle,
             phantom: PhantomData,
-        }
+        })
     }
 }
 
@@ -65,8 +75,15 @@ where
     <P as Decodable>::Decoder: rodio::Source + Send + Sync,
     <<P as Decodable>::Decoder as Iterator>::Item: rodio::Sample + Send + Sync,
 {
-    let audio_output = resources.get_thread_local::<AudioOutput<P>>().unwrap();
     let mut audio = resources.get_mut::<Audio<P>>().unwrap();
+    let audio_output = match resources.get_thread_local::<AudioOutput<P>>() {
+        Some(audio_output) => audio_output,
+        None => {
+            // audio is disabled, so drop queued sounds instead of letting them pile up
+            audio.queue.write().clear();
+            return;
+        }
+    };
 
     if let Some(audio_sources) = resources.get::<Assets<P>>() {
         audio_output.try_play_queued(&*audio_sources, &mut *audio);
@@ -10,8 +10,12 @@ pub mod prelude {
     pub use crate::{Audio, AudioOutput, AudioSource, Decodable};
 }
 
-use bevy_app::prelude::*;
+use bevy_app::{prelude::*, Capabilities};
 use bevy_asset::AddAsset;
+use bevy_utils::tracing::warn;
+
+/// The name of the audio output [Capabilities]. When it is disabled, queued audio is discarded.
+pub const AUDIO_CAPABILITY: &str = "audio";
 
 /// Adds support for audio playback to an App
 #[derive(Default)]
@@ -19,8 +23,26 @@ pub struct AudioPlugin;
 
 impl Plugin for AudioPlugin {
     fn build(&self, app: &mut AppBuilder) {
-        app.init_thread_local_resource::<AudioOutput<AudioSource>>()
-            .add_asset::<AudioSource>()
+        let audio_output = {
+            let mut capabilities = app
+                .resources_mut()
+                .get_or_insert_with(Capabilities::default);
+            if capabilities.is_enabled(AUDIO_CAPABILITY) {
+                let audio_output = AudioOutput::<AudioSource>::try_default();
+                if audio_output.is_none() {
+                    warn!("No audio device found. Audio is disabled.");
+                    capabilities.set_unavailable(AUDIO_CAPABILITY);
+                }
+                audio_output
+            } else {
+                None
+            }
+        };
+
+        if let Some(audio_output) = audio_output {
+            app.add_thread_local_resource(audio_output);
+        }
+        app.add_asset::<AudioSource>()
             .init_asset_loader::<Mp3Loader>()
             .init_resource::<Audio<AudioSource>>()
             .add_system_to_stage(stage::POST_UPDATE, play_queued_audio_system::<AudioSource>);
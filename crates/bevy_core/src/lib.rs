@@ -11,7 +11,10 @@ pub use task_pool_options::DefaultTaskPoolOptions;
 pub use time::*;
 
 pub mod prelude {
-    pub use crate::{DefaultTaskPoolOptions, EntityLabels, Labels, Time, Timer};
+    pub use crate::{
+        fixed_stage, AddFixedSystem, DefaultTaskPoolOptions, EntityLabels, FixedTime, Labels,
+        Stopwatch, Time, Timer,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -19,20 +22,33 @@ use bevy_math::{Mat3, Mat4, Quat, Vec2, Vec3};
 use bevy_type_registry::RegisterType;
 
 /// Adds core functionality to Apps.
+///
+/// A [DefaultTaskPoolOptions] resource inserted before this plugin is added takes precedence over
+/// `task_pool_options`.
 #[derive(Default)]
-pub struct CorePlugin;
+pub struct CorePlugin {
+    /// Configures the default task pools, unless they have already been inserted
+    pub task_pool_options: DefaultTaskPoolOptions,
+}
 
 impl Plugin for CorePlugin {
     fn build(&self, app: &mut AppBuilder) {
         // Setup the default bevy task pools
         app.resources_mut()
             .get_cloned::<DefaultTaskPoolOptions>()
-            .unwrap_or_else(DefaultTaskPoolOptions::default)
+            .unwrap_or_else(|| self.task_pool_options.clone())
             .create_default_pools(app.resources_mut());
 
+        // the fixed rate can be configured and fixed systems can be added before this plugin
+        app.resources_mut().get_or_insert_with(FixedTime::default);
+        app.resources_mut()
+            .get_or_insert_with(FixedUpdateSchedule::default);
+
         app.init_resource::<Time>()
             .init_resource::<EntityLabels>()
             .register_component::<Timer>()
+            .register_component::<Stopwatch>()
+            .register_component::<Labels>()
             .register_property::<Vec2>()
             .register_property::<Vec3>()
             .register_property::<Mat3>()
@@ -40,6 +56,8 @@ impl Plugin for CorePlugin {
             .register_property::<Quat>()
             .register_property::<Option<String>>()
             .add_system_to_stage(stage::FIRST, time_system)
+            .add_system_to_stage(stage::FIRST, fixed_time_system)
+            .add_system_to_stage(stage::FIXED_UPDATE, fixed_update_system)
             .add_system_to_stage(stage::PRE_UPDATE, entity_labels_system);
     }
 }
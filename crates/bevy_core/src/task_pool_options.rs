@@ -152,3 +152,26 @@ impl DefaultTaskPoolOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DefaultTaskPoolOptions;
+
+    #[test]
+    fn default_thread_assignment() {
+        let options = DefaultTaskPoolOptions::default();
+
+        // 25% of the cores for io and async compute each, and the rest for compute
+        assert_eq!(options.io.get_number_of_threads(16, 16), 4);
+        assert_eq!(options.async_compute.get_number_of_threads(12, 16), 4);
+        assert_eq!(options.compute.get_number_of_threads(8, 16), 8);
+
+        // io and async compute are capped at 4 threads each
+        assert_eq!(options.io.get_number_of_threads(64, 64), 4);
+
+        // every pool gets at least one thread, even if that oversubscribes the cores
+        assert_eq!(options.io.get_number_of_threads(1, 1), 1);
+        assert_eq!(options.async_compute.get_number_of_threads(0, 1), 1);
+        assert_eq!(options.compute.get_number_of_threads(0, 1), 1);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::Time;
+use bevy_ecs::{Res, ResMut};
+use bevy_utils::Duration;
+
+/// A clock that advances in fixed increments, independent of the frame rate.
+///
+/// The frame's delta time is added to an accumulator every update. Simulation code then calls
+/// [FixedTime::expend] in a loop, running one fixed step each time it returns `true`. The time
+/// left over in the accumulator is exposed through [FixedTime::alpha], which can be used to
+/// interpolate between the last two simulated states when rendering.
+///
+/// The [FixedUpdateSchedule](crate::FixedUpdateSchedule) calls [FixedTime::expend] itself and
+/// runs its systems once for each accumulated step.
+///
+/// At most [FixedTime::max_accumulated_steps] steps can be accumulated. Time beyond that is
+/// dropped, so a long frame (for example while the window is being dragged) doesn't make the
+/// simulation fall further behind with every frame it spends catching up.
+#[derive(Debug, Clone)]
+pub struct FixedTime {
+    step: Duration,
+    accumulated: Duration,
+    elapsed: Duration,
+    steps: u64,
+    max_accumulated_steps: u32,
+}
+
+impl FixedTime {
+    pub const DEFAULT_MAX_ACCUMULATED_STEPS: u32 = 8;
+
+    /// Creates a clock that advances by `step`, which must not be zero
+    pub fn new(step: Duration) -> Self {
+        assert!(
+            step > Duration::from_secs(0),
+            "FixedTime step must be greater than zero"
+        );
+        FixedTime {
+            step,
+            accumulated: Duration::from_secs(0),
+            elapsed: Duration::from_secs(0),
+            steps: 0,
+            max_accumulated_steps: Self::DEFAULT_MAX_ACCUMULATED_STEPS,
+        }
+    }
+
+    /// Creates a clock that runs `rate` steps per second, which must be positive
+    pub fn from_hz(rate: f64) -> Self {
+        assert!(
+            rate > 0.0 && rate.is_finite(),
+            "FixedTime rate must be a positive number of steps per second, got {}",
+            rate
+        );
+        Self::new(Duration::from_secs_f64(1.0 / rate))
+    }
+
+    /// The amount of time simulated by a single step
+    #[inline]
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    #[inline]
+    pub fn step_seconds(&self) -> f32 {
+        self.step.as_secs_f32()
+    }
+
+    #[inline]
+    pub fn set_step(&mut self, step: Duration) {
+        assert!(
+            step > Duration::from_secs(0),
+            "FixedTime step must be greater than zero"
+        );
+        self.step = step;
+    }
+
+    /// The most steps that can be accumulated before further time is dropped
+    #[inline]
+    pub fn max_accumulated_steps(&self) -> u32 {
+        self.max_accumulated_steps
+    }
+
+    #[inline]
+    pub fn set_max_accumulated_steps(&mut self, max_accumulated_steps: u32) {
+        self.max_accumulated_steps = max_accumulated_steps;
+    }
+
+    /// The total number of steps that have been expended since startup
+    #[inline]
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// The total amount of time simulated by the steps that have been expended since startup
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    #[inline]
+    pub fn seconds_since_startup(&self) -> f64 {
+        self.elapsed.as_secs_f64()
+    }
+
+    /// Time that has been accumulated but not yet expended by a step
+    #[inline]
+    pub fn accumulated(&self) -> Duration {
+        self.accumulated
+    }
+
+    /// Adds `delta` to the accumulator, dropping the time beyond
+    /// [FixedTime::max_accumulated_steps] steps.
+    pub fn tick(&mut self, delta: Duration) {
+        let max_accumulated = self.step * self.max_accumulated_steps;
+        self.accumulated = (self.accumulated + delta).min(max_accumulated);
+    }
+
+    /// Consumes one step worth of accumulated time. Returns `false` (and consumes nothing) if
+    /// less than a full step has been accumulated.
+    pub fn expend(&mut self) -> bool {
+        if self.accumulated >= self.step {
+            self.accumulated -= self.step;
+            self.elapsed += self.step;
+            self.steps += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// How far the current frame is between the last step and the next one (`0.0..1.0`).
+    pub fn alpha(&self) -> f32 {
+        (self.accumulated.as_secs_f64() / self.step.as_secs_f64()) as f32
+    }
+}
+
+impl Default for FixedTime {
+    fn default() -> Self {
+        FixedTime::from_hz(60.0)
+    }
+}
+
+pub(crate) fn fixed_time_system(time: Res<Time>, mut fixed_time: ResMut<FixedTime>) {
+    fixed_time.tick(time.delta);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedTime;
+    use bevy_utils::Duration;
+
+    #[test]
+    fn expend_steps() {
+        let mut fixed_time = FixedTime::new(Duration::from_millis(10));
+        fixed_time.tick(Duration::from_millis(25));
+        assert!(fixed_time.expend());
+        assert!(fixed_time.expend());
+        assert!(!fixed_time.expend());
+        assert_eq!(fixed_time.steps(), 2);
+        assert_eq!(fixed_time.elapsed(), Duration::from_millis(20));
+        assert_eq!(fixed_time.accumulated(), Duration::from_millis(5));
+        assert!((fixed_time.alpha() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn accumulated_time_is_clamped() {
+        let mut fixed_time = FixedTime::new(Duration::from_millis(10));
+        fixed_time.set_max_accumulated_steps(3);
+        fixed_time.tick(Duration::from_secs(10));
+        assert_eq!(fixed_time.accumulated(), Duration::from_millis(30));
+        while fixed_time.expend() {}
+        assert_eq!(fixed_time.steps(), 3);
+        assert_eq!(fixed_time.accumulated(), Duration::from_secs(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "FixedTime rate must be a positive number of steps per second")]
+    fn zero_rate_panics() {
+        FixedTime::from_hz(0.0);
+    }
+}
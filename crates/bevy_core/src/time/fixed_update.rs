@@ -0,0 +1,208 @@
+use crate::FixedTime;
+use bevy_app::AppBuilder;
+use bevy_ecs::{IntoSystem, ParallelExecutor, Resources, Schedule, System, World};
+
+/// The names of the stages of the [FixedUpdateSchedule]
+pub mod fixed_stage {
+    /// Name of the fixed update stage that runs before FIXED_UPDATE
+    pub const PRE_FIXED_UPDATE: &str = "pre_fixed_update";
+
+    /// Name of the fixed update stage that systems are added to by default
+    pub const FIXED_UPDATE: &str = "fixed_update";
+
+    /// Name of the fixed update stage that runs after FIXED_UPDATE
+    pub const POST_FIXED_UPDATE: &str = "post_fixed_update";
+}
+
+/// Systems that run at the rate of the [FixedTime] clock instead of once per frame.
+///
+/// The schedule is run from the [FIXED_UPDATE](bevy_app::stage::FIXED_UPDATE) app stage, once for
+/// every step that has accumulated on the [FixedTime] clock. Depending on the frame rate, that can
+/// be several times in a single frame or not at all. Systems in this schedule should use
+/// [FixedTime::step_seconds] as their delta time instead of [Time](crate::Time).
+pub struct FixedUpdateSchedule {
+    schedule: Schedule,
+    executor: ParallelExecutor,
+}
+
+impl Default for FixedUpdateSchedule {
+    fn default() -> Self {
+        let mut schedule = Schedule::default();
+        schedule.add_stage(fixed_stage::PRE_FIXED_UPDATE);
+        schedule.add_stage(fixed_stage::FIXED_UPDATE);
+        schedule.add_stage(fixed_stage::POST_FIXED_UPDATE);
+        FixedUpdateSchedule {
+            schedule,
+            // change trackers are cleared once per frame by the app's executor, so systems that run
+            // per frame still see changes made by fixed update systems
+            executor: ParallelExecutor::without_tracker_clears(),
+        }
+    }
+}
+
+impl FixedUpdateSchedule {
+    pub fn add_system<S, Params, IntoS>(&mut self, system: IntoS) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.add_system_to_stage(fixed_stage::FIXED_UPDATE, system)
+    }
+
+    pub fn add_system_to_stage<S, Params, IntoS>(
+        &mut self,
+        stage_name: &'static str,
+        system: IntoS,
+    ) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.schedule.add_system_to_stage(stage_name, system);
+        self
+    }
+}
+
+/// Adds systems to the [FixedUpdateSchedule]
+pub trait AddFixedSystem {
+    fn add_fixed_system<S, Params, IntoS>(&mut self, system: IntoS) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>;
+    fn add_fixed_system_to_stage<S, Params, IntoS>(
+        &mut self,
+        stage_name: &'static str,
+        system: IntoS,
+    ) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>;
+}
+
+impl AddFixedSystem for AppBuilder {
+    fn add_fixed_system<S, Params, IntoS>(&mut self, system: IntoS) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.add_fixed_system_to_stage(fixed_stage::FIXED_UPDATE, system)
+    }
+
+    fn add_fixed_system_to_stage<S, Params, IntoS>(
+        &mut self,
+        stage_name: &'static str,
+        system: IntoS,
+    ) -> &mut Self
+    where
+        S: System<Input = (), Output = ()>,
+        IntoS: IntoSystem<Params, S>,
+    {
+        self.resources_mut()
+            .get_or_insert_with(FixedUpdateSchedule::default)
+            .add_system_to_stage(stage_name, system);
+        self
+    }
+}
+
+/// Runs the [FixedUpdateSchedule] once for every step that has accumulated on the [FixedTime] clock.
+/// The clock drops time beyond [FixedTime::max_accumulated_steps], which caps the number of steps
+/// run in a single frame.
+pub fn fixed_update_system(world: &mut World, resources: &mut Resources) {
+    // move the schedule out of its resource so it can be run with mutable access to the resources
+    let (mut schedule, mut executor) = match resources.get_mut::<FixedUpdateSchedule>() {
+        Some(mut fixed_update) => (
+            std::mem::take(&mut fixed_update.schedule),
+            std::mem::take(&mut fixed_update.executor),
+        ),
+        None => return,
+    };
+
+    while resources
+        .get_mut::<FixedTime>()
+        .map_or(false, |mut fixed_time| fixed_time.expend())
+    {
+        schedule.initialize(world, resources);
+        executor.run(&mut schedule, world, resources);
+    }
+
+    if let Some(mut fixed_update) = resources.get_mut::<FixedUpdateSchedule>() {
+        fixed_update.schedule = schedule;
+        fixed_update.executor = executor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fixed_update_system, FixedUpdateSchedule};
+    use crate::FixedTime;
+    use bevy_ecs::{ResMut, Resources, World};
+    use bevy_utils::Duration;
+
+    #[derive(Default)]
+    struct StepCount(usize);
+
+    fn count_steps(mut count: ResMut<StepCount>) {
+        count.0 += 1;
+    }
+
+    #[test]
+    fn runs_once_per_step() {
+        let mut world = World::new();
+        let mut resources = Resources::default();
+        resources.insert(StepCount::default());
+        resources.insert(FixedTime::new(Duration::from_millis(10)));
+        let mut fixed_update = FixedUpdateSchedule::default();
+        fixed_update.add_system(count_steps);
+        resources.insert(fixed_update);
+
+        resources
+            .get_mut::<FixedTime>()
+            .unwrap()
+            .tick(Duration::from_millis(25));
+        fixed_update_system(&mut world, &mut resources);
+        assert_eq!(resources.get::<StepCount>().unwrap().0, 2);
+
+        // the remaining 5ms aren't enough for another step
+        fixed_update_system(&mut world, &mut resources);
+        assert_eq!(resources.get::<StepCount>().unwrap().0, 2);
+
+        resources
+            .get_mut::<FixedTime>()
+            .unwrap()
+            .tick(Duration::from_millis(5));
+        fixed_update_system(&mut world, &mut resources);
+        assert_eq!(resources.get::<StepCount>().unwrap().0, 3);
+    }
+
+    #[test]
+    fn long_frames_run_a_limited_number_of_steps() {
+        let mut world = World::new();
+        let mut resources = Resources::default();
+        resources.insert(StepCount::default());
+        resources.insert(FixedTime::new(Duration::from_millis(10)));
+        let mut fixed_update = FixedUpdateSchedule::default();
+        fixed_update.add_system(count_steps);
+        resources.insert(fixed_update);
+
+        resources
+            .get_mut::<FixedTime>()
+            .unwrap()
+            .tick(Duration::from_secs(10));
+        fixed_update_system(&mut world, &mut resources);
+        assert_eq!(
+            resources.get::<StepCount>().unwrap().0,
+            FixedTime::DEFAULT_MAX_ACCUMULATED_STEPS as usize
+        );
+
+        // the rest of the long frame was dropped rather than run in the following frames
+        fixed_update_system(&mut world, &mut resources);
+        assert_eq!(
+            resources.get::<StepCount>().unwrap().0,
+            FixedTime::DEFAULT_MAX_ACCUMULATED_STEPS as usize
+        );
+        assert_eq!(
+            resources.get::<FixedTime>().unwrap().accumulated(),
+            Duration::from_secs(0)
+        );
+    }
+}
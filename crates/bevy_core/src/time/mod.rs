@@ -1,6 +1,12 @@
+mod fixed_time;
+mod fixed_update;
+mod stopwatch;
 #[allow(clippy::module_inception)]
 mod time;
 mod timer;
 
+pub use fixed_time::*;
+pub use fixed_update::*;
+pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
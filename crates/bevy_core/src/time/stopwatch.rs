@@ -0,0 +1,81 @@
+use bevy_property::Properties;
+
+/// Tracks how much time has passed since it was started or reset, for things like lap times or
+/// how long a button has been held down.
+///
+/// Paused stopwatches will not have elapsed time increased.
+#[derive(Clone, Debug, Default, Properties)]
+pub struct Stopwatch {
+    elapsed: f32,
+    paused: bool,
+}
+
+impl Stopwatch {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Advances the stopwatch by `delta` seconds, unless it is paused.
+    pub fn tick(&mut self, delta: f32) -> &Self {
+        if !self.paused {
+            self.elapsed += delta;
+        }
+        self
+    }
+
+    /// Returns the time elapsed on the stopwatch, in seconds
+    #[inline]
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    #[inline]
+    pub fn set_elapsed(&mut self, elapsed: f32) {
+        self.elapsed = elapsed
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true
+    }
+
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stopwatch;
+
+    #[test]
+    fn test_stopwatch() {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.tick(0.5);
+        stopwatch.tick(0.25);
+        assert_eq!(stopwatch.elapsed(), 0.75);
+
+        stopwatch.pause();
+        stopwatch.tick(1.0);
+        assert_eq!(stopwatch.elapsed(), 0.75);
+
+        stopwatch.resume();
+        stopwatch.tick(1.0);
+        assert_eq!(stopwatch.elapsed(), 1.75);
+
+        stopwatch.reset();
+        assert_eq!(stopwatch.elapsed(), 0.0);
+        assert!(!stopwatch.is_paused());
+    }
+}
@@ -2,6 +2,11 @@ use bevy_ecs::ResMut;
 use bevy_utils::{Duration, Instant};
 
 /// Tracks elapsed time since the last update and since the App has started
+///
+/// The time can be paused and scaled with [Time::pause] and [Time::set_relative_speed]. `delta`
+/// and `seconds_since_startup` are scaled and stop while paused, so gameplay systems slow down
+/// and stop with them. The unscaled frame time is available with [Time::raw_delta], for things
+/// like menus that keep running while the game is paused.
 #[derive(Debug)]
 pub struct Time {
     pub delta: Duration,
@@ -10,6 +15,11 @@ pub struct Time {
     pub delta_seconds: f32,
     pub seconds_since_startup: f64,
     pub startup: Instant,
+    raw_delta: Duration,
+    paused: bool,
+    relative_speed: f64,
+    wrap_period: Duration,
+    frame_step: Option<Duration>,
 }
 
 impl Default for Time {
@@ -21,29 +31,210 @@ impl Default for Time {
             delta_seconds_f64: 0.0,
             seconds_since_startup: 0.0,
             delta_seconds: 0.0,
+            raw_delta: Duration::from_secs(0),
+            paused: false,
+            relative_speed: 1.0,
+            wrap_period: Duration::from_secs(3600),
+            frame_step: None,
         }
     }
 }
 
 impl Time {
     pub fn update(&mut self) {
-        let now = Instant::now();
-        if let Some(instant) = self.instant {
-            self.delta = now - instant;
+        self.update_with_instant(Instant::now());
+    }
+
+    pub fn update_with_instant(&mut self, now: Instant) {
+        let last_update = self.instant.unwrap_or(self.startup);
+        let scale = if self.paused {
+            0.0
+        } else {
+            self.relative_speed
+        };
+        let real_delta = now - last_update;
+        let step = match self.frame_step {
+            Some(frame_step) if self.instant.is_some() => frame_step,
+            _ => real_delta,
+        };
+        if self.instant.is_some() {
+            self.raw_delta = real_delta;
+            self.delta = step.mul_f64(scale);
             self.delta_seconds_f64 = self.delta.as_secs_f64();
             self.delta_seconds = self.delta.as_secs_f32();
         }
 
-        let duration_since_startup = now - self.startup;
-        self.seconds_since_startup = duration_since_startup.as_secs_f64();
+        self.seconds_since_startup += step.as_secs_f64() * scale;
         self.instant = Some(now);
     }
 
+    /// The real time since startup, which isn't affected by pausing or [Time::relative_speed]
     pub fn time_since_startup(&self) -> Duration {
         Instant::now() - self.startup
     }
+
+    /// Stops `delta` and `seconds_since_startup` from advancing, starting with the next update
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// How fast time passes compared to real time, `1.0` by default
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed as f32
+    }
+
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.relative_speed
+    }
+
+    /// Scales the time that passes from the next update on, e.g. `0.5` for slow motion
+    ///
+    /// # Panics
+    ///
+    /// Panics if `relative_speed` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, relative_speed: f32) {
+        self.set_relative_speed_f64(relative_speed as f64);
+    }
+
+    pub fn set_relative_speed_f64(&mut self, relative_speed: f64) {
+        assert!(
+            relative_speed.is_finite() && relative_speed >= 0.0,
+            "relative speed must be finite and not negative, got {}",
+            relative_speed
+        );
+        self.relative_speed = relative_speed;
+    }
+
+    /// The real time since the last update, which isn't affected by pausing or
+    /// [Time::relative_speed]
+    #[inline]
+    pub fn raw_delta(&self) -> Duration {
+        self.raw_delta
+    }
+
+    #[inline]
+    pub fn raw_delta_seconds(&self) -> f32 {
+        self.raw_delta.as_secs_f32()
+    }
+
+    #[inline]
+    pub fn raw_delta_seconds_f64(&self) -> f64 {
+        self.raw_delta.as_secs_f64()
+    }
+
+    /// `seconds_since_startup` wrapped to [Time::wrap_period]. An `f32` loses precision as time
+    /// goes on, so use this instead of casting `seconds_since_startup` when passing the time to
+    /// shaders.
+    #[inline]
+    pub fn seconds_since_startup_wrapped_f32(&self) -> f32 {
+        (self.seconds_since_startup % self.wrap_period.as_secs_f64()) as f32
+    }
+
+    /// The period after which [Time::seconds_since_startup_wrapped_f32] starts over at zero, one
+    /// hour by default
+    #[inline]
+    pub fn wrap_period(&self) -> Duration {
+        self.wrap_period
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `wrap_period` is zero.
+    #[inline]
+    pub fn set_wrap_period(&mut self, wrap_period: Duration) {
+        assert!(
+            wrap_period != Duration::from_secs(0),
+            "wrap period can't be zero"
+        );
+        self.wrap_period = wrap_period;
+    }
+
+    /// The time that passes every update regardless of the real time, see [Time::set_frame_step]
+    #[inline]
+    pub fn frame_step(&self) -> Option<Duration> {
+        self.frame_step
+    }
+
+    /// Advances the time by `frame_step` every update instead of by the real time that has passed,
+    /// for example to record a video that plays smoothly however long each frame takes to render.
+    /// [Time::raw_delta] is still the real time. `None` goes back to real time.
+    #[inline]
+    pub fn set_frame_step(&mut self, frame_step: Option<Duration>) {
+        self.frame_step = frame_step;
+    }
 }
 
 pub(crate) fn time_system(mut time: ResMut<Time>) {
     time.update();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+    use bevy_utils::{Duration, Instant};
+
+    #[test]
+    fn pause_and_scale() {
+        let start = Instant::now();
+        let mut time = Time {
+            startup: start,
+            ..Default::default()
+        };
+        time.update_with_instant(start);
+        time.update_with_instant(start + Duration::from_secs(1));
+        assert_eq!(time.delta, Duration::from_secs(1));
+        assert!((time.seconds_since_startup - 1.0).abs() < 1e-9);
+
+        time.set_relative_speed(0.5);
+        time.update_with_instant(start + Duration::from_secs(2));
+        assert_eq!(time.delta, Duration::from_millis(500));
+        assert_eq!(time.raw_delta(), Duration::from_secs(1));
+        assert!((time.seconds_since_startup - 1.5).abs() < 1e-9);
+
+        time.pause();
+        time.update_with_instant(start + Duration::from_secs(3));
+        assert_eq!(time.delta, Duration::from_secs(0));
+        assert_eq!(time.raw_delta(), Duration::from_secs(1));
+        assert!((time.seconds_since_startup - 1.5).abs() < 1e-9);
+
+        time.unpause();
+        time.set_wrap_period(Duration::from_secs(1));
+        time.update_with_instant(start + Duration::from_secs(4));
+        assert!((time.seconds_since_startup - 2.0).abs() < 1e-9);
+        assert!(time.seconds_since_startup_wrapped_f32() < 1e-6);
+    }
+
+    #[test]
+    fn frame_step() {
+        let start = Instant::now();
+        let mut time = Time {
+            startup: start,
+            ..Default::default()
+        };
+        time.update_with_instant(start);
+        time.set_frame_step(Some(Duration::from_millis(10)));
+        time.update_with_instant(start + Duration::from_secs(1));
+        assert_eq!(time.delta, Duration::from_millis(10));
+        assert_eq!(time.raw_delta(), Duration::from_secs(1));
+        assert!((time.seconds_since_startup - 0.01).abs() < 1e-9);
+
+        time.set_frame_step(None);
+        time.update_with_instant(start + Duration::from_secs(2));
+        assert_eq!(time.delta, Duration::from_secs(1));
+        assert!((time.seconds_since_startup - 1.01).abs() < 1e-9);
+    }
+}
@@ -14,6 +14,8 @@ pub struct Timer {
     finished: bool,
     /// Will only be true on the tick `duration` is reached or exceeded.
     just_finished: bool,
+    /// How many times `duration` was reached or exceeded on the last tick
+    times_finished: u32,
     paused: bool,
     repeating: bool,
 }
@@ -86,6 +88,14 @@ impl Timer {
         self.just_finished
     }
 
+    /// How many times the timer's duration was reached or exceeded on the last tick. This can be
+    /// more than one for repeating timers that are ticked by more than their duration, e.g. when
+    /// spawning things at a higher rate than the frame rate.
+    #[inline]
+    pub fn times_finished(&self) -> u32 {
+        self.times_finished
+    }
+
     #[inline]
     pub fn is_repeating(&self) -> bool {
         self.repeating
@@ -96,15 +106,30 @@ impl Timer {
         self.repeating = repeating
     }
 
-    /// Advances the timer by `delta` seconds.
+    /// Advances the timer by `delta` seconds, unless it is paused.
     pub fn tick(&mut self, delta: f32) -> &Self {
         let prev_finished = self.finished;
-        self.elapsed += delta;
+        if !self.paused {
+            self.elapsed += delta;
+        }
 
         self.finished = self.elapsed >= self.duration;
-        self.just_finished = !prev_finished && self.finished;
+        if self.repeating {
+            // Repeating timers finish again every time they wrap around
+            self.just_finished = self.finished;
+            self.times_finished = if !self.finished {
+                0
+            } else if self.duration > 0.0 {
+                (self.elapsed / self.duration) as u32
+            } else {
+                1
+            };
+        } else {
+            self.just_finished = !prev_finished && self.finished;
+            self.times_finished = self.just_finished as u32;
+        }
         if self.finished {
-            if self.repeating {
+            if self.repeating && self.duration > 0.0 {
                 // Repeating timers wrap around
                 self.elapsed %= self.duration;
             } else {
@@ -119,9 +144,16 @@ impl Timer {
     pub fn reset(&mut self) {
         self.finished = false;
         self.just_finished = false;
+        self.times_finished = 0;
         self.elapsed = 0.0;
     }
 
+    /// Returns the time left until the timer finishes, in seconds
+    #[inline]
+    pub fn remaining(&self) -> f32 {
+        self.duration - self.elapsed
+    }
+
     /// Percent timer has elapsed (goes from 0.0 to 1.0)
     pub fn percent(&self) -> f32 {
         self.elapsed / self.duration
@@ -192,4 +224,37 @@ mod tests {
         assert_eq!(t.percent(), 0.625);
         assert_eq!(t.percent_left(), 0.375);
     }
+
+    #[test]
+    fn test_times_finished_and_remaining() {
+        let mut t = Timer::from_seconds(1.0, true);
+        t.tick(3.5);
+        assert_eq!(t.times_finished(), 3);
+        assert_eq!(t.remaining(), 0.5);
+        // Finishing on consecutive ticks counts every time
+        t.tick(0.5);
+        assert_eq!(t.just_finished(), true);
+        assert_eq!(t.times_finished(), 1);
+        t.tick(0.25);
+        assert_eq!(t.times_finished(), 0);
+
+        let mut t = Timer::from_seconds(1.0, false);
+        t.tick(5.0);
+        assert_eq!(t.times_finished(), 1);
+        assert_eq!(t.remaining(), 0.0);
+        t.tick(5.0);
+        assert_eq!(t.times_finished(), 0);
+    }
+
+    #[test]
+    fn test_paused() {
+        let mut t = Timer::from_seconds(1.0, false);
+        t.pause();
+        t.tick(2.0);
+        assert_eq!(t.elapsed(), 0.0);
+        assert_eq!(t.finished, false);
+        t.resume();
+        t.tick(2.0);
+        assert_eq!(t.just_finished(), true);
+    }
 }
@@ -8,7 +8,9 @@ mod render_resource;
 mod render_resources;
 mod resource;
 mod shader_defs;
+mod std140;
 mod type_uuid;
+mod vertex;
 
 use proc_macro::TokenStream;
 
@@ -45,6 +47,20 @@ pub fn derive_shader_defs(input: TokenStream) -> TokenStream {
     shader_defs::derive_shader_defs(input)
 }
 
+/// Derives the Std140 trait, laying out the fields with the padding std140 uniform blocks require.
+/// Each field must implement Std140 or this will fail. This also implements Bytes with that layout.
+#[proc_macro_derive(Std140, attributes(as_crate))]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+    std140::derive_std140(input)
+}
+
+/// Derives the Vertex trait, which describes the struct as a vertex buffer with one attribute per
+/// field, named `Vertex_{FieldName}`. Each field must implement AsVertexFormats or this will fail.
+#[proc_macro_derive(Vertex, attributes(as_crate))]
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    vertex::derive_vertex(input)
+}
+
 /// Generates a dynamic plugin entry point function for the given `Plugin` type.  
 #[proc_macro_derive(DynamicPlugin)]
 pub fn derive_dynamic_plugin(input: TokenStream) -> TokenStream {
@@ -0,0 +1,67 @@
+use crate::modules::{get_modules, get_path};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, Path};
+
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => panic!("expected a struct with named fields"),
+    };
+
+    let modules = get_modules(&ast.attrs);
+    let bevy_render_path: Path = get_path(&modules.bevy_render);
+    let bevy_core_path: Path = get_path(&modules.bevy_core);
+    let std140_path = quote! { #bevy_render_path::pipeline::Std140 };
+    let align = quote! { #bevy_render_path::pipeline::std140_align };
+
+    let field_names = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+
+    let generics = ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let struct_name = &ast.ident;
+
+    TokenStream::from(quote! {
+        impl #impl_generics #std140_path for #struct_name#ty_generics #where_clause {
+            const ALIGNMENT: usize = #bevy_render_path::pipeline::STD140_STRUCT_ALIGNMENT;
+            const SIZE: usize = {
+                let offset = 0;
+                #(let offset = #align(offset, <#field_types as #std140_path>::ALIGNMENT)
+                    + <#field_types as #std140_path>::SIZE;)*
+                #align(offset, #bevy_render_path::pipeline::STD140_STRUCT_ALIGNMENT)
+            };
+
+            fn write_std140(&self, buffer: &mut [u8]) {
+                let mut offset: usize = 0;
+                #(offset = #align(offset, <#field_types as #std140_path>::ALIGNMENT);
+                let size = <#field_types as #std140_path>::SIZE;
+                #std140_path::write_std140(&self.#field_names, &mut buffer[offset..(offset + size)]);
+                offset += size;)*
+            }
+
+            fn uniform_property() -> #bevy_render_path::pipeline::UniformProperty {
+                #bevy_render_path::pipeline::UniformProperty::Struct(vec![
+                    #(<#field_types as #std140_path>::uniform_property(),)*
+                ])
+            }
+        }
+
+        impl #impl_generics #bevy_core_path::Bytes for #struct_name#ty_generics #where_clause {
+            fn write_bytes(&self, buffer: &mut [u8]) {
+                #std140_path::write_std140(self, buffer);
+            }
+            fn byte_len(&self) -> usize {
+                <Self as #std140_path>::SIZE
+            }
+        }
+    })
+}
@@ -0,0 +1,55 @@
+use crate::modules::{get_modules, get_path};
+use inflector::Inflector;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, Path};
+
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => panic!("expected a struct with named fields"),
+    };
+
+    let modules = get_modules(&ast.attrs);
+    let bevy_render_path: Path = get_path(&modules.bevy_render);
+    let as_vertex_formats = quote! { #bevy_render_path::pipeline::AsVertexFormats };
+
+    let field_names = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let attribute_names = field_names
+        .iter()
+        .map(|name| format!("Vertex_{}", name.to_string().to_pascal_case()));
+
+    let generics = ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let struct_name = &ast.ident;
+    let struct_name_string = struct_name.to_string();
+
+    TokenStream::from(quote! {
+        impl #impl_generics #bevy_render_path::pipeline::Vertex for #struct_name#ty_generics #where_clause {
+            fn vertex_buffer_descriptor() -> #bevy_render_path::pipeline::VertexBufferDescriptor {
+                let mut descriptor = #bevy_render_path::pipeline::VertexBufferDescriptor {
+                    name: #struct_name_string.into(),
+                    ..Default::default()
+                };
+                #(descriptor.push_attributes(#attribute_names, <#field_types as #as_vertex_formats>::as_vertex_formats());)*
+                descriptor
+            }
+
+            fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+                let mut offset: usize = 0;
+                #(let size = <#field_types as #as_vertex_formats>::vertex_byte_len();
+                #as_vertex_formats::write_vertex_bytes(&self.#field_names, &mut buffer[offset..(offset + size)]);
+                offset += size;)*
+            }
+        }
+    })
+}
@@ -60,8 +60,9 @@ impl Diagnostic {
         }
     }
 
+    /// The most recent measurement
     pub fn value(&self) -> Option<f64> {
-        self.history.back().map(|measurement| measurement.value)
+        self.history.front().map(|measurement| measurement.value)
     }
 
     pub fn sum(&self) -> f64 {
@@ -97,6 +98,21 @@ impl Diagnostic {
     pub fn get_max_history_length(&self) -> usize {
         self.max_history_length
     }
+
+    /// Iterates over the measurements in the history, from newest to oldest
+    pub fn measurements(&self) -> impl Iterator<Item = &DiagnosticMeasurement> {
+        self.history.iter()
+    }
+
+    /// Iterates over the measured values in the history, from newest to oldest
+    pub fn values(&self) -> impl Iterator<Item = &f64> {
+        self.history.iter().map(|measurement| &measurement.value)
+    }
+
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.sum = 0.0;
+    }
 }
 
 /// A collection of [Diagnostic]s
@@ -134,3 +150,28 @@ impl Diagnostics {
         self.diagnostics.values()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn history_is_rolling() {
+        let mut diagnostic = Diagnostic::new(DiagnosticId::default(), "test", 3);
+        for value in 1..=5 {
+            diagnostic.add_measurement(value as f64);
+        }
+
+        assert_eq!(diagnostic.history_len(), 3);
+        assert_eq!(diagnostic.value(), Some(5.0));
+        assert_eq!(
+            diagnostic.values().cloned().collect::<Vec<f64>>(),
+            vec![5.0, 4.0, 3.0]
+        );
+        assert_eq!(diagnostic.average(), Some(4.0));
+
+        diagnostic.clear_history();
+        assert_eq!(diagnostic.value(), None);
+        assert_eq!(diagnostic.sum(), 0.0);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{Diagnostic, DiagnosticId, Diagnostics};
+use bevy_app::prelude::*;
+use bevy_ecs::{ResMut, Resources, World};
+use bevy_utils::HashMap;
+use std::any::TypeId;
+
+/// Adds "entity count" and "archetype count" diagnostics to an App, and optionally a diagnostic
+/// counting the entities that have each component type
+pub struct EntityCountDiagnosticsPlugin {
+    /// Whether to add a "component count" diagnostic for every component type in the World
+    pub component_counts: bool,
+}
+
+impl Default for EntityCountDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            component_counts: true,
+        }
+    }
+}
+
+/// State used by the [EntityCountDiagnosticsPlugin]
+#[derive(Default)]
+pub struct EntityCountDiagnosticsState {
+    component_counts: bool,
+    component_diagnostic_ids: HashMap<TypeId, DiagnosticId>,
+}
+
+impl Plugin for EntityCountDiagnosticsPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.add_startup_system(Self::setup_system)
+            .add_resource(EntityCountDiagnosticsState {
+                component_counts: self.component_counts,
+                ..Default::default()
+            })
+            .add_system(Self::diagnostic_system);
+    }
+}
+
+impl EntityCountDiagnosticsPlugin {
+    pub const ENTITY_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(187513512115068938494459732780662867798);
+    pub const ARCHETYPE_COUNT: DiagnosticId =
+        DiagnosticId::from_u128(266720939548541284128624963522440226512);
+
+    /// The prefix of every component count diagnostic's name
+    pub const COMPONENT_COUNT_PREFIX: &'static str = "component_count/";
+
+    pub fn setup_system(mut diagnostics: ResMut<Diagnostics>) {
+        diagnostics.add(Diagnostic::new(Self::ENTITY_COUNT, "entity_count", 20));
+        diagnostics.add(Diagnostic::new(Self::ARCHETYPE_COUNT, "archetype_count", 1));
+    }
+
+    pub fn diagnostic_system(world: &mut World, resources: &mut Resources) {
+        let mut diagnostics = resources.get_mut::<Diagnostics>().unwrap();
+        let mut state = resources.get_mut::<EntityCountDiagnosticsState>().unwrap();
+
+        let mut entity_count = 0;
+        let mut component_counts = HashMap::<TypeId, (&'static str, usize)>::default();
+        for archetype in world.archetypes() {
+            entity_count += archetype.len();
+            if state.component_counts {
+                for type_info in archetype.types() {
+                    component_counts
+                        .entry(type_info.id())
+                        .or_insert((type_info.type_name(), 0))
+                        .1 += archetype.len();
+                }
+            }
+        }
+
+        diagnostics.add_measurement(Self::ENTITY_COUNT, entity_count as f64);
+        diagnostics.add_measurement(Self::ARCHETYPE_COUNT, world.archetypes().len() as f64);
+
+        for (type_id, (type_name, count)) in component_counts.iter() {
+            let diagnostic_id = if let Some(id) = state.component_diagnostic_ids.get(type_id) {
+                *id
+            } else {
+                let diagnostic = Diagnostic::new(
+                    DiagnosticId::default(),
+                    &format!("{}{}", Self::COMPONENT_COUNT_PREFIX, type_name),
+                    1,
+                );
+                let id = diagnostic.id;
+                diagnostics.add(diagnostic);
+                state.component_diagnostic_ids.insert(*type_id, id);
+                id
+            };
+            diagnostics.add_measurement(diagnostic_id, *count as f64);
+        }
+
+        // components that no entity has anymore are reported as zero
+        for (type_id, diagnostic_id) in state.component_diagnostic_ids.iter() {
+            if !component_counts.contains_key(type_id) {
+                diagnostics.add_measurement(*diagnostic_id, 0.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts_entities_and_components() {
+        let mut world = World::new();
+        let mut resources = Resources::default();
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.add(Diagnostic::new(
+            EntityCountDiagnosticsPlugin::ENTITY_COUNT,
+            "entity_count",
+            1,
+        ));
+        resources.insert(diagnostics);
+        resources.insert(EntityCountDiagnosticsState {
+            component_counts: true,
+            ..Default::default()
+        });
+
+        world.spawn((1u32,));
+        world.spawn((2u32, 1.0f32));
+        world.spawn((1.0f32,));
+        EntityCountDiagnosticsPlugin::diagnostic_system(&mut world, &mut resources);
+
+        let component_count = |diagnostics: &Diagnostics, name: &str| {
+            diagnostics
+                .iter()
+                .find(|diagnostic| {
+                    diagnostic.name
+                        == format!(
+                            "{}{}",
+                            EntityCountDiagnosticsPlugin::COMPONENT_COUNT_PREFIX,
+                            name
+                        )
+                })
+                .and_then(|diagnostic| diagnostic.value())
+        };
+        {
+            let diagnostics = resources.get::<Diagnostics>().unwrap();
+            let entity_count = diagnostics.get(EntityCountDiagnosticsPlugin::ENTITY_COUNT);
+            assert_eq!(entity_count.and_then(|d| d.value()), Some(3.0));
+            assert_eq!(component_count(&diagnostics, "u32"), Some(2.0));
+            assert_eq!(component_count(&diagnostics, "f32"), Some(2.0));
+        }
+
+        world.clear();
+        EntityCountDiagnosticsPlugin::diagnostic_system(&mut world, &mut resources);
+        let diagnostics = resources.get::<Diagnostics>().unwrap();
+        assert_eq!(component_count(&diagnostics, "u32"), Some(0.0));
+    }
+}
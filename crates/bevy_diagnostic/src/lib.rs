@@ -1,9 +1,15 @@
 mod diagnostic;
+mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
-mod print_diagnostics_plugin;
+mod log_diagnostics_plugin;
+mod system_time_diagnostics_plugin;
 pub use diagnostic::*;
+pub use entity_count_diagnostics_plugin::{
+    EntityCountDiagnosticsPlugin, EntityCountDiagnosticsState,
+};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
-pub use print_diagnostics_plugin::PrintDiagnosticsPlugin;
+pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+pub use system_time_diagnostics_plugin::{SystemTimeDiagnosticsPlugin, SystemTimeDiagnosticsState};
 
 use bevy_app::prelude::*;
 
@@ -0,0 +1,103 @@
+use super::{Diagnostic, DiagnosticId, Diagnostics};
+use bevy_app::prelude::*;
+use bevy_core::{Time, Timer};
+use bevy_ecs::{Res, ResMut};
+use bevy_log::{debug, info};
+use bevy_utils::Duration;
+
+/// An App Plugin that logs diagnostics to the console
+pub struct LogDiagnosticsPlugin {
+    pub debug: bool,
+    pub wait_duration: Duration,
+    pub filter: Option<Vec<DiagnosticId>>,
+}
+
+/// State used by the [LogDiagnosticsPlugin]
+pub struct LogDiagnosticsState {
+    timer: Timer,
+    filter: Option<Vec<DiagnosticId>>,
+}
+
+impl Default for LogDiagnosticsPlugin {
+    fn default() -> Self {
+        LogDiagnosticsPlugin {
+            debug: false,
+            wait_duration: Duration::from_secs(1),
+            filter: None,
+        }
+    }
+}
+
+impl Plugin for LogDiagnosticsPlugin {
+    fn build(&self, app: &mut bevy_app::AppBuilder) {
+        app.add_resource(LogDiagnosticsState {
+            timer: Timer::new(self.wait_duration, true),
+            filter: self.filter.clone(),
+        });
+
+        if self.debug {
+            app.add_system_to_stage(stage::POST_UPDATE, Self::log_diagnostics_debug_system);
+        } else {
+            app.add_system_to_stage(stage::POST_UPDATE, Self::log_diagnostics_system);
+        }
+    }
+}
+
+impl LogDiagnosticsPlugin {
+    pub fn filtered(filter: Vec<DiagnosticId>) -> Self {
+        LogDiagnosticsPlugin {
+            filter: Some(filter),
+            ..Default::default()
+        }
+    }
+
+    fn log_diagnostic(diagnostic: &Diagnostic) {
+        if let Some(value) = diagnostic.value() {
+            if let Some(average) = diagnostic.average() {
+                info!(
+                    "{:<65}: {:<10.6}  (avg {:.6})",
+                    diagnostic.name, value, average
+                );
+            } else {
+                info!("{:<65}: {:<10.6}", diagnostic.name, value);
+            }
+        }
+    }
+
+    /// Iterates over the diagnostics that pass the filter. Filtered diagnostics that haven't been
+    /// registered yet are skipped.
+    fn filtered_diagnostics<'a>(
+        filter: &'a Option<Vec<DiagnosticId>>,
+        diagnostics: &'a Diagnostics,
+    ) -> Box<dyn Iterator<Item = &'a Diagnostic> + 'a> {
+        if let Some(filter) = filter {
+            Box::new(filter.iter().filter_map(move |id| diagnostics.get(*id)))
+        } else {
+            Box::new(diagnostics.iter())
+        }
+    }
+
+    pub fn log_diagnostics_system(
+        mut state: ResMut<LogDiagnosticsState>,
+        time: Res<Time>,
+        diagnostics: Res<Diagnostics>,
+    ) {
+        if state.timer.tick(time.raw_delta_seconds()).is_finished() {
+            for diagnostic in Self::filtered_diagnostics(&state.filter, &diagnostics) {
+                Self::log_diagnostic(diagnostic);
+            }
+        }
+    }
+
+    pub fn log_diagnostics_debug_system(
+        mut state: ResMut<LogDiagnosticsState>,
+        time: Res<Time>,
+        diagnostics: Res<Diagnostics>,
+    ) {
+        if state.timer.tick(time.raw_delta_seconds()).is_finished() {
+            for diagnostic in Self::filtered_diagnostics(&state.filter, &diagnostics) {
+                debug!("{:#?}\n", diagnostic);
+            }
+        }
+    }
+}
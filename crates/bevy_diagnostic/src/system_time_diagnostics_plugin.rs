@@ -0,0 +1,53 @@
+use crate::{Diagnostic, DiagnosticId, Diagnostics};
+use bevy_app::prelude::*;
+use bevy_ecs::{Local, Res, ResMut, SystemTimings};
+use bevy_utils::HashMap;
+use std::borrow::Cow;
+
+/// Adds a "system time" diagnostic for every system in the App, which records how many seconds
+/// the system spent running each frame. Timing systems has a small cost, so this plugin should
+/// only be added while profiling.
+#[derive(Default)]
+pub struct SystemTimeDiagnosticsPlugin;
+
+/// State used by the [SystemTimeDiagnosticsPlugin]
+#[derive(Default)]
+pub struct SystemTimeDiagnosticsState {
+    diagnostic_ids: HashMap<Cow<'static, str>, DiagnosticId>,
+}
+
+impl Plugin for SystemTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        // the executor only times systems while this resource exists
+        app.add_resource(SystemTimings::default())
+            .add_system_to_stage(stage::LAST, Self::diagnostic_system);
+    }
+}
+
+impl SystemTimeDiagnosticsPlugin {
+    /// The prefix of every system time diagnostic's name
+    pub const NAME_PREFIX: &'static str = "system_time/";
+
+    pub fn diagnostic_system(
+        mut state: Local<SystemTimeDiagnosticsState>,
+        mut diagnostics: ResMut<Diagnostics>,
+        timings: Res<SystemTimings>,
+    ) {
+        for (system_name, duration) in timings.drain() {
+            let diagnostic_id = if let Some(id) = state.diagnostic_ids.get(&system_name) {
+                *id
+            } else {
+                let diagnostic = Diagnostic::new(
+                    DiagnosticId::default(),
+                    &format!("{}{}", Self::NAME_PREFIX, system_name),
+                    20,
+                );
+                let id = diagnostic.id;
+                diagnostics.add(diagnostic);
+                state.diagnostic_ids.insert(system_name, id);
+                id
+            };
+            diagnostics.add_measurement(diagnostic_id, duration.as_secs_f64());
+        }
+    }
+}
@@ -48,17 +48,11 @@ impl Archetype {
     fn assert_type_info(types: &[TypeInfo]) {
         types.windows(2).for_each(|x| match x[0].cmp(&x[1]) {
             core::cmp::Ordering::Less => (),
-            #[cfg(debug_assertions)]
             core::cmp::Ordering::Equal => panic!(
                 "attempted to allocate entity with duplicate {} components; \
                  each type must occur at most once!",
                 x[0].type_name
             ),
-            #[cfg(not(debug_assertions))]
-            core::cmp::Ordering::Equal => panic!(
-                "attempted to allocate entity with duplicate components; \
-                 each type must occur at most once!"
-            ),
             core::cmp::Ordering::Greater => panic!("type info is unsorted"),
         });
     }
@@ -484,7 +478,6 @@ pub struct TypeInfo {
     id: TypeId,
     layout: Layout,
     drop: unsafe fn(*mut u8),
-    #[cfg(debug_assertions)]
     type_name: &'static str,
 }
 
@@ -499,7 +492,6 @@ impl TypeInfo {
             id: TypeId::of::<T>(),
             layout: Layout::new::<T>(),
             drop: drop_ptr::<T>,
-            #[cfg(debug_assertions)]
             type_name: core::any::type_name::<T>(),
         }
     }
@@ -516,6 +508,12 @@ impl TypeInfo {
         self.layout
     }
 
+    #[allow(missing_docs)]
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
     pub(crate) unsafe fn drop(&self, data: *mut u8) {
         (self.drop)(data)
     }
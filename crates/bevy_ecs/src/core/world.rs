@@ -542,8 +542,14 @@ impl World {
 
     #[allow(missing_docs)]
     pub fn removed<C: Component>(&self) -> &[Entity] {
+        self.removed_with_type_id(TypeId::of::<C>())
+    }
+
+    /// Returns the entities whose component of type `ty` was removed, or which were despawned with
+    /// one, since the last call to `clear_trackers`
+    pub fn removed_with_type_id(&self, ty: TypeId) -> &[Entity] {
         self.removed_components
-            .get(&TypeId::of::<C>())
+            .get(&ty)
             .map_or(&[], |entities| entities.as_slice())
     }
 
@@ -1,6 +1,8 @@
 mod parallel_executor;
 #[allow(clippy::module_inception)]
 mod schedule;
+mod system_timings;
 
 pub use parallel_executor::*;
 pub use schedule::*;
+pub use system_timings::*;
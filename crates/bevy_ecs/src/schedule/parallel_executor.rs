@@ -1,4 +1,4 @@
-use super::Schedule;
+use super::{Schedule, SystemTimings};
 use crate::{
     resource::Resources,
     system::{System, ThreadLocalExecution},
@@ -7,7 +7,10 @@ use crate::{
 use bevy_tasks::{ComputeTaskPool, CountdownEvent, TaskPool};
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
-use bevy_utils::tracing::trace;
+use bevy_utils::{
+    tracing::{info, trace},
+    Instant,
+};
 use fixedbitset::FixedBitSet;
 use std::ops::Range;
 
@@ -83,19 +86,19 @@ impl ParallelExecutor {
     }
 
     pub fn print_order(&self, schedule: &Schedule) {
-        println!("----------------------------");
+        info!("----------------------------");
         for (stage_name, executor_stage) in schedule.stage_order.iter().zip(self.stages.iter()) {
-            println!("stage {:?}", stage_name);
+            info!("stage {:?}", stage_name);
             if let Some(stage_systems) = schedule.stages.get(stage_name) {
                 for (i, system) in stage_systems.iter().enumerate() {
-                    println!("  {}-{}", i, system.name());
-                    println!(
+                    info!("  {}-{}", i, system.name());
+                    info!(
                         "      dependencies({:?})",
                         executor_stage.system_dependencies[i]
                             .ones()
                             .collect::<Vec<usize>>()
                     );
-                    println!(
+                    info!(
                         "      dependants({:?})",
                         executor_stage.system_dependents[i]
                     );
@@ -332,6 +335,7 @@ impl ExecutorStage {
         systems: &mut [Box<dyn System<Input = (), Output = ()>>],
         prepared_system_range: Range<usize>,
         compute_pool: &TaskPool,
+        timings: Option<&SystemTimings>,
     ) {
         // Generate tasks for systems in the given range and block until they are complete
         trace!("running systems {:?}", prepared_system_range);
@@ -402,10 +406,16 @@ impl ExecutorStage {
                         #[cfg(feature = "trace")]
                         let _system_guard = system_span.enter();
 
+                        let start = timings.map(|_| Instant::now());
+
                         // SAFETY: scheduler ensures safe world / resource access
                         unsafe {
                             system.run_unsafe((), world_ref, resources_ref);
                         }
+
+                        if let (Some(timings), Some(start)) = (timings, start) {
+                            timings.record(system.name(), start.elapsed());
+                        }
                     }
 
                     // Notify dependents that this task is done
@@ -427,6 +437,7 @@ impl ExecutorStage {
     ) {
         let start_archetypes_generation = world.archetypes_generation();
         let compute_pool = resources.get_cloned::<ComputeTaskPool>().unwrap();
+        let timings = resources.get_cloned::<SystemTimings>();
 
         // if the schedule has changed, clear executor state / fill it with new defaults
         // This is mostly zeroing out a bunch of arrays parallel to the systems array. They will get
@@ -481,6 +492,7 @@ impl ExecutorStage {
                 systems,
                 prepared_system_range,
                 &*compute_pool,
+                timings.as_ref(),
             );
         }
 
@@ -502,8 +514,12 @@ impl ExecutorStage {
                 #[cfg(feature = "trace")]
                 let _system_guard = system_span.enter();
 
+                let start = timings.as_ref().map(|_| Instant::now());
                 system.run((), world, resources);
                 system.run_thread_local(world, resources);
+                if let (Some(timings), Some(start)) = (&timings, start) {
+                    timings.record(system.name(), start.elapsed());
+                }
             }
 
             // Now that the previous thread local system has run, time to advance to the next one
@@ -524,6 +540,7 @@ impl ExecutorStage {
                 systems,
                 run_ready_system_index_range,
                 &*compute_pool,
+                timings.as_ref(),
             );
         }
 
@@ -535,7 +552,13 @@ impl ExecutorStage {
                     let system_span = info_span!("system", name = system.name().as_ref());
                     #[cfg(feature = "trace")]
                     let _system_guard = system_span.enter();
+
+                    // applying a system's commands counts towards the time spent in the system
+                    let start = timings.as_ref().map(|_| Instant::now());
                     system.run_thread_local(world, resources);
+                    if let (Some(timings), Some(start)) = (&timings, start) {
+                        timings.record(system.name(), start.elapsed());
+                    }
                 }
                 ThreadLocalExecution::Immediate => { /* already ran */ }
             }
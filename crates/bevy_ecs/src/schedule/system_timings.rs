@@ -0,0 +1,70 @@
+use bevy_utils::{Duration, HashMap};
+use parking_lot::Mutex;
+use std::{borrow::Cow, sync::Arc};
+
+/// Collects the wall-clock time each system spends running.
+///
+/// Measuring systems is opt-in: the [ParallelExecutor](super::ParallelExecutor) only times systems
+/// while this resource exists. Timings accumulate until they are drained, so whoever inserts this
+/// resource should call [SystemTimings::drain] once per frame.
+#[derive(Debug, Clone, Default)]
+pub struct SystemTimings {
+    timings: Arc<Mutex<HashMap<Cow<'static, str>, Duration>>>,
+}
+
+impl SystemTimings {
+    /// Adds `duration` to the time spent in the system called `system_name`. Systems that are
+    /// scheduled more than once are reported as a single system.
+    pub fn record(&self, system_name: Cow<'static, str>, duration: Duration) {
+        *self.timings.lock().entry(system_name).or_default() += duration;
+    }
+
+    /// Returns the time spent in each system since the last drain and resets the timings
+    pub fn drain(&self) -> Vec<(Cow<'static, str>, Duration)> {
+        self.timings.lock().drain().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SystemTimings;
+    use crate::{
+        resource::Resources,
+        schedule::{ParallelExecutor, Schedule},
+        system::Query,
+        Entity, World,
+    };
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    #[test]
+    fn executor_records_system_timings() {
+        fn slow_system(_entities: Query<Entity>) {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        fn other_system(_entities: Query<Entity>) {}
+
+        let mut world = World::new();
+        let mut resources = Resources::default();
+        resources.insert(ComputeTaskPool(TaskPool::default()));
+        resources.insert(SystemTimings::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("update");
+        schedule.add_system_to_stage("update", slow_system);
+        schedule.add_system_to_stage("update", other_system);
+
+        let mut executor = ParallelExecutor::default();
+        schedule.initialize(&mut world, &mut resources);
+        executor.run(&mut schedule, &mut world, &mut resources);
+
+        let mut timings = resources.get::<SystemTimings>().unwrap().drain();
+        timings.sort_by(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(timings.len(), 2);
+        assert!(timings[0].0.ends_with("other_system"));
+        assert!(timings[1].0.ends_with("slow_system"));
+        assert!(timings[1].1 >= std::time::Duration::from_millis(1));
+
+        // timings are reset once drained
+        assert!(resources.get::<SystemTimings>().unwrap().drain().is_empty());
+    }
+}
@@ -1,27 +1,59 @@
+#[cfg(not(target_arch = "wasm32"))]
 mod converter;
+#[cfg(not(target_arch = "wasm32"))]
 mod gilrs_system;
+#[cfg(target_arch = "wasm32")]
+mod web_gamepad_system;
 
-use bevy_app::{prelude::*, startup_stage::PRE_STARTUP};
-use bevy_utils::tracing::error;
-use gilrs::GilrsBuilder;
-use gilrs_system::{gilrs_event_startup_system, gilrs_event_system};
+use bevy_app::{prelude::*, Capabilities};
 
+/// The name of the gamepad input [Capabilities]. When it is disabled, no gamepad events are sent.
+pub const GAMEPAD_CAPABILITY: &str = "gamepad";
+
+/// Sends gamepad events using Gilrs on native platforms and the browser Gamepad API on wasm
 #[derive(Default)]
 pub struct GilrsPlugin;
 
 impl Plugin for GilrsPlugin {
     fn build(&self, app: &mut AppBuilder) {
-        match GilrsBuilder::new()
-            .with_default_filters(false)
-            .set_update_state(false)
-            .build()
+        if !app
+            .resources_mut()
+            .get_or_insert_with(Capabilities::default)
+            .is_enabled(GAMEPAD_CAPABILITY)
         {
-            Ok(gilrs) => {
-                app.add_thread_local_resource(gilrs)
-                    .add_startup_system_to_stage(PRE_STARTUP, gilrs_event_startup_system)
-                    .add_system_to_stage(stage::PRE_EVENT, gilrs_event_system);
-            }
-            Err(err) => error!("Failed to start Gilrs. {}", err),
+            return;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        add_gilrs(app);
+        #[cfg(target_arch = "wasm32")]
+        app.add_system_to_stage(stage::PRE_EVENT, web_gamepad_system::web_gamepad_system);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn add_gilrs(app: &mut AppBuilder) {
+    use bevy_app::startup_stage::PRE_STARTUP;
+    use bevy_utils::tracing::error;
+    use gilrs::GilrsBuilder;
+    use gilrs_system::{gilrs_event_startup_system, gilrs_event_system};
+
+    match GilrsBuilder::new()
+        .with_default_filters(false)
+        .set_update_state(false)
+        .build()
+    {
+        Ok(gilrs) => {
+            app.add_thread_local_resource(gilrs)
+                .add_startup_system_to_stage(PRE_STARTUP, gilrs_event_startup_system)
+                .add_system_to_stage(stage::PRE_EVENT, gilrs_event_system);
+        }
+        Err(err) => {
+            error!("Failed to start Gilrs. {}", err);
+            app.resources_mut()
+                .get_mut::<Capabilities>()
+                .unwrap()
+                .set_unavailable(GAMEPAD_CAPABILITY);
         }
     }
 }
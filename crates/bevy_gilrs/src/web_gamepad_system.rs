@@ -0,0 +1,131 @@
+use bevy_app::Events;
+use bevy_ecs::{Local, ResMut};
+use bevy_input::{gamepad::GamepadEventRaw, prelude::*};
+use bevy_utils::{tracing::warn, HashMap};
+use wasm_bindgen::JsCast;
+use web_sys::GamepadMappingType;
+
+/// The button layout of gamepads with the browser's
+/// ["standard" mapping](https://www.w3.org/TR/gamepad/#remapping)
+const STANDARD_BUTTONS: [GamepadButtonType; 17] = [
+    GamepadButtonType::South,
+    GamepadButtonType::East,
+    GamepadButtonType::West,
+    GamepadButtonType::North,
+    GamepadButtonType::LeftTrigger,
+    GamepadButtonType::RightTrigger,
+    GamepadButtonType::LeftTrigger2,
+    GamepadButtonType::RightTrigger2,
+    GamepadButtonType::Select,
+    GamepadButtonType::Start,
+    GamepadButtonType::LeftThumb,
+    GamepadButtonType::RightThumb,
+    GamepadButtonType::DPadUp,
+    GamepadButtonType::DPadDown,
+    GamepadButtonType::DPadLeft,
+    GamepadButtonType::DPadRight,
+    GamepadButtonType::Mode,
+];
+
+/// The axis layout of gamepads with the browser's "standard" mapping. The browser reports the
+/// y axes pointing down, so they are flipped to match Gilrs.
+const STANDARD_AXES: [(GamepadAxisType, f32); 4] = [
+    (GamepadAxisType::LeftStickX, 1.0),
+    (GamepadAxisType::LeftStickY, -1.0),
+    (GamepadAxisType::RightStickX, 1.0),
+    (GamepadAxisType::RightStickY, -1.0),
+];
+
+#[derive(Default)]
+pub struct WebGamepadState {
+    gamepads: HashMap<usize, GamepadSnapshot>,
+}
+
+#[derive(Default)]
+struct GamepadSnapshot {
+    buttons: [f32; STANDARD_BUTTONS.len()],
+    axes: [f32; STANDARD_AXES.len()],
+}
+
+/// Polls the browser Gamepad API and sends the changes since the last poll as gamepad events.
+///
+/// Browsers only expose a gamepad once a button has been pressed on it. Only gamepads with the
+/// "standard" mapping send button and axis events, because the layout of other gamepads is
+/// unknown.
+pub fn web_gamepad_system(
+    mut state: Local<WebGamepadState>,
+    mut events: ResMut<Events<GamepadEventRaw>>,
+) {
+    let gamepads = match web_sys::window().map(|window| window.navigator().get_gamepads()) {
+        Some(Ok(gamepads)) => gamepads,
+        _ => return,
+    };
+
+    let mut connected = Vec::new();
+    for gamepad in gamepads.iter() {
+        let gamepad = match gamepad.dyn_into::<web_sys::Gamepad>() {
+            Ok(gamepad) if gamepad.connected() => gamepad,
+            _ => continue,
+        };
+        let id = gamepad.index() as usize;
+        connected.push(id);
+
+        if !state.gamepads.contains_key(&id) {
+            events.send(GamepadEventRaw(Gamepad(id), GamepadEventType::Connected));
+            if gamepad.mapping() != GamepadMappingType::Standard {
+                warn!(
+                    "Gamepad \"{}\" has no standard mapping, its input is ignored",
+                    gamepad.id()
+                );
+            }
+        }
+        let snapshot = state.gamepads.entry(id).or_default();
+        if gamepad.mapping() != GamepadMappingType::Standard {
+            continue;
+        }
+
+        for (button, value) in gamepad.buttons().iter().enumerate() {
+            let value = match value.dyn_into::<web_sys::GamepadButton>() {
+                Ok(value) => value.value() as f32,
+                Err(_) => continue,
+            };
+            if let Some(button_type) = STANDARD_BUTTONS.get(button) {
+                if snapshot.buttons[button] != value {
+                    snapshot.buttons[button] = value;
+                    events.send(GamepadEventRaw(
+                        Gamepad(id),
+                        GamepadEventType::ButtonChanged(*button_type, value),
+                    ));
+                }
+            }
+        }
+
+        for (axis, value) in gamepad.axes().iter().enumerate() {
+            let value = match value.as_f64() {
+                Some(value) => value as f32,
+                None => continue,
+            };
+            if let Some((axis_type, direction)) = STANDARD_AXES.get(axis) {
+                let value = value * direction;
+                if snapshot.axes[axis] != value {
+                    snapshot.axes[axis] = value;
+                    events.send(GamepadEventRaw(
+                        Gamepad(id),
+                        GamepadEventType::AxisChanged(*axis_type, value),
+                    ));
+                }
+            }
+        }
+    }
+
+    state.gamepads.retain(|id, _| {
+        let is_connected = connected.contains(id);
+        if !is_connected {
+            events.send(GamepadEventRaw(
+                Gamepad(*id),
+                GamepadEventType::Disconnected,
+            ));
+        }
+        is_connected
+    });
+}
@@ -0,0 +1,160 @@
+use bevy_math::{Mat4, Quat, Vec3};
+use bevy_render::{color::Color, primitives::Aabb};
+use bevy_transform::components::GlobalTransform;
+
+/// The number of line segments of circles and spheres
+const CIRCLE_SEGMENTS: usize = 32;
+
+/// Draws debug lines and wireframe shapes for one frame. Gizmos drawn before the POST_UPDATE
+/// stage are rendered in the same frame, later ones in the next frame. Use it from any system
+/// with `ResMut<Gizmos>`.
+#[derive(Debug, Default)]
+pub struct Gizmos {
+    positions: Vec<[f32; 3]>,
+    colors: Vec<[f32; 4]>,
+}
+
+impl Gizmos {
+    pub fn line(&mut self, start: Vec3, end: Vec3, color: Color) -> &mut Self {
+        let color = color.as_linear_rgba_f32();
+        self.positions.push(start.into());
+        self.positions.push(end.into());
+        self.colors.push(color);
+        self.colors.push(color);
+        self
+    }
+
+    /// Draws a line from `origin` to `origin + vector`
+    pub fn ray(&mut self, origin: Vec3, vector: Vec3, color: Color) -> &mut Self {
+        self.line(origin, origin + vector, color)
+    }
+
+    /// Draws a circle around `center`, in the plane facing `normal`
+    pub fn circle(&mut self, center: Vec3, normal: Vec3, radius: f32, color: Color) -> &mut Self {
+        // two axes of the circle's plane
+        let normal = normal.normalize();
+        let other = if normal.x.abs() < 0.9 {
+            Vec3::unit_x()
+        } else {
+            Vec3::unit_y()
+        };
+        let u = normal.cross(other).normalize();
+        let v = normal.cross(u);
+        let point = |index: usize| {
+            let angle = index as f32 / CIRCLE_SEGMENTS as f32 * std::f32::consts::PI * 2.0;
+            center + (u * angle.cos() + v * angle.sin()) * radius
+        };
+        for index in 0..CIRCLE_SEGMENTS {
+            self.line(point(index), point(index + 1), color);
+        }
+        self
+    }
+
+    /// Draws a wireframe sphere as three circles around its axes
+    pub fn sphere(&mut self, center: Vec3, radius: f32, color: Color) -> &mut Self {
+        self.circle(center, Vec3::unit_x(), radius, color)
+            .circle(center, Vec3::unit_y(), radius, color)
+            .circle(center, Vec3::unit_z(), radius, color)
+    }
+
+    /// Draws the edges of a unit cube centered on the origin, moved by `transform`
+    pub fn cuboid(&mut self, transform: &GlobalTransform, color: Color) -> &mut Self {
+        self.box_edges(transform.compute_matrix(), color)
+    }
+
+    /// Draws the edges of an axis-aligned bounding box
+    pub fn aabb(&mut self, aabb: &Aabb, color: Color) -> &mut Self {
+        let matrix = Mat4::from_scale_rotation_translation(
+            aabb.half_extents * 2.0,
+            Quat::identity(),
+            aabb.center,
+        );
+        self.box_edges(matrix, color)
+    }
+
+    /// Draws the local x, y and z axes of `transform` in red, green and blue
+    pub fn axes(&mut self, transform: &GlobalTransform, length: f32) -> &mut Self {
+        let origin = transform.translation;
+        self.ray(
+            origin,
+            transform.rotation * Vec3::unit_x() * length,
+            Color::RED,
+        )
+        .ray(
+            origin,
+            transform.rotation * Vec3::unit_y() * length,
+            Color::GREEN,
+        )
+        .ray(
+            origin,
+            transform.rotation * Vec3::unit_z() * length,
+            Color::BLUE,
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// The number of lines drawn this frame
+    pub fn len(&self) -> usize {
+        self.positions.len() / 2
+    }
+
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.colors.clear();
+    }
+
+    /// Returns the positions and colors of the lines' vertices and clears the gizmos
+    pub(crate) fn take_vertices(&mut self) -> (Vec<[f32; 3]>, Vec<[f32; 4]>) {
+        (
+            std::mem::take(&mut self.positions),
+            std::mem::take(&mut self.colors),
+        )
+    }
+
+    /// Draws the edges of the unit cube centered on the origin, transformed by `matrix`
+    fn box_edges(&mut self, matrix: Mat4, color: Color) -> &mut Self {
+        let corner = |index: usize| {
+            let corner = Vec3::new(
+                if index & 1 == 0 { -0.5 } else { 0.5 },
+                if index & 2 == 0 { -0.5 } else { 0.5 },
+                if index & 4 == 0 { -0.5 } else { 0.5 },
+            );
+            matrix.transform_point3(corner)
+        };
+        // corners whose indices differ in one bit share an edge
+        for index in 0..8 {
+            for bit in &[1, 2, 4] {
+                if index & bit == 0 {
+                    self.line(corner(index), corner(index | bit), color);
+                }
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shapes_draw_their_edges() {
+        let mut gizmos = Gizmos::default();
+        gizmos.cuboid(&GlobalTransform::identity(), Color::WHITE);
+        assert_eq!(gizmos.len(), 12);
+
+        gizmos.clear();
+        gizmos.sphere(Vec3::zero(), 1.0, Color::WHITE);
+        assert_eq!(gizmos.len(), 3 * CIRCLE_SEGMENTS);
+
+        // every point of the circles is on the sphere
+        let (positions, _) = gizmos.take_vertices();
+        for position in positions.iter().map(|position| Vec3::from(*position)) {
+            assert!((position.length() - 1.0).abs() < 1e-5);
+        }
+        assert!(gizmos.is_empty());
+    }
+}
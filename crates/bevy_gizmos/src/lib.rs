@@ -0,0 +1,29 @@
+mod gizmos;
+pub mod render;
+
+pub use gizmos::*;
+
+pub mod prelude {
+    pub use crate::Gizmos;
+}
+
+use bevy_app::prelude::*;
+use bevy_asset::Assets;
+use bevy_render::{pipeline::PipelineDescriptor, shader::Shader};
+use render::{build_gizmo_pipeline, gizmo_system, GIZMO_PIPELINE_HANDLE};
+
+/// Adds the [Gizmos] resource and draws the gizmos in the main pass
+#[derive(Default)]
+pub struct GizmoPlugin;
+
+impl Plugin for GizmoPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.init_resource::<Gizmos>()
+            .add_system_to_stage(stage::POST_UPDATE, gizmo_system);
+
+        let resources = app.resources();
+        let mut shaders = resources.get_mut::<Assets<Shader>>().unwrap();
+        let mut pipelines = resources.get_mut::<Assets<PipelineDescriptor>>().unwrap();
+        pipelines.set_untracked(GIZMO_PIPELINE_HANDLE, build_gizmo_pipeline(&mut shaders));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::Gizmos;
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{Commands, Entity, Local, Query, ResMut};
+use bevy_render::{
+    camera::NoFrustumCulling,
+    draw::Draw,
+    mesh::{Indices, Mesh},
+    pipeline::{
+        BlendDescriptor, BlendFactor, BlendOperation, ColorStateDescriptor, ColorWrite,
+        CompareFunction, CullMode, DepthStencilStateDescriptor, FrontFace, PipelineDescriptor,
+        PrimitiveTopology, RasterizationStateDescriptor, RenderPipeline, RenderPipelines,
+        StencilStateDescriptor, StencilStateFaceDescriptor,
+    },
+    prelude::MeshBundle,
+    shader::{Shader, ShaderStage, ShaderStages},
+    texture::TextureFormat,
+};
+use bevy_type_registry::TypeUuid;
+
+pub const GIZMO_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
+    Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 7423960152317908226);
+
+/// Builds the unlit pipeline that draws gizmo lines with their vertex colors
+pub fn build_gizmo_pipeline(shaders: &mut Assets<Shader>) -> PipelineDescriptor {
+    PipelineDescriptor {
+        rasterization_state: Some(RasterizationStateDescriptor {
+            front_face: FrontFace::Ccw,
+            cull_mode: CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+            clamp_depth: false,
+        }),
+        depth_stencil_state: Some(DepthStencilStateDescriptor {
+            format: TextureFormat::Depth32Float,
+            // gizmos are hidden by meshes in front of them, but don't hide anything themselves
+            depth_write_enabled: false,
+            depth_compare: CompareFunction::LessEqual,
+            stencil: StencilStateDescriptor {
+                front: StencilStateFaceDescriptor::IGNORE,
+                back: StencilStateFaceDescriptor::IGNORE,
+                read_mask: 0,
+                write_mask: 0,
+            },
+        }),
+        color_states: vec![ColorStateDescriptor {
+            format: TextureFormat::default(),
+            color_blend: BlendDescriptor {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha_blend: BlendDescriptor {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            write_mask: ColorWrite::ALL,
+        }],
+        ..PipelineDescriptor::new(ShaderStages {
+            vertex: shaders.add(Shader::from_glsl(
+                ShaderStage::Vertex,
+                include_str!("gizmo.vert"),
+            )),
+            fragment: Some(shaders.add(Shader::from_glsl(
+                ShaderStage::Fragment,
+                include_str!("gizmo.frag"),
+            ))),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct GizmoSystemState {
+    /// The entity that draws the gizmos, spawned when the first gizmo is drawn
+    entity: Option<(Entity, Handle<Mesh>)>,
+}
+
+/// Moves the lines drawn with [Gizmos] this frame into the mesh of the gizmo entity
+pub fn gizmo_system(
+    commands: &mut Commands,
+    mut state: Local<GizmoSystemState>,
+    mut gizmos: ResMut<Gizmos>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut draws: Query<&mut Draw>,
+) {
+    if gizmos.is_empty() {
+        // meshes without vertices can't be uploaded, so the old mesh is hidden instead
+        if let Some((entity, _)) = &state.entity {
+            if let Ok(mut draw) = draws.get_mut(*entity) {
+                draw.is_visible = false;
+            }
+        }
+        return;
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+    let (positions, colors) = gizmos.take_vertices();
+    mesh.set_indices(Some(Indices::U32((0..positions.len() as u32).collect())));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+
+    match &state.entity {
+        Some((entity, handle)) => {
+            meshes.set(handle, mesh);
+            if let Ok(mut draw) = draws.get_mut(*entity) {
+                draw.is_visible = true;
+            }
+        }
+        None => {
+            let handle = meshes.add(mesh);
+            commands
+                .spawn(MeshBundle {
+                    mesh: handle.clone(),
+                    render_pipelines: RenderPipelines::from_pipelines(vec![RenderPipeline::new(
+                        GIZMO_PIPELINE_HANDLE,
+                    )]),
+                    draw: Draw {
+                        is_transparent: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                // the mesh changes every frame, so its bounds would always be out of date
+                .with(NoFrustumCulling);
+            state.entity = Some((commands.current_entity().unwrap(), handle));
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use anyhow::Result;
+use bevy_animation::{AnimationClip, EntityPath, Interpolation, Keyframes, VariableCurve};
 use bevy_asset::{AssetIoError, AssetLoader, AssetPath, LoadContext, LoadedAsset};
-use bevy_ecs::{bevy_utils::BoxedFuture, World, WorldBuilderSource};
-use bevy_math::Mat4;
-use bevy_pbr::prelude::{PbrBundle, StandardMaterial};
+use bevy_core::Labels;
+use bevy_ecs::{bevy_utils::BoxedFuture, Entity, World, WorldBuilderSource};
+use bevy_math::{Mat4, Quat, Vec3};
+use bevy_pbr::{
+    prelude::{PbrBundle, StandardMaterial},
+    SkinnedMesh, SkinnedMeshInverseBindposes,
+};
 use bevy_render::{
     camera::{
         Camera, CameraProjection, OrthographicProjection, PerspectiveProjection, VisibleEntities,
@@ -20,13 +25,15 @@ use bevy_transform::{
     hierarchy::{BuildWorldChildren, WorldChildBuilder},
     prelude::{GlobalTransform, Transform},
 };
+use bevy_utils::HashMap;
 use gltf::{
+    animation::util::ReadOutputs,
     mesh::Mode,
     texture::{MagFilter, MinFilter, WrappingMode},
     Primitive,
 };
 use image::{GenericImageView, ImageFormat};
-use std::path::Path;
+use std::{borrow::Cow, path::Path};
 use thiserror::Error;
 
 /// An error that occurs when loading a GLTF file
@@ -52,7 +59,13 @@ pub enum GltfError {
     AssetIoError(#[from] AssetIoError),
 }
 
-/// Loads meshes from GLTF files into Mesh assets
+/// Loads meshes from GLTF files into Mesh assets.
+///
+/// Each node is labeled with its name (see [Labels]), or `Node{index}` if it has none, and the
+/// root entity of each scene is labeled `Scene{index}`. Animations are loaded as
+/// [AnimationClip]s labeled `Animation{index}`, which animate the nodes of a scene when they are
+/// played by an [AnimationPlayer](bevy_animation::AnimationPlayer) on the scene's root entity.
+/// Meshes with a skin get a [SkinnedMesh] whose joints are the entities of the skin's nodes.
 #[derive(Default)]
 pub struct GltfLoader;
 
@@ -78,8 +91,6 @@ async fn load_gltf<'a, 'b>(
     let mut world = World::default();
     let buffer_data = load_buffers(&gltf, load_context, load_context.path()).await?;
 
-    let world_builder = &mut world.build();
-
     for mesh in gltf.meshes() {
         for primitive in mesh.primitives() {
             let primitive_label = primitive_label(&mesh, &primitive);
@@ -110,6 +121,23 @@ async fn load_gltf<'a, 'b>(
                     mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vertex_attribute);
                 }
 
+                if let Some(vertex_attribute) = reader.read_joints(0).map(|v| {
+                    VertexAttributeValues::Uint4(
+                        v.into_u16()
+                            .map(|[a, b, c, d]| [a as u32, b as u32, c as u32, d as u32])
+                            .collect(),
+                    )
+                }) {
+                    mesh.set_attribute(Mesh::ATTRIBUTE_JOINT_INDEX, vertex_attribute);
+                }
+
+                if let Some(vertex_attribute) = reader
+                    .read_weights(0)
+                    .map(|v| VertexAttributeValues::Float4(v.into_f32().collect()))
+                {
+                    mesh.set_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT, vertex_attribute);
+                }
+
                 if let Some(indices) = reader.read_indices() {
                     mesh.set_indices(Some(Indices::U32(indices.into_u32().collect())));
                 };
@@ -170,11 +198,12 @@ async fn load_gltf<'a, 'b>(
         } else {
             None
         };
+        // glTF colors are linear
         let color = pbr.base_color_factor();
         load_context.set_labeled_asset(
             &material_label,
             LoadedAsset::new(StandardMaterial {
-                albedo: Color::rgba(color[0], color[1], color[2], color[3]),
+                albedo: Color::from(color),
                 albedo_texture: texture_handle,
                 ..Default::default()
             })
@@ -182,13 +211,89 @@ async fn load_gltf<'a, 'b>(
         )
     }
 
+    for skin in gltf.skins() {
+        let reader = skin.reader(|buffer| Some(&buffer_data[buffer.index()]));
+        let inverse_bindposes = match reader.read_inverse_bind_matrices() {
+            Some(matrices) => matrices
+                .map(|matrix| Mat4::from_cols_array_2d(&matrix))
+                .collect(),
+            // the joints are already in their bind pose
+            None => vec![Mat4::identity(); skin.joints().count()],
+        };
+        load_context.set_labeled_asset(
+            &skin_label(&skin),
+            LoadedAsset::new(SkinnedMeshInverseBindposes(inverse_bindposes)),
+        );
+    }
+
+    let node_paths = node_paths(&gltf);
+    for animation in gltf.animations() {
+        let mut clip = AnimationClip::default();
+        for channel in animation.channels() {
+            let path = match node_paths.get(&channel.target().node().index()) {
+                Some(path) => path.clone(),
+                None => continue,
+            };
+            let reader = channel.reader(|buffer| Some(&buffer_data[buffer.index()]));
+            let keyframe_timestamps: Vec<f32> = match reader.read_inputs() {
+                Some(inputs) => inputs.collect(),
+                None => continue,
+            };
+            let interpolation = channel.sampler().interpolation();
+            let keyframes = match reader.read_outputs() {
+                Some(ReadOutputs::Translations(values)) => {
+                    Keyframes::Translation(keyframe_values(values.map(Vec3::from), interpolation))
+                }
+                Some(ReadOutputs::Rotations(values)) => Keyframes::Rotation(keyframe_values(
+                    values
+                        .into_f32()
+                        .map(|[x, y, z, w]| Quat::from_xyzw(x, y, z, w)),
+                    interpolation,
+                )),
+                Some(ReadOutputs::Scales(values)) => {
+                    Keyframes::Scale(keyframe_values(values.map(Vec3::from), interpolation))
+                }
+                // morph targets aren't supported yet
+                _ => continue,
+            };
+            if keyframes.len() != keyframe_timestamps.len() {
+                continue;
+            }
+            let interpolation = match interpolation {
+                gltf::animation::Interpolation::Step => Interpolation::Step,
+                gltf::animation::Interpolation::Linear
+                | gltf::animation::Interpolation::CubicSpline => Interpolation::Linear,
+            };
+            clip.add_curve(
+                path,
+                VariableCurve::new(keyframe_timestamps, keyframes)
+                    .with_interpolation(interpolation),
+            );
+        }
+        load_context.set_labeled_asset(&animation_label(&animation), LoadedAsset::new(clip));
+    }
+
     for scene in gltf.scenes() {
         let mut err = None;
-        world_builder
-            .spawn((Transform::default(), GlobalTransform::default()))
+        let mut node_entities = HashMap::default();
+        let mut skinned_primitives = Vec::new();
+        world
+            .build()
+            .spawn((
+                Transform::default(),
+                GlobalTransform::default(),
+                Labels::from(vec![scene_label(&scene)]),
+            ))
             .with_children(|parent| {
                 for node in scene.nodes() {
-                    let result = load_node(&node, parent, load_context, &buffer_data);
+                    let result = load_node(
+                        &node,
+                        parent,
+                        load_context,
+                        &buffer_data,
+                        &mut node_entities,
+                        &mut skinned_primitives,
+                    );
                     if result.is_err() {
                         err = Some(result);
                         return;
@@ -198,6 +303,22 @@ async fn load_gltf<'a, 'b>(
         if let Some(Err(err)) = err {
             return Err(err);
         }
+
+        // joints can come after the meshes they move, so skins are resolved once the whole scene
+        // has been spawned
+        for (entity, skin) in skinned_primitives {
+            let joints = skin
+                .joints()
+                .map(|joint| node_entities.get(&joint.index()).copied())
+                .collect::<Option<Vec<_>>>();
+            if let Some(joints) = joints {
+                let inverse_bindposes_path =
+                    AssetPath::new_ref(load_context.path(), Some(&skin_label(&skin)));
+                let skinned_mesh =
+                    SkinnedMesh::new(load_context.get_handle(inverse_bindposes_path), joints);
+                world.insert_one(entity, skinned_mesh).unwrap();
+            }
+        }
     }
 
     load_context.set_default_asset(LoadedAsset::new(Scene::new(world)));
@@ -205,18 +326,22 @@ async fn load_gltf<'a, 'b>(
     Ok(())
 }
 
-fn load_node(
-    gltf_node: &gltf::Node,
+fn load_node<'a>(
+    gltf_node: &gltf::Node<'a>,
     world_builder: &mut WorldChildBuilder,
     load_context: &mut LoadContext,
     buffer_data: &[Vec<u8>],
+    node_entities: &mut HashMap<usize, Entity>,
+    skinned_primitives: &mut Vec<(Entity, gltf::Skin<'a>)>,
 ) -> Result<(), GltfError> {
     let transform = gltf_node.transform();
     let mut gltf_error = None;
     let node = world_builder.spawn((
         Transform::from_matrix(Mat4::from_cols_array_2d(&transform.matrix())),
         GlobalTransform::default(),
+        Labels::from(vec![node_label(gltf_node)]),
     ));
+    node_entities.insert(gltf_node.index(), node.current_entity().unwrap());
 
     // create camera node
     if let Some(camera) = gltf_node.camera() {
@@ -283,12 +408,22 @@ fn load_node(
                     material: load_context.get_handle(material_asset_path),
                     ..Default::default()
                 });
+                if let Some(skin) = gltf_node.skin() {
+                    skinned_primitives.push((parent.current_entity().unwrap(), skin));
+                }
             }
         }
 
         // append other nodes
         for child in gltf_node.children() {
-            if let Err(err) = load_node(&child, parent, load_context, buffer_data) {
+            if let Err(err) = load_node(
+                &child,
+                parent,
+                load_context,
+                buffer_data,
+                node_entities,
+                skinned_primitives,
+            ) {
                 gltf_error = Some(err);
                 return;
             }
@@ -317,6 +452,55 @@ fn texture_label(texture: &gltf::Texture) -> String {
     format!("Texture{}", texture.index())
 }
 
+fn skin_label(skin: &gltf::Skin) -> String {
+    format!("Skin{}/InverseBindMatrices", skin.index())
+}
+
+fn animation_label(animation: &gltf::Animation) -> String {
+    format!("Animation{}", animation.index())
+}
+
+fn scene_label(scene: &gltf::Scene) -> String {
+    format!("Scene{}", scene.index())
+}
+
+fn node_label(node: &gltf::Node) -> String {
+    match node.name() {
+        Some(name) => name.to_string(),
+        None => format!("Node{}", node.index()),
+    }
+}
+
+/// The path of labels from the root entity of a scene to each node
+fn node_paths(gltf: &gltf::Gltf) -> HashMap<usize, EntityPath> {
+    let mut paths = HashMap::default();
+    let mut nodes = gltf
+        .scenes()
+        .flat_map(|scene| scene.nodes())
+        .map(|node| (node, Vec::new()))
+        .collect::<Vec<_>>();
+    while let Some((node, mut parts)) = nodes.pop() {
+        parts.push(Cow::Owned(node_label(&node)));
+        for child in node.children() {
+            nodes.push((child, parts.clone()));
+        }
+        paths.insert(node.index(), EntityPath { parts });
+    }
+    paths
+}
+
+/// Cubic spline keyframes store an in-tangent, a value and an out-tangent each. Only the values
+/// are kept, which are then interpolated linearly.
+fn keyframe_values<T>(
+    values: impl Iterator<Item = T>,
+    interpolation: gltf::animation::Interpolation,
+) -> Vec<T> {
+    match interpolation {
+        gltf::animation::Interpolation::CubicSpline => values.skip(1).step_by(3).collect(),
+        _ => values.collect(),
+    }
+}
+
 fn texture_sampler(texture: &gltf::Texture) -> Result<SamplerDescriptor, GltfError> {
     let gltf_sampler = texture.sampler();
 
@@ -69,6 +69,8 @@ pub enum GamepadAxisType {
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct GamepadAxis(pub Gamepad, pub GamepadAxisType);
 
+/// The deadzones, livezones and thresholds of gamepad axes and buttons. The defaults are used for
+/// the axes and buttons that have no settings of their own.
 #[derive(Default, Debug)]
 pub struct GamepadSettings {
     pub default_button_settings: ButtonSettings,
@@ -124,12 +126,17 @@ impl ButtonSettings {
     }
 }
 
+/// Filters the raw values of an axis before they are stored in [Axis] and sent as [GamepadEvent]s
 #[derive(Debug, Clone)]
 pub struct AxisSettings {
+    /// Values at or above this livezone are reported as `1.0`
     pub positive_high: f32,
+    /// Values between `negative_low` and `positive_low`, the deadzone, are reported as `0.0`
     pub positive_low: f32,
+    /// Values at or below this livezone are reported as `-1.0`
     pub negative_high: f32,
     pub negative_low: f32,
+    /// The smallest change of the value that is reported
     pub threshold: f32,
 }
 
@@ -147,28 +154,28 @@ impl Default for AxisSettings {
 
 impl AxisSettings {
     fn filter(&self, new_value: f32, old_value: Option<f32>) -> Option<f32> {
-        if let Some(old_value) = old_value {
-            if (new_value - old_value).abs() <= self.threshold {
-                return None;
-            }
-        }
         if new_value <= self.positive_low && new_value >= self.negative_low {
-            return Some(0.0);
+            return filter_change(0.0, old_value, None);
         }
         if new_value >= self.positive_high {
-            return Some(1.0);
+            return filter_change(1.0, old_value, None);
         }
         if new_value <= self.negative_high {
-            return Some(-1.0);
+            return filter_change(-1.0, old_value, None);
         }
-        Some(new_value)
+        filter_change(new_value, old_value, Some(self.threshold))
     }
 }
 
+/// Filters the raw values of buttons like triggers before they are stored in [Axis] and sent as
+/// [GamepadEvent]s
 #[derive(Debug, Clone)]
 pub struct ButtonAxisSettings {
+    /// Values at or above this livezone are reported as `1.0`
     pub high: f32,
+    /// Values at or below this deadzone are reported as `0.0`
     pub low: f32,
+    /// The smallest change of the value that is reported
     pub threshold: f32,
 }
 
@@ -184,18 +191,28 @@ impl Default for ButtonAxisSettings {
 
 impl ButtonAxisSettings {
     fn filter(&self, new_value: f32, old_value: Option<f32>) -> Option<f32> {
-        if let Some(old_value) = old_value {
-            if (new_value - old_value).abs() <= self.threshold {
-                return None;
-            }
-        }
         if new_value <= self.low {
-            return Some(0.0);
+            return filter_change(0.0, old_value, None);
         }
         if new_value >= self.high {
-            return Some(1.0);
+            return filter_change(1.0, old_value, None);
         }
+        filter_change(new_value, old_value, Some(self.threshold))
+    }
+}
+
+/// Returns `new_value` if it differs enough from `old_value` to be reported. Values clamped by a
+/// deadzone or livezone are compared without a threshold: a stick that is released slowly could
+/// otherwise stay just outside of the deadzone, and make characters creep on their own.
+fn filter_change(new_value: f32, old_value: Option<f32>, threshold: Option<f32>) -> Option<f32> {
+    let change = match old_value {
+        Some(old_value) => (new_value - old_value).abs(),
+        None => return Some(new_value),
+    };
+    if change > threshold.unwrap_or(0.0) {
         Some(new_value)
+    } else {
+        None
     }
 }
 
@@ -305,3 +322,31 @@ const ALL_AXIS_TYPES: [GamepadAxisType; 8] = [
     GamepadAxisType::DPadX,
     GamepadAxisType::DPadY,
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisSettings, ButtonAxisSettings};
+
+    #[test]
+    fn axis_changes_below_threshold_are_ignored() {
+        let settings = AxisSettings::default();
+        assert_eq!(settings.filter(0.5, None), Some(0.5));
+        assert_eq!(settings.filter(0.505, Some(0.5)), None);
+        assert_eq!(settings.filter(0.52, Some(0.5)), Some(0.52));
+        assert_eq!(settings.filter(-0.97, Some(-0.94)), Some(-1.0));
+        assert_eq!(settings.filter(-0.99, Some(-1.0)), None);
+    }
+
+    #[test]
+    fn released_axis_returns_to_center() {
+        let settings = AxisSettings::default();
+        // the last value outside of the deadzone is within the threshold of the first one inside
+        assert_eq!(settings.filter(0.045, Some(0.051)), Some(0.0));
+        assert_eq!(settings.filter(-0.045, Some(-0.051)), Some(0.0));
+        assert_eq!(settings.filter(0.0, Some(0.0)), None);
+
+        let settings = ButtonAxisSettings::default();
+        assert_eq!(settings.filter(0.049, Some(0.051)), Some(0.0));
+        assert_eq!(settings.filter(0.951, Some(0.949)), Some(1.0));
+    }
+}
@@ -13,6 +13,7 @@ impl PluginGroup for DefaultPlugins {
         group.add(bevy_window::WindowPlugin::default());
         group.add(bevy_asset::AssetPlugin::default());
         group.add(bevy_scene::ScenePlugin::default());
+        group.add(bevy_animation::AnimationPlugin::default());
 
         #[cfg(feature = "bevy_render")]
         group.add(bevy_render::RenderPlugin::default());
@@ -23,6 +24,12 @@ impl PluginGroup for DefaultPlugins {
         #[cfg(feature = "bevy_pbr")]
         group.add(bevy_pbr::PbrPlugin::default());
 
+        #[cfg(feature = "bevy_gizmos")]
+        group.add(bevy_gizmos::GizmoPlugin::default());
+
+        #[cfg(feature = "bevy_picking")]
+        group.add(bevy_picking::PickingPlugin::default());
+
         #[cfg(feature = "bevy_ui")]
         group.add(bevy_ui::UiPlugin::default());
 
@@ -55,3 +62,22 @@ impl PluginGroup for MinimalPlugins {
         group.add(bevy_app::ScheduleRunnerPlugin::default());
     }
 }
+
+/// The plugins of [DefaultPlugins] that don't need a window or a GPU, for apps such as dedicated
+/// servers and tests. The schedule is run by the
+/// [ScheduleRunnerPlugin](bevy_app::ScheduleRunnerPlugin), which can be configured with the
+/// [ScheduleRunnerSettings](bevy_app::ScheduleRunnerSettings) resource.
+pub struct HeadlessPlugins;
+
+impl PluginGroup for HeadlessPlugins {
+    fn build(&mut self, group: &mut PluginGroupBuilder) {
+        group.add(bevy_log::LogPlugin::default());
+        group.add(bevy_type_registry::TypeRegistryPlugin::default());
+        group.add(bevy_core::CorePlugin::default());
+        group.add(bevy_transform::TransformPlugin::default());
+        group.add(bevy_diagnostic::DiagnosticsPlugin::default());
+        group.add(bevy_asset::AssetPlugin::default());
+        group.add(bevy_scene::ScenePlugin::default());
+        group.add(bevy_app::ScheduleRunnerPlugin::default());
+    }
+}
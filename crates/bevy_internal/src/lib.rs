@@ -4,6 +4,11 @@ pub mod prelude;
 mod default_plugins;
 pub use default_plugins::*;
 
+pub mod animation {
+    //! Keyframe animation clips and players.
+    pub use bevy_animation::*;
+}
+
 pub mod app {
     //! Build bevy apps, create plugins, and read events.
     pub use bevy_app::*;
@@ -84,6 +89,12 @@ pub mod audio {
     pub use bevy_audio::*;
 }
 
+#[cfg(feature = "bevy_gizmos")]
+pub mod gizmos {
+    //! Debug lines and wireframe shapes drawn for one frame.
+    pub use bevy_gizmos::*;
+}
+
 #[cfg(feature = "bevy_gltf")]
 pub mod gltf {
     //! Support for GLTF file loading.
@@ -97,6 +108,12 @@ pub mod pbr {
     pub use bevy_pbr::*;
 }
 
+#[cfg(feature = "bevy_picking")]
+pub mod picking {
+    //! Entities under the cursor, found by casting rays from the camera.
+    pub use bevy_picking::*;
+}
+
 #[cfg(feature = "bevy_render")]
 pub mod render {
     //! Cameras, meshes, textures, shaders, and pipelines.
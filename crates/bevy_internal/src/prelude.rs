@@ -1,8 +1,8 @@
 pub use crate::{
-    app::prelude::*, asset::prelude::*, core::prelude::*, ecs::prelude::*, input::prelude::*,
-    log::prelude::*, math::prelude::*, property::prelude::*, scene::prelude::*,
+    animation::prelude::*, app::prelude::*, asset::prelude::*, core::prelude::*, ecs::prelude::*,
+    input::prelude::*, log::prelude::*, math::prelude::*, property::prelude::*, scene::prelude::*,
     transform::prelude::*, type_registry::RegisterType, window::prelude::*, DefaultPlugins,
-    MinimalPlugins,
+    HeadlessPlugins, MinimalPlugins,
 };
 
 pub use bevy_derive::bevy_main;
@@ -10,9 +10,15 @@ pub use bevy_derive::bevy_main;
 #[cfg(feature = "bevy_audio")]
 pub use crate::audio::prelude::*;
 
+#[cfg(feature = "bevy_gizmos")]
+pub use crate::gizmos::prelude::*;
+
 #[cfg(feature = "bevy_pbr")]
 pub use crate::pbr::prelude::*;
 
+#[cfg(feature = "bevy_picking")]
+pub use crate::picking::prelude::*;
+
 #[cfg(feature = "bevy_render")]
 pub use crate::render::prelude::*;
 
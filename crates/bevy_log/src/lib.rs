@@ -1,5 +1,8 @@
 #[cfg(target_os = "android")]
 mod android_tracing;
+mod log_sink;
+
+pub use log_sink::{LogRecord, LogSink, LogSinks};
 
 pub mod prelude {
     pub use bevy_utils::tracing::{
@@ -15,12 +18,35 @@ use bevy_app::{AppBuilder, Plugin};
 use tracing_subscriber::{prelude::*, registry::Registry, EnvFilter};
 
 /// Adds logging to Apps.
-#[derive(Default)]
-pub struct LogPlugin;
+///
+/// A [LogSettings] resource inserted before this plugin is added takes precedence over the plugin's
+/// settings, and the `RUST_LOG` environment variable takes precedence over both when it is set.
+pub struct LogPlugin {
+    /// Filters logs using the [EnvFilter] format, which supports per-module levels such as
+    /// `wgpu=warn,bevy_ecs=debug`
+    pub filter: String,
+
+    /// Filters out logs that are "less than" the given level.
+    /// This can be further filtered using the `filter` setting.
+    pub level: Level,
+}
+
+impl Default for LogPlugin {
+    fn default() -> Self {
+        let settings = LogSettings::default();
+        Self {
+            filter: settings.filter,
+            level: settings.level,
+        }
+    }
+}
 
-/// LogPlugin settings
+/// LogPlugin settings. The `RUST_LOG` environment variable takes precedence over these settings
+/// when it is set.
+#[derive(Debug, Clone)]
 pub struct LogSettings {
-    /// Filters logs using the [EnvFilter] format
+    /// Filters logs using the [EnvFilter] format, which supports per-module levels such as
+    /// `wgpu=warn,bevy_ecs=debug`
     pub filter: String,
 
     /// Filters out logs that are "less than" the given level.
@@ -40,14 +66,23 @@ impl Default for LogSettings {
 impl Plugin for LogPlugin {
     fn build(&self, app: &mut AppBuilder) {
         let default_filter = {
-            let settings = app.resources_mut().get_or_insert_with(LogSettings::default);
+            let settings = app.resources_mut().get_or_insert_with(|| LogSettings {
+                filter: self.filter.clone(),
+                level: self.level,
+            });
             format!("{},{}", settings.level, settings.filter)
         };
+        let sinks = app
+            .resources_mut()
+            .get_or_insert_with(LogSinks::default)
+            .clone();
 
         let filter_layer = EnvFilter::try_from_default_env()
             .or_else(|_| EnvFilter::try_new(&default_filter))
             .unwrap();
-        let subscriber = Registry::default().with(filter_layer);
+        let subscriber = Registry::default()
+            .with(filter_layer)
+            .with(log_sink::LogSinkLayer::new(sinks));
 
         #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
         {
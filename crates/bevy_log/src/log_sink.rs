@@ -0,0 +1,154 @@
+use bevy_utils::tracing::{field::Field, Event, Level, Subscriber};
+use std::{
+    cell::Cell,
+    fmt::{Debug, Write},
+    sync::{Arc, RwLock},
+};
+use tracing_subscriber::{field::Visit, layer::Context, Layer};
+
+/// A log event that is forwarded to each [LogSink]
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub level: Level,
+    /// The module path of the code that logged the event
+    pub target: String,
+    /// The formatted message, followed by any other fields of the event
+    pub message: String,
+}
+
+/// A destination for log events, such as an in-game console or a log file
+pub trait LogSink: Send + Sync + 'static {
+    fn log(&self, record: &LogRecord);
+}
+
+impl<F: Fn(&LogRecord) + Send + Sync + 'static> LogSink for F {
+    fn log(&self, record: &LogRecord) {
+        self(record)
+    }
+}
+
+/// The custom [LogSink]s that receive every log event that passes the
+/// [LogSettings](crate::LogSettings) filters. Sinks can be added at any time, either by inserting
+/// this resource before the [LogPlugin](crate::LogPlugin) or by adding to the resource later on.
+#[derive(Clone, Default)]
+pub struct LogSinks {
+    sinks: Arc<RwLock<Vec<Arc<dyn LogSink>>>>,
+}
+
+impl LogSinks {
+    pub fn add(&self, sink: impl LogSink) -> &Self {
+        self.sinks.write().unwrap().push(Arc::new(sink));
+        self
+    }
+}
+
+#[derive(Default)]
+struct MessageRecorder {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageRecorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            write!(self.message, "{:?}", value).unwrap();
+        } else {
+            write!(self.fields, " {}={:?}", field.name(), value).unwrap();
+        }
+    }
+}
+
+thread_local! {
+    /// Set while this thread runs the sinks, so events that the sinks log themselves are not
+    /// forwarded to them again
+    static IN_SINK: Cell<bool> = Cell::new(false);
+}
+
+/// Forwards log events to the [LogSinks]
+pub(crate) struct LogSinkLayer {
+    sinks: LogSinks,
+}
+
+impl LogSinkLayer {
+    pub(crate) fn new(sinks: LogSinks) -> Self {
+        Self { sinks }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogSinkLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if IN_SINK.with(|in_sink| in_sink.get()) {
+            return;
+        }
+        // the sinks run without holding the lock, so they can add sinks themselves
+        let sinks = self.sinks.sinks.read().unwrap().clone();
+        if sinks.is_empty() {
+            return;
+        }
+
+        let mut recorder = MessageRecorder::default();
+        event.record(&mut recorder);
+        let metadata = event.metadata();
+        let record = LogRecord {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: recorder.message + &recorder.fields,
+        };
+        IN_SINK.with(|in_sink| in_sink.set(true));
+        for sink in sinks.iter() {
+            sink.log(&record);
+        }
+        IN_SINK.with(|in_sink| in_sink.set(false));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{info, warn};
+    use std::sync::Mutex;
+    use tracing_subscriber::{prelude::*, registry::Registry};
+
+    #[test]
+    fn sinks_receive_events() {
+        let sinks = LogSinks::default();
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink_records = records.clone();
+        sinks.add(move |record: &LogRecord| sink_records.lock().unwrap().push(record.clone()));
+
+        let subscriber = Registry::default().with(LogSinkLayer::new(sinks));
+        bevy_utils::tracing::subscriber::with_default(subscriber, || {
+            info!("hello");
+            warn!(count = 3, "world");
+        });
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, Level::INFO);
+        assert_eq!(records[0].message, "hello");
+        assert_eq!(records[1].message, "world count=3");
+        assert!(records[1].target.ends_with("log_sink::tests"));
+    }
+
+    #[test]
+    fn sinks_can_log_and_add_sinks() {
+        let sinks = LogSinks::default();
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink_records = records.clone();
+        let sink_sinks = sinks.clone();
+        sinks.add(move |record: &LogRecord| {
+            sink_records.lock().unwrap().push(record.clone());
+            // neither of these may deadlock, and the event logged here is not forwarded again
+            warn!("logged from a sink");
+            sink_sinks.add(|_: &LogRecord| {});
+        });
+
+        let subscriber = Registry::default().with(LogSinkLayer::new(sinks.clone()));
+        bevy_utils::tracing::subscriber::with_default(subscriber, || {
+            info!("hello");
+        });
+
+        assert_eq!(records.lock().unwrap().len(), 1);
+        assert_eq!(sinks.sinks.read().unwrap().len(), 2);
+    }
+}
@@ -1,12 +1,16 @@
 mod clamp;
 mod face_toward;
 mod geometry;
+mod interpolate;
+mod ray;
 
 pub use clamp::*;
 pub use face_toward::*;
 pub use geometry::*;
 pub use glam::*;
+pub use interpolate::*;
+pub use ray::*;
 
 pub mod prelude {
-    pub use crate::{FaceToward, Mat3, Mat4, Quat, Rect, Size, Vec2, Vec3, Vec4};
+    pub use crate::{FaceToward, Mat3, Mat4, Quat, Ray, Rect, Size, Vec2, Vec3, Vec4};
 }
@@ -0,0 +1,36 @@
+use crate::Vec3;
+
+/// A half-line starting at `origin` and going in `direction`
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Ray {
+    pub origin: Vec3,
+    /// The direction of the ray. It is normalized for rays created by cameras.
+    pub direction: Vec3,
+}
+
+impl Ray {
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// Returns the point `distance` direction lengths along the ray
+    pub fn get_point(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Returns the distance along the ray at which it hits the plane through `plane_origin` with
+    /// the normal `plane_normal`, or `None` if the ray is parallel to the plane or points away
+    /// from it
+    pub fn intersect_plane(&self, plane_origin: Vec3, plane_normal: Vec3) -> Option<f32> {
+        let denominator = plane_normal.dot(self.direction);
+        if denominator.abs() <= std::f32::EPSILON {
+            return None;
+        }
+        let distance = (plane_origin - self.origin).dot(plane_normal) / denominator;
+        if distance >= 0.0 {
+            Some(distance)
+        } else {
+            None
+        }
+    }
+}
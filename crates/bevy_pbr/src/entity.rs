@@ -2,6 +2,7 @@ use crate::{light::Light, material::StandardMaterial, render_graph::FORWARD_PIPE
 use bevy_asset::Handle;
 use bevy_ecs::Bundle;
 use bevy_render::{
+    camera::ComputedVisibility,
     draw::Draw,
     mesh::Mesh,
     pipeline::{RenderPipeline, RenderPipelines},
@@ -19,6 +20,7 @@ pub struct PbrBundle {
     pub render_pipelines: RenderPipelines,
     pub transform: Transform,
     pub global_transform: GlobalTransform,
+    pub computed_visibility: ComputedVisibility,
 }
 
 impl Default for PbrBundle {
@@ -33,6 +35,7 @@ impl Default for PbrBundle {
             draw: Default::default(),
             transform: Default::default(),
             global_transform: Default::default(),
+            computed_visibility: Default::default(),
         }
     }
 }
@@ -3,13 +3,15 @@ pub mod render_graph;
 mod entity;
 mod light;
 mod material;
+mod skinning;
 
 pub use entity::*;
 pub use light::*;
 pub use material::*;
+pub use skinning::*;
 
 pub mod prelude {
-    pub use crate::{entity::*, light::Light, material::StandardMaterial};
+    pub use crate::{entity::*, light::Light, material::StandardMaterial, skinning::SkinnedMesh};
 }
 
 use bevy_app::prelude::*;
@@ -20,18 +22,36 @@ use light::Light;
 use material::StandardMaterial;
 use render_graph::add_pbr_graph;
 
+/// The names of pbr App stages
+pub mod stage {
+    /// Stage where the joint matrices and bounds of skinned meshes are computed. It runs after
+    /// POST_UPDATE, so the joints' global transforms are those of the current frame, and before
+    /// [VISIBILITY](bevy_render::stage::VISIBILITY), so culling uses the current bounds.
+    pub const SKINNING: &str = "skinning";
+}
+
 /// NOTE: this isn't PBR yet. consider this name "aspirational" :)
 #[derive(Default)]
 pub struct PbrPlugin;
 
 impl Plugin for PbrPlugin {
     fn build(&self, app: &mut AppBuilder) {
-        app.add_asset::<StandardMaterial>()
+        app.add_stage_before(bevy_render::stage::VISIBILITY, stage::SKINNING)
+            .add_asset::<StandardMaterial>()
+            .add_asset::<SkinnedMeshInverseBindposes>()
             .register_component::<Light>()
+            .register_component_with::<SkinnedMesh>(|reg| reg.map_entities())
             .add_system_to_stage(
-                stage::POST_UPDATE,
+                bevy_app::stage::POST_UPDATE,
                 shader::asset_shader_defs_system::<StandardMaterial>,
             )
+            .add_system_to_stage(
+                bevy_app::stage::POST_UPDATE,
+                shader::shader_defs_system::<SkinnedMesh>,
+            )
+            // registration order matters here. the bounds are computed from the joint matrices
+            .add_system_to_stage(stage::SKINNING, skinning::skinned_mesh_joints_system)
+            .add_system_to_stage(stage::SKINNING, skinning::skinned_mesh_bounds_system)
             .init_resource::<AmbientLight>();
         let resources = app.resources();
         let mut render_graph = resources.get_mut::<RenderGraph>().unwrap();
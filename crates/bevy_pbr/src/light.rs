@@ -49,7 +49,7 @@ impl LightRaw {
         LightRaw {
             proj: proj.to_cols_array_2d(),
             pos: [x, y, z, 1.0],
-            color: light.color.into(),
+            color: light.color.as_linear_rgba_f32(),
         }
     }
 }
@@ -1,13 +1,15 @@
 use bevy_asset::{self, Handle};
+use bevy_property::Properties;
 use bevy_render::{color::Color, renderer::RenderResources, shader::ShaderDefs, texture::Texture};
 use bevy_type_registry::TypeUuid;
 
 /// A material with "standard" properties used in PBR lighting
-#[derive(Debug, RenderResources, ShaderDefs, TypeUuid)]
+#[derive(Debug, Properties, RenderResources, ShaderDefs, TypeUuid)]
 #[uuid = "dace545e-4bc6-4595-a79d-c224fc694975"]
 pub struct StandardMaterial {
     pub albedo: Color,
     #[shader_def]
+    #[property(ignore)]
     pub albedo_texture: Option<Handle<Texture>>,
     #[render_resources(ignore)]
     #[shader_def]
@@ -3,6 +3,10 @@
 layout(location = 0) in vec3 Vertex_Position;
 layout(location = 1) in vec3 Vertex_Normal;
 layout(location = 2) in vec2 Vertex_Uv;
+#ifdef SKINNED
+layout(location = 3) in uvec4 Vertex_JointIndex;
+layout(location = 4) in vec4 Vertex_JointWeight;
+#endif
 
 layout(location = 0) out vec3 v_Position;
 layout(location = 1) out vec3 v_Normal;
@@ -15,11 +19,25 @@ layout(set = 0, binding = 0) uniform Camera {
 layout(set = 2, binding = 0) uniform Transform {
     mat4 Model;
 };
+#ifdef SKINNED
+layout(set = 2, binding = 1) uniform SkinnedMesh_joint_matrices {
+    mat4 Joints[256];
+};
+#endif
 
 void main() {
-    v_Normal = (Model * vec4(Vertex_Normal, 1.0)).xyz;
-    v_Normal = mat3(Model) * Vertex_Normal;
-    v_Position = (Model * vec4(Vertex_Position, 1.0)).xyz;
+#ifdef SKINNED
+    // the joint matrices already place the mesh in the world, so its own transform is ignored
+    mat4 model = Vertex_JointWeight.x * Joints[Vertex_JointIndex.x]
+        + Vertex_JointWeight.y * Joints[Vertex_JointIndex.y]
+        + Vertex_JointWeight.z * Joints[Vertex_JointIndex.z]
+        + Vertex_JointWeight.w * Joints[Vertex_JointIndex.w];
+#else
+    mat4 model = Model;
+#endif
+    // the inverse-transpose keeps normals perpendicular to surfaces under non-uniform scale
+    v_Normal = mat3(transpose(inverse(model))) * Vertex_Normal;
+    v_Position = (model * vec4(Vertex_Position, 1.0)).xyz;
     v_Uv = Vertex_Uv;
     gl_Position = ViewProj * vec4(v_Position, 1.0);
 }
@@ -7,7 +7,7 @@ use bevy_ecs::{Commands, IntoSystem, Local, Query, Res, ResMut, Resources, Syste
 use bevy_render::{
     render_graph::{CommandQueue, Node, ResourceSlots, SystemNode},
     renderer::{
-        BufferId, BufferInfo, BufferUsage, RenderContext, RenderResourceBinding,
+        BufferId, BufferInfo, BufferMapMode, BufferUsage, RenderContext, RenderResourceBinding,
         RenderResourceBindings, RenderResourceContext,
     },
 };
@@ -86,7 +86,7 @@ pub fn lights_node_system(
     let state = &mut state;
     let render_resource_context = &**render_resource_context;
 
-    let ambient_light: [f32; 4] = ambient_light_resource.color.into();
+    let ambient_light = ambient_light_resource.color.as_linear_rgba_f32();
     let ambient_light_size = std::mem::size_of::<[f32; 4]>();
     let light_count = query.iter().count();
     let size = std::mem::size_of::<LightRaw>();
@@ -101,7 +101,7 @@ pub fn lights_node_system(
             return;
         }
 
-        render_resource_context.map_buffer(staging_buffer);
+        render_resource_context.map_buffer(staging_buffer, BufferMapMode::Write);
     } else {
         let buffer = render_resource_context.create_buffer(BufferInfo {
             size: max_light_uniform_size,
@@ -8,6 +8,7 @@ pub use lights_node::*;
 pub mod node {
     pub const TRANSFORM: &str = "transform";
     pub const STANDARD_MATERIAL: &str = "standard_material";
+    pub const SKINNED_MESH: &str = "skinned_mesh";
     pub const LIGHTS: &str = "lights";
 }
 
@@ -16,7 +17,7 @@ pub mod uniform {
     pub const LIGHTS: &str = "Lights";
 }
 
-use crate::prelude::StandardMaterial;
+use crate::prelude::{SkinnedMesh, StandardMaterial};
 use bevy_asset::Assets;
 use bevy_ecs::Resources;
 use bevy_render::{
@@ -35,6 +36,10 @@ pub(crate) fn add_pbr_graph(graph: &mut RenderGraph, resources: &Resources) {
         node::STANDARD_MATERIAL,
         AssetRenderResourcesNode::<StandardMaterial>::new(true),
     );
+    graph.add_system_node(
+        node::SKINNED_MESH,
+        RenderResourcesNode::<SkinnedMesh>::new(true),
+    );
     graph.add_system_node(node::LIGHTS, LightsNode::new(10));
     let mut shaders = resources.get_mut::<Assets<Shader>>().unwrap();
     let mut pipelines = resources.get_mut::<Assets<PipelineDescriptor>>().unwrap();
@@ -50,6 +55,9 @@ pub(crate) fn add_pbr_graph(graph: &mut RenderGraph, resources: &Resources) {
     graph
         .add_node_edge(node::TRANSFORM, base::node::MAIN_PASS)
         .unwrap();
+    graph
+        .add_node_edge(node::SKINNED_MESH, base::node::MAIN_PASS)
+        .unwrap();
     graph
         .add_node_edge(node::LIGHTS, base::node::MAIN_PASS)
         .unwrap();
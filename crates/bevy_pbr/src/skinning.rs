@@ -0,0 +1,304 @@
+use bevy_app::prelude::{EventReader, Events};
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_core::Bytes;
+use bevy_ecs::{Entity, Local, MapEntities, Query, Res};
+use bevy_math::{Mat4, Vec3};
+use bevy_property::Properties;
+use bevy_render::{
+    mesh::Mesh,
+    primitives::{Aabb, BoundingSphere},
+    renderer::{RenderResource, RenderResourceType, RenderResources},
+    shader::{ShaderDefIterator, ShaderDefs},
+    texture::Texture,
+};
+use bevy_transform::prelude::GlobalTransform;
+use bevy_type_registry::TypeUuid;
+use bevy_utils::HashMap;
+use smallvec::SmallVec;
+
+/// The maximum number of joints of a [SkinnedMesh]. Vertices that are moved by further joints
+/// collapse to the origin.
+///
+/// The joint matrices are uploaded as one uniform buffer, and 256 matrices of 64 bytes fill
+/// exactly the 16 KiB that is the smallest maximum uniform buffer size a GPU may have.
+pub const MAX_JOINTS: usize = 256;
+
+/// The inverse bind matrices of a skin, which move each joint from its pose when the mesh was
+/// bound to the skeleton back to the origin
+#[derive(Debug, Default, TypeUuid)]
+#[uuid = "b9f155a9-54ec-4026-988f-e0a03e99a76f"]
+pub struct SkinnedMeshInverseBindposes(pub Vec<Mat4>);
+
+/// Deforms the mesh of an entity with the transforms of joint entities. Vertices are moved by the
+/// joints in their [Mesh::ATTRIBUTE_JOINT_INDEX](bevy_render::mesh::Mesh::ATTRIBUTE_JOINT_INDEX)
+/// attribute, so the mesh's own transform is ignored.
+#[derive(Debug, Default, Properties, RenderResources)]
+pub struct SkinnedMesh {
+    #[render_resources(ignore)]
+    pub inverse_bindposes: Handle<SkinnedMeshInverseBindposes>,
+    /// The joint entities, in the order of the inverse bind matrices
+    #[render_resources(ignore)]
+    pub joints: SmallVec<[Entity; 8]>,
+    #[property(ignore)]
+    joint_matrices: JointMatrices,
+}
+
+impl SkinnedMesh {
+    pub fn new(
+        inverse_bindposes: Handle<SkinnedMeshInverseBindposes>,
+        joints: impl IntoIterator<Item = Entity>,
+    ) -> Self {
+        SkinnedMesh {
+            inverse_bindposes,
+            joints: joints.into_iter().collect(),
+            joint_matrices: Default::default(),
+        }
+    }
+}
+
+impl MapEntities for SkinnedMesh {
+    fn map_entities(
+        &mut self,
+        entity_map: &bevy_ecs::EntityMap,
+    ) -> Result<(), bevy_ecs::MapEntitiesError> {
+        for joint in self.joints.iter_mut() {
+            *joint = entity_map.get(*joint)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ShaderDefs for SkinnedMesh {
+    fn shader_defs_len(&self) -> usize {
+        1
+    }
+
+    fn get_shader_def(&self, index: usize) -> Option<&str> {
+        match index {
+            0 => Some("SKINNED"),
+            _ => None,
+        }
+    }
+
+    fn iter_shader_defs(&self) -> ShaderDefIterator {
+        ShaderDefIterator::new(self)
+    }
+}
+
+/// The world space transform of each joint relative to its bind pose. They are uploaded as an
+/// array of [MAX_JOINTS] matrices, so every skinned mesh can share one uniform buffer layout.
+#[derive(Debug, Default)]
+struct JointMatrices(Vec<Mat4>);
+
+impl RenderResource for JointMatrices {
+    fn resource_type(&self) -> Option<RenderResourceType> {
+        Some(RenderResourceType::Buffer)
+    }
+
+    fn write_buffer_bytes(&self, buffer: &mut [u8]) {
+        let matrix_size = std::mem::size_of::<Mat4>();
+        for (matrix, bytes) in self.0.iter().zip(buffer.chunks_mut(matrix_size)) {
+            matrix.write_bytes(bytes);
+        }
+        // the rest of the array is unused
+        for byte in buffer[self.0.len() * matrix_size..].iter_mut() {
+            *byte = 0;
+        }
+    }
+
+    fn buffer_byte_len(&self) -> Option<usize> {
+        Some(MAX_JOINTS * std::mem::size_of::<Mat4>())
+    }
+
+    fn texture(&self) -> Option<&Handle<Texture>> {
+        None
+    }
+}
+
+/// Computes the joint matrices of each [SkinnedMesh] from the global transforms of its joints.
+/// This runs after transforms are propagated, so the joints can be animated in earlier stages.
+pub fn skinned_mesh_joints_system(
+    inverse_bindposes: Res<Assets<SkinnedMeshInverseBindposes>>,
+    mut skinned_meshes: Query<&mut SkinnedMesh>,
+    joints: Query<&GlobalTransform>,
+) {
+    for mut skinned_mesh in skinned_meshes.iter_mut() {
+        let inverse_bindposes = match inverse_bindposes.get(&skinned_mesh.inverse_bindposes) {
+            Some(inverse_bindposes) => inverse_bindposes,
+            None => continue,
+        };
+        let skinned_mesh = &mut *skinned_mesh;
+        compute_joint_matrices(
+            &mut skinned_mesh.joint_matrices.0,
+            &skinned_mesh.joints,
+            &inverse_bindposes.0,
+            |joint| {
+                joints
+                    .get(joint)
+                    .ok()
+                    .map(|transform| transform.compute_matrix())
+            },
+        );
+    }
+}
+
+/// Replaces `joint_matrices` with the global transform of each joint times its inverse bind
+/// matrix. Joints without a transform keep their bind pose.
+fn compute_joint_matrices(
+    joint_matrices: &mut Vec<Mat4>,
+    joints: &[Entity],
+    inverse_bindposes: &[Mat4],
+    joint_transform: impl Fn(Entity) -> Option<Mat4>,
+) {
+    joint_matrices.clear();
+    for (joint, inverse_bindpose) in joints.iter().zip(inverse_bindposes.iter()).take(MAX_JOINTS) {
+        let joint_transform = joint_transform(*joint).unwrap_or_else(Mat4::identity);
+        joint_matrices.push(joint_transform * *inverse_bindpose);
+    }
+}
+
+#[derive(Default)]
+pub struct SkinnedMeshBoundsState {
+    mesh_event_reader: EventReader<AssetEvent<Mesh>>,
+    /// The bounds of each mesh in its bind pose
+    bind_pose_aabbs: HashMap<Handle<Mesh>, Option<Aabb>>,
+}
+
+/// Updates the [Aabb] and [BoundingSphere] of each [SkinnedMesh] to enclose its mesh in the
+/// current pose, so skinned meshes aren't culled while their joints move them into view. The
+/// bounds enclose the mesh's bind pose bounds moved by each joint, so they can be larger than
+/// the posed mesh.
+pub fn skinned_mesh_bounds_system(
+    mut state: Local<SkinnedMeshBoundsState>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_events: Res<Events<AssetEvent<Mesh>>>,
+    mut query: Query<(
+        &SkinnedMesh,
+        &Handle<Mesh>,
+        &GlobalTransform,
+        &mut Aabb,
+        &mut BoundingSphere,
+    )>,
+) {
+    let state = &mut *state;
+    for event in state.mesh_event_reader.iter(&mesh_events) {
+        if let AssetEvent::Modified { handle } | AssetEvent::Removed { handle } = event {
+            state.bind_pose_aabbs.remove(handle);
+        }
+    }
+
+    for (skinned_mesh, mesh_handle, global_transform, mut aabb, mut bounding_sphere) in
+        query.iter_mut()
+    {
+        let bind_pose_aabb = match meshes.get(mesh_handle) {
+            Some(mesh) => state
+                .bind_pose_aabbs
+                .entry(mesh_handle.clone_weak())
+                .or_insert_with(|| mesh.compute_aabb()),
+            None => continue,
+        };
+        let bind_pose_aabb = match bind_pose_aabb {
+            Some(bind_pose_aabb) => *bind_pose_aabb,
+            None => continue,
+        };
+        let posed_aabb = compute_posed_aabb(
+            &bind_pose_aabb,
+            &skinned_mesh.joint_matrices.0,
+            global_transform,
+        );
+        if let Some(posed_aabb) = posed_aabb {
+            *aabb = posed_aabb;
+            *bounding_sphere = BoundingSphere::from(&posed_aabb);
+        }
+    }
+}
+
+/// Returns the bounds in the entity's space that enclose `bind_pose_aabb` moved by each of the
+/// joint matrices, or `None` if there are no joints
+fn compute_posed_aabb(
+    bind_pose_aabb: &Aabb,
+    joint_matrices: &[Mat4],
+    global_transform: &GlobalTransform,
+) -> Option<Aabb> {
+    let (min, max) = (bind_pose_aabb.min(), bind_pose_aabb.max());
+    let corners = (0..8).map(|index| {
+        Vec3::new(
+            if index & 1 == 0 { min.x } else { max.x },
+            if index & 2 == 0 { min.y } else { max.y },
+            if index & 4 == 0 { min.z } else { max.z },
+        )
+    });
+    // joint matrices move vertices to world space, but the bounds are in the entity's space
+    let world_to_local = global_transform.compute_matrix().inverse();
+    Aabb::enclosing(joint_matrices.iter().flat_map(|joint_matrix| {
+        let joint_to_local = world_to_local * *joint_matrix;
+        corners
+            .clone()
+            .map(move |corner| joint_to_local.transform_point3(corner))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joint_matrices_move_joints_from_their_bind_pose() {
+        let joints = [Entity::new(0), Entity::new(1), Entity::new(2)];
+        let inverse_bindposes = [
+            Mat4::from_translation(Vec3::new(0.0, -1.0, 0.0)),
+            Mat4::from_translation(Vec3::new(0.0, -2.0, 0.0)),
+        ];
+        let joint_transform = |joint: Entity| match joint.id() {
+            0 => Some(Mat4::from_translation(Vec3::new(3.0, 1.0, 0.0))),
+            _ => None,
+        };
+
+        let mut joint_matrices = vec![Mat4::zero()];
+        compute_joint_matrices(
+            &mut joint_matrices,
+            &joints,
+            &inverse_bindposes,
+            joint_transform,
+        );
+        // the third joint has no inverse bind matrix, and the second one has no transform
+        assert_eq!(
+            joint_matrices,
+            vec![
+                Mat4::from_translation(Vec3::new(3.0, 0.0, 0.0)),
+                inverse_bindposes[1],
+            ]
+        );
+
+        let joints = vec![Entity::new(0); MAX_JOINTS + 1];
+        let inverse_bindposes = vec![Mat4::identity(); MAX_JOINTS + 1];
+        compute_joint_matrices(
+            &mut joint_matrices,
+            &joints,
+            &inverse_bindposes,
+            joint_transform,
+        );
+        assert_eq!(joint_matrices.len(), MAX_JOINTS);
+    }
+
+    #[test]
+    fn posed_bounds_enclose_every_joint() {
+        let bind_pose_aabb = Aabb::from_min_max(Vec3::new(-1.0, -1.0, -1.0), Vec3::one());
+        let joint_matrices = [
+            Mat4::identity(),
+            Mat4::from_translation(Vec3::new(4.0, 0.0, 0.0)),
+        ];
+        let global_transform = GlobalTransform::from_translation(Vec3::new(0.0, 2.0, 0.0));
+
+        let posed_aabb =
+            compute_posed_aabb(&bind_pose_aabb, &joint_matrices, &global_transform).unwrap();
+        assert_eq!(posed_aabb.min(), Vec3::new(-1.0, -3.0, -1.0));
+        assert_eq!(posed_aabb.max(), Vec3::new(5.0, -1.0, 1.0));
+
+        assert_eq!(
+            compute_posed_aabb(&bind_pose_aabb, &[], &global_transform),
+            None
+        );
+    }
+}
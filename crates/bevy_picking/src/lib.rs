@@ -0,0 +1,24 @@
+mod pick;
+pub mod raycast;
+
+pub use pick::*;
+
+pub mod prelude {
+    pub use crate::{PickEvent, PickEventKind, PickState, Pickable, PickingMode};
+}
+
+use bevy_app::prelude::*;
+
+/// Casts a ray from the camera through the cursor each frame and sends [PickEvent]s for the
+/// [Pickable] entities it hits
+#[derive(Default)]
+pub struct PickingPlugin;
+
+impl Plugin for PickingPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.init_resource::<PickingSettings>()
+            .init_resource::<PickState>()
+            .add_event::<PickEvent>()
+            .add_system_to_stage(bevy_app::stage::PRE_UPDATE, picking_system);
+    }
+}
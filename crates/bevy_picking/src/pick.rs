@@ -0,0 +1,220 @@
+use crate::raycast::intersect_mesh;
+use bevy_app::{EventReader, Events};
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_ecs::{Entity, Local, Query, Res, ResMut};
+use bevy_input::{mouse::MouseButton, Input};
+use bevy_math::{Ray, Vec2, Vec3};
+use bevy_render::{
+    camera::{ActiveCameras, Camera},
+    mesh::Mesh,
+    pipeline::PrimitiveTopology,
+    primitives::Aabb,
+    render_graph::base,
+};
+use bevy_transform::components::GlobalTransform;
+use bevy_utils::HashMap;
+use bevy_window::{CursorMoved, WindowId, Windows};
+use std::borrow::Cow;
+
+/// Makes an entity with a `Handle<Mesh>` pickable with the cursor
+#[derive(Debug, Clone, Default)]
+pub struct Pickable {
+    pub mode: PickingMode,
+}
+
+/// How precisely the cursor ray is tested against a [Pickable] entity
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PickingMode {
+    /// The ray is tested against the entity's [Aabb], or the bounding box of its mesh if it has
+    /// none. This is fast, but the hit can be outside of the mesh.
+    Bounds,
+    /// The ray is tested against each triangle of the mesh
+    Triangles,
+}
+
+impl Default for PickingMode {
+    fn default() -> Self {
+        PickingMode::Bounds
+    }
+}
+
+/// Configures picking
+#[derive(Debug, Clone)]
+pub struct PickingSettings {
+    /// The name of the [ActiveCameras] camera that casts rays through the cursor
+    pub camera: Cow<'static, str>,
+}
+
+impl Default for PickingSettings {
+    fn default() -> Self {
+        PickingSettings {
+            camera: base::camera::CAMERA3D.into(),
+        }
+    }
+}
+
+/// Where the cursor ray hits a [Pickable] entity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PickHit {
+    pub entity: Entity,
+    /// The distance from the camera's near plane along the cursor ray
+    pub distance: f32,
+    /// The world space position of the hit
+    pub position: Vec3,
+}
+
+/// The pickable entity under the cursor, updated in the PRE_UPDATE stage
+#[derive(Debug, Default)]
+pub struct PickState {
+    hovered: Option<PickHit>,
+}
+
+impl PickState {
+    pub fn hovered(&self) -> Option<&PickHit> {
+        self.hovered.as_ref()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PickEvent {
+    pub kind: PickEventKind,
+    /// The hit this frame, or the last hit for [PickEventKind::HoverEnded]
+    pub hit: PickHit,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PickEventKind {
+    /// The cursor moved onto the entity
+    HoverStarted,
+    /// The cursor moved off the entity
+    HoverEnded,
+    /// A mouse button was pressed while the cursor was over the entity
+    Clicked(MouseButton),
+}
+
+#[derive(Default)]
+pub struct PickingSystemState {
+    cursor_moved_event_reader: EventReader<CursorMoved>,
+    cursor: Option<(WindowId, Vec2)>,
+    mesh_event_reader: EventReader<AssetEvent<Mesh>>,
+    mesh_aabbs: HashMap<Handle<Mesh>, Option<Aabb>>,
+}
+
+/// Finds the closest [Pickable] entity under the cursor and sends [PickEvent]s when it changes
+/// or is clicked
+#[allow(clippy::too_many_arguments)]
+pub fn picking_system(
+    mut state: Local<PickingSystemState>,
+    settings: Res<PickingSettings>,
+    mut pick_state: ResMut<PickState>,
+    mut pick_events: ResMut<Events<PickEvent>>,
+    cursor_moved_events: Res<Events<CursorMoved>>,
+    mesh_events: Res<Events<AssetEvent<Mesh>>>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    windows: Res<Windows>,
+    active_cameras: Res<ActiveCameras>,
+    meshes: Res<Assets<Mesh>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    pickables: Query<(
+        Entity,
+        &Pickable,
+        &Handle<Mesh>,
+        &GlobalTransform,
+        Option<&Aabb>,
+    )>,
+) {
+    let state = &mut *state;
+    if let Some(cursor_moved) = state.cursor_moved_event_reader.latest(&cursor_moved_events) {
+        state.cursor = Some((cursor_moved.id, cursor_moved.position));
+    }
+    for event in state.mesh_event_reader.iter(&mesh_events) {
+        match event {
+            AssetEvent::Created { handle }
+            | AssetEvent::Modified { handle }
+            | AssetEvent::Removed { handle } => {
+                state.mesh_aabbs.remove(handle);
+            }
+        }
+    }
+
+    let ray = state.cursor.and_then(|(window_id, cursor_position)| {
+        let (camera, camera_transform) = active_cameras
+            .get(&settings.camera)
+            .and_then(|camera| cameras.get(camera).ok())?;
+        if camera.window != window_id {
+            return None;
+        }
+        camera.viewport_to_world(&windows, camera_transform, cursor_position)
+    });
+
+    let mut closest: Option<PickHit> = None;
+    if let Some(ray) = ray {
+        for (entity, pickable, mesh_handle, transform, aabb) in pickables.iter() {
+            let mesh = match meshes.get(mesh_handle) {
+                Some(mesh) => mesh,
+                None => continue,
+            };
+            // entities without bounds of their own use the bounds of their mesh
+            let aabb = match aabb.copied().or_else(|| {
+                *state
+                    .mesh_aabbs
+                    .entry(mesh_handle.clone_weak())
+                    .or_insert_with(|| mesh.compute_aabb())
+            }) {
+                Some(aabb) => aabb,
+                None => continue,
+            };
+            // the local ray isn't normalized, so distances along it are world space distances
+            let world_to_local = transform.compute_matrix().inverse();
+            let local_ray = Ray::new(
+                world_to_local.transform_point3(ray.origin),
+                world_to_local.transform_vector3(ray.direction),
+            );
+            let distance = match aabb.intersect_ray(&local_ray) {
+                Some(distance) if pickable.mode == PickingMode::Triangles => {
+                    // meshes that aren't triangle lists are picked by their bounds
+                    if mesh.primitive_topology() == PrimitiveTopology::TriangleList {
+                        intersect_mesh(&local_ray, mesh)
+                    } else {
+                        Some(distance)
+                    }
+                }
+                distance => distance,
+            };
+            if let Some(distance) = distance {
+                if closest.map_or(true, |closest| distance < closest.distance) {
+                    closest = Some(PickHit {
+                        entity,
+                        distance,
+                        position: ray.get_point(distance),
+                    });
+                }
+            }
+        }
+    }
+
+    let previous = pick_state.hovered;
+    if previous.map(|hit| hit.entity) != closest.map(|hit| hit.entity) {
+        if let Some(hit) = previous {
+            pick_events.send(PickEvent {
+                kind: PickEventKind::HoverEnded,
+                hit,
+            });
+        }
+        if let Some(hit) = closest {
+            pick_events.send(PickEvent {
+                kind: PickEventKind::HoverStarted,
+                hit,
+            });
+        }
+    }
+    if let Some(hit) = closest {
+        for button in mouse_button_input.get_just_pressed() {
+            pick_events.send(PickEvent {
+                kind: PickEventKind::Clicked(*button),
+                hit,
+            });
+        }
+    }
+    pick_state.hovered = closest;
+}
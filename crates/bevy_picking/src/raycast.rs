@@ -0,0 +1,89 @@
+use bevy_math::{Ray, Vec3};
+use bevy_render::{
+    mesh::{Indices, Mesh, VertexAttributeValues},
+    pipeline::PrimitiveTopology,
+};
+
+/// Returns the distance along `ray` at which it hits the triangle with the given corners, from
+/// either side. Returns `None` if the ray misses the triangle.
+pub fn intersect_triangle(ray: &Ray, triangle: [Vec3; 3]) -> Option<f32> {
+    // Möller–Trumbore intersection
+    let edge_1 = triangle[1] - triangle[0];
+    let edge_2 = triangle[2] - triangle[0];
+    let p = ray.direction.cross(edge_2);
+    let determinant = edge_1.dot(p);
+    if determinant.abs() <= std::f32::EPSILON {
+        // the ray is parallel to the triangle
+        return None;
+    }
+    let inverse_determinant = 1.0 / determinant;
+    let t = ray.origin - triangle[0];
+    let u = t.dot(p) * inverse_determinant;
+    if u < 0.0 || u > 1.0 {
+        return None;
+    }
+    let q = t.cross(edge_1);
+    let v = ray.direction.dot(q) * inverse_determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let distance = edge_2.dot(q) * inverse_determinant;
+    if distance >= 0.0 {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+/// Returns the distance along `ray` to the closest triangle of `mesh` that it hits, with the ray
+/// in the mesh's local space. Returns `None` if the ray misses the mesh, or if the mesh isn't a
+/// [PrimitiveTopology::TriangleList] with `Float3` positions.
+pub fn intersect_mesh(ray: &Ray, mesh: &Mesh) -> Option<f32> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float3(positions) => positions,
+        _ => return None,
+    };
+    let indices: Box<dyn Iterator<Item = usize>> = match mesh.indices() {
+        Some(Indices::U16(indices)) => Box::new(indices.iter().map(|index| *index as usize)),
+        Some(Indices::U32(indices)) => Box::new(indices.iter().map(|index| *index as usize)),
+        None => Box::new(0..positions.len()),
+    };
+
+    let mut closest: Option<f32> = None;
+    let mut corners =
+        indices.map(|index| positions.get(index).map(|position| Vec3::from(*position)));
+    while let (Some(a), Some(b), Some(c)) = (corners.next(), corners.next(), corners.next()) {
+        let (a, b, c) = match (a, b, c) {
+            (Some(a), Some(b), Some(c)) => (a, b, c),
+            // skip triangles with out of range indices
+            _ => continue,
+        };
+        if let Some(distance) = intersect_triangle(ray, [a, b, c]) {
+            if closest.map_or(true, |closest| distance < closest) {
+                closest = Some(distance);
+            }
+        }
+    }
+    closest
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy_render::mesh::shape;
+
+    #[test]
+    fn ray_hits_closest_triangle() {
+        let mesh = Mesh::from(shape::Cube { size: 1.0 });
+
+        let ray = Ray::new(Vec3::new(0.2, 0.1, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        let distance = intersect_mesh(&ray, &mesh).unwrap();
+        assert!((distance - 4.5).abs() < 1e-5);
+
+        let ray = Ray::new(Vec3::new(2.0, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(intersect_mesh(&ray, &mesh), None);
+    }
+}
@@ -1,9 +1,10 @@
 use super::CameraProjection;
 use bevy_app::prelude::{EventReader, Events};
 use bevy_ecs::{Added, Component, Entity, Local, Query, QuerySet, Res};
-use bevy_math::Mat4;
+use bevy_math::{Mat4, Ray, Vec2, Vec3};
 use bevy_property::Properties;
-use bevy_window::{WindowCreated, WindowId, WindowResized, Windows};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::{WindowCreated, WindowId, WindowResized, WindowScaleFactorChanged, Windows};
 
 #[derive(Default, Debug, Properties)]
 pub struct Camera {
@@ -15,6 +16,59 @@ pub struct Camera {
     pub depth_calculation: DepthCalculation,
 }
 
+impl Camera {
+    /// Returns the ray from the camera through `viewport_position`, in logical pixels from the
+    /// bottom left of the camera's window like the positions of
+    /// [CursorMoved](bevy_window::CursorMoved) events. Returns `None` if the camera's window
+    /// doesn't exist.
+    pub fn viewport_to_world(
+        &self,
+        windows: &Windows,
+        camera_transform: &GlobalTransform,
+        viewport_position: Vec2,
+    ) -> Option<Ray> {
+        let window = windows.get(self.window)?;
+        let window_size = Vec2::new(window.width(), window.height());
+        let ndc = viewport_position / window_size * 2.0 - Vec2::one();
+        let near = self.ndc_to_world(camera_transform, Vec3::new(ndc.x, ndc.y, 0.0));
+        let far = self.ndc_to_world(camera_transform, Vec3::new(ndc.x, ndc.y, 1.0));
+        Some(Ray::new(near, (far - near).normalize()))
+    }
+
+    /// Returns the position of `world_position` in logical pixels from the bottom left of the
+    /// camera's window. Returns `None` if the position is outside of the camera's depth range or
+    /// the camera's window doesn't exist.
+    pub fn world_to_viewport(
+        &self,
+        windows: &Windows,
+        camera_transform: &GlobalTransform,
+        world_position: Vec3,
+    ) -> Option<Vec2> {
+        let window = windows.get(self.window)?;
+        let window_size = Vec2::new(window.width(), window.height());
+        let ndc = self.world_to_ndc(camera_transform, world_position);
+        if ndc.z < 0.0 || ndc.z > 1.0 {
+            return None;
+        }
+        Some((Vec2::new(ndc.x, ndc.y) + Vec2::one()) / 2.0 * window_size)
+    }
+
+    /// Converts a position in normalized device coordinates, where x and y go from -1 to 1 across
+    /// the viewport and z from 0 at the near plane to 1 at the far plane, to world space
+    pub fn ndc_to_world(&self, camera_transform: &GlobalTransform, ndc: Vec3) -> Vec3 {
+        let ndc_to_world = camera_transform.compute_matrix() * self.projection_matrix.inverse();
+        let position = ndc_to_world * ndc.extend(1.0);
+        position.truncate() / position.w
+    }
+
+    /// Converts a world space position to normalized device coordinates
+    pub fn world_to_ndc(&self, camera_transform: &GlobalTransform, world_position: Vec3) -> Vec3 {
+        let world_to_ndc = self.projection_matrix * camera_transform.compute_matrix().inverse();
+        let position = world_to_ndc * world_position.extend(1.0);
+        position.truncate() / position.w
+    }
+}
+
 #[derive(Debug)]
 pub enum DepthCalculation {
     Distance,
@@ -31,12 +85,14 @@ impl Default for DepthCalculation {
 pub struct CameraSystemState {
     window_resized_event_reader: EventReader<WindowResized>,
     window_created_event_reader: EventReader<WindowCreated>,
+    window_scale_factor_changed_event_reader: EventReader<WindowScaleFactorChanged>,
 }
 
 pub fn camera_system<T: CameraProjection + Component>(
     mut state: Local<CameraSystemState>,
     window_resized_events: Res<Events<WindowResized>>,
     window_created_events: Res<Events<WindowCreated>>,
+    window_scale_factor_changed_events: Res<Events<WindowScaleFactorChanged>>,
     windows: Res<Windows>,
     mut queries: QuerySet<(
         Query<(Entity, &mut Camera, &mut T)>,
@@ -70,6 +126,16 @@ pub fn camera_system<T: CameraProjection + Component>(
         changed_window_ids.push(event.id);
     }
 
+    // the size of the window in physical pixels changes with its scale factor
+    for event in state
+        .window_scale_factor_changed_event_reader
+        .iter(&window_scale_factor_changed_events)
+    {
+        if !changed_window_ids.contains(&event.id) {
+            changed_window_ids.push(event.id);
+        }
+    }
+
     let mut added_cameras = vec![];
     for entity in &mut queries.q1().iter() {
         added_cameras.push(entity);
@@ -77,10 +143,41 @@ pub fn camera_system<T: CameraProjection + Component>(
     for (entity, mut camera, mut camera_projection) in queries.q0_mut().iter_mut() {
         if let Some(window) = windows.get(camera.window) {
             if changed_window_ids.contains(&window.id()) || added_cameras.contains(&entity) {
-                camera_projection.update(window.width() as usize, window.height() as usize);
+                camera_projection.update_with_scale_factor(
+                    window.width(),
+                    window.height(),
+                    window.scale_factor() as f32,
+                );
                 camera.projection_matrix = camera_projection.get_projection_matrix();
                 camera.depth_calculation = camera_projection.depth_calculation();
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::camera::{CameraProjection, PerspectiveProjection};
+
+    #[test]
+    fn ndc_round_trips_through_world_space() {
+        let mut projection = PerspectiveProjection::default();
+        projection.update(800.0, 600.0);
+        let camera = Camera {
+            projection_matrix: projection.get_projection_matrix(),
+            ..Default::default()
+        };
+        let camera_transform = GlobalTransform::from_translation(Vec3::new(0.0, 2.0, 10.0));
+
+        // the center of the viewport is straight ahead of the camera
+        let near = camera.ndc_to_world(&camera_transform, Vec3::new(0.0, 0.0, 0.0));
+        assert!((near - Vec3::new(0.0, 2.0, 10.0 - projection.near)).length() < 1e-4);
+
+        let world_position = Vec3::new(1.0, -1.0, 0.0);
+        let ndc = camera.world_to_ndc(&camera_transform, world_position);
+        assert!(ndc.z > 0.0 && ndc.z < 1.0);
+        let round_trip = camera.ndc_to_world(&camera_transform, ndc);
+        assert!((round_trip - world_position).length() < 1e-3);
+    }
+}
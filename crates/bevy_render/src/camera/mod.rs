@@ -2,9 +2,11 @@ mod active_cameras;
 #[allow(clippy::module_inception)]
 mod camera;
 mod projection;
+mod sorting_layer;
 mod visible_entities;
 
 pub use active_cameras::*;
 pub use camera::*;
 pub use projection::*;
+pub use sorting_layer::*;
 pub use visible_entities::*;
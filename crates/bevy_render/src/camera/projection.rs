@@ -1,11 +1,19 @@
 use super::DepthCalculation;
+use bevy_ecs::{Changed, Query};
 use bevy_math::Mat4;
 use bevy_property::{Properties, Property};
+use bevy_transform::components::GlobalTransform;
 use serde::{Deserialize, Serialize};
 
 pub trait CameraProjection {
     fn get_projection_matrix(&self) -> Mat4;
-    fn update(&mut self, width: usize, height: usize);
+    /// Updates the projection for a window that is `width` by `height` logical pixels
+    fn update(&mut self, width: f32, height: f32);
+    /// Like [CameraProjection::update], for projections that depend on the number of physical
+    /// pixels of the window
+    fn update_with_scale_factor(&mut self, width: f32, height: f32, _scale_factor: f32) {
+        self.update(width, height);
+    }
     fn depth_calculation(&self) -> DepthCalculation;
 }
 
@@ -22,8 +30,8 @@ impl CameraProjection for PerspectiveProjection {
         Mat4::perspective_rh(self.fov, self.aspect_ratio, self.near, self.far)
     }
 
-    fn update(&mut self, width: usize, height: usize) {
-        self.aspect_ratio = width as f32 / height as f32;
+    fn update(&mut self, width: f32, height: f32) {
+        self.aspect_ratio = width / height;
     }
 
     fn depth_calculation(&self) -> DepthCalculation {
@@ -49,6 +57,44 @@ pub enum WindowOrigin {
     BottomLeft,
 }
 
+/// Determines how many pixels an [OrthographicProjection] uses for each world unit
+#[derive(Debug, Clone, Property, Serialize, Deserialize)]
+pub enum ScalingMode {
+    /// One world unit is one pixel
+    WindowSize,
+    /// Each world unit covers a whole number of physical pixels, so low resolution pixel art is
+    /// drawn without shimmering, also when the window's scale factor is fractional. The largest
+    /// factor that still shows at least `min_width` by `min_height` world units is used, but never
+    /// less than one.
+    PixelPerfect { min_width: f32, min_height: f32 },
+}
+
+impl ScalingMode {
+    /// Returns the number of physical pixels per world unit for a window that is `physical_width`
+    /// by `physical_height` pixels and has the given scale factor
+    pub fn scale_factor(
+        &self,
+        physical_width: f32,
+        physical_height: f32,
+        window_scale_factor: f32,
+    ) -> f32 {
+        match self {
+            ScalingMode::WindowSize => window_scale_factor,
+            ScalingMode::PixelPerfect {
+                min_width,
+                min_height,
+            } => (physical_width / min_width)
+                .min(physical_height / min_height)
+                .floor()
+                .max(1.0),
+        }
+    }
+
+    pub fn is_pixel_perfect(&self) -> bool {
+        matches!(self, ScalingMode::PixelPerfect { .. })
+    }
+}
+
 #[derive(Debug, Clone, Properties)]
 pub struct OrthographicProjection {
     pub left: f32,
@@ -58,6 +104,7 @@ pub struct OrthographicProjection {
     pub near: f32,
     pub far: f32,
     pub window_origin: WindowOrigin,
+    pub scaling_mode: ScalingMode,
 }
 
 impl CameraProjection for OrthographicProjection {
@@ -72,23 +119,28 @@ impl CameraProjection for OrthographicProjection {
         )
     }
 
-    fn update(&mut self, width: usize, height: usize) {
-        match self.window_origin {
-            WindowOrigin::Center => {
-                let half_width = width as f32 / 2.0;
-                let half_height = height as f32 / 2.0;
-                self.left = -half_width;
-                self.right = half_width;
-                self.top = half_height;
-                self.bottom = -half_height;
-            }
-            WindowOrigin::BottomLeft => {
-                self.left = 0.0;
-                self.right = width as f32;
-                self.top = height as f32;
-                self.bottom = 0.0;
-            }
+    fn update(&mut self, width: f32, height: f32) {
+        self.update_with_scale_factor(width, height, 1.0);
+    }
+
+    fn update_with_scale_factor(&mut self, width: f32, height: f32, scale_factor: f32) {
+        // compute the bounds in physical pixels first, then convert them to world units
+        let (width, height) = (width * scale_factor, height * scale_factor);
+        let (mut left, mut bottom) = match self.window_origin {
+            WindowOrigin::Center => (-width / 2.0, -height / 2.0),
+            WindowOrigin::BottomLeft => (0.0, 0.0),
+        };
+        if self.scaling_mode.is_pixel_perfect() {
+            // keep the origin on a pixel boundary when the window has an odd size
+            left = left.floor();
+            bottom = bottom.floor();
         }
+
+        let scale = self.scaling_mode.scale_factor(width, height, scale_factor);
+        self.left = left / scale;
+        self.right = (left + width) / scale;
+        self.bottom = bottom / scale;
+        self.top = (bottom + height) / scale;
     }
 
     fn depth_calculation(&self) -> DepthCalculation {
@@ -106,6 +158,73 @@ impl Default for OrthographicProjection {
             near: 0.0,
             far: 1000.0,
             window_origin: WindowOrigin::Center,
+            scaling_mode: ScalingMode::WindowSize,
+        }
+    }
+}
+
+/// Snaps cameras that use [ScalingMode::PixelPerfect] to whole world units, so the pixel grid
+/// doesn't shift while the camera moves
+pub fn pixel_perfect_camera_system(
+    mut query: Query<(&OrthographicProjection, &mut GlobalTransform), Changed<GlobalTransform>>,
+) {
+    for (projection, mut global_transform) in query.iter_mut() {
+        if !projection.scaling_mode.is_pixel_perfect() {
+            continue;
         }
+
+        let translation = global_transform.translation;
+        let snapped_x = translation.x.round();
+        let snapped_y = translation.y.round();
+        #[allow(clippy::float_cmp)]
+        let is_snapped = translation.x == snapped_x && translation.y == snapped_y;
+        if !is_snapped {
+            global_transform.translation.x = snapped_x;
+            global_transform.translation.y = snapped_y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pixel_perfect_projection_uses_whole_pixels() {
+        let mut projection = OrthographicProjection {
+            scaling_mode: ScalingMode::PixelPerfect {
+                min_width: 320.0,
+                min_height: 180.0,
+            },
+            ..Default::default()
+        };
+
+        // 1001 / 320 = 3.1, 700 / 180 = 3.9, so each world unit covers 3 pixels
+        projection.update(1001.0, 700.0);
+        assert_eq!(projection.left, -501.0 / 3.0);
+        assert_eq!(projection.right, 500.0 / 3.0);
+        assert_eq!(projection.bottom, -350.0 / 3.0);
+        assert_eq!(projection.top, 350.0 / 3.0);
+
+        // smaller windows never scale below one pixel per world unit
+        projection.update(200.0, 100.0);
+        assert_eq!(projection.right - projection.left, 200.0);
+
+        // 800x450 logical pixels at a scale factor of 1.5 are 1200x675 physical pixels, so each
+        // world unit covers 3 physical pixels rather than 2.5 logical ones
+        projection.update_with_scale_factor(800.0, 450.0, 1.5);
+        assert_eq!(projection.right - projection.left, 400.0);
+        assert_eq!(projection.bottom, -338.0 / 3.0);
+        assert_eq!(projection.top, 337.0 / 3.0);
+    }
+
+    #[test]
+    fn window_size_projection_uses_logical_pixels() {
+        let mut projection = OrthographicProjection::default();
+        projection.update_with_scale_factor(800.0, 600.0, 1.5);
+        assert_eq!(projection.left, -400.0);
+        assert_eq!(projection.right, 400.0);
+        assert_eq!(projection.bottom, -300.0);
+        assert_eq!(projection.top, 300.0);
     }
 }
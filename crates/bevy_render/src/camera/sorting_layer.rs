@@ -0,0 +1,97 @@
+use bevy_property::Properties;
+
+/// The named sorting layers transparent entities can be drawn on, listed back-to-front.
+///
+/// Entities on a later layer are always drawn on top of entities on an earlier layer, regardless of
+/// their depth. Entities without a [SortingLayer] and entities on a layer that isn't listed are
+/// drawn on the [SortingLayers::DEFAULT] layer.
+#[derive(Debug, Clone)]
+pub struct SortingLayers {
+    layers: Vec<String>,
+}
+
+impl SortingLayers {
+    pub const DEFAULT: &'static str = "Default";
+
+    /// Creates sorting layers in the given back-to-front order. The default layer is added in front
+    /// of the other layers if it isn't listed.
+    pub fn new<T: Into<String>>(layers: impl IntoIterator<Item = T>) -> Self {
+        let mut layers = layers.into_iter().map(Into::into).collect::<Vec<String>>();
+        if !layers.iter().any(|layer| layer == Self::DEFAULT) {
+            layers.insert(0, Self::DEFAULT.to_string());
+        }
+        Self { layers }
+    }
+
+    /// Adds a layer in front of every existing layer
+    pub fn push(&mut self, layer: impl Into<String>) -> &mut Self {
+        let layer = layer.into();
+        if !self.layers.contains(&layer) {
+            self.layers.push(layer);
+        }
+        self
+    }
+
+    /// Returns the position of `layer`, where layers with a larger position are drawn on top
+    pub fn order(&self, layer: &str) -> Option<usize> {
+        self.layers.iter().position(|name| name == layer)
+    }
+
+    /// Returns the position of `layer`, falling back to the position of the default layer
+    pub fn order_or_default(&self, layer: &str) -> usize {
+        self.order(layer)
+            .or_else(|| self.order(Self::DEFAULT))
+            .unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.layers.iter().map(|layer| layer.as_str())
+    }
+}
+
+impl Default for SortingLayers {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+/// Places a transparent entity on one of the [SortingLayers]. Within a layer, entities with a
+/// larger `order` are drawn on top. Entities with the same layer and order fall back to being
+/// sorted by depth.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Properties)]
+pub struct SortingLayer {
+    pub layer: String,
+    pub order: i32,
+}
+
+impl SortingLayer {
+    pub fn new(layer: impl Into<String>, order: i32) -> Self {
+        Self {
+            layer: layer.into(),
+            order,
+        }
+    }
+}
+
+impl Default for SortingLayer {
+    fn default() -> Self {
+        Self::new(SortingLayers::DEFAULT, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unknown_layers_use_default_order() {
+        let layers = SortingLayers::new(vec!["Background", "Default", "Foreground"]);
+        assert_eq!(layers.order_or_default("Background"), 0);
+        assert_eq!(layers.order_or_default("Foreground"), 2);
+        assert_eq!(layers.order_or_default("Missing"), 1);
+
+        let layers = SortingLayers::new(vec!["Foreground"]);
+        assert_eq!(layers.order("Default"), Some(0));
+        assert_eq!(layers.order("Foreground"), Some(1));
+    }
+}
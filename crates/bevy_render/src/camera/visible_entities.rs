@@ -1,9 +1,13 @@
-use super::{Camera, DepthCalculation};
-use crate::Draw;
+use super::{Camera, DepthCalculation, SortingLayer, SortingLayers};
+use crate::{
+    primitives::{Aabb, Frustum},
+    Draw,
+};
 use bevy_core::FloatOrd;
-use bevy_ecs::{Entity, Query, With};
+use bevy_ecs::{Entity, Query, Res, With, Without};
 use bevy_property::Properties;
 use bevy_transform::prelude::GlobalTransform;
+use bevy_utils::HashSet;
 
 #[derive(Debug)]
 pub struct VisibleEntity {
@@ -23,14 +27,37 @@ impl VisibleEntities {
     }
 }
 
+/// Whether an entity was visible to any camera in the last update. Entities are visible when
+/// their [Draw] is visible and their [Aabb], if they have one, is inside a camera's frustum.
+#[derive(Debug, Default, Clone, Properties)]
+pub struct ComputedVisibility {
+    pub is_visible: bool,
+}
+
+/// Keeps an entity from being frustum culled, for example because its vertices are moved on the
+/// GPU so its [Aabb] can't be computed from its mesh
+#[derive(Debug, Default, Clone, Copy, Properties)]
+pub struct NoFrustumCulling;
+
+/// Collects the entities each camera sees in draw order, skipping entities whose [Aabb] is outside
+/// of the camera's frustum, and updates their [ComputedVisibility]
 pub fn visible_entities_system(
+    sorting_layers: Res<SortingLayers>,
     mut camera_query: Query<(&Camera, &GlobalTransform, &mut VisibleEntities)>,
     draw_query: Query<(Entity, &Draw)>,
     draw_transform_query: Query<&GlobalTransform, With<Draw>>,
+    sorting_layer_query: Query<&SortingLayer, With<Draw>>,
+    bounds_query: Query<&Aabb, (With<Draw>, Without<NoFrustumCulling>)>,
+    mut visibility_query: Query<(Entity, &mut ComputedVisibility)>,
 ) {
+    let default_layer = sorting_layers.order_or_default(SortingLayers::DEFAULT);
+    let mut visible = HashSet::default();
     for (camera, camera_global_transform, mut visible_entities) in camera_query.iter_mut() {
         visible_entities.value.clear();
         let camera_position = camera_global_transform.translation;
+        let frustum = Frustum::from_view_projection(
+            &(camera.projection_matrix * camera_global_transform.compute_matrix().inverse()),
+        );
 
         let mut no_transform_order = 0.0;
         let mut transparent_entities = Vec::new();
@@ -38,6 +65,14 @@ pub fn visible_entities_system(
             if !draw.is_visible {
                 continue;
             }
+            if let (Ok(aabb), Ok(global_transform)) =
+                (bounds_query.get(entity), draw_transform_query.get(entity))
+            {
+                if !frustum.intersects_obb(aabb, &global_transform.compute_matrix()) {
+                    continue;
+                }
+            }
+            visible.insert(entity);
 
             let order = if let Ok(global_transform) = draw_transform_query.get(entity) {
                 let position = global_transform.translation;
@@ -53,19 +88,40 @@ pub fn visible_entities_system(
             };
 
             if draw.is_transparent {
-                transparent_entities.push(VisibleEntity { entity, order })
+                let layer = sorting_layer_query
+                    .get(entity)
+                    .map(|sorting_layer| {
+                        (
+                            sorting_layers.order_or_default(&sorting_layer.layer),
+                            sorting_layer.order,
+                        )
+                    })
+                    .unwrap_or((default_layer, 0));
+                transparent_entities.push((layer, VisibleEntity { entity, order }))
             } else {
                 visible_entities.value.push(VisibleEntity { entity, order })
             }
         }
 
         // sort opaque entities front-to-back
-        visible_entities.value.sort_by_key(|e| e.order);
+        // ties are broken by entity so that entities at the same depth keep a stable draw order
+        visible_entities.value.sort_by_key(|e| (e.order, e.entity));
 
-        // sort transparent entities front-to-back
-        transparent_entities.sort_by_key(|e| -e.order);
-        visible_entities.value.extend(transparent_entities);
+        // sort transparent entities by sorting layer, then back-to-front within each layer
+        transparent_entities.sort_by_key(|(layer, e)| (*layer, -e.order, e.entity));
+        visible_entities
+            .value
+            .extend(transparent_entities.into_iter().map(|(_, e)| e));
 
         // TODO: check for big changes in visible entities len() vs capacity() (ex: 2x) and resize to prevent holding unneeded memory
     }
+
+    for (entity, mut computed_visibility) in visibility_query.iter_mut() {
+        let is_visible = visible.contains(&entity);
+        // only write changes, so queries for mutated visibility see when entities enter or leave
+        // the view
+        if computed_visibility.is_visible != is_visible {
+            computed_visibility.is_visible = is_visible;
+        }
+    }
 }
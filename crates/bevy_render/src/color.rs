@@ -6,14 +6,18 @@ use crate::{
 };
 use bevy_asset::Handle;
 use bevy_core::{Byteable, Bytes};
-use bevy_math::{Vec3, Vec4};
+use bevy_math::{Interpolate, Vec3, Vec4};
 use bevy_property::Property;
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Mul, MulAssign};
+use thiserror::Error;
 
-// TODO: Separate types for non-linear sRGB and linear sRGB, with conversions between
-// see comment on bevy issue #688 https://github.com/bevyengine/bevy/pull/688#issuecomment-711414011
 /// RGBA color in the Linear sRGB colorspace (often colloquially referred to as "linear", "RGB", or "linear RGB").
+///
+/// Colors are stored and sent to shaders as linear values. The constructors and accessors without
+/// a `_linear` suffix, as well as the HSL and HSV ones, work with non-linear sRGB values, which is
+/// what color pickers and image editors show. Conversions to and from `[f32; 4]` and `Vec4`, and
+/// arithmetic, use the linear values.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Property)]
 pub struct Color {
@@ -25,6 +29,17 @@ pub struct Color {
 
 unsafe impl Byteable for Color {}
 
+impl Interpolate for Color {
+    fn interpolate(self, other: Self, t: f32) -> Self {
+        Color {
+            red: self.red.interpolate(other.red, t),
+            green: self.green.interpolate(other.green, t),
+            blue: self.blue.interpolate(other.blue, t),
+            alpha: self.alpha.interpolate(other.alpha, t),
+        }
+    }
+}
+
 impl Color {
     pub const ALICE_BLUE: Color = Color::rgb_linear(0.94, 0.97, 1.0);
     pub const ANTIQUE_WHITE: Color = Color::rgb_linear(0.98, 0.92, 0.84);
@@ -109,9 +124,11 @@ impl Color {
         }
     }
 
-    /// New ``Color`` from sRGB colorspace.
+    /// New ``Color`` from sRGB colorspace, given as `RGB`, `RGBA`, `RRGGBB` or `RRGGBBAA` hex
+    /// digits with an optional leading `#`.
     pub fn hex<T: AsRef<str>>(hex: T) -> Result<Color, HexColorError> {
         let hex = hex.as_ref();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
 
         // RGB
         if hex.len() == 3 {
@@ -163,6 +180,32 @@ impl Color {
         )
     }
 
+    /// New ``Color`` from hue in degrees, and saturation and lightness in `0.0..=1.0`, in sRGB
+    /// colorspace.
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        Color::hsla(hue, saturation, lightness, 1.0)
+    }
+
+    /// New ``Color`` from hue in degrees, and saturation and lightness in `0.0..=1.0`, in sRGB
+    /// colorspace.
+    pub fn hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Color {
+        let [r, g, b] = HslRepresentation::hsl_to_nonlinear_srgb(hue, saturation, lightness);
+        Color::rgba(r, g, b, alpha)
+    }
+
+    /// New ``Color`` from hue in degrees, and saturation and value in `0.0..=1.0`, in sRGB
+    /// colorspace.
+    pub fn hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        Color::hsva(hue, saturation, value, 1.0)
+    }
+
+    /// New ``Color`` from hue in degrees, and saturation and value in `0.0..=1.0`, in sRGB
+    /// colorspace.
+    pub fn hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+        let [r, g, b] = HsvRepresentation::hsv_to_nonlinear_srgb(hue, saturation, value);
+        Color::rgba(r, g, b, alpha)
+    }
+
     fn as_nonlinear_srgb_to_linear_srgb(self) -> Color {
         Color {
             red: self.red.nonlinear_to_linear_srgb(),
@@ -172,6 +215,40 @@ impl Color {
         }
     }
 
+    // Conversions
+
+    /// Get red, green, blue and alpha in sRGB colorspace.
+    pub fn as_rgba_f32(&self) -> [f32; 4] {
+        [self.r(), self.g(), self.b(), self.a()]
+    }
+
+    /// Get red, green, blue and alpha in linear colorspace. This is what shaders expect, for
+    /// example in vertex colors.
+    pub fn as_linear_rgba_f32(&self) -> [f32; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
+    /// Get red, green, blue and alpha as bytes in sRGB colorspace, as stored in most images.
+    pub fn as_rgba_u8(&self) -> [u8; 4] {
+        let [r, g, b, a] = self.as_rgba_f32();
+        let to_u8 = |component: f32| (component.max(0.0).min(1.0) * u8::MAX as f32).round() as u8;
+        [to_u8(r), to_u8(g), to_u8(b), to_u8(a)]
+    }
+
+    /// Get hue in degrees, saturation, lightness and alpha in sRGB colorspace.
+    pub fn as_hsla_f32(&self) -> [f32; 4] {
+        let (hue, saturation, lightness) =
+            HslRepresentation::nonlinear_srgb_to_hsl([self.r(), self.g(), self.b()]);
+        [hue, saturation, lightness, self.alpha]
+    }
+
+    /// Get hue in degrees, saturation, value and alpha in sRGB colorspace.
+    pub fn as_hsva_f32(&self) -> [f32; 4] {
+        let (hue, saturation, value) =
+            HsvRepresentation::nonlinear_srgb_to_hsv([self.r(), self.g(), self.b()]);
+        [hue, saturation, value, self.alpha]
+    }
+
     // non-linear-sRGB Component Getter
 
     /// Get red in sRGB colorspace.
@@ -303,25 +380,25 @@ impl Add<Vec4> for Color {
 
 impl From<Color> for [f32; 4] {
     fn from(color: Color) -> Self {
-        [color.r(), color.g(), color.b(), color.a()]
+        color.as_linear_rgba_f32()
     }
 }
 
 impl From<[f32; 4]> for Color {
     fn from([r, g, b, a]: [f32; 4]) -> Self {
-        Color::rgba(r, g, b, a)
+        Color::rgba_linear(r, g, b, a)
     }
 }
 
 impl From<Color> for Vec4 {
     fn from(color: Color) -> Self {
-        Vec4::new(color.r(), color.g(), color.b(), color.a())
+        Vec4::from(color.as_linear_rgba_f32())
     }
 }
 
 impl From<Vec4> for Color {
     fn from(vec4: Vec4) -> Self {
-        Color::rgba(vec4.x, vec4.y, vec4.z, vec4.w)
+        Color::rgba_linear(vec4.x, vec4.y, vec4.z, vec4.w)
     }
 }
 
@@ -329,15 +406,18 @@ impl Mul<f32> for Color {
     type Output = Color;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        Color::rgba(self.r() * rhs, self.g() * rhs, self.b() * rhs, self.a())
+        Color::rgba_linear(
+            self.red * rhs,
+            self.green * rhs,
+            self.blue * rhs,
+            self.alpha,
+        )
     }
 }
 
 impl MulAssign<f32> for Color {
     fn mul_assign(&mut self, rhs: f32) {
-        self.set_r(self.r() * rhs);
-        self.set_g(self.g() * rhs);
-        self.set_b(self.b() * rhs);
+        *self = *self * rhs;
     }
 }
 
@@ -345,21 +425,13 @@ impl Mul<Vec4> for Color {
     type Output = Color;
 
     fn mul(self, rhs: Vec4) -> Self::Output {
-        Color::rgba(
-            self.r() * rhs.x,
-            self.g() * rhs.y,
-            self.b() * rhs.z,
-            self.a() * rhs.w,
-        )
+        Color::from(Vec4::from(self) * rhs)
     }
 }
 
 impl MulAssign<Vec4> for Color {
     fn mul_assign(&mut self, rhs: Vec4) {
-        self.set_r(self.r() * rhs.x);
-        self.set_g(self.g() * rhs.y);
-        self.set_b(self.b() * rhs.z);
-        self.set_a(self.a() * rhs.w);
+        *self = *self * rhs;
     }
 }
 
@@ -367,53 +439,41 @@ impl Mul<Vec3> for Color {
     type Output = Color;
 
     fn mul(self, rhs: Vec3) -> Self::Output {
-        Color::rgba(
-            self.r() * rhs.x,
-            self.g() * rhs.y,
-            self.b() * rhs.z,
-            self.a(),
-        )
+        self * rhs.extend(1.0)
     }
 }
 
 impl MulAssign<Vec3> for Color {
     fn mul_assign(&mut self, rhs: Vec3) {
-        self.set_r(self.r() * rhs.x);
-        self.set_g(self.g() * rhs.y);
-        self.set_b(self.b() * rhs.z);
+        *self = *self * rhs;
     }
 }
 
 impl Mul<[f32; 4]> for Color {
     type Output = Color;
 
-    fn mul(self, [r, g, b, a]: [f32; 4]) -> Self::Output {
-        Color::rgba(self.r() * r, self.g() * g, self.b() * b, self.a() * a)
+    fn mul(self, rhs: [f32; 4]) -> Self::Output {
+        self * Vec4::from(rhs)
     }
 }
 
 impl MulAssign<[f32; 4]> for Color {
-    fn mul_assign(&mut self, [r, g, b, a]: [f32; 4]) {
-        self.set_r(self.r() * r);
-        self.set_g(self.g() * g);
-        self.set_b(self.b() * b);
-        self.set_a(self.a() * a);
+    fn mul_assign(&mut self, rhs: [f32; 4]) {
+        *self = *self * rhs;
     }
 }
 
 impl Mul<[f32; 3]> for Color {
     type Output = Color;
 
-    fn mul(self, [r, g, b]: [f32; 3]) -> Self::Output {
-        Color::rgba(self.r() * r, self.g() * g, self.b() * b, self.a())
+    fn mul(self, rhs: [f32; 3]) -> Self::Output {
+        self * Vec3::from(rhs)
     }
 }
 
 impl MulAssign<[f32; 3]> for Color {
-    fn mul_assign(&mut self, [r, g, b]: [f32; 3]) {
-        self.set_r(self.r() * r);
-        self.set_g(self.g() * g);
-        self.set_b(self.b() * b);
+    fn mul_assign(&mut self, rhs: [f32; 3]) {
+        *self = *self * rhs;
     }
 }
 
@@ -465,10 +525,12 @@ impl From<Handle<Texture>> for ColorSource {
 
 impl_render_resource_bytes!(Color);
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum HexColorError {
+    #[error("Unexpected length of hex string")]
     Length,
-    Hex(hex::FromHexError),
+    #[error("Error parsing hex value")]
+    Hex(#[from] hex::FromHexError),
 }
 
 fn decode_rgb(data: &[u8]) -> Result<Color, HexColorError> {
@@ -536,6 +598,51 @@ fn test_hex_color() {
     assert!(Color::hex("1234567890").is_err());
 }
 
+#[test]
+fn test_hex_color_prefix() {
+    assert_eq!(Color::hex("#FF8000").unwrap(), Color::rgb_u8(255, 128, 0));
+    assert_eq!(
+        Color::hex("#FF8000").unwrap().as_rgba_u8(),
+        [255, 128, 0, 255]
+    );
+    assert!(Color::hex("#").is_err());
+}
+
+#[test]
+fn test_srgb_and_linear_conversions() {
+    let color = Color::rgba(0.5, 0.5, 0.5, 0.5);
+    const EPS: f32 = 0.001;
+    // mid-gray in sRGB is much darker in linear space
+    assert!((color.as_linear_rgba_f32()[0] - 0.214).abs() < EPS);
+    assert_eq!(<[f32; 4]>::from(color), color.as_linear_rgba_f32());
+    for (srgb, expected) in color.as_rgba_f32().iter().zip(&[0.5, 0.5, 0.5, 0.5]) {
+        assert!((srgb - expected).abs() < EPS);
+    }
+}
+
+#[test]
+fn test_hsl_hsv_color() {
+    const EPS: f32 = 0.001;
+    let red = Color::hsl(0.0, 1.0, 0.5);
+    assert_eq!(red.as_rgba_u8(), [255, 0, 0, 255]);
+    assert_eq!(Color::hsv(120.0, 1.0, 1.0).as_rgba_u8(), [0, 255, 0, 255]);
+    assert_eq!(
+        Color::hsla(240.0, 1.0, 0.25, 0.5).as_rgba_u8(),
+        [0, 0, 128, 128]
+    );
+
+    let orange = Color::rgb_u8(255, 128, 0);
+    let [h, s, l, a] = orange.as_hsla_f32();
+    let roundtrip = Color::hsla(h, s, l, a).as_rgba_f32();
+    for (component, expected) in roundtrip.iter().zip(&orange.as_rgba_f32()) {
+        assert!((component - expected).abs() < EPS);
+    }
+    let [h, s, v, _] = orange.as_hsva_f32();
+    assert!((h - 30.1).abs() < 0.1);
+    assert!((s - 1.0).abs() < EPS);
+    assert!((v - 1.0).abs() < EPS);
+}
+
 #[test]
 fn test_conversions_vec4() {
     let starting_vec4 = Vec4::new(0.4, 0.5, 0.6, 1.0);
@@ -554,11 +661,11 @@ fn test_conversions_vec4() {
 #[test]
 fn test_mul_and_mulassign_f32() {
     let transformation = 0.5;
-    let starting_color = Color::rgba(0.4, 0.5, 0.6, 1.0);
+    let starting_color = Color::rgba_linear(0.4, 0.5, 0.6, 1.0);
 
     assert_eq!(
         starting_color * transformation,
-        Color::rgba(0.4 * 0.5, 0.5 * 0.5, 0.6 * 0.5, 1.0),
+        Color::rgba_linear(0.4 * 0.5, 0.5 * 0.5, 0.6 * 0.5, 1.0),
     );
 
     let mut mutated_color = starting_color;
@@ -570,11 +677,11 @@ fn test_mul_and_mulassign_f32() {
 #[test]
 fn test_mul_and_mulassign_f32by3() {
     let transformation = [0.4, 0.5, 0.6];
-    let starting_color = Color::rgba(0.4, 0.5, 0.6, 1.0);
+    let starting_color = Color::rgba_linear(0.4, 0.5, 0.6, 1.0);
 
     assert_eq!(
         starting_color * transformation,
-        Color::rgba(0.4 * 0.4, 0.5 * 0.5, 0.6 * 0.6, 1.0),
+        Color::rgba_linear(0.4 * 0.4, 0.5 * 0.5, 0.6 * 0.6, 1.0),
     );
 
     let mut mutated_color = starting_color;
@@ -586,11 +693,11 @@ fn test_mul_and_mulassign_f32by3() {
 #[test]
 fn test_mul_and_mulassign_f32by4() {
     let transformation = [0.4, 0.5, 0.6, 0.9];
-    let starting_color = Color::rgba(0.4, 0.5, 0.6, 1.0);
+    let starting_color = Color::rgba_linear(0.4, 0.5, 0.6, 1.0);
 
     assert_eq!(
         starting_color * transformation,
-        Color::rgba(0.4 * 0.4, 0.5 * 0.5, 0.6 * 0.6, 1.0 * 0.9),
+        Color::rgba_linear(0.4 * 0.4, 0.5 * 0.5, 0.6 * 0.6, 1.0 * 0.9),
     );
 
     let mut mutated_color = starting_color;
@@ -602,11 +709,11 @@ fn test_mul_and_mulassign_f32by4() {
 #[test]
 fn test_mul_and_mulassign_vec3() {
     let transformation = Vec3::new(0.2, 0.3, 0.4);
-    let starting_color = Color::rgba(0.4, 0.5, 0.6, 1.0);
+    let starting_color = Color::rgba_linear(0.4, 0.5, 0.6, 1.0);
 
     assert_eq!(
         starting_color * transformation,
-        Color::rgba(0.4 * 0.2, 0.5 * 0.3, 0.6 * 0.4, 1.0),
+        Color::rgba_linear(0.4 * 0.2, 0.5 * 0.3, 0.6 * 0.4, 1.0),
     );
 
     let mut mutated_color = starting_color;
@@ -618,11 +725,11 @@ fn test_mul_and_mulassign_vec3() {
 #[test]
 fn test_mul_and_mulassign_vec4() {
     let transformation = Vec4::new(0.2, 0.3, 0.4, 0.5);
-    let starting_color = Color::rgba(0.4, 0.5, 0.6, 1.0);
+    let starting_color = Color::rgba_linear(0.4, 0.5, 0.6, 1.0);
 
     assert_eq!(
         starting_color * transformation,
-        Color::rgba(0.4 * 0.2, 0.5 * 0.3, 0.6 * 0.4, 1.0 * 0.5),
+        Color::rgba_linear(0.4 * 0.2, 0.5 * 0.3, 0.6 * 0.4, 1.0 * 0.5),
     );
 
     let mut mutated_color = starting_color;
@@ -48,3 +48,117 @@ fn test_srgb_full_roundtrip() {
     }
 }
 //==================================================================================================
+
+// HSL and HSV
+//==================================================================================================
+/// Converts between non-linear sRGB and hue, saturation and lightness. Hue is in degrees, the other
+/// components are in `0.0..=1.0`.
+pub struct HslRepresentation;
+
+impl HslRepresentation {
+    pub fn hsl_to_nonlinear_srgb(hue: f32, saturation: f32, lightness: f32) -> [f32; 3] {
+        // https://en.wikipedia.org/wiki/HSL_and_HSV#HSL_to_RGB
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let [r, g, b] = hue_to_rgb(hue, chroma);
+        let m = lightness - chroma / 2.0;
+        [r + m, g + m, b + m]
+    }
+
+    pub fn nonlinear_srgb_to_hsl([red, green, blue]: [f32; 3]) -> (f32, f32, f32) {
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+        let saturation = if lightness <= 0.0 || lightness >= 1.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        (
+            rgb_to_hue([red, green, blue], chroma),
+            saturation,
+            lightness,
+        )
+    }
+}
+
+/// Converts between non-linear sRGB and hue, saturation and value. Hue is in degrees, the other
+/// components are in `0.0..=1.0`.
+pub struct HsvRepresentation;
+
+impl HsvRepresentation {
+    pub fn hsv_to_nonlinear_srgb(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
+        // https://en.wikipedia.org/wiki/HSL_and_HSV#HSV_to_RGB
+        let chroma = value * saturation;
+        let [r, g, b] = hue_to_rgb(hue, chroma);
+        let m = value - chroma;
+        [r + m, g + m, b + m]
+    }
+
+    pub fn nonlinear_srgb_to_hsv([red, green, blue]: [f32; 3]) -> (f32, f32, f32) {
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let chroma = max - min;
+        let saturation = if max <= 0.0 { 0.0 } else { chroma / max };
+        (rgb_to_hue([red, green, blue], chroma), saturation, max)
+    }
+}
+
+/// The RGB color with the given hue and chroma, and the smallest component at zero
+fn hue_to_rgb(hue: f32, chroma: f32) -> [f32; 3] {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    match sector as u32 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    }
+}
+
+fn rgb_to_hue([red, green, blue]: [f32; 3], chroma: f32) -> f32 {
+    if chroma <= 0.0 {
+        // grays have no hue
+        0.0
+    } else if red >= green && red >= blue {
+        60.0 * ((green - blue) / chroma).rem_euclid(6.0)
+    } else if green >= blue {
+        60.0 * ((blue - red) / chroma + 2.0)
+    } else {
+        60.0 * ((red - green) / chroma + 4.0)
+    }
+}
+
+#[test]
+fn test_hsl_hsv_roundtrip() {
+    const EPS: f32 = 0.0001;
+    let colors = [
+        [1.0, 0.0, 0.0],
+        [0.2, 0.8, 0.4],
+        [0.1, 0.3, 0.9],
+        [0.9, 0.2, 0.7],
+        [0.5, 0.5, 0.5],
+    ];
+    for color in colors.iter() {
+        let (h, s, l) = HslRepresentation::nonlinear_srgb_to_hsl(*color);
+        let hsl_roundtrip = HslRepresentation::hsl_to_nonlinear_srgb(h, s, l);
+        let (h, s, v) = HsvRepresentation::nonlinear_srgb_to_hsv(*color);
+        let hsv_roundtrip = HsvRepresentation::hsv_to_nonlinear_srgb(h, s, v);
+        for ((c, hsl), hsv) in color.iter().zip(&hsl_roundtrip).zip(&hsv_roundtrip) {
+            assert!((c - hsl).abs() < EPS);
+            assert!((c - hsv).abs() < EPS);
+        }
+    }
+
+    assert_eq!(
+        HslRepresentation::nonlinear_srgb_to_hsl([0.0, 1.0, 0.0]),
+        (120.0, 1.0, 0.5)
+    );
+    assert_eq!(
+        HsvRepresentation::nonlinear_srgb_to_hsv([0.0, 0.0, 1.0]),
+        (240.0, 1.0, 1.0)
+    );
+}
+//==================================================================================================
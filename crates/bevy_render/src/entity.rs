@@ -1,5 +1,8 @@
 use crate::{
-    camera::{Camera, OrthographicProjection, PerspectiveProjection, VisibleEntities},
+    camera::{
+        Camera, ComputedVisibility, OrthographicProjection, PerspectiveProjection, ScalingMode,
+        VisibleEntities,
+    },
     pipeline::RenderPipelines,
     render_graph::base,
     Draw, Mesh,
@@ -19,6 +22,7 @@ pub struct MeshBundle {
     pub main_pass: MainPass,
     pub transform: Transform,
     pub global_transform: GlobalTransform,
+    pub computed_visibility: ComputedVisibility,
 }
 
 /// A component bundle for "3d camera" entities
@@ -76,3 +80,16 @@ impl Default for Camera2dBundle {
         }
     }
 }
+
+impl Camera2dBundle {
+    /// Creates a 2d camera that draws each world unit with a whole number of pixels, showing at
+    /// least `min_width` by `min_height` world units. See [ScalingMode::PixelPerfect].
+    pub fn pixel_perfect(min_width: f32, min_height: f32) -> Self {
+        let mut camera = Camera2dBundle::default();
+        camera.orthographic_projection.scaling_mode = ScalingMode::PixelPerfect {
+            min_width,
+            min_height,
+        };
+        camera
+    }
+}
@@ -0,0 +1,312 @@
+use crate::render_graph::{to_rgba8, Screenshot, ScreenshotId, ScreenshotRead};
+use bevy_app::prelude::*;
+use bevy_core::Time;
+use bevy_ecs::{Local, Res, ResMut};
+use bevy_utils::{
+    tracing::{error, warn},
+    Duration, HashMap,
+};
+use bevy_window::WindowId;
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+};
+
+/// Where [FrameSequenceCapture] writes the frames it captures
+#[derive(Debug)]
+pub enum FrameSequenceOutput {
+    /// Saves every frame as a PNG in `directory`, named `frame_00000.png`, `frame_00001.png` and
+    /// so on
+    Images { directory: PathBuf },
+    /// Writes the RGBA8 pixels of every frame, without any header, to the standard input of the
+    /// command, for example `ffmpeg -f rawvideo -pix_fmt rgba -s 1280x720 -r 60 -i - trailer.mp4`
+    Pipe(Command),
+}
+
+/// Records a sequence of frames of a window, for example for trailers.
+///
+/// While recording, [Time] advances by [FrameSequenceCapture::frame_step] every frame, so the
+/// recording plays smoothly however long the frames take to render. Frames are captured with
+/// [Screenshot] and written a frame or two after they were rendered.
+#[derive(Debug)]
+pub struct FrameSequenceCapture {
+    pub window_id: WindowId,
+    /// The time that passes between two frames while recording
+    pub frame_step: Duration,
+    /// Captures every `frame_interval`th frame, e.g. `2` to record at 60 frames per second while
+    /// the app runs at 120 frames per second
+    pub frame_interval: u32,
+    pub output: FrameSequenceOutput,
+    recording: bool,
+    frame: u64,
+    frames_captured: u64,
+    pipe: Option<FramePipe>,
+}
+
+#[derive(Debug)]
+struct FramePipe {
+    child: Child,
+    frames: FrameQueue,
+}
+
+/// The number of updates after which a frame that hasn't been read back is skipped, for example
+/// because its window was closed
+const FRAME_TIMEOUT: u64 = 30;
+
+/// Puts the frames that are read back for a [FramePipe] in the order they were captured
+#[derive(Debug, Default)]
+struct FrameQueue {
+    /// The frame index of each requested screenshot, and the update it was requested in
+    requested: HashMap<ScreenshotId, (u64, u64)>,
+    /// Frames that have been read back but not written, because an earlier frame is still pending
+    received: BTreeMap<u64, Vec<u8>>,
+    next_frame: u64,
+    update: u64,
+}
+
+impl FrameQueue {
+    fn new(next_frame: u64) -> Self {
+        FrameQueue {
+            next_frame,
+            ..Default::default()
+        }
+    }
+
+    fn request(&mut self, id: ScreenshotId, index: u64) {
+        self.requested.insert(id, (index, self.update));
+    }
+
+    fn receive(&mut self, id: ScreenshotId, data: Vec<u8>) {
+        if let Some((index, _)) = self.requested.remove(&id) {
+            self.received.insert(index, data);
+        }
+    }
+
+    /// Advances to the next update and skips the frames that have been waited on for longer than
+    /// [FRAME_TIMEOUT], so they don't hold up later frames
+    fn advance(&mut self) {
+        self.update += 1;
+        let update = self.update;
+        let received = &mut self.received;
+        self.requested.retain(|_, (index, requested_at)| {
+            if update - *requested_at <= FRAME_TIMEOUT {
+                return true;
+            }
+            warn!("Frame {} was not read back in time and is skipped", index);
+            received.insert(*index, Vec::new());
+            false
+        });
+    }
+
+    /// Writes the frames that are next in order
+    fn write_ready(&mut self, out: &mut impl Write) -> io::Result<()> {
+        while let Some(data) = self.received.remove(&self.next_frame) {
+            self.next_frame += 1;
+            out.write_all(&data)?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether every requested frame has been written or skipped
+    fn is_empty(&self) -> bool {
+        self.requested.is_empty() && self.received.is_empty()
+    }
+}
+
+impl Default for FrameSequenceCapture {
+    fn default() -> Self {
+        FrameSequenceCapture::new(FrameSequenceOutput::Images {
+            directory: PathBuf::from("frames"),
+        })
+    }
+}
+
+impl FrameSequenceCapture {
+    /// Records the primary window at 60 frames per second
+    pub fn new(output: FrameSequenceOutput) -> Self {
+        FrameSequenceCapture {
+            window_id: WindowId::primary(),
+            frame_step: Duration::from_secs_f64(1.0 / 60.0),
+            frame_interval: 1,
+            output,
+            recording: false,
+            frame: 0,
+            frames_captured: 0,
+            pipe: None,
+        }
+    }
+
+    /// Starts recording with the next frame. Creates the output directory or starts the command
+    /// frames are piped to.
+    pub fn start(&mut self) -> io::Result<()> {
+        if self.recording {
+            return Ok(());
+        }
+        match &mut self.output {
+            FrameSequenceOutput::Images { directory } => std::fs::create_dir_all(directory)?,
+            FrameSequenceOutput::Pipe(command) => {
+                if self.pipe.is_none() {
+                    self.pipe = Some(FramePipe {
+                        child: command.stdin(Stdio::piped()).spawn()?,
+                        frames: FrameQueue::new(self.frames_captured),
+                    });
+                }
+            }
+        }
+        self.recording = true;
+        self.frame = 0;
+        Ok(())
+    }
+
+    /// Stops recording. Frames that have been captured are still written, after which the
+    /// command frames are piped to is closed.
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// The number of frames that have been captured, including ones that haven't been written yet
+    pub fn frames_captured(&self) -> u64 {
+        self.frames_captured
+    }
+}
+
+/// Advances the [Time] by the frame step and captures frames while a [FrameSequenceCapture] is
+/// recording
+pub fn frame_sequence_capture_system(
+    mut screenshot_read_event_reader: Local<EventReader<ScreenshotRead>>,
+    mut capture: ResMut<FrameSequenceCapture>,
+    mut time: ResMut<Time>,
+    mut screenshot: ResMut<Screenshot>,
+    screenshot_read_events: Res<Events<ScreenshotRead>>,
+) {
+    let capture = &mut *capture;
+    time.set_frame_step(if capture.recording {
+        Some(capture.frame_step)
+    } else {
+        None
+    });
+
+    if let Some(pipe) = &mut capture.pipe {
+        pipe.frames.advance();
+    }
+
+    if capture.recording {
+        if capture.frame % capture.frame_interval.max(1) as u64 == 0 {
+            let index = capture.frames_captured;
+            capture.frames_captured += 1;
+            match (&capture.output, &mut capture.pipe) {
+                (FrameSequenceOutput::Images { directory }, _) => screenshot.save_to_file(
+                    capture.window_id,
+                    directory.join(format!("frame_{:05}.png", index)),
+                ),
+                (FrameSequenceOutput::Pipe(_), Some(pipe)) => {
+                    let id = screenshot.read(capture.window_id);
+                    pipe.frames.request(id, index);
+                }
+                (FrameSequenceOutput::Pipe(_), None) => {}
+            }
+        }
+        capture.frame += 1;
+    }
+
+    let pipe = match &mut capture.pipe {
+        Some(pipe) => pipe,
+        None => return,
+    };
+    for event in screenshot_read_event_reader.iter(&screenshot_read_events) {
+        // frames that can't be converted are skipped, so they don't hold up later frames
+        let data = to_rgba8(&event.data, event.format).unwrap_or_default();
+        pipe.frames.receive(event.id, data);
+    }
+
+    let stdin = pipe.child.stdin.as_mut().unwrap();
+    let failed = match pipe.frames.write_ready(stdin) {
+        Ok(()) => false,
+        Err(err) => {
+            error!("Failed to pipe captured frame: {}", err);
+            true
+        }
+    };
+
+    if failed || (!capture.recording && pipe.frames.is_empty()) {
+        let mut pipe = capture.pipe.take().unwrap();
+        // closing the pipe tells the command that there are no more frames
+        drop(pipe.child.stdin.take());
+        if let Err(err) = pipe.child.wait() {
+            error!("Failed to wait for the frame pipe command: {}", err);
+        }
+        capture.recording = false;
+    }
+}
+
+/// Adds a [FrameSequenceCapture] resource, which saves frames to the `frames` directory unless it
+/// was added with a different configuration before this plugin
+#[derive(Default)]
+pub struct FrameSequencePlugin;
+
+impl Plugin for FrameSequencePlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.resources_mut()
+            .get_or_insert_with(FrameSequenceCapture::default);
+        app.add_system_to_stage(stage::POST_UPDATE, frame_sequence_capture_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_are_written_in_order() {
+        let mut screenshot = Screenshot::default();
+        let ids = (0..3)
+            .map(|_| screenshot.read(WindowId::primary()))
+            .collect::<Vec<_>>();
+        let mut frames = FrameQueue::new(0);
+        for (index, id) in ids.iter().enumerate() {
+            frames.request(*id, index as u64);
+        }
+
+        let mut out = Vec::new();
+        frames.receive(ids[2], vec![2]);
+        frames.receive(ids[0], vec![0]);
+        frames.write_ready(&mut out).unwrap();
+        assert_eq!(out, vec![0]);
+        assert!(!frames.is_empty());
+
+        frames.receive(ids[1], vec![1]);
+        frames.write_ready(&mut out).unwrap();
+        assert_eq!(out, vec![0, 1, 2]);
+        assert!(frames.is_empty());
+    }
+
+    #[test]
+    fn frames_that_never_arrive_are_skipped() {
+        let mut screenshot = Screenshot::default();
+        let dropped = screenshot.read(WindowId::primary());
+        let next = screenshot.read(WindowId::primary());
+        let mut frames = FrameQueue::new(0);
+        frames.request(dropped, 0);
+        frames.request(next, 1);
+        frames.receive(next, vec![1]);
+
+        let mut out = Vec::new();
+        for _ in 0..FRAME_TIMEOUT {
+            frames.advance();
+            frames.write_ready(&mut out).unwrap();
+        }
+        assert!(out.is_empty());
+        assert!(!frames.is_empty());
+
+        frames.advance();
+        frames.write_ready(&mut out).unwrap();
+        assert_eq!(out, vec![1]);
+        assert!(frames.is_empty());
+    }
+}
@@ -3,9 +3,11 @@ pub mod color;
 pub mod colorspace;
 pub mod draw;
 pub mod entity;
+pub mod frame_sequence;
 pub mod mesh;
 pub mod pass;
 pub mod pipeline;
+pub mod primitives;
 pub mod render_graph;
 pub mod renderer;
 pub mod shader;
@@ -17,6 +19,7 @@ pub use once_cell;
 pub mod prelude {
     pub use crate::{
         base::Msaa,
+        camera::{ComputedVisibility, NoFrustumCulling, SortingLayer, SortingLayers},
         color::Color,
         draw::Draw,
         entity::*,
@@ -33,7 +36,8 @@ use base::{MainPass, Msaa};
 use bevy_app::prelude::*;
 use bevy_asset::AddAsset;
 use camera::{
-    ActiveCameras, Camera, OrthographicProjection, PerspectiveProjection, VisibleEntities,
+    ActiveCameras, Camera, ComputedVisibility, NoFrustumCulling, OrthographicProjection,
+    PerspectiveProjection, SortingLayer, SortingLayers, VisibleEntities,
 };
 use pipeline::{
     IndexFormat, PipelineCompiler, PipelineDescriptor, PipelineSpecialization, PrimitiveTopology,
@@ -41,18 +45,25 @@ use pipeline::{
 };
 use render_graph::{
     base::{self, BaseRenderGraphBuilder, BaseRenderGraphConfig},
-    RenderGraph,
+    FrameCapture, GpuReadback, ReadbackComplete, RenderGraph, Screenshot, ScreenshotCaptured,
+    ScreenshotRead,
+};
+use renderer::{
+    AssetRenderResourceBindings, HeadlessRenderResourceContext, RenderResourceBindings,
+    RenderResourceContext, SharedBuffers,
 };
-use renderer::{AssetRenderResourceBindings, RenderResourceBindings};
 use std::ops::Range;
 #[cfg(feature = "hdr")]
 use texture::HdrTextureLoader;
 #[cfg(feature = "png")]
 use texture::ImageTextureLoader;
-use texture::TextureResourceSystemState;
+use texture::{TextureResourceSystemState, TextureSuspendSettings};
 
 /// The names of "render" App stages
 pub mod stage {
+    /// Stage where the entities visible to each camera are found. It runs after POST_UPDATE, so
+    /// bounds computed there are up to date.
+    pub const VISIBILITY: &str = "visibility";
     /// Stage where render resources are set up
     pub const RENDER_RESOURCE: &str = "render_resource";
     /// Stage where Render Graph systems are run. In general you shouldn't add systems to this stage manually.
@@ -92,7 +103,8 @@ impl Plugin for RenderPlugin {
             app.resources_mut().insert(ClearColor::default());
         }
 
-        app.add_stage_after(bevy_asset::stage::ASSET_EVENTS, stage::RENDER_RESOURCE)
+        app.add_stage_after(bevy_app::stage::POST_UPDATE, stage::VISIBILITY)
+            .add_stage_after(bevy_asset::stage::ASSET_EVENTS, stage::RENDER_RESOURCE)
             .add_stage_after(stage::RENDER_RESOURCE, stage::RENDER_GRAPH_SYSTEMS)
             .add_stage_after(stage::RENDER_GRAPH_SYSTEMS, stage::DRAW)
             .add_stage_after(stage::DRAW, stage::RENDER)
@@ -108,6 +120,9 @@ impl Plugin for RenderPlugin {
             .register_component::<PerspectiveProjection>()
             .register_component::<MainPass>()
             .register_component::<VisibleEntities>()
+            .register_component::<SortingLayer>()
+            .register_component::<ComputedVisibility>()
+            .register_component::<NoFrustumCulling>()
             .register_property::<Color>()
             .register_property::<Range<f32>>()
             .register_property::<ShaderSpecialization>()
@@ -120,6 +135,12 @@ impl Plugin for RenderPlugin {
             .init_resource::<TextureResourceSystemState>()
             .init_resource::<AssetRenderResourceBindings>()
             .init_resource::<ActiveCameras>()
+            .init_resource::<SortingLayers>()
+            .init_resource::<GpuReadback>()
+            .add_event::<ReadbackComplete>()
+            .init_resource::<Screenshot>()
+            .add_event::<ScreenshotCaptured>()
+            .add_event::<ScreenshotRead>()
             .add_system_to_stage(bevy_app::stage::PRE_UPDATE, draw::clear_draw_system)
             .add_system_to_stage(bevy_app::stage::POST_UPDATE, camera::active_cameras_system)
             .add_system_to_stage(
@@ -130,26 +151,68 @@ impl Plugin for RenderPlugin {
                 bevy_app::stage::POST_UPDATE,
                 camera::camera_system::<PerspectiveProjection>,
             )
-            // registration order matters here. this must come after all camera_system::<T> systems
             .add_system_to_stage(
                 bevy_app::stage::POST_UPDATE,
-                camera::visible_entities_system,
+                camera::pixel_perfect_camera_system,
+            )
+            .add_system_to_stage(bevy_app::stage::POST_UPDATE, mesh::calculate_bounds_system)
+            .add_system_to_stage(stage::VISIBILITY, camera::visible_entities_system)
+            .add_system_to_stage(
+                bevy_app::stage::POST_UPDATE,
+                render_graph::remove_closed_window_nodes_system,
             )
             // TODO: turn these "resource systems" into graph nodes and remove the RENDER_RESOURCE stage
             .add_system_to_stage(stage::RENDER_RESOURCE, mesh::mesh_resource_provider_system)
+            // this must come before texture_resource_system so textures are uploaded again in the
+            // same update the app is resumed
+            .add_system_to_stage(stage::RENDER_RESOURCE, Texture::texture_suspend_system)
             .add_system_to_stage(stage::RENDER_RESOURCE, Texture::texture_resource_system)
             .add_system_to_stage(
                 stage::RENDER_GRAPH_SYSTEMS,
                 render_graph::render_graph_schedule_executor_system,
             )
             .add_system_to_stage(stage::DRAW, pipeline::draw_render_pipelines_system)
-            .add_system_to_stage(stage::POST_RENDER, shader::clear_shader_defs_system);
+            .add_system_to_stage(stage::POST_RENDER, shader::clear_shader_defs_system)
+            .add_system_to_stage(stage::POST_RENDER, render_graph::gpu_readback_system)
+            .add_system_to_stage(stage::POST_RENDER, render_graph::screenshot_system)
+            .add_system_to_stage(stage::POST_RENDER, renderer::free_shared_buffers_system);
 
         if app.resources().get::<Msaa>().is_none() {
             app.init_resource::<Msaa>();
         }
+        if app.resources().get::<TextureSuspendSettings>().is_none() {
+            app.init_resource::<TextureSuspendSettings>();
+        }
+
+        // apps without a render backend (such as servers and tests) still run the render systems,
+        // so give them a render resource context that doesn't need a GPU. render backends replace
+        // these resources with their own when they are added
+        if app
+            .resources()
+            .get::<Box<dyn RenderResourceContext>>()
+            .is_none()
+        {
+            app.resources_mut()
+                .insert::<Box<dyn RenderResourceContext>>(Box::new(
+                    HeadlessRenderResourceContext::default(),
+                ));
+        }
+        if app.resources().get::<SharedBuffers>().is_none() {
+            app.resources_mut().insert(SharedBuffers::new(Box::new(
+                HeadlessRenderResourceContext::default(),
+            )));
+        }
 
         if let Some(ref config) = self.base_render_graph_config {
+            if config.offscreen {
+                app.init_resource::<FrameCapture>()
+                    .add_system_to_stage(
+                        bevy_app::stage::FIRST,
+                        bevy_window::create_offscreen_windows_system,
+                    )
+                    .add_system_to_stage(stage::POST_RENDER, render_graph::frame_capture_system);
+            }
+
             let resources = app.resources();
             let mut render_graph = resources.get_mut::<RenderGraph>().unwrap();
             let msaa = resources.get::<Msaa>().unwrap();
@@ -0,0 +1,60 @@
+use super::Mesh;
+use crate::{
+    camera::{ComputedVisibility, NoFrustumCulling},
+    primitives::{Aabb, BoundingSphere},
+};
+use bevy_app::prelude::{EventReader, Events};
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_ecs::{Changed, Commands, Entity, Local, Query, Res, With, Without};
+use bevy_utils::HashSet;
+
+#[derive(Default)]
+pub struct CalculateBoundsState {
+    mesh_event_reader: EventReader<AssetEvent<Mesh>>,
+}
+
+/// Computes the [Aabb] and [BoundingSphere] of entities with a [ComputedVisibility] from their
+/// mesh, and updates them when the entity's mesh handle or the mesh itself changes
+pub fn calculate_bounds_system(
+    commands: &mut Commands,
+    mut state: Local<CalculateBoundsState>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_events: Res<Events<AssetEvent<Mesh>>>,
+    without_bounds: Query<
+        (Entity, &Handle<Mesh>),
+        (
+            With<ComputedVisibility>,
+            Without<Aabb>,
+            Without<NoFrustumCulling>,
+        ),
+    >,
+    changed_handles: Query<Entity, (With<Aabb>, Changed<Handle<Mesh>>)>,
+    mut with_bounds: Query<(Entity, &Handle<Mesh>, &mut Aabb, &mut BoundingSphere)>,
+) {
+    let mut changed_meshes = HashSet::default();
+    for event in state.mesh_event_reader.iter(&mesh_events) {
+        if let AssetEvent::Modified { handle } = event {
+            changed_meshes.insert(handle.clone_weak());
+        }
+    }
+
+    for (entity, handle) in without_bounds.iter() {
+        if let Some(aabb) = meshes.get(handle).and_then(|mesh| mesh.compute_aabb()) {
+            commands.insert(entity, (aabb, BoundingSphere::from(&aabb)));
+        }
+    }
+
+    let changed_entities = changed_handles.iter().collect::<HashSet<_>>();
+    if changed_meshes.is_empty() && changed_entities.is_empty() {
+        return;
+    }
+    for (entity, handle, mut aabb, mut bounding_sphere) in with_bounds.iter_mut() {
+        if !changed_meshes.contains(handle) && !changed_entities.contains(&entity) {
+            continue;
+        }
+        if let Some(new_aabb) = meshes.get(handle).and_then(|mesh| mesh.compute_aabb()) {
+            *aabb = new_aabb;
+            *bounding_sphere = BoundingSphere::from(&new_aabb);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     pipeline::{IndexFormat, PrimitiveTopology, RenderPipelines, VertexFormat},
+    primitives::Aabb,
     renderer::{BufferInfo, BufferUsage, RenderResourceContext, RenderResourceId},
 };
 use bevy_app::prelude::{EventReader, Events};
@@ -22,6 +23,7 @@ pub enum VertexAttributeValues {
     Float2(Vec<[f32; 2]>),
     Float3(Vec<[f32; 3]>),
     Float4(Vec<[f32; 4]>),
+    Uint4(Vec<[u32; 4]>),
 }
 
 impl VertexAttributeValues {
@@ -31,6 +33,7 @@ impl VertexAttributeValues {
             VertexAttributeValues::Float2(ref values) => values.len(),
             VertexAttributeValues::Float3(ref values) => values.len(),
             VertexAttributeValues::Float4(ref values) => values.len(),
+            VertexAttributeValues::Uint4(ref values) => values.len(),
         }
     }
 
@@ -45,6 +48,7 @@ impl VertexAttributeValues {
             VertexAttributeValues::Float2(values) => values.as_slice().as_bytes(),
             VertexAttributeValues::Float3(values) => values.as_slice().as_bytes(),
             VertexAttributeValues::Float4(values) => values.as_slice().as_bytes(),
+            VertexAttributeValues::Uint4(values) => values.as_slice().as_bytes(),
         }
     }
 }
@@ -56,6 +60,7 @@ impl From<&VertexAttributeValues> for VertexFormat {
             VertexAttributeValues::Float2(_) => VertexFormat::Float2,
             VertexAttributeValues::Float3(_) => VertexFormat::Float3,
             VertexAttributeValues::Float4(_) => VertexFormat::Float4,
+            VertexAttributeValues::Uint4(_) => VertexFormat::Uint4,
         }
     }
 }
@@ -84,6 +89,12 @@ impl From<Vec<[f32; 4]>> for VertexAttributeValues {
     }
 }
 
+impl From<Vec<[u32; 4]>> for VertexAttributeValues {
+    fn from(vec: Vec<[u32; 4]>) -> Self {
+        VertexAttributeValues::Uint4(vec)
+    }
+}
+
 #[derive(Debug)]
 pub enum Indices {
     U16(Vec<u16>),
@@ -113,6 +124,12 @@ impl Mesh {
     pub const ATTRIBUTE_NORMAL: &'static str = "Vertex_Normal";
     pub const ATTRIBUTE_POSITION: &'static str = "Vertex_Position";
     pub const ATTRIBUTE_UV_0: &'static str = "Vertex_Uv";
+    /// The color of each vertex, in linear RGBA
+    pub const ATTRIBUTE_COLOR: &'static str = "Vertex_Color";
+    /// The indices of the joints that move a vertex of a skinned mesh
+    pub const ATTRIBUTE_JOINT_INDEX: &'static str = "Vertex_JointIndex";
+    /// How much each of the joints in [Mesh::ATTRIBUTE_JOINT_INDEX] moves a vertex
+    pub const ATTRIBUTE_JOINT_WEIGHT: &'static str = "Vertex_JointWeight";
 
     pub fn new(primitive_topology: PrimitiveTopology) -> Self {
         Mesh {
@@ -147,6 +164,17 @@ impl Mesh {
         self.indices.as_ref()
     }
 
+    /// Computes the bounding box of the mesh's [Mesh::ATTRIBUTE_POSITION] values. Returns `None`
+    /// if the mesh has no `Float3` positions.
+    pub fn compute_aabb(&self) -> Option<Aabb> {
+        match self.attribute(Mesh::ATTRIBUTE_POSITION)? {
+            VertexAttributeValues::Float3(positions) => {
+                Aabb::enclosing(positions.iter().map(|position| Vec3::from(*position)))
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_index_buffer_bytes(&self) -> Option<Vec<u8>> {
         self.indices.as_ref().map(|indices| match &indices {
             Indices::U16(indices) => indices.as_slice().as_bytes().to_vec(),
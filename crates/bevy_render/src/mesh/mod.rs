@@ -1,6 +1,8 @@
+mod bounds;
 #[allow(clippy::module_inception)]
 mod mesh;
 /// Generation for some primitive shape meshes.
 pub mod shape;
 
+pub use bounds::*;
 pub use mesh::*;
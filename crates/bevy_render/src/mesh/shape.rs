@@ -322,3 +322,302 @@ impl From<Icosphere> for Mesh {
         mesh
     }
 }
+
+/// A regular polygon on the XY plane, with its first vertex pointing up.
+#[derive(Debug, Clone, Copy)]
+pub struct RegularPolygon {
+    /// The distance from the center to each vertex.
+    pub circumradius: f32,
+    /// The number of sides. Must be at least 3.
+    pub sides: usize,
+}
+
+impl RegularPolygon {
+    pub fn new(circumradius: f32, sides: usize) -> Self {
+        Self {
+            circumradius,
+            sides,
+        }
+    }
+}
+
+impl Default for RegularPolygon {
+    fn default() -> Self {
+        Self {
+            circumradius: 0.5,
+            sides: 6,
+        }
+    }
+}
+
+impl From<RegularPolygon> for Mesh {
+    fn from(polygon: RegularPolygon) -> Self {
+        assert!(
+            polygon.sides >= 3,
+            "a regular polygon needs at least 3 sides"
+        );
+        let outline = (0..polygon.sides)
+            .map(|i| {
+                let angle = std::f32::consts::FRAC_PI_2
+                    + i as f32 * 2.0 * std::f32::consts::PI / polygon.sides as f32;
+                Vec2::new(angle.cos(), angle.sin()) * polygon.circumradius
+            })
+            .collect::<Vec<Vec2>>();
+        convex_polygon_mesh(&outline, Vec2::splat(polygon.circumradius))
+    }
+}
+
+/// A circle on the XY plane.
+#[derive(Debug, Clone, Copy)]
+pub struct Circle {
+    pub radius: f32,
+    /// The number of vertices used to approximate the circle's outline.
+    pub vertices: usize,
+}
+
+impl Circle {
+    pub fn new(radius: f32) -> Self {
+        Self {
+            radius,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Circle {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            vertices: 64,
+        }
+    }
+}
+
+impl From<Circle> for Mesh {
+    fn from(circle: Circle) -> Self {
+        RegularPolygon::new(circle.radius, circle.vertices).into()
+    }
+}
+
+/// A flat ring on the XY plane. The texture is mapped as if the ring were a filled circle of
+/// `outer_radius`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ring {
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+    /// The number of vertices used to approximate each of the ring's edges.
+    pub vertices: usize,
+}
+
+impl Ring {
+    pub fn new(inner_radius: f32, outer_radius: f32) -> Self {
+        Self {
+            inner_radius,
+            outer_radius,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Ring {
+    fn default() -> Self {
+        Self {
+            inner_radius: 0.25,
+            outer_radius: 0.5,
+            vertices: 64,
+        }
+    }
+}
+
+impl From<Ring> for Mesh {
+    fn from(ring: Ring) -> Self {
+        assert!(ring.vertices >= 3, "a ring needs at least 3 vertices");
+        let extents = Vec2::splat(ring.outer_radius);
+        let mut positions = Vec::with_capacity(ring.vertices * 2);
+        let mut normals = Vec::with_capacity(ring.vertices * 2);
+        let mut uvs = Vec::with_capacity(ring.vertices * 2);
+        for i in 0..ring.vertices {
+            let angle = i as f32 * 2.0 * std::f32::consts::PI / ring.vertices as f32;
+            let direction = Vec2::new(angle.cos(), angle.sin());
+            for radius in [ring.outer_radius, ring.inner_radius].iter() {
+                let position = direction * *radius;
+                positions.push([position.x, position.y, 0.0]);
+                normals.push([0.0, 0.0, 1.0]);
+                uvs.push(planar_uv(position, extents));
+            }
+        }
+
+        let mut indices = Vec::with_capacity(ring.vertices * 6);
+        for i in 0..ring.vertices as u32 {
+            let outer = i * 2;
+            let inner = outer + 1;
+            let next_outer = (outer + 2) % (ring.vertices as u32 * 2);
+            let next_inner = next_outer + 1;
+            indices.extend_from_slice(&[outer, next_outer, inner, inner, next_outer, next_inner]);
+        }
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh
+    }
+}
+
+/// A rectangle with rounded corners on the XY plane.
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedRect {
+    /// Full width and height of the rectangle.
+    pub size: Vec2,
+    /// The radius of the corners. This is clamped to half of the rectangle's shortest side.
+    pub radius: f32,
+    /// The number of vertices used to approximate each corner.
+    pub corner_vertices: usize,
+}
+
+impl RoundedRect {
+    pub fn new(size: Vec2, radius: f32) -> Self {
+        Self {
+            size,
+            radius,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for RoundedRect {
+    fn default() -> Self {
+        Self {
+            size: Vec2::one(),
+            radius: 0.1,
+            corner_vertices: 8,
+        }
+    }
+}
+
+impl From<RoundedRect> for Mesh {
+    fn from(rect: RoundedRect) -> Self {
+        let extents = rect.size / 2.0;
+        let radius = rect.radius.max(0.0).min(extents.x.min(extents.y));
+        let corner_vertices = rect.corner_vertices.max(1);
+        let inner = extents - Vec2::splat(radius);
+
+        // corners in counter-clockwise order, starting at the top right
+        let corners = [
+            Vec2::new(inner.x, inner.y),
+            Vec2::new(-inner.x, inner.y),
+            Vec2::new(-inner.x, -inner.y),
+            Vec2::new(inner.x, -inner.y),
+        ];
+        let mut outline = Vec::with_capacity(corner_vertices * 4);
+        for (corner_index, corner) in corners.iter().enumerate() {
+            let start_angle = corner_index as f32 * std::f32::consts::FRAC_PI_2;
+            for i in 0..corner_vertices {
+                let t = if corner_vertices == 1 {
+                    0.5
+                } else {
+                    i as f32 / (corner_vertices - 1) as f32
+                };
+                let angle = start_angle + t * std::f32::consts::FRAC_PI_2;
+                outline.push(*corner + Vec2::new(angle.cos(), angle.sin()) * radius);
+            }
+        }
+        convex_polygon_mesh(&outline, extents)
+    }
+}
+
+/// Maps a position inside of a shape with the given extents to the shape's texture. Texture
+/// coordinates start at the top left.
+fn planar_uv(position: Vec2, extents: Vec2) -> [f32; 2] {
+    [
+        0.5 + position.x / (2.0 * extents.x),
+        0.5 - position.y / (2.0 * extents.y),
+    ]
+}
+
+/// Builds a triangle fan around the origin from the counter-clockwise outline of a convex shape.
+fn convex_polygon_mesh(outline: &[Vec2], extents: Vec2) -> Mesh {
+    let mut positions = Vec::with_capacity(outline.len() + 1);
+    let mut normals = Vec::with_capacity(outline.len() + 1);
+    let mut uvs = Vec::with_capacity(outline.len() + 1);
+    for position in std::iter::once(&Vec2::zero()).chain(outline.iter()) {
+        positions.push([position.x, position.y, 0.0]);
+        normals.push([0.0, 0.0, 1.0]);
+        uvs.push(planar_uv(*position, extents));
+    }
+
+    let mut indices = Vec::with_capacity(outline.len() * 3);
+    for i in 0..outline.len() as u32 {
+        let next = (i + 1) % outline.len() as u32;
+        indices.extend_from_slice(&[0, i + 1, next + 1]);
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mesh::VertexAttributeValues;
+
+    fn positions(mesh: &Mesh) -> &Vec<[f32; 3]> {
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float3(positions)) => positions,
+            _ => panic!("shape meshes should have Float3 positions"),
+        }
+    }
+
+    #[test]
+    fn regular_polygon_points_up() {
+        let mesh = Mesh::from(RegularPolygon::new(2.0, 4));
+        let positions = positions(&mesh);
+        assert_eq!(positions.len(), 5);
+        assert_eq!(positions[0], [0.0, 0.0, 0.0]);
+        assert!((Vec3::from(positions[1]) - Vec3::new(0.0, 2.0, 0.0)).length() < 1e-5);
+
+        match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float2(uvs)) => {
+                assert_eq!(uvs[0], [0.5, 0.5]);
+                assert!((uvs[1][0] - 0.5).abs() < 1e-5 && uvs[1][1].abs() < 1e-5);
+            }
+            _ => panic!("shape meshes should have Float2 uvs"),
+        }
+        match mesh.indices() {
+            Some(Indices::U32(indices)) => {
+                assert_eq!(indices, &vec![0, 1, 2, 0, 2, 3, 0, 3, 4, 0, 4, 1])
+            }
+            _ => panic!("shape meshes should use u32 indices"),
+        }
+    }
+
+    #[test]
+    fn ring_spans_both_radii() {
+        let mesh = Mesh::from(Ring {
+            inner_radius: 1.0,
+            outer_radius: 2.0,
+            vertices: 8,
+        });
+        let positions = positions(&mesh);
+        assert_eq!(positions.len(), 16);
+        assert_eq!(positions[0], [2.0, 0.0, 0.0]);
+        assert_eq!(positions[1], [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn rounded_rect_clamps_radius() {
+        let mesh = Mesh::from(RoundedRect::new(Vec2::new(4.0, 2.0), 5.0));
+        for position in positions(&mesh).iter() {
+            assert!(position[0].abs() <= 2.0 + 1e-5 && position[1].abs() <= 1.0 + 1e-5);
+        }
+        // the right edge is a half circle, so its rightmost point is on the x axis
+        assert!(positions(&mesh)
+            .iter()
+            .any(|position| (position[0] - 2.0).abs() < 1e-5 && position[1].abs() < 1e-5));
+    }
+}
@@ -6,6 +6,7 @@ mod pipeline_compiler;
 mod pipeline_layout;
 mod render_pipelines;
 mod state_descriptors;
+mod std140;
 mod vertex_buffer_descriptor;
 mod vertex_format;
 
@@ -16,5 +17,6 @@ pub use pipeline_compiler::*;
 pub use pipeline_layout::*;
 pub use render_pipelines::*;
 pub use state_descriptors::*;
+pub use std140::*;
 pub use vertex_buffer_descriptor::*;
 pub use vertex_format::*;
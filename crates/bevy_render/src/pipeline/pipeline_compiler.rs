@@ -1,4 +1,7 @@
-use super::{state_descriptors::PrimitiveTopology, IndexFormat, PipelineDescriptor};
+use super::{
+    state_descriptors::PrimitiveTopology, uniform_layout_matches, IndexFormat, PipelineDescriptor,
+    Std140, UniformProperty,
+};
 use crate::{
     pipeline::{BindType, InputStepMode, VertexBufferDescriptor},
     renderer::RenderResourceContext,
@@ -61,9 +64,20 @@ struct SpecializedPipeline {
 pub struct PipelineCompiler {
     specialized_shaders: HashMap<Handle<Shader>, Vec<SpecializedShader>>,
     specialized_pipelines: HashMap<Handle<PipelineDescriptor>, Vec<SpecializedPipeline>>,
+    /// The type names and layouts of uniforms registered with [PipelineCompiler::register_uniform]
+    uniform_layouts: HashMap<String, (&'static str, UniformProperty)>,
 }
 
 impl PipelineCompiler {
+    /// Checks that the uniform block named `binding_name` matches the std140 layout of `T` whenever
+    /// a pipeline that uses it is compiled
+    pub fn register_uniform<T: Std140>(&mut self, binding_name: &str) {
+        self.uniform_layouts.insert(
+            binding_name.to_string(),
+            (std::any::type_name::<T>(), T::uniform_property()),
+        );
+    }
+
     fn compile_shader(
         &mut self,
         render_resource_context: &dyn RenderResourceContext,
@@ -188,6 +202,22 @@ impl PipelineCompiler {
                 }
             }
         }
+        for binding in layout
+            .bind_groups
+            .iter()
+            .flat_map(|bind_group| bind_group.bindings.iter())
+        {
+            if let BindType::Uniform { ref property, .. } = binding.bind_type {
+                if let Some((type_name, expected)) = self.uniform_layouts.get(&binding.name) {
+                    if !uniform_layout_matches(expected, property) {
+                        panic!(
+                            "Uniform {} is declared as {:?} by the shader, but {} is laid out as {:?}.",
+                            binding.name, property, type_name, expected,
+                        );
+                    }
+                }
+            }
+        }
         specialized_descriptor.layout = Some(layout);
 
         // create a vertex layout that provides all attributes from either the specialized vertex buffers or a zero buffer
@@ -213,6 +243,17 @@ impl PipelineCompiler {
                 .iter()
                 .find(|x| x.name == shader_vertex_attribute.name)
             {
+                if !target_vertex_attribute
+                    .format
+                    .is_compatible_with(shader_vertex_attribute.format)
+                {
+                    panic!(
+                        "Attribute {} is declared as {:?} by the shader, but supplied as {:?} by the mesh.",
+                        shader_vertex_attribute.name,
+                        shader_vertex_attribute.format,
+                        target_vertex_attribute.format,
+                    );
+                }
                 // copy shader location from reflected layout
                 let mut compiled_vertex_attribute = target_vertex_attribute.clone();
                 compiled_vertex_attribute.shader_location = shader_vertex_attribute.shader_location;
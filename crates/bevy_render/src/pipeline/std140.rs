@@ -0,0 +1,177 @@
+use super::UniformProperty;
+use crate::Color;
+use bevy_core::Bytes;
+pub use bevy_derive::Std140;
+use bevy_math::{Mat3, Mat4, Vec2, Vec3, Vec4};
+
+/// A type that can be written to a uniform buffer with the std140 layout, which GLSL uses for
+/// uniform blocks.
+///
+/// `#[derive(Std140)]` implements this for structs whose fields implement it, inserting the padding
+/// std140 requires between fields. The derive also implements [Bytes] with that layout, so the
+/// struct can be used as a [RenderResource](crate::renderer::RenderResource). Use
+/// [PipelineCompiler::register_uniform](super::PipelineCompiler::register_uniform) to check the
+/// layout against the shader's uniform block when pipelines are compiled.
+pub trait Std140 {
+    /// The alignment of the type's offset in a uniform block, in bytes
+    const ALIGNMENT: usize;
+    /// The size of the type in a uniform block, in bytes. Structs include their trailing padding.
+    const SIZE: usize;
+
+    /// Writes the type to `buffer`, which is [Std140::SIZE] bytes long
+    fn write_std140(&self, buffer: &mut [u8]);
+
+    /// The layout of the type, as reflected from a shader's uniform block
+    fn uniform_property() -> UniformProperty;
+}
+
+/// Rounds `offset` up to the next multiple of `alignment`
+pub const fn std140_align(offset: usize, alignment: usize) -> usize {
+    (offset + alignment - 1) / alignment * alignment
+}
+
+/// The alignment of structs and matrices, which is rounded up to that of a `vec4`
+pub const STD140_STRUCT_ALIGNMENT: usize = 16;
+
+macro_rules! impl_std140 {
+    ($ty:ty, $alignment:expr, $size:expr, $property:ident) => {
+        impl Std140 for $ty {
+            const ALIGNMENT: usize = $alignment;
+            const SIZE: usize = $size;
+
+            fn write_std140(&self, buffer: &mut [u8]) {
+                self.write_bytes(buffer);
+            }
+
+            fn uniform_property() -> UniformProperty {
+                UniformProperty::$property
+            }
+        }
+    };
+}
+
+impl_std140!(f32, 4, 4, Float);
+impl_std140!(u32, 4, 4, UInt);
+impl_std140!(i32, 4, 4, Int);
+impl_std140!(Vec2, 8, 8, Vec2);
+impl_std140!(Vec4, 16, 16, Vec4);
+impl_std140!(Mat4, STD140_STRUCT_ALIGNMENT, 64, Mat4);
+
+impl Std140 for Vec3 {
+    const ALIGNMENT: usize = 16;
+    // a vec3 is aligned like a vec4, but a scalar that follows it fills the remaining 4 bytes
+    const SIZE: usize = 12;
+
+    fn write_std140(&self, buffer: &mut [u8]) {
+        <[f32; 3]>::from(*self).write_bytes(buffer);
+    }
+
+    fn uniform_property() -> UniformProperty {
+        UniformProperty::Vec3
+    }
+}
+
+impl Std140 for Mat3 {
+    const ALIGNMENT: usize = STD140_STRUCT_ALIGNMENT;
+    // each column is padded to a vec4
+    const SIZE: usize = 48;
+
+    fn write_std140(&self, buffer: &mut [u8]) {
+        let columns = self.to_cols_array();
+        for (column, chunk) in columns.chunks(3).zip(buffer.chunks_mut(16)) {
+            column.write_bytes(&mut chunk[..12]);
+        }
+    }
+
+    fn uniform_property() -> UniformProperty {
+        UniformProperty::Mat3
+    }
+}
+
+impl Std140 for Color {
+    const ALIGNMENT: usize = 16;
+    const SIZE: usize = 16;
+
+    fn write_std140(&self, buffer: &mut [u8]) {
+        self.as_linear_rgba_f32().write_bytes(buffer);
+    }
+
+    fn uniform_property() -> UniformProperty {
+        UniformProperty::Vec4
+    }
+}
+
+/// Returns whether a type with the `expected` layout can be bound to a uniform block that was
+/// reflected as `reflected`. Blocks with a single member also accept a type with that member's
+/// layout.
+pub fn uniform_layout_matches(expected: &UniformProperty, reflected: &UniformProperty) -> bool {
+    if expected == reflected {
+        return true;
+    }
+    match reflected {
+        UniformProperty::Struct(properties) if properties.len() == 1 => expected == &properties[0],
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Std140)]
+    #[as_crate(bevy_render)]
+    struct Material {
+        color: Color,
+        direction: Vec3,
+        roughness: f32,
+        scale: Vec2,
+        transform: Mat4,
+    }
+
+    #[test]
+    fn derived_layout() {
+        assert_eq!(Material::ALIGNMENT, 16);
+        // color 0..16, direction 16..28, roughness 28..32, scale 32..40, transform 48..112
+        assert_eq!(Material::SIZE, 112);
+        assert_eq!(
+            Material::uniform_property(),
+            UniformProperty::Struct(vec![
+                UniformProperty::Vec4,
+                UniformProperty::Vec3,
+                UniformProperty::Float,
+                UniformProperty::Vec2,
+                UniformProperty::Mat4,
+            ])
+        );
+
+        let material = Material {
+            color: Color::rgba_linear(0.1, 0.2, 0.3, 0.4),
+            direction: Vec3::new(1.0, 2.0, 3.0),
+            roughness: 0.5,
+            scale: Vec2::new(6.0, 7.0),
+            transform: Mat4::identity(),
+        };
+        assert_eq!(material.byte_len(), 112);
+        let mut buffer = vec![0; material.byte_len()];
+        material.write_bytes(&mut buffer);
+        let floats = buffer
+            .chunks(4)
+            .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect::<Vec<_>>();
+        assert_eq!(&floats[4..10], &[1.0, 2.0, 3.0, 0.5, 6.0, 7.0]);
+        assert_eq!(&floats[12..16], &[1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn single_member_blocks() {
+        let reflected = UniformProperty::Struct(vec![UniformProperty::Vec4]);
+        assert!(uniform_layout_matches(
+            &Color::uniform_property(),
+            &reflected
+        ));
+        assert!(!uniform_layout_matches(
+            &f32::uniform_property(),
+            &reflected
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use super::VertexFormat;
+pub use bevy_derive::Vertex;
 use bevy_property::Property;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -26,6 +27,49 @@ impl VertexBufferDescriptor {
             attributes: vec![attribute.clone()],
         }
     }
+
+    /// Appends attributes with the given formats after the existing attributes and grows the
+    /// stride to fit them. Types with several formats, like matrices, get one attribute per
+    /// format, named `{name}_{index}`.
+    pub fn push_attributes(&mut self, name: &str, formats: &[VertexFormat]) {
+        for (index, format) in formats.iter().enumerate() {
+            let name = if formats.len() == 1 {
+                name.to_string()
+            } else {
+                format!("{}_{}", name, index)
+            };
+            self.attributes.push(VertexAttributeDescriptor {
+                name: name.into(),
+                offset: self.stride,
+                format: *format,
+                shader_location: 0,
+            });
+            self.stride += format.get_size();
+        }
+    }
+}
+
+/// A vertex of a custom vertex buffer. `#[derive(Vertex)]` describes structs as a buffer with an
+/// attribute per field, named `Vertex_{FieldName}`, which are matched with the shader's inputs by
+/// name when pipelines are compiled.
+pub trait Vertex {
+    fn vertex_buffer_descriptor() -> VertexBufferDescriptor;
+
+    /// Writes the vertex to `buffer`, laid out as described by [Vertex::vertex_buffer_descriptor]
+    fn write_vertex_bytes(&self, buffer: &mut [u8]);
+
+    /// Returns the contents of a vertex buffer holding `vertices`
+    fn vertex_buffer_bytes(vertices: &[Self]) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        let stride = Self::vertex_buffer_descriptor().stride as usize;
+        let mut bytes = vec![0; stride * vertices.len()];
+        for (vertex, buffer) in vertices.iter().zip(bytes.chunks_exact_mut(stride)) {
+            vertex.write_vertex_bytes(buffer);
+        }
+        bytes
+    }
 }
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum InputStepMode {
@@ -53,3 +97,49 @@ pub fn get_vertex_attribute_name_id(name: &str) -> u64 {
     hasher.write(&name.as_bytes());
     hasher.finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::{Mat4, Vec2, Vec3};
+
+    #[derive(Vertex)]
+    #[as_crate(bevy_render)]
+    struct InstancedVertex {
+        position: Vec3,
+        uv: Vec2,
+        model: Mat4,
+    }
+
+    #[test]
+    fn derived_vertex_buffer_descriptor() {
+        let descriptor = InstancedVertex::vertex_buffer_descriptor();
+        assert_eq!(descriptor.name, "InstancedVertex");
+        assert_eq!(descriptor.stride, 12 + 8 + 64);
+        let attributes = descriptor
+            .attributes
+            .iter()
+            .map(|attribute| (&*attribute.name, attribute.offset, attribute.format))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            vec![
+                ("Vertex_Position", 0, VertexFormat::Float3),
+                ("Vertex_Uv", 12, VertexFormat::Float2),
+                ("Vertex_Model_0", 20, VertexFormat::Float4),
+                ("Vertex_Model_1", 36, VertexFormat::Float4),
+                ("Vertex_Model_2", 52, VertexFormat::Float4),
+                ("Vertex_Model_3", 68, VertexFormat::Float4),
+            ]
+        );
+
+        let bytes = InstancedVertex::vertex_buffer_bytes(&[InstancedVertex {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            uv: Vec2::new(4.0, 5.0),
+            model: Mat4::identity(),
+        }]);
+        assert_eq!(bytes.len(), 84);
+        assert_eq!(&bytes[12..16], &4.0f32.to_le_bytes());
+        assert_eq!(&bytes[20..24], &1.0f32.to_le_bytes());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::Color;
+use bevy_core::Bytes;
 use bevy_math::{Mat4, Vec2, Vec3, Vec4};
 use serde::{Deserialize, Serialize};
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -70,34 +71,97 @@ impl VertexFormat {
             VertexFormat::Int4 => 4 * 4,
         }
     }
+
+    /// Returns whether a shader input that was reflected as `shader_format` can read this format.
+    /// Shaders read normalized and half formats as floats, so only the scalar types need to match.
+    pub fn is_compatible_with(&self, shader_format: VertexFormat) -> bool {
+        self.scalar_type() == shader_format.scalar_type()
+    }
+
+    fn scalar_type(&self) -> ScalarType {
+        match *self {
+            VertexFormat::Uchar2
+            | VertexFormat::Uchar4
+            | VertexFormat::Ushort2
+            | VertexFormat::Ushort4
+            | VertexFormat::Uint
+            | VertexFormat::Uint2
+            | VertexFormat::Uint3
+            | VertexFormat::Uint4 => ScalarType::UInt,
+            VertexFormat::Char2
+            | VertexFormat::Char4
+            | VertexFormat::Short2
+            | VertexFormat::Short4
+            | VertexFormat::Int
+            | VertexFormat::Int2
+            | VertexFormat::Int3
+            | VertexFormat::Int4 => ScalarType::Int,
+            _ => ScalarType::Float,
+        }
+    }
+}
+
+#[derive(PartialEq)]
+enum ScalarType {
+    Int,
+    UInt,
+    Float,
 }
 
+/// A type that can be a field of a vertex, see [Vertex](super::Vertex)
 pub trait AsVertexFormats {
     fn as_vertex_formats() -> &'static [VertexFormat];
+
+    /// Writes the value to `buffer` without padding, as described by its formats
+    fn write_vertex_bytes(&self, buffer: &mut [u8]);
+
+    /// The number of bytes written by [AsVertexFormats::write_vertex_bytes]
+    fn vertex_byte_len() -> usize {
+        Self::as_vertex_formats()
+            .iter()
+            .map(|format| format.get_size() as usize)
+            .sum()
+    }
 }
 
 impl AsVertexFormats for f32 {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for Vec2 {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float2]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for Vec3 {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float3]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        <[f32; 3]>::from(*self).write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for Vec4 {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float4]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for Mat4 {
@@ -109,28 +173,48 @@ impl AsVertexFormats for Mat4 {
             VertexFormat::Float4,
         ]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for Color {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float4]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.as_linear_rgba_f32().write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for [f32; 2] {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float2]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for [f32; 3] {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float3]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
 
 impl AsVertexFormats for [f32; 4] {
     fn as_vertex_formats() -> &'static [VertexFormat] {
         &[VertexFormat::Float4]
     }
+
+    fn write_vertex_bytes(&self, buffer: &mut [u8]) {
+        self.write_bytes(buffer);
+    }
 }
@@ -0,0 +1,197 @@
+use bevy_math::{Mat4, Ray, Vec3, Vec4};
+
+/// An axis-aligned bounding box. As a component, it bounds the entity's mesh in the entity's local
+/// space and is used for frustum culling. It is computed from the mesh by
+/// [calculate_bounds_system](crate::mesh::calculate_bounds_system), but can be set by hand for
+/// meshes whose vertices are moved on the GPU.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Aabb {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+impl Aabb {
+    pub fn from_min_max(min: Vec3, max: Vec3) -> Self {
+        Aabb {
+            center: (min + max) * 0.5,
+            half_extents: (max - min) * 0.5,
+        }
+    }
+
+    pub fn min(&self) -> Vec3 {
+        self.center - self.half_extents
+    }
+
+    pub fn max(&self) -> Vec3 {
+        self.center + self.half_extents
+    }
+
+    /// Returns the smallest box that contains all of `points`, or `None` if there are no points
+    pub fn enclosing(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (min.min(point), max.max(point))
+        });
+        Some(Aabb::from_min_max(min, max))
+    }
+
+    /// Returns the distance along `ray` at which it enters the box, or 0 if the ray starts inside
+    /// the box. Returns `None` if the ray misses the box.
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        let min: [f32; 3] = self.min().into();
+        let max: [f32; 3] = self.max().into();
+        let origin: [f32; 3] = ray.origin.into();
+        let direction: [f32; 3] = ray.direction.into();
+        let mut near = 0.0f32;
+        let mut far = f32::INFINITY;
+        for axis in 0..3 {
+            let (origin, direction) = (origin[axis], direction[axis]);
+            if direction.abs() <= std::f32::EPSILON {
+                // the ray is parallel to this pair of faces, so it has to start between them
+                if origin < min[axis] || origin > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let mut entry = (min[axis] - origin) / direction;
+            let mut exit = (max[axis] - origin) / direction;
+            if entry > exit {
+                std::mem::swap(&mut entry, &mut exit);
+            }
+            near = near.max(entry);
+            far = far.min(exit);
+            if near > far {
+                return None;
+            }
+        }
+        Some(near)
+    }
+}
+
+/// A sphere that encloses an entity's mesh, in the entity's local space. It is computed along
+/// with the [Aabb] and can be used for cheap distance checks.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct BoundingSphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl From<&Aabb> for BoundingSphere {
+    fn from(aabb: &Aabb) -> Self {
+        BoundingSphere {
+            center: aabb.center,
+            radius: aabb.half_extents.length(),
+        }
+    }
+}
+
+/// The volume a camera sees, as six planes whose normals point inwards
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Frustum {
+    /// Each plane is the normal in `xyz` and the distance from the origin in `w`
+    pub planes: [Vec4; 6],
+}
+
+impl Frustum {
+    /// Extracts the planes of a camera's projection matrix multiplied by its view matrix, with
+    /// depth going from 0 at the near plane to 1 at the far plane
+    pub fn from_view_projection(view_projection: &Mat4) -> Self {
+        let columns = view_projection.to_cols_array_2d();
+        let row = |index: usize| {
+            Vec4::new(
+                columns[0][index],
+                columns[1][index],
+                columns[2][index],
+                columns[3][index],
+            )
+        };
+        let planes = [
+            row(3) + row(0),
+            row(3) - row(0),
+            row(3) + row(1),
+            row(3) - row(1),
+            row(2),
+            row(3) - row(2),
+        ];
+        let mut normalized = [Vec4::zero(); 6];
+        for (normalized, plane) in normalized.iter_mut().zip(planes.iter()) {
+            *normalized = *plane / plane.truncate().length();
+        }
+        Frustum { planes: normalized }
+    }
+
+    /// Returns false if the sphere is entirely outside of the frustum
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.truncate().dot(center) + plane.w >= -radius)
+    }
+
+    /// Returns false if `aabb`, moved by `model`, is entirely outside of the frustum. Boxes near
+    /// the frustum's corners can be reported as intersecting even though they are outside.
+    pub fn intersects_obb(&self, aabb: &Aabb, model: &Mat4) -> bool {
+        let columns = model.to_cols_array_2d();
+        let axis =
+            |index: usize| Vec3::new(columns[index][0], columns[index][1], columns[index][2]);
+        let center = model.transform_point3(aabb.center);
+        let half_axes = [
+            axis(0) * aabb.half_extents.x,
+            axis(1) * aabb.half_extents.y,
+            axis(2) * aabb.half_extents.z,
+        ];
+        self.planes.iter().all(|plane| {
+            let normal = plane.truncate();
+            let radius: f32 = half_axes
+                .iter()
+                .map(|half_axis| normal.dot(*half_axis).abs())
+                .sum();
+            normal.dot(center) + plane.w >= -radius
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy_math::FaceToward;
+
+    #[test]
+    fn ray_intersects_aabb() {
+        let aabb = Aabb::from_min_max(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+
+        let ray = Ray::new(Vec3::new(0.5, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(aabb.intersect_ray(&ray), Some(4.0));
+
+        // rays starting inside the box hit it immediately
+        let ray = Ray::new(Vec3::zero(), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(aabb.intersect_ray(&ray), Some(0.0));
+
+        let ray = Ray::new(Vec3::new(2.0, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(aabb.intersect_ray(&ray), None);
+
+        // the box is behind the ray
+        let ray = Ray::new(Vec3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(aabb.intersect_ray(&ray), None);
+    }
+
+    #[test]
+    fn frustum_culls_boxes_outside_of_view() {
+        let view =
+            Mat4::face_toward(Vec3::new(0.0, 0.0, 10.0), Vec3::zero(), Vec3::unit_y()).inverse();
+        let projection = Mat4::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 100.0);
+        let frustum = Frustum::from_view_projection(&(projection * view));
+        let aabb = Aabb::from_min_max(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+
+        assert!(frustum.intersects_obb(&aabb, &Mat4::identity()));
+        // behind the camera
+        assert!(!frustum.intersects_obb(&aabb, &Mat4::from_translation(Vec3::new(0.0, 0.0, 20.0))));
+        // far to the side, unless the box is scaled up to reach into view
+        let side = Mat4::from_translation(Vec3::new(30.0, 0.0, 0.0));
+        assert!(!frustum.intersects_obb(&aabb, &side));
+        assert!(frustum.intersects_obb(&aabb, &(side * Mat4::from_scale(Vec3::splat(25.0)))));
+
+        assert!(frustum.intersects_sphere(Vec3::zero(), 1.0));
+        assert!(!frustum.intersects_sphere(Vec3::new(0.0, 0.0, 200.0), 1.0));
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
-    CameraNode, PassNode, RenderGraph, SharedBuffersNode, TextureCopyNode, WindowSwapChainNode,
-    WindowTextureNode,
+    CameraNode, FrameCaptureNode, GpuReadbackNode, PassNode, RenderGraph, ScreenshotNode,
+    SharedBuffersNode, TextureCopyNode, WindowSwapChainNode, WindowTextureNode,
 };
 use crate::{
     pass::{
@@ -59,6 +59,11 @@ pub struct BaseRenderGraphConfig {
     pub add_main_pass: bool,
     pub connect_main_pass_to_swapchain: bool,
     pub connect_main_pass_to_main_depth_texture: bool,
+    /// Renders the primary window into a texture instead of a swap chain, so nothing is presented
+    /// and no display is needed, for example when running rendering tests in CI. The
+    /// [PRIMARY_SWAP_CHAIN](node::PRIMARY_SWAP_CHAIN) node outputs that texture, and rendered
+    /// frames can be read back with [FrameCapture](super::FrameCapture).
+    pub offscreen: bool,
 }
 
 pub mod node {
@@ -70,6 +75,9 @@ pub mod node {
     pub const MAIN_SAMPLED_COLOR_ATTACHMENT: &str = "main_pass_sampled_color_attachment";
     pub const MAIN_PASS: &str = "main_pass";
     pub const SHARED_BUFFERS: &str = "shared_buffers";
+    pub const FRAME_CAPTURE: &str = "frame_capture";
+    pub const GPU_READBACK: &str = "gpu_readback";
+    pub const SCREENSHOT: &str = "screenshot";
 }
 
 pub mod camera {
@@ -86,6 +94,7 @@ impl Default for BaseRenderGraphConfig {
             add_main_depth_texture: true,
             connect_main_pass_to_swapchain: true,
             connect_main_pass_to_main_depth_texture: true,
+            offscreen: false,
         }
     }
 }
@@ -108,6 +117,7 @@ impl BaseRenderGraphBuilder for RenderGraph {
         }
 
         self.add_node(node::SHARED_BUFFERS, SharedBuffersNode::default());
+        self.add_node(node::GPU_READBACK, GpuReadbackNode::default());
         if config.add_main_depth_texture {
             self.add_node(
                 node::MAIN_DEPTH_TEXTURE,
@@ -174,12 +184,70 @@ impl BaseRenderGraphBuilder for RenderGraph {
             if config.add_2d_camera {
                 self.add_node_edge(node::CAMERA2D, node::MAIN_PASS).unwrap();
             }
+
+            // readbacks see what the main pass rendered
+            self.add_node_edge(node::MAIN_PASS, node::GPU_READBACK)
+                .unwrap();
+        }
+
+        if config.offscreen {
+            // the offscreen texture takes the place of the swap chain, so passes that render to
+            // the primary swap chain render to the texture instead
+            self.add_node(
+                node::PRIMARY_SWAP_CHAIN,
+                WindowTextureNode::new(
+                    WindowId::primary(),
+                    TextureDescriptor {
+                        size: Extent3d {
+                            depth: 1,
+                            width: 1,
+                            height: 1,
+                        },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: TextureDimension::D2,
+                        format: TextureFormat::default(),
+                        usage: TextureUsage::OUTPUT_ATTACHMENT | TextureUsage::COPY_SRC,
+                    },
+                ),
+            );
+            self.add_node(
+                node::FRAME_CAPTURE,
+                FrameCaptureNode::new(WindowId::primary(), TextureFormat::default()),
+            );
+            self.add_slot_edge(
+                node::PRIMARY_SWAP_CHAIN,
+                WindowTextureNode::OUT_TEXTURE,
+                node::FRAME_CAPTURE,
+                FrameCaptureNode::IN_TEXTURE,
+            )
+            .unwrap();
+            if config.add_main_pass {
+                self.add_node_edge(node::MAIN_PASS, node::FRAME_CAPTURE)
+                    .unwrap();
+            }
+        } else {
+            self.add_node(
+                node::PRIMARY_SWAP_CHAIN,
+                WindowSwapChainNode::new(WindowId::primary()),
+            );
         }
 
         self.add_node(
-            node::PRIMARY_SWAP_CHAIN,
-            WindowSwapChainNode::new(WindowId::primary()),
+            node::SCREENSHOT,
+            ScreenshotNode::new(WindowId::primary(), TextureFormat::default()),
         );
+        self.add_slot_edge(
+            node::PRIMARY_SWAP_CHAIN,
+            WindowSwapChainNode::OUT_TEXTURE,
+            node::SCREENSHOT,
+            ScreenshotNode::IN_TEXTURE,
+        )
+        .unwrap();
+        if config.add_main_pass {
+            self.add_node_edge(node::MAIN_PASS, node::SCREENSHOT)
+                .unwrap();
+        }
 
         if config.connect_main_pass_to_swapchain {
             self.add_slot_edge(
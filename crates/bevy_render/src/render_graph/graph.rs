@@ -47,6 +47,33 @@ impl RenderGraph {
         self.add_node(name, node)
     }
 
+    /// Removes a node and all edges to and from it, for example because the window it renders to
+    /// was closed. The system of a [SystemNode] keeps running.
+    pub fn remove_node(&mut self, label: impl Into<NodeLabel>) -> Result<(), RenderGraphError> {
+        let label = label.into();
+        let id = self.get_node_id(&label)?;
+        let node_state = self
+            .nodes
+            .remove(&id)
+            .ok_or(RenderGraphError::InvalidNode(label))?;
+        if let Some(name) = node_state.name {
+            self.node_names.remove(&name);
+        }
+
+        for edge in node_state.edges.input_edges {
+            if let Some(output_node) = self.nodes.get_mut(&edge.get_output_node()) {
+                output_node.edges.output_edges.retain(|e| *e != edge);
+            }
+        }
+        for edge in node_state.edges.output_edges {
+            if let Some(input_node) = self.nodes.get_mut(&edge.get_input_node()) {
+                input_node.edges.input_edges.retain(|e| *e != edge);
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn get_node_state(
         &self,
         label: impl Into<NodeLabel>,
@@ -404,6 +431,43 @@ mod tests {
         assert!(output_nodes("D", &graph).is_empty(), "D has no outputs");
     }
 
+    #[test]
+    pub fn test_remove_node() {
+        let mut graph = RenderGraph::default();
+        let a_id = graph.add_node("A", TestNode::new(0, 1));
+        graph.add_node("B", TestNode::new(1, 1));
+        let c_id = graph.add_node("C", TestNode::new(1, 0));
+
+        graph.add_slot_edge("A", 0, "B", 0).unwrap();
+        graph.add_slot_edge("B", 0, "C", 0).unwrap();
+        graph.add_node_edge("A", "C").unwrap();
+
+        graph.remove_node("B").unwrap();
+
+        assert!(graph.get_node_state("B").is_err(), "B was removed");
+        assert_eq!(
+            graph
+                .iter_node_outputs("A")
+                .unwrap()
+                .map(|(_edge, node)| node.id)
+                .collect::<Vec<_>>(),
+            vec![c_id],
+            "A only outputs to C"
+        );
+        assert_eq!(
+            graph
+                .iter_node_inputs("C")
+                .unwrap()
+                .map(|(_edge, node)| node.id)
+                .collect::<Vec<_>>(),
+            vec![a_id],
+            "C only has an input from A"
+        );
+        // B's slots can be connected again
+        graph.add_node("B", TestNode::new(1, 1));
+        graph.add_slot_edge("A", 0, "B", 0).unwrap();
+    }
+
     #[test]
     pub fn test_get_node_typed() {
         struct MyNode {
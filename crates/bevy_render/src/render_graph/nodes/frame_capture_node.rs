@@ -0,0 +1,181 @@
+use crate::{
+    render_graph::{Node, ResourceSlotInfo, ResourceSlots},
+    renderer::{
+        BufferId, BufferInfo, BufferMapMode, BufferUsage, RenderContext, RenderResourceContext,
+        RenderResourceId, RenderResourceType,
+    },
+    texture::{Extent3d, Texture, TextureDimension, TextureFormat},
+};
+use bevy_ecs::{Res, ResMut, Resources, World};
+use bevy_window::{WindowId, Windows};
+use std::borrow::Cow;
+
+/// Reads rendered frames back to the CPU. Frames can only be captured when the base render graph
+/// renders offscreen, see
+/// [BaseRenderGraphConfig::offscreen](crate::render_graph::base::BaseRenderGraphConfig::offscreen).
+///
+/// Call [FrameCapture::request] and the next rendered frame becomes available from
+/// [FrameCapture::take_frame] once that frame's update has finished.
+#[derive(Debug, Default)]
+pub struct FrameCapture {
+    requested: bool,
+    pending: Option<PendingFrame>,
+    frame: Option<Texture>,
+}
+
+impl FrameCapture {
+    /// Captures the next rendered frame
+    pub fn request(&mut self) {
+        self.requested = true;
+    }
+
+    /// Returns the most recently captured frame, in the format of the offscreen render target
+    pub fn take_frame(&mut self) -> Option<Texture> {
+        self.frame.take()
+    }
+}
+
+#[derive(Debug)]
+struct PendingFrame {
+    buffer: BufferId,
+    size: Extent3d,
+    format: TextureFormat,
+    padded_bytes_per_row: usize,
+}
+
+/// Copies its input texture into a buffer the CPU can read when a [FrameCapture] is requested
+pub struct FrameCaptureNode {
+    window_id: WindowId,
+    format: TextureFormat,
+}
+
+impl FrameCaptureNode {
+    pub const IN_TEXTURE: &'static str = "texture";
+
+    /// Creates a node that captures a texture of the given window's size and format
+    pub fn new(window_id: WindowId, format: TextureFormat) -> Self {
+        FrameCaptureNode { window_id, format }
+    }
+}
+
+impl Node for FrameCaptureNode {
+    fn input(&self) -> &[ResourceSlotInfo] {
+        static INPUT: &[ResourceSlotInfo] = &[ResourceSlotInfo {
+            name: Cow::Borrowed(FrameCaptureNode::IN_TEXTURE),
+            resource_type: RenderResourceType::Texture,
+        }];
+        INPUT
+    }
+
+    fn update(
+        &mut self,
+        _world: &World,
+        resources: &Resources,
+        render_context: &mut dyn RenderContext,
+        input: &ResourceSlots,
+        _output: &mut ResourceSlots,
+    ) {
+        const INPUT_TEXTURE: usize = 0;
+        let mut frame_capture = match resources.get_mut::<FrameCapture>() {
+            Some(frame_capture) => frame_capture,
+            None => return,
+        };
+        if !frame_capture.requested || frame_capture.pending.is_some() {
+            return;
+        }
+
+        let windows = resources.get::<Windows>().unwrap();
+        let window = match windows.get(self.window_id) {
+            Some(window) => window,
+            None => return,
+        };
+        let texture = match input.get(INPUT_TEXTURE) {
+            Some(RenderResourceId::Texture(texture)) => texture,
+            _ => return,
+        };
+
+        let size = Extent3d::new(window.physical_width(), window.physical_height(), 1);
+        let aligned_width = render_context
+            .resources()
+            .get_aligned_texture_size(size.width as usize);
+        let padded_bytes_per_row = aligned_width * self.format.pixel_size();
+        let buffer = render_context.resources().create_buffer(BufferInfo {
+            size: padded_bytes_per_row * size.height as usize,
+            buffer_usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+            ..Default::default()
+        });
+        render_context.copy_texture_to_buffer(
+            texture,
+            [0, 0, 0],
+            0,
+            buffer,
+            0,
+            padded_bytes_per_row as u32,
+            size,
+        );
+
+        frame_capture.requested = false;
+        frame_capture.pending = Some(PendingFrame {
+            buffer,
+            size,
+            format: self.format,
+            padded_bytes_per_row,
+        });
+    }
+}
+
+/// Reads frames copied by the [FrameCaptureNode] back once they have been rendered. This blocks
+/// until the GPU has finished the frame.
+pub fn frame_capture_system(
+    mut frame_capture: ResMut<FrameCapture>,
+    render_resource_context: Res<Box<dyn RenderResourceContext>>,
+) {
+    let pending = match frame_capture.pending.take() {
+        Some(pending) => pending,
+        None => return,
+    };
+
+    let render_resource_context = &**render_resource_context;
+    let bytes_per_row = pending.size.width as usize * pending.format.pixel_size();
+    let buffer_size = pending.padded_bytes_per_row * pending.size.height as usize;
+    let mut data = Vec::new();
+    render_resource_context.map_buffer(pending.buffer, BufferMapMode::Read);
+    render_resource_context.read_mapped_buffer(
+        pending.buffer,
+        0..buffer_size as u64,
+        &mut |bytes, _render_resource_context| {
+            data = remove_row_padding(bytes, pending.padded_bytes_per_row, bytes_per_row);
+        },
+    );
+    render_resource_context.unmap_buffer(pending.buffer);
+    render_resource_context.remove_buffer(pending.buffer);
+
+    frame_capture.frame = Some(Texture::new(
+        pending.size,
+        TextureDimension::D2,
+        data,
+        pending.format,
+    ));
+}
+
+/// Texture copies pad each row to the GPU's alignment, which readers of the frame don't expect
+pub(crate) fn remove_row_padding(
+    data: &[u8],
+    padded_bytes_per_row: usize,
+    bytes_per_row: usize,
+) -> Vec<u8> {
+    data.chunks_exact(padded_bytes_per_row)
+        .flat_map(|row| row[..bytes_per_row].iter().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_row_padding;
+
+    #[test]
+    fn row_padding_is_removed() {
+        let padded = [1, 2, 0, 0, 3, 4, 0, 0];
+        assert_eq!(remove_row_padding(&padded, 4, 2), vec![1, 2, 3, 4]);
+    }
+}
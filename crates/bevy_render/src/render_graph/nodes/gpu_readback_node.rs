@@ -0,0 +1,366 @@
+use super::frame_capture_node::remove_row_padding;
+use crate::{
+    render_graph::{Node, ResourceSlots},
+    renderer::{
+        BufferId, BufferInfo, BufferMapMode, BufferUsage, RenderContext, RenderResourceContext,
+        TextureId,
+    },
+    texture::{Extent3d, TextureFormat},
+};
+use bevy_app::prelude::Events;
+use bevy_ecs::{Res, ResMut, Resources, World};
+use bevy_utils::tracing::error;
+use std::ops::Range;
+
+/// Identifies a readback requested from [GpuReadback]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReadbackId(u64);
+
+/// Sent once the data of a readback requested from [GpuReadback] has arrived on the CPU
+#[derive(Debug, Clone)]
+pub struct ReadbackComplete {
+    pub id: ReadbackId,
+    /// The contents of the buffer range, or the texture's pixels with tightly packed rows
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+enum ReadbackSource {
+    Buffer {
+        buffer: BufferId,
+        range: Range<u64>,
+    },
+    Texture {
+        texture: TextureId,
+        size: Extent3d,
+        format: TextureFormat,
+    },
+}
+
+#[derive(Debug)]
+struct PendingReadback {
+    id: ReadbackId,
+    buffer: BufferId,
+    size: usize,
+    /// The padded and unpadded row sizes of texture readbacks
+    row_padding: Option<(usize, usize)>,
+    mapping: bool,
+}
+
+/// Reads the contents of buffers and textures back to the CPU, e.g. the results of a shader or a
+/// rendered image.
+///
+/// Readbacks are copied after the main pass of the frame they are requested in. They complete
+/// asynchronously, usually a frame or two later, with a [ReadbackComplete] event. The source
+/// buffer or texture must have been created with the `COPY_SRC` usage.
+#[derive(Debug, Default)]
+pub struct GpuReadback {
+    next_id: u64,
+    requested: Vec<(ReadbackId, ReadbackSource)>,
+    pending: Vec<PendingReadback>,
+}
+
+impl GpuReadback {
+    /// Reads `range` of `buffer`, in bytes. GPUs copy buffers in steps of 4 bytes, so the range
+    /// must start and end at multiples of 4.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty or doesn't start and end at multiples of 4.
+    pub fn read_buffer(&mut self, buffer: BufferId, range: Range<u64>) -> ReadbackId {
+        assert!(
+            range.start < range.end && range.start % 4 == 0 && range.end % 4 == 0,
+            "buffer readbacks need a non-empty range that starts and ends at multiples of 4, got {:?}",
+            range
+        );
+        self.request(ReadbackSource::Buffer { buffer, range })
+    }
+
+    /// Reads the first mip level of a 2D `texture`
+    pub fn read_texture(
+        &mut self,
+        texture: TextureId,
+        size: Extent3d,
+        format: TextureFormat,
+    ) -> ReadbackId {
+        self.request(ReadbackSource::Texture {
+            texture,
+            size,
+            format,
+        })
+    }
+
+    /// Returns whether there are readbacks that haven't completed yet
+    pub fn is_busy(&self) -> bool {
+        !self.requested.is_empty() || !self.pending.is_empty()
+    }
+
+    /// Copies the first mip level of a 2D `texture` right away, for nodes that only have access to
+    /// the texture while the render graph runs
+    pub(crate) fn read_texture_now(
+        &mut self,
+        render_context: &mut dyn RenderContext,
+        texture: TextureId,
+        size: Extent3d,
+        format: TextureFormat,
+    ) -> ReadbackId {
+        let id = self.next_id();
+        let pending = copy_texture(render_context, id, texture, size, format);
+        self.pending.push(pending);
+        id
+    }
+
+    fn request(&mut self, source: ReadbackSource) -> ReadbackId {
+        let id = self.next_id();
+        self.requested.push((id, source));
+        id
+    }
+
+    fn next_id(&mut self) -> ReadbackId {
+        let id = ReadbackId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+}
+
+/// Copies the sources of requested [GpuReadback]s into buffers the CPU can read
+#[derive(Debug, Default)]
+pub struct GpuReadbackNode;
+
+impl Node for GpuReadbackNode {
+    fn update(
+        &mut self,
+        _world: &World,
+        resources: &Resources,
+        render_context: &mut dyn RenderContext,
+        _input: &ResourceSlots,
+        _output: &mut ResourceSlots,
+    ) {
+        let mut gpu_readback = match resources.get_mut::<GpuReadback>() {
+            Some(gpu_readback) => gpu_readback,
+            None => return,
+        };
+
+        for (id, source) in std::mem::take(&mut gpu_readback.requested) {
+            let pending = match source {
+                ReadbackSource::Buffer { buffer, range } => {
+                    let size = (range.end - range.start) as usize;
+                    let readback_buffer = create_readback_buffer(render_context, size);
+                    render_context.copy_buffer_to_buffer(
+                        buffer,
+                        range.start,
+                        readback_buffer,
+                        0,
+                        size as u64,
+                    );
+                    PendingReadback {
+                        id,
+                        buffer: readback_buffer,
+                        size,
+                        row_padding: None,
+                        mapping: false,
+                    }
+                }
+                ReadbackSource::Texture {
+                    texture,
+                    size,
+                    format,
+                } => copy_texture(render_context, id, texture, size, format),
+            };
+            gpu_readback.pending.push(pending);
+        }
+    }
+}
+
+fn copy_texture(
+    render_context: &mut dyn RenderContext,
+    id: ReadbackId,
+    texture: TextureId,
+    size: Extent3d,
+    format: TextureFormat,
+) -> PendingReadback {
+    let aligned_width = render_context
+        .resources()
+        .get_aligned_texture_size(size.width as usize);
+    let padded_bytes_per_row = aligned_width * format.pixel_size();
+    let bytes_per_row = size.width as usize * format.pixel_size();
+    let buffer_size = padded_bytes_per_row * size.height as usize;
+    let readback_buffer = create_readback_buffer(render_context, buffer_size);
+    render_context.copy_texture_to_buffer(
+        texture,
+        [0, 0, 0],
+        0,
+        readback_buffer,
+        0,
+        padded_bytes_per_row as u32,
+        Extent3d::new(size.width, size.height, 1),
+    );
+    PendingReadback {
+        id,
+        buffer: readback_buffer,
+        size: buffer_size,
+        row_padding: Some((padded_bytes_per_row, bytes_per_row)),
+        mapping: false,
+    }
+}
+
+fn create_readback_buffer(render_context: &mut dyn RenderContext, size: usize) -> BufferId {
+    render_context.resources().create_buffer(BufferInfo {
+        size,
+        buffer_usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+        ..Default::default()
+    })
+}
+
+/// Maps the buffers copied by the [GpuReadbackNode] once the GPU is done with them, and sends a
+/// [ReadbackComplete] event for each of them. This doesn't wait for the GPU.
+pub fn gpu_readback_system(
+    mut gpu_readback: ResMut<GpuReadback>,
+    mut readback_events: ResMut<Events<ReadbackComplete>>,
+    render_resource_context: Res<Box<dyn RenderResourceContext>>,
+) {
+    let render_resource_context = &**render_resource_context;
+    let mut index = 0;
+    while index < gpu_readback.pending.len() {
+        let pending = &mut gpu_readback.pending[index];
+        // the copy has been submitted by the time this runs, so mapping can start
+        if !pending.mapping {
+            render_resource_context.map_buffer_async(pending.buffer, BufferMapMode::Read);
+            pending.mapping = true;
+        }
+        match render_resource_context.is_buffer_mapped(pending.buffer) {
+            Ok(true) => {}
+            Ok(false) => {
+                index += 1;
+                continue;
+            }
+            Err(err) => {
+                let pending = gpu_readback.pending.swap_remove(index);
+                error!("Dropping readback {:?}: {}", pending.id, err);
+                render_resource_context.remove_buffer(pending.buffer);
+                continue;
+            }
+        }
+
+        let pending = gpu_readback.pending.swap_remove(index);
+        let mut data = Vec::new();
+        render_resource_context.read_mapped_buffer(
+            pending.buffer,
+            0..pending.size as u64,
+            &mut |bytes, _render_resource_context| {
+                data = match pending.row_padding {
+                    Some((padded_bytes_per_row, bytes_per_row)) => {
+                        remove_row_padding(bytes, padded_bytes_per_row, bytes_per_row)
+                    }
+                    None => bytes.to_vec(),
+                };
+            },
+        );
+        render_resource_context.unmap_buffer(pending.buffer);
+        render_resource_context.remove_buffer(pending.buffer);
+        readback_events.send(ReadbackComplete {
+            id: pending.id,
+            data,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::HeadlessRenderResourceContext;
+    use bevy_ecs::{IntoSystem, Schedule};
+
+    #[test]
+    fn requests_get_unique_ids() {
+        let mut gpu_readback = GpuReadback::default();
+        assert!(!gpu_readback.is_busy());
+
+        let buffer = BufferId::new();
+        let first = gpu_readback.read_buffer(buffer, 0..16);
+        let second = gpu_readback.read_buffer(buffer, 4..8);
+        assert_ne!(first, second);
+        assert!(gpu_readback.is_busy());
+        assert_eq!(
+            gpu_readback
+                .requested
+                .iter()
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>(),
+            vec![first, second]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn unaligned_buffer_readbacks_are_rejected() {
+        GpuReadback::default().read_buffer(BufferId::new(), 2..8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_buffer_readbacks_are_rejected() {
+        GpuReadback::default().read_buffer(BufferId::new(), 8..8);
+    }
+
+    #[test]
+    fn mapped_readbacks_complete() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        let render_resource_context = HeadlessRenderResourceContext::default();
+        let readback_buffer = |size| {
+            render_resource_context.create_buffer(BufferInfo {
+                size,
+                buffer_usage: BufferUsage::MAP_READ | BufferUsage::COPY_DST,
+                ..Default::default()
+            })
+        };
+        let buffer_readback = readback_buffer(16);
+        let texture_readback = readback_buffer(24);
+
+        let mut gpu_readback = GpuReadback::default();
+        let buffer_id = gpu_readback.next_id();
+        let texture_id = gpu_readback.next_id();
+        gpu_readback.pending.push(PendingReadback {
+            id: buffer_id,
+            buffer: buffer_readback,
+            size: 16,
+            row_padding: None,
+            mapping: false,
+        });
+        gpu_readback.pending.push(PendingReadback {
+            id: texture_id,
+            buffer: texture_readback,
+            size: 24,
+            row_padding: Some((8, 6)),
+            mapping: false,
+        });
+        resources.insert(gpu_readback);
+        resources.insert(Events::<ReadbackComplete>::default());
+        resources.insert::<Box<dyn RenderResourceContext>>(Box::new(render_resource_context));
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("update");
+        schedule.add_system_to_stage("update", gpu_readback_system.system());
+        schedule.initialize(&mut world, &mut resources);
+        schedule.run(&mut world, &mut resources);
+
+        assert!(!resources.get::<GpuReadback>().unwrap().is_busy());
+        let events = resources.get::<Events<ReadbackComplete>>().unwrap();
+        let mut completed = events
+            .iter_current_update_events()
+            .map(|readback| (readback.id, readback.data.len()))
+            .collect::<Vec<_>>();
+        completed.sort_by_key(|(id, _)| id.0);
+        // the padding at the end of each of the three texture rows is removed
+        assert_eq!(completed, vec![(buffer_id, 16), (texture_id, 18)]);
+
+        let render_resource_context = resources.get::<Box<dyn RenderResourceContext>>().unwrap();
+        assert!(render_resource_context
+            .get_buffer_info(buffer_readback)
+            .is_none());
+        assert!(render_resource_context
+            .get_buffer_info(texture_readback)
+            .is_none());
+    }
+}
@@ -0,0 +1,301 @@
+use super::{GpuReadback, ReadbackComplete, ReadbackId};
+use crate::{
+    render_graph::{Node, ResourceSlotInfo, ResourceSlots},
+    renderer::{RenderContext, RenderResourceId, RenderResourceType},
+    texture::{Extent3d, Texture, TextureDimension, TextureFormat},
+};
+use bevy_app::prelude::{EventReader, Events};
+use bevy_asset::{Assets, Handle, HandleId};
+use bevy_ecs::{Local, Res, ResMut, Resources, World};
+use bevy_tasks::IoTaskPool;
+use bevy_utils::tracing::error;
+use bevy_window::{WindowId, Windows};
+use std::{borrow::Cow, path::PathBuf};
+
+/// Sent when a screenshot requested with [Screenshot::capture] has been read back
+#[derive(Debug, Clone)]
+pub struct ScreenshotCaptured {
+    pub window_id: WindowId,
+    /// The screenshot, in the format of the window's swap chain
+    pub texture: Handle<Texture>,
+}
+
+/// Identifies a screenshot requested with [Screenshot::read]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScreenshotId(u64);
+
+/// Sent when a screenshot requested with [Screenshot::read] has been read back
+#[derive(Debug, Clone)]
+pub struct ScreenshotRead {
+    pub id: ScreenshotId,
+    pub window_id: WindowId,
+    pub size: Extent3d,
+    /// The format of the window's swap chain, which is the format of `data`
+    pub format: TextureFormat,
+    /// The pixels of the screenshot, without row padding
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+enum ScreenshotTarget {
+    Asset(HandleId),
+    File(PathBuf),
+    Data(ScreenshotId),
+}
+
+#[derive(Debug)]
+struct PendingScreenshot {
+    readback: ReadbackId,
+    window_id: WindowId,
+    target: ScreenshotTarget,
+    size: Extent3d,
+    format: TextureFormat,
+}
+
+/// Takes screenshots of windows, for bug reports, photo modes or visual tests.
+///
+/// A screenshot is a copy of the next frame that is rendered to the window, read back
+/// asynchronously with [GpuReadback] a frame or two later. The primary window can be captured
+/// when the base render graph is used; other windows need a [ScreenshotNode] in the render graph.
+#[derive(Debug, Default)]
+pub struct Screenshot {
+    requested: Vec<(WindowId, ScreenshotTarget)>,
+    pending: Vec<PendingScreenshot>,
+    next_id: u64,
+}
+
+impl Screenshot {
+    /// Captures the next frame of the window into a [Texture] asset. The returned handle is weak:
+    /// the asset is added once the frame has been read back, and only kept while there are strong
+    /// handles to it, like the one of the [ScreenshotCaptured] event sent at the same time.
+    pub fn capture(&mut self, window_id: WindowId) -> Handle<Texture> {
+        let id = HandleId::random::<Texture>();
+        self.requested
+            .push((window_id, ScreenshotTarget::Asset(id)));
+        Handle::weak(id)
+    }
+
+    /// Saves the next frame of the window as a PNG at `path`. The file is written on the
+    /// [IoTaskPool] once the frame has been read back.
+    pub fn save_to_file(&mut self, window_id: WindowId, path: impl Into<PathBuf>) {
+        self.requested
+            .push((window_id, ScreenshotTarget::File(path.into())));
+    }
+
+    /// Reads the next frame of the window back without creating a [Texture] asset. A
+    /// [ScreenshotRead] event with the returned id and the frame's pixels is sent once the frame
+    /// has been read back.
+    pub fn read(&mut self, window_id: WindowId) -> ScreenshotId {
+        let id = ScreenshotId(self.next_id);
+        self.next_id += 1;
+        self.requested.push((window_id, ScreenshotTarget::Data(id)));
+        id
+    }
+
+    /// Returns whether there are screenshots that haven't been read back yet
+    pub fn is_busy(&self) -> bool {
+        !self.requested.is_empty() || !self.pending.is_empty()
+    }
+}
+
+/// Copies its input texture, the window's swap chain or offscreen render target, when a
+/// [Screenshot] of the window is requested. The swap chain has to be rendered before this runs,
+/// so every pass that renders to it needs a node edge to this node, like the base graph's main
+/// pass and the UI pass have to the [SCREENSHOT](crate::render_graph::base::node::SCREENSHOT)
+/// node.
+pub struct ScreenshotNode {
+    window_id: WindowId,
+    format: TextureFormat,
+}
+
+impl ScreenshotNode {
+    pub const IN_TEXTURE: &'static str = "texture";
+
+    /// Creates a node that captures a texture of the given window's size and format
+    pub fn new(window_id: WindowId, format: TextureFormat) -> Self {
+        ScreenshotNode { window_id, format }
+    }
+}
+
+impl Node for ScreenshotNode {
+    fn input(&self) -> &[ResourceSlotInfo] {
+        static INPUT: &[ResourceSlotInfo] = &[ResourceSlotInfo {
+            name: Cow::Borrowed(ScreenshotNode::IN_TEXTURE),
+            resource_type: RenderResourceType::Texture,
+        }];
+        INPUT
+    }
+
+    fn update(
+        &mut self,
+        _world: &World,
+        resources: &Resources,
+        render_context: &mut dyn RenderContext,
+        input: &ResourceSlots,
+        _output: &mut ResourceSlots,
+    ) {
+        const INPUT_TEXTURE: usize = 0;
+        let mut screenshot = match resources.get_mut::<Screenshot>() {
+            Some(screenshot) => screenshot,
+            None => return,
+        };
+        if !screenshot
+            .requested
+            .iter()
+            .any(|(window_id, _)| *window_id == self.window_id)
+        {
+            return;
+        }
+
+        let (requested, remaining) = std::mem::take(&mut screenshot.requested)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(window_id, _)| *window_id == self.window_id);
+        screenshot.requested = remaining;
+
+        // the requests can't be fulfilled, so drop them instead of keeping the screenshot busy
+        let windows = resources.get::<Windows>().unwrap();
+        let window = match windows.get(self.window_id) {
+            Some(window) => window,
+            None => {
+                error!(
+                    "Dropping {} screenshot(s) of window {:?}: the window does not exist",
+                    requested.len(),
+                    self.window_id
+                );
+                return;
+            }
+        };
+        let texture = match input.get(INPUT_TEXTURE) {
+            Some(RenderResourceId::Texture(texture)) => texture,
+            _ => {
+                error!(
+                    "Dropping {} screenshot(s) of window {:?}: the screenshot node has no input texture",
+                    requested.len(),
+                    self.window_id
+                );
+                return;
+            }
+        };
+        let mut gpu_readback = resources.get_mut::<GpuReadback>().unwrap();
+
+        let size = Extent3d::new(window.physical_width(), window.physical_height(), 1);
+        let readback = gpu_readback.read_texture_now(render_context, texture, size, self.format);
+        for (window_id, target) in requested {
+            screenshot.pending.push(PendingScreenshot {
+                readback,
+                window_id,
+                target,
+                size,
+                format: self.format,
+            });
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ScreenshotSystemState {
+    readback_event_reader: EventReader<ReadbackComplete>,
+}
+
+/// Turns the frames copied by the [ScreenshotNode] into [Texture] assets, PNG files and
+/// [ScreenshotRead] events once they have been read back
+pub fn screenshot_system(
+    mut state: Local<ScreenshotSystemState>,
+    mut screenshot: ResMut<Screenshot>,
+    readback_events: Res<Events<ReadbackComplete>>,
+    mut screenshot_events: ResMut<Events<ScreenshotCaptured>>,
+    mut screenshot_read_events: ResMut<Events<ScreenshotRead>>,
+    mut textures: ResMut<Assets<Texture>>,
+    io_task_pool: Res<IoTaskPool>,
+) {
+    for readback in state.readback_event_reader.iter(&readback_events) {
+        let mut index = 0;
+        while index < screenshot.pending.len() {
+            if screenshot.pending[index].readback != readback.id {
+                index += 1;
+                continue;
+            }
+
+            let pending = screenshot.pending.swap_remove(index);
+            match pending.target {
+                ScreenshotTarget::Asset(id) => {
+                    let texture = Texture::new(
+                        pending.size,
+                        TextureDimension::D2,
+                        readback.data.clone(),
+                        pending.format,
+                    );
+                    screenshot_events.send(ScreenshotCaptured {
+                        window_id: pending.window_id,
+                        texture: textures.set(id, texture),
+                    });
+                }
+                ScreenshotTarget::Data(id) => screenshot_read_events.send(ScreenshotRead {
+                    id,
+                    window_id: pending.window_id,
+                    size: pending.size,
+                    format: pending.format,
+                    data: readback.data.clone(),
+                }),
+                ScreenshotTarget::File(path) => {
+                    let data = match to_rgba8(&readback.data, pending.format) {
+                        Some(data) => data,
+                        None => {
+                            error!(
+                                "Screenshots of {:?} textures can't be saved, {} was not written",
+                                pending.format,
+                                path.display()
+                            );
+                            continue;
+                        }
+                    };
+                    let size = pending.size;
+                    io_task_pool
+                        .spawn(async move {
+                            if let Err(err) = image::save_buffer(
+                                &path,
+                                &data,
+                                size.width,
+                                size.height,
+                                image::ColorType::Rgba8,
+                            ) {
+                                error!("Failed to save screenshot {}: {}", path.display(), err);
+                            }
+                        })
+                        .detach();
+                }
+            }
+        }
+    }
+}
+
+/// Converts 8 bit RGBA and BGRA pixels to RGBA, which is what PNG files store
+pub(crate) fn to_rgba8(data: &[u8], format: TextureFormat) -> Option<Vec<u8>> {
+    match format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => Some(data.to_vec()),
+        TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => {
+            let mut data = data.to_vec();
+            for pixel in data.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+            Some(data)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_rgba8;
+    use crate::texture::TextureFormat;
+
+    #[test]
+    fn bgra_is_swizzled() {
+        let bgra = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(
+            to_rgba8(&bgra, TextureFormat::Bgra8UnormSrgb),
+            Some(vec![3, 2, 1, 4, 7, 6, 5, 8])
+        );
+        assert_eq!(to_rgba8(&bgra, TextureFormat::R32Float), None);
+    }
+}
@@ -23,6 +23,10 @@ impl WindowSwapChainNode {
             window_resized_event_reader: Default::default(),
         }
     }
+
+    pub fn window_id(&self) -> WindowId {
+        self.window_id
+    }
 }
 
 impl Node for WindowSwapChainNode {
@@ -26,6 +26,10 @@ impl WindowTextureNode {
             window_resized_event_reader: Default::default(),
         }
     }
+
+    pub fn window_id(&self) -> WindowId {
+        self.window_id
+    }
 }
 
 impl Node for WindowTextureNode {
@@ -68,8 +72,8 @@ impl Node for WindowTextureNode {
                 render_resource_context.remove_texture(old_texture);
             }
 
-            self.descriptor.size.width = window.width();
-            self.descriptor.size.height = window.height();
+            self.descriptor.size.width = window.physical_width();
+            self.descriptor.size.height = window.physical_height();
             let texture_resource = render_resource_context.create_texture(self.descriptor);
             output.set(WINDOW_TEXTURE, RenderResourceId::Texture(texture_resource));
         }
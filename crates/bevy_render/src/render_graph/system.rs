@@ -1,5 +1,7 @@
-use super::RenderGraph;
-use bevy_ecs::{Resources, World};
+use super::{Edge, NodeId, RenderGraph, WindowSwapChainNode, WindowTextureNode};
+use bevy_app::prelude::{EventReader, Events};
+use bevy_ecs::{Local, Res, ResMut, Resources, World};
+use bevy_window::{WindowClosed, WindowId};
 
 pub fn render_graph_schedule_executor_system(world: &mut World, resources: &mut Resources) {
     // run render graph systems
@@ -22,3 +24,140 @@ pub fn render_graph_schedule_executor_system(world: &mut World, resources: &mut
         render_graph.set_schedule(schedule);
     }
 }
+
+/// Removes the nodes that render to a window once it is closed. See [remove_window_nodes].
+pub fn remove_closed_window_nodes_system(
+    mut window_closed_event_reader: Local<EventReader<WindowClosed>>,
+    window_closed_events: Res<Events<WindowClosed>>,
+    mut render_graph: ResMut<RenderGraph>,
+) {
+    for event in window_closed_event_reader.iter(&window_closed_events) {
+        remove_window_nodes(&mut render_graph, event.id);
+    }
+}
+
+/// Removes the [WindowSwapChainNode]s and [WindowTextureNode]s of `window_id`, along with every
+/// node that reads their output through a slot edge, directly or indirectly, because those nodes
+/// can't run without the window.
+pub fn remove_window_nodes(render_graph: &mut RenderGraph, window_id: WindowId) {
+    let mut removed = render_graph
+        .iter_nodes()
+        .filter(|node_state| {
+            node_state
+                .node::<WindowSwapChainNode>()
+                .map(|node| node.window_id())
+                .or_else(|_| {
+                    node_state
+                        .node::<WindowTextureNode>()
+                        .map(|node| node.window_id())
+                })
+                .map_or(false, |id| id == window_id)
+        })
+        .map(|node_state| node_state.id)
+        .collect::<Vec<NodeId>>();
+
+    let mut index = 0;
+    while index < removed.len() {
+        let dependents = render_graph
+            .iter_node_outputs(removed[index])
+            .unwrap()
+            .filter(|(edge, _)| matches!(edge, Edge::SlotEdge { .. }))
+            .map(|(_, node_state)| node_state.id)
+            .collect::<Vec<NodeId>>();
+        for id in dependents {
+            if !removed.contains(&id) {
+                removed.push(id);
+            }
+        }
+        index += 1;
+    }
+
+    for id in removed {
+        render_graph.remove_node(id).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        render_graph::{Node, ResourceSlotInfo, ResourceSlots},
+        renderer::{RenderContext, RenderResourceType},
+        texture::TextureDescriptor,
+    };
+    use std::borrow::Cow;
+
+    struct ColorPassNode;
+
+    impl Node for ColorPassNode {
+        fn input(&self) -> &[ResourceSlotInfo] {
+            static INPUT: &[ResourceSlotInfo] = &[
+                ResourceSlotInfo {
+                    name: Cow::Borrowed("color_attachment"),
+                    resource_type: RenderResourceType::Texture,
+                },
+                ResourceSlotInfo {
+                    name: Cow::Borrowed("depth"),
+                    resource_type: RenderResourceType::Texture,
+                },
+            ];
+            INPUT
+        }
+
+        fn update(
+            &mut self,
+            _: &World,
+            _: &Resources,
+            _: &mut dyn RenderContext,
+            _: &ResourceSlots,
+            _: &mut ResourceSlots,
+        ) {
+        }
+    }
+
+    fn add_window_nodes(render_graph: &mut RenderGraph, name: &'static str, window_id: WindowId) {
+        let swap_chain = render_graph.add_node(
+            format!("{}_swap_chain", name),
+            WindowSwapChainNode::new(window_id),
+        );
+        let depth = render_graph.add_node(
+            format!("{}_depth", name),
+            WindowTextureNode::new(window_id, TextureDescriptor::default()),
+        );
+        let pass = render_graph.add_node(format!("{}_pass", name), ColorPassNode);
+        render_graph.add_slot_edge(swap_chain, 0, pass, 0).unwrap();
+        render_graph.add_slot_edge(depth, 0, pass, 1).unwrap();
+    }
+
+    #[test]
+    fn closed_window_nodes_are_removed() {
+        let mut render_graph = RenderGraph::default();
+        let second_window = WindowId::new();
+        add_window_nodes(&mut render_graph, "primary", WindowId::primary());
+        add_window_nodes(&mut render_graph, "second", second_window);
+        // the passes of both windows are ordered, which doesn't make the primary one depend on
+        // the second window
+        render_graph
+            .add_node_edge("second_pass", "primary_pass")
+            .unwrap();
+
+        remove_window_nodes(&mut render_graph, second_window);
+
+        let mut names = render_graph
+            .iter_nodes()
+            .map(|node_state| node_state.name.as_deref().unwrap().to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            vec!["primary_depth", "primary_pass", "primary_swap_chain"]
+        );
+        assert_eq!(
+            render_graph
+                .iter_node_inputs("primary_pass")
+                .unwrap()
+                .count(),
+            2
+        );
+    }
+}
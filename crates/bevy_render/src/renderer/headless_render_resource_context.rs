@@ -1,7 +1,10 @@
 use super::RenderResourceContext;
 use crate::{
     pipeline::{BindGroupDescriptorId, PipelineDescriptor},
-    renderer::{BindGroup, BufferId, BufferInfo, RenderResourceId, SamplerId, TextureId},
+    renderer::{
+        BindGroup, BufferId, BufferInfo, BufferMapError, BufferMapMode, RenderResourceId,
+        SamplerId, TextureId,
+    },
     shader::Shader,
     texture::{SamplerDescriptor, TextureDescriptor},
 };
@@ -66,7 +69,24 @@ impl RenderResourceContext for HeadlessRenderResourceContext {
         write(&mut buffer, self);
     }
 
-    fn map_buffer(&self, _id: BufferId) {}
+    fn read_mapped_buffer(
+        &self,
+        id: BufferId,
+        _range: Range<u64>,
+        read: &mut dyn FnMut(&[u8], &dyn RenderResourceContext),
+    ) {
+        let size = self.buffer_info.read().get(&id).unwrap().size;
+        let buffer = vec![0; size];
+        read(&buffer, self);
+    }
+
+    fn map_buffer(&self, _id: BufferId, _mode: BufferMapMode) {}
+
+    fn map_buffer_async(&self, _id: BufferId, _mode: BufferMapMode) {}
+
+    fn is_buffer_mapped(&self, _id: BufferId) -> Result<bool, BufferMapError> {
+        Ok(true)
+    }
 
     fn unmap_buffer(&self, _id: BufferId) {}
 
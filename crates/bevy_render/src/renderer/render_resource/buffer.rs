@@ -1,3 +1,4 @@
+use thiserror::Error;
 use uuid::Uuid;
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
@@ -27,6 +28,23 @@ impl Default for BufferInfo {
     }
 }
 
+/// Whether a buffer is mapped so the CPU can read from it or write to it
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BufferMapMode {
+    Read,
+    Write,
+}
+
+/// An error that occurs while waiting for a buffer to be mapped with
+/// [RenderResourceContext::map_buffer_async](crate::renderer::RenderResourceContext::map_buffer_async)
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum BufferMapError {
+    #[error("Buffer is not being mapped")]
+    NotMapping(BufferId),
+    #[error("Failed to map buffer to host")]
+    MapFailed(BufferId),
+}
+
 bitflags::bitflags! {
     #[repr(transparent)]
     #[cfg_attr(feature = "trace", derive(Serialize))]
@@ -159,6 +159,25 @@ impl_render_resource_bytes!(i64);
 impl_render_resource_bytes!(f32);
 impl_render_resource_bytes!(f64);
 
+// GLSL uniforms can't hold booleans, so they are written as a uint
+impl RenderResource for bool {
+    fn resource_type(&self) -> Option<RenderResourceType> {
+        Some(RenderResourceType::Buffer)
+    }
+
+    fn write_buffer_bytes(&self, buffer: &mut [u8]) {
+        (*self as u32).write_bytes(buffer);
+    }
+
+    fn buffer_byte_len(&self) -> Option<usize> {
+        Some(std::mem::size_of::<u32>())
+    }
+
+    fn texture(&self) -> Option<&Handle<Texture>> {
+        None
+    }
+}
+
 impl<T> RenderResource for Vec<T>
 where
     T: Sized + Byteable,
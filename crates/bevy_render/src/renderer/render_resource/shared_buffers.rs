@@ -96,4 +96,7 @@ impl SharedBuffers {
 // TODO: remove this when this actually uses shared buffers
 pub fn free_shared_buffers_system(shared_buffers: Res<SharedBuffers>) {
     shared_buffers.free_buffers();
+    // without a render backend (or a shared buffers node) nothing submits the queued copies, and
+    // they would only refer to the buffers freed above
+    shared_buffers.reset_command_queue();
 }
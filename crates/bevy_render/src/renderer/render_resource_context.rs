@@ -1,6 +1,9 @@
 use crate::{
     pipeline::{BindGroupDescriptorId, PipelineDescriptor, PipelineLayout},
-    renderer::{BindGroup, BufferId, BufferInfo, RenderResourceId, SamplerId, TextureId},
+    renderer::{
+        BindGroup, BufferId, BufferInfo, BufferMapError, BufferMapMode, RenderResourceId,
+        SamplerId, TextureId,
+    },
     shader::{Shader, ShaderLayout, ShaderStages},
     texture::{SamplerDescriptor, TextureDescriptor},
 };
@@ -24,7 +27,21 @@ pub trait RenderResourceContext: Downcast + Send + Sync + 'static {
         range: Range<u64>,
         write: &mut dyn FnMut(&mut [u8], &dyn RenderResourceContext),
     );
-    fn map_buffer(&self, id: BufferId);
+    fn read_mapped_buffer(
+        &self,
+        id: BufferId,
+        range: Range<u64>,
+        read: &mut dyn FnMut(&[u8], &dyn RenderResourceContext),
+    );
+    /// Maps the buffer, blocking until the GPU is done with it
+    fn map_buffer(&self, id: BufferId, mode: BufferMapMode);
+    /// Starts mapping the buffer without waiting for the GPU. The buffer can be used once
+    /// [RenderResourceContext::is_buffer_mapped] returns `true`.
+    fn map_buffer_async(&self, id: BufferId, mode: BufferMapMode);
+    /// Returns whether a buffer passed to [RenderResourceContext::map_buffer_async] has been
+    /// mapped, without blocking. Returns an error if the mapping failed, or if the buffer isn't
+    /// being mapped, which is also the case once this has returned `Ok(true)` for it.
+    fn is_buffer_mapped(&self, id: BufferId) -> Result<bool, BufferMapError>;
     fn unmap_buffer(&self, id: BufferId);
     fn create_buffer_with_data(&self, buffer_info: BufferInfo, data: &[u8]) -> BufferId;
     fn create_shader_module(&self, shader_handle: &Handle<Shader>, shaders: &Assets<Shader>);
@@ -4,9 +4,10 @@ use crate::renderer::{
 };
 use bevy_app::prelude::{EventReader, Events};
 use bevy_asset::{AssetEvent, Assets, Handle};
-use bevy_ecs::{Res, ResMut};
+use bevy_ecs::{Local, Res, ResMut};
 use bevy_type_registry::TypeUuid;
 use bevy_utils::HashSet;
+use bevy_window::AppLifecycle;
 
 pub const TEXTURE_ASSET_INDEX: u64 = 0;
 pub const SAMPLER_ASSET_INDEX: u64 = 1;
@@ -172,6 +173,43 @@ impl Texture {
         }
     }
 
+    /// Releases the GPU resources of every texture when the app is suspended and uploads them again
+    /// when it is resumed, if enabled by [TextureSuspendSettings]. Textures are uploaded again by
+    /// sending an [AssetEvent::Modified] event for each of them.
+    pub fn texture_suspend_system(
+        mut state: Local<TextureSuspendState>,
+        settings: Res<TextureSuspendSettings>,
+        render_resource_context: Res<Box<dyn RenderResourceContext>>,
+        textures: Res<Assets<Texture>>,
+        app_lifecycle_events: Res<Events<AppLifecycle>>,
+        mut texture_events: ResMut<Events<AssetEvent<Texture>>>,
+    ) {
+        let state = &mut *state;
+        let render_resource_context = &**render_resource_context;
+        for event in state.event_reader.iter(&app_lifecycle_events) {
+            match event {
+                AppLifecycle::Suspended if settings.release_on_suspend && !state.released => {
+                    for id in textures.ids() {
+                        Self::remove_current_texture_resources(
+                            render_resource_context,
+                            &Handle::weak(id),
+                        );
+                    }
+                    state.released = true;
+                }
+                AppLifecycle::Resumed if state.released => {
+                    for id in textures.ids() {
+                        texture_events.send(AssetEvent::Modified {
+                            handle: Handle::weak(id),
+                        });
+                    }
+                    state.released = false;
+                }
+                _ => {}
+            }
+        }
+    }
+
     fn remove_current_texture_resources(
         render_resource_context: &dyn RenderResourceContext,
         handle: &Handle<Texture>,
@@ -196,6 +234,20 @@ pub struct TextureResourceSystemState {
     event_reader: EventReader<AssetEvent<Texture>>,
 }
 
+/// Configures whether the GPU copies of textures are released while the app is suspended. Mobile
+/// OSes are more likely to kill suspended apps that hold on to a lot of memory, but textures have
+/// to be uploaded again when the app is resumed.
+#[derive(Debug, Clone, Default)]
+pub struct TextureSuspendSettings {
+    pub release_on_suspend: bool,
+}
+
+#[derive(Default)]
+pub struct TextureSuspendState {
+    event_reader: EventReader<AppLifecycle>,
+    released: bool,
+}
+
 impl RenderResource for Option<Handle<Texture>> {
     fn resource_type(&self) -> Option<RenderResourceType> {
         self.as_ref().map(|_texture| RenderResourceType::Texture)
@@ -0,0 +1,344 @@
+use bevy_ecs::{Component, Entity, EntityMap, Resources, World};
+use bevy_property::{
+    property_serde::DynamicPropertiesSerializer, ron::deserialize_dynamic_properties,
+};
+use bevy_type_registry::TypeRegistry;
+use bevy_utils::{tracing::warn, HashSet};
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+use thiserror::Error;
+
+/// A change to a component of an entity, or the despawn of the entity, recorded by
+/// [ComponentChanges]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentChange {
+    /// The entity in the world the change was recorded in, as [Entity::to_bits]
+    pub entity: u64,
+    pub kind: ComponentChangeKind,
+    /// The [ComponentChanges::tick] the change was recorded in
+    pub tick: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ComponentChangeKind {
+    /// The component was added or mutated. The new value is serialized as RON.
+    Set {
+        type_name: String,
+        value: String,
+    },
+    Remove {
+        type_name: String,
+    },
+    /// The entity was despawned along with all of its components
+    Despawn,
+}
+
+#[derive(Error, Debug)]
+pub enum ComponentChangeError {
+    #[error("Change is to an unregistered component.")]
+    UnregisteredComponent { type_name: String },
+    #[error("Change has an invalid value for {type_name}: {error}")]
+    InvalidValue {
+        type_name: String,
+        error: ron::Error,
+    },
+}
+
+/// Records the changes to tracked components, so they can be sent to another world, e.g. over the
+/// network, and applied there with [apply_component_changes].
+///
+/// Changes are detected with the same flags as `Changed<T>` queries, so only components that were
+/// added, mutated or removed are serialized. They are recorded at the end of every frame and kept
+/// until they are drained. Tracked components must be registered in the [TypeRegistry]. Changes
+/// to components that can't be serialized are skipped with a warning.
+///
+/// Despawns are only recorded for entities that had a tracked component.
+#[derive(Debug, Default)]
+pub struct ComponentChanges {
+    tick: u64,
+    tracked: HashSet<TypeId>,
+    changes: Vec<ComponentChange>,
+}
+
+impl ComponentChanges {
+    /// Starts recording changes to `T` components
+    pub fn track<T: Component>(&mut self) {
+        self.tracked.insert(TypeId::of::<T>());
+    }
+
+    pub fn untrack<T: Component>(&mut self) {
+        self.tracked.remove(&TypeId::of::<T>());
+    }
+
+    pub fn is_tracked<T: Component>(&self) -> bool {
+        self.tracked.contains(&TypeId::of::<T>())
+    }
+
+    /// The number of frames whose changes have been recorded
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ComponentChange> {
+        self.changes.iter()
+    }
+
+    /// Removes and returns the recorded changes, oldest first
+    pub fn drain(&mut self) -> std::vec::Drain<ComponentChange> {
+        self.changes.drain(..)
+    }
+
+    /// Records the changes to tracked components in `world` since its trackers were last cleared
+    pub fn record(&mut self, world: &World, type_registry: &TypeRegistry) {
+        self.tick += 1;
+        if self.tracked.is_empty() {
+            return;
+        }
+
+        let component_registry = type_registry.component.read();
+        let property_registry = type_registry.property.read();
+        let mut despawned = HashSet::<Entity>::default();
+        for ty in self.tracked.iter() {
+            let registration = match component_registry.get(ty) {
+                Some(registration) => registration,
+                None => continue,
+            };
+
+            for entity in world.removed_with_type_id(*ty) {
+                if !world.contains(*entity) {
+                    // despawned entities have all of their components removed
+                    if despawned.insert(*entity) {
+                        self.changes.push(ComponentChange {
+                            entity: entity.to_bits(),
+                            kind: ComponentChangeKind::Despawn,
+                            tick: self.tick,
+                        });
+                    }
+                    continue;
+                }
+                // the component may have been removed and added again in the same frame
+                if world.has_component_type(*entity, *ty) {
+                    continue;
+                }
+                self.changes.push(ComponentChange {
+                    entity: entity.to_bits(),
+                    kind: ComponentChangeKind::Remove {
+                        type_name: registration.long_name.to_string(),
+                    },
+                    tick: self.tick,
+                });
+            }
+
+            for archetype in world.archetypes() {
+                let component_flags = match archetype.get_type_state(*ty) {
+                    Some(type_state) => type_state.component_flags(),
+                    None => continue,
+                };
+                for (index, entity) in archetype.iter_entities().enumerate() {
+                    // there are flags for every entity of the archetype, so this is safe
+                    let flags = unsafe { *component_flags.as_ptr().add(index) };
+                    if flags.is_empty() {
+                        continue;
+                    }
+
+                    let properties = registration
+                        .get_component_properties(archetype, index)
+                        .to_dynamic();
+                    let value = match ron::ser::to_string(&DynamicPropertiesSerializer::new(
+                        &properties,
+                        &property_registry,
+                    )) {
+                        Ok(value) => value,
+                        Err(error) => {
+                            // e.g. a field whose type isn't registered as a property
+                            warn!(
+                                "Skipping change to {} of {:?}: {}",
+                                registration.long_name, entity, error
+                            );
+                            continue;
+                        }
+                    };
+                    self.changes.push(ComponentChange {
+                        entity: entity.to_bits(),
+                        kind: ComponentChangeKind::Set {
+                            type_name: registration.long_name.to_string(),
+                            value,
+                        },
+                        tick: self.tick,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Records the changes to tracked components at the end of every frame
+pub fn component_changes_system(world: &mut World, resources: &mut Resources) {
+    let mut component_changes = resources.get_mut::<ComponentChanges>().unwrap();
+    let type_registry = resources.get::<TypeRegistry>().unwrap();
+    component_changes.record(world, &type_registry);
+}
+
+/// Applies changes recorded by [ComponentChanges] in another world to `world`, in order.
+///
+/// `entity_map` maps the entities of the other world to entities of `world`. Entities that aren't
+/// in the map yet are spawned when a component is first added to them, and despawned entities are
+/// removed from it. The changes applied here are detected as changes in `world` as well.
+pub fn apply_component_changes(
+    world: &mut World,
+    resources: &Resources,
+    changes: &[ComponentChange],
+    entity_map: &mut EntityMap,
+) -> Result<(), ComponentChangeError> {
+    let type_registry = resources.get::<TypeRegistry>().unwrap();
+    let component_registry = type_registry.component.read();
+    let property_registry = type_registry.property.read();
+    let get_registration = |type_name: &str| {
+        component_registry.get_with_name(type_name).ok_or_else(|| {
+            ComponentChangeError::UnregisteredComponent {
+                type_name: type_name.to_string(),
+            }
+        })
+    };
+    for change in changes.iter() {
+        let remote_entity = Entity::from_bits(change.entity);
+        match &change.kind {
+            ComponentChangeKind::Set { type_name, value } => {
+                let registration = get_registration(type_name)?;
+                let properties = deserialize_dynamic_properties(value, &property_registry)
+                    .map_err(|error| ComponentChangeError::InvalidValue {
+                        type_name: type_name.clone(),
+                        error,
+                    })?;
+                let entity = *entity_map
+                    .entry(remote_entity)
+                    .or_insert_with(|| world.reserve_entity());
+                if world.has_component_type(entity, registration.ty) {
+                    registration.apply_property_to_entity(world, entity, &properties);
+                } else {
+                    registration.add_property_to_entity(world, resources, entity, &properties);
+                }
+            }
+            ComponentChangeKind::Remove { type_name } => {
+                let registration = get_registration(type_name)?;
+                if let Ok(entity) = entity_map.get(remote_entity) {
+                    registration.remove_from_entity(world, entity);
+                }
+            }
+            ComponentChangeKind::Despawn => {
+                if let Ok(entity) = entity_map.get(remote_entity) {
+                    // the entity may have been despawned in `world` already
+                    let _ = world.despawn(entity);
+                    entity_map.remove(remote_entity);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_property::Properties;
+
+    #[derive(Properties, Default)]
+    struct Health {
+        value: f32,
+    }
+
+    /// Records the changes of one frame in `local` and applies them to `remote`
+    fn replicate_frame(
+        local: &mut World,
+        remote: &mut World,
+        resources: &Resources,
+        component_changes: &mut ComponentChanges,
+        entity_map: &mut EntityMap,
+    ) -> Vec<ComponentChange> {
+        component_changes.record(local, &resources.get::<TypeRegistry>().unwrap());
+        local.clear_trackers();
+        let changes = component_changes.drain().collect::<Vec<_>>();
+        apply_component_changes(remote, resources, &changes, entity_map).unwrap();
+        changes
+    }
+
+    fn remote_health(remote: &World, entity_map: &EntityMap, entity: Entity) -> Option<f32> {
+        let remote_entity = entity_map.get(entity).ok()?;
+        remote
+            .get::<Health>(remote_entity)
+            .ok()
+            .map(|health| health.value)
+    }
+
+    #[test]
+    fn changes_round_trip() {
+        let mut resources = Resources::default();
+        let type_registry = TypeRegistry::default();
+        type_registry.component.write().register::<Health>();
+        type_registry.property.write().register::<Health>();
+        resources.insert(type_registry);
+        let mut component_changes = ComponentChanges::default();
+        component_changes.track::<Health>();
+        let mut local = World::new();
+        let mut remote = World::new();
+        let mut entity_map = EntityMap::default();
+        let mut replicate = |local: &mut World, remote: &mut World, entity_map: &mut EntityMap| {
+            replicate_frame(
+                local,
+                remote,
+                &resources,
+                &mut component_changes,
+                entity_map,
+            )
+        };
+
+        // added
+        let entity = local.spawn((Health { value: 100.0 },));
+        replicate(&mut local, &mut remote, &mut entity_map);
+        assert_eq!(remote_health(&remote, &entity_map, entity), Some(100.0));
+        let remote_entity = entity_map.get(entity).unwrap();
+
+        // mutated
+        local.get_mut::<Health>(entity).unwrap().value = 50.0;
+        replicate(&mut local, &mut remote, &mut entity_map);
+        assert_eq!(remote_health(&remote, &entity_map, entity), Some(50.0));
+        assert!(replicate(&mut local, &mut remote, &mut entity_map).is_empty());
+
+        // removed and added again in the same frame
+        local.remove_one::<Health>(entity).unwrap();
+        local.insert_one(entity, Health { value: 75.0 }).unwrap();
+        let changes = replicate(&mut local, &mut remote, &mut entity_map);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(changes[0].kind, ComponentChangeKind::Set { .. }));
+        assert_eq!(remote_health(&remote, &entity_map, entity), Some(75.0));
+
+        // removed
+        local.remove_one::<Health>(entity).unwrap();
+        replicate(&mut local, &mut remote, &mut entity_map);
+        assert_eq!(remote_health(&remote, &entity_map, entity), None);
+        assert!(remote.contains(remote_entity));
+
+        // despawned
+        local.insert_one(entity, Health { value: 10.0 }).unwrap();
+        replicate(&mut local, &mut remote, &mut entity_map);
+        local.despawn(entity).unwrap();
+        let changes = replicate(&mut local, &mut remote, &mut entity_map);
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| &change.kind)
+                .collect::<Vec<_>>(),
+            vec![&ComponentChangeKind::Despawn]
+        );
+        assert!(!remote.contains(remote_entity));
+        assert!(entity_map.get(entity).is_err());
+
+        // a new entity that reuses the despawned entity's id is a different remote entity
+        let reused = local.spawn((Health { value: 1.0 },));
+        assert_eq!(reused.id(), entity.id());
+        replicate(&mut local, &mut remote, &mut entity_map);
+        assert_eq!(remote_health(&remote, &entity_map, reused), Some(1.0));
+        assert!(entity_map.get(entity).is_err());
+    }
+}
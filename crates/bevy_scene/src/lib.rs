@@ -1,4 +1,5 @@
 mod command;
+mod component_changes;
 mod dynamic_scene;
 mod scene;
 mod scene_loader;
@@ -6,6 +7,7 @@ mod scene_spawner;
 pub mod serde;
 
 pub use command::*;
+pub use component_changes::*;
 pub use dynamic_scene::*;
 pub use scene::*;
 pub use scene_loader::*;
@@ -29,7 +31,9 @@ impl Plugin for ScenePlugin {
             .add_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
             .init_resource::<SceneSpawner>()
+            .init_resource::<ComponentChanges>()
             .add_stage_after(stage::EVENT, SCENE_STAGE)
-            .add_system_to_stage(SCENE_STAGE, scene_spawner_system);
+            .add_system_to_stage(SCENE_STAGE, scene_spawner_system)
+            .add_system_to_stage(stage::LAST, component_changes_system);
     }
 }
@@ -2,12 +2,33 @@ use bevy_asset::{self, Handle};
 use bevy_render::{color::Color, renderer::RenderResources, shader::ShaderDefs, texture::Texture};
 use bevy_type_registry::TypeUuid;
 
+/// Determines how a sprite's color is combined with what has already been drawn. Sprite colors
+/// are premultiplied by their alpha before blending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Regular alpha blending
+    Alpha,
+    /// Adds the sprite's color to the background, for glows and flashes
+    Additive,
+    /// Multiplies the background by the sprite's color, for shadows and tinting
+    Multiply,
+}
+
+impl Default for BlendMode {
+    fn default() -> Self {
+        BlendMode::Alpha
+    }
+}
+
 #[derive(Debug, RenderResources, ShaderDefs, TypeUuid)]
 #[uuid = "506cff92-a9f3-4543-862d-6851c7fdfc99"]
 pub struct ColorMaterial {
     pub color: Color,
     #[shader_def]
     pub texture: Option<Handle<Texture>>,
+    /// How sprites using this material are blended. This has no effect on UI nodes.
+    #[render_resources(ignore)]
+    pub blend_mode: BlendMode,
 }
 
 impl ColorMaterial {
@@ -15,6 +36,7 @@ impl ColorMaterial {
         ColorMaterial {
             color,
             texture: None,
+            blend_mode: BlendMode::Alpha,
         }
     }
 
@@ -22,6 +44,7 @@ impl ColorMaterial {
         ColorMaterial {
             color: Color::WHITE,
             texture: Some(texture),
+            blend_mode: BlendMode::Alpha,
         }
     }
 
@@ -29,8 +52,15 @@ impl ColorMaterial {
         ColorMaterial {
             color,
             texture: Some(texture),
+            blend_mode: BlendMode::Alpha,
         }
     }
+
+    /// Returns this material with the given blend mode
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
 }
 
 impl Default for ColorMaterial {
@@ -38,6 +68,7 @@ impl Default for ColorMaterial {
         ColorMaterial {
             color: Color::rgb(1.0, 1.0, 1.0),
             texture: None,
+            blend_mode: BlendMode::Alpha,
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::{
 };
 use bevy_asset::Handle;
 use bevy_ecs::Bundle;
+use bevy_math::Vec2;
 use bevy_render::{
     mesh::Mesh,
     pipeline::{RenderPipeline, RenderPipelines},
@@ -44,6 +45,20 @@ impl Default for SpriteBundle {
     }
 }
 
+impl SpriteBundle {
+    /// Creates a sprite that draws `mesh` at its own size instead of the sprite quad. This can be
+    /// used to draw the 2D primitives in [shape](bevy_render::mesh::shape) with a [ColorMaterial].
+    pub fn from_mesh(mesh: Handle<Mesh>, material: Handle<ColorMaterial>) -> Self {
+        Self {
+            // the sprite's size scales the mesh, so leave it at one
+            sprite: Sprite::new(Vec2::one()),
+            mesh,
+            material,
+            ..Default::default()
+        }
+    }
+}
+
 /// A Bundle of components for drawing a single sprite from a sprite sheet (also referred
 /// to as a `TextureAtlas`)
 #[derive(Bundle)]
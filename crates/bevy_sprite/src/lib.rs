@@ -6,21 +6,28 @@ mod dynamic_texture_atlas_builder;
 mod rect;
 mod render;
 mod sprite;
+mod sprite_batch;
+mod sprite_border;
 mod texture_atlas;
 mod texture_atlas_builder;
+mod texture_atlas_loader;
 
 pub use color_material::*;
 pub use dynamic_texture_atlas_builder::*;
 pub use rect::*;
 pub use render::*;
 pub use sprite::*;
+pub use sprite_batch::*;
+pub use sprite_border::*;
 pub use texture_atlas::*;
 pub use texture_atlas_builder::*;
+pub use texture_atlas_loader::*;
 
 pub mod prelude {
     pub use crate::{
         entity::{SpriteBundle, SpriteSheetBundle},
-        ColorMaterial, Sprite, SpriteResizeMode, TextureAtlas, TextureAtlasSprite,
+        BlendMode, ColorMaterial, Sprite, SpriteAnchor, SpriteBatching, SpriteBorder,
+        SpriteResizeMode, TextureAtlas, TextureAtlasSprite,
     };
 }
 
@@ -33,7 +40,9 @@ use bevy_render::{
     shader::asset_shader_defs_system,
 };
 use bevy_type_registry::TypeUuid;
-use sprite::sprite_system;
+use sprite::{sprite_blend_mode_system, sprite_pixel_snap_system, sprite_system};
+use sprite_batch::{sprite_batch_draw_system, sprite_batch_system};
+use sprite_border::sprite_border_system;
 
 #[derive(Default)]
 pub struct SpritePlugin;
@@ -44,11 +53,20 @@ impl Plugin for SpritePlugin {
     fn build(&self, app: &mut AppBuilder) {
         app.add_asset::<ColorMaterial>()
             .add_asset::<TextureAtlas>()
+            .init_asset_loader::<TextureAtlasLoader>()
+            .init_resource::<SpriteBatching>()
+            .init_resource::<SpriteBatches>()
             .add_system_to_stage(stage::POST_UPDATE, sprite_system)
+            .add_system_to_stage(stage::POST_UPDATE, sprite_blend_mode_system)
+            .add_system_to_stage(stage::POST_UPDATE, sprite_pixel_snap_system)
+            .add_system_to_stage(stage::POST_UPDATE, sprite_border_system)
+            .add_system_to_stage(stage::POST_UPDATE, sprite_batch_system)
+            .add_system_to_stage(stage::POST_UPDATE, texture_atlas_packing_system)
             .add_system_to_stage(
                 stage::POST_UPDATE,
                 asset_shader_defs_system::<ColorMaterial>,
-            );
+            )
+            .add_system_to_stage(bevy_render::stage::DRAW, sprite_batch_draw_system);
 
         let resources = app.resources_mut();
         let mut render_graph = resources.get_mut::<RenderGraph>().unwrap();
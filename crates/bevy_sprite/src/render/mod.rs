@@ -1,4 +1,4 @@
-use crate::{ColorMaterial, Sprite, TextureAtlas, TextureAtlasSprite};
+use crate::{BlendMode, ColorMaterial, Sprite, TextureAtlas, TextureAtlasSprite};
 use bevy_asset::{Assets, Handle};
 use bevy_ecs::Resources;
 use bevy_render::{
@@ -19,6 +19,92 @@ pub const SPRITE_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
 pub const SPRITE_SHEET_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
     Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 9016885805180281612);
 
+pub const SPRITE_ADDITIVE_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
+    Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 12710409651473219805);
+
+pub const SPRITE_MULTIPLY_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
+    Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 6352785466817530261);
+
+pub const SPRITE_BATCH_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
+    Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 4418640196203817582);
+
+pub const SPRITE_BATCH_ADDITIVE_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
+    Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 15869047310632318114);
+
+pub const SPRITE_BATCH_MULTIPLY_PIPELINE_HANDLE: Handle<PipelineDescriptor> =
+    Handle::weak_from_u64(PipelineDescriptor::TYPE_UUID, 8224061437995113590);
+
+const BLEND_MODES: [BlendMode; 3] = [BlendMode::Alpha, BlendMode::Additive, BlendMode::Multiply];
+
+impl BlendMode {
+    /// The pipeline used to draw individual sprites with this blend mode
+    pub fn sprite_pipeline(&self) -> Handle<PipelineDescriptor> {
+        match self {
+            BlendMode::Alpha => SPRITE_PIPELINE_HANDLE,
+            BlendMode::Additive => SPRITE_ADDITIVE_PIPELINE_HANDLE,
+            BlendMode::Multiply => SPRITE_MULTIPLY_PIPELINE_HANDLE,
+        }
+    }
+
+    /// The pipeline used to draw sprite batches with this blend mode
+    pub fn sprite_batch_pipeline(&self) -> Handle<PipelineDescriptor> {
+        match self {
+            BlendMode::Alpha => SPRITE_BATCH_PIPELINE_HANDLE,
+            BlendMode::Additive => SPRITE_BATCH_ADDITIVE_PIPELINE_HANDLE,
+            BlendMode::Multiply => SPRITE_BATCH_MULTIPLY_PIPELINE_HANDLE,
+        }
+    }
+
+    /// Returns the blend mode of the given sprite pipeline, or `None` if it isn't one of the
+    /// built in sprite pipelines
+    pub fn from_sprite_pipeline(pipeline: &Handle<PipelineDescriptor>) -> Option<BlendMode> {
+        BLEND_MODES
+            .iter()
+            .find(|blend_mode| blend_mode.sprite_pipeline() == *pipeline)
+            .cloned()
+    }
+
+    /// Blend descriptors for colors that are premultiplied by their alpha
+    fn blend_descriptors(&self) -> (BlendDescriptor, BlendDescriptor) {
+        let (color_blend, alpha_blend) = match self {
+            BlendMode::Alpha => (
+                (BlendFactor::One, BlendFactor::OneMinusSrcAlpha),
+                (BlendFactor::One, BlendFactor::OneMinusSrcAlpha),
+            ),
+            BlendMode::Additive => (
+                (BlendFactor::One, BlendFactor::One),
+                (BlendFactor::One, BlendFactor::One),
+            ),
+            BlendMode::Multiply => (
+                (BlendFactor::DstColor, BlendFactor::OneMinusSrcAlpha),
+                (BlendFactor::Zero, BlendFactor::One),
+            ),
+        };
+        let descriptor = |(src_factor, dst_factor)| BlendDescriptor {
+            src_factor,
+            dst_factor,
+            operation: BlendOperation::Add,
+        };
+        (descriptor(color_blend), descriptor(alpha_blend))
+    }
+}
+
+/// Sprites are drawn in sorting layer order, so they must not hide each other through the depth
+/// buffer
+fn sprite_depth_stencil_state() -> DepthStencilStateDescriptor {
+    DepthStencilStateDescriptor {
+        format: TextureFormat::Depth32Float,
+        depth_write_enabled: false,
+        depth_compare: CompareFunction::LessEqual,
+        stencil: StencilStateDescriptor {
+            front: StencilStateFaceDescriptor::IGNORE,
+            back: StencilStateFaceDescriptor::IGNORE,
+            read_mask: 0,
+            write_mask: 0,
+        },
+    }
+}
+
 pub fn build_sprite_sheet_pipeline(shaders: &mut Assets<Shader>) -> PipelineDescriptor {
     PipelineDescriptor {
         rasterization_state: Some(RasterizationStateDescriptor {
@@ -29,17 +115,7 @@ pub fn build_sprite_sheet_pipeline(shaders: &mut Assets<Shader>) -> PipelineDesc
             depth_bias_clamp: 0.0,
             clamp_depth: false,
         }),
-        depth_stencil_state: Some(DepthStencilStateDescriptor {
-            format: TextureFormat::Depth32Float,
-            depth_write_enabled: true,
-            depth_compare: CompareFunction::LessEqual,
-            stencil: StencilStateDescriptor {
-                front: StencilStateFaceDescriptor::IGNORE,
-                back: StencilStateFaceDescriptor::IGNORE,
-                read_mask: 0,
-                write_mask: 0,
-            },
-        }),
+        depth_stencil_state: Some(sprite_depth_stencil_state()),
         color_states: vec![ColorStateDescriptor {
             format: TextureFormat::default(),
             color_blend: BlendDescriptor {
@@ -67,7 +143,11 @@ pub fn build_sprite_sheet_pipeline(shaders: &mut Assets<Shader>) -> PipelineDesc
     }
 }
 
-pub fn build_sprite_pipeline(shaders: &mut Assets<Shader>) -> PipelineDescriptor {
+pub fn build_sprite_pipeline(
+    shaders: &mut Assets<Shader>,
+    blend_mode: BlendMode,
+) -> PipelineDescriptor {
+    let (color_blend, alpha_blend) = blend_mode.blend_descriptors();
     PipelineDescriptor {
         rasterization_state: Some(RasterizationStateDescriptor {
             front_face: FrontFace::Ccw,
@@ -77,29 +157,11 @@ pub fn build_sprite_pipeline(shaders: &mut Assets<Shader>) -> PipelineDescriptor
             depth_bias_clamp: 0.0,
             clamp_depth: false,
         }),
-        depth_stencil_state: Some(DepthStencilStateDescriptor {
-            format: TextureFormat::Depth32Float,
-            depth_write_enabled: true,
-            depth_compare: CompareFunction::LessEqual,
-            stencil: StencilStateDescriptor {
-                front: StencilStateFaceDescriptor::IGNORE,
-                back: StencilStateFaceDescriptor::IGNORE,
-                read_mask: 0,
-                write_mask: 0,
-            },
-        }),
+        depth_stencil_state: Some(sprite_depth_stencil_state()),
         color_states: vec![ColorStateDescriptor {
             format: TextureFormat::default(),
-            color_blend: BlendDescriptor {
-                src_factor: BlendFactor::SrcAlpha,
-                dst_factor: BlendFactor::OneMinusSrcAlpha,
-                operation: BlendOperation::Add,
-            },
-            alpha_blend: BlendDescriptor {
-                src_factor: BlendFactor::One,
-                dst_factor: BlendFactor::One,
-                operation: BlendOperation::Add,
-            },
+            color_blend,
+            alpha_blend,
             write_mask: ColorWrite::ALL,
         }],
         ..PipelineDescriptor::new(ShaderStages {
@@ -115,6 +177,43 @@ pub fn build_sprite_pipeline(shaders: &mut Assets<Shader>) -> PipelineDescriptor
     }
 }
 
+/// Builds the pipeline used to draw merged sprite batches. Batch meshes are already sized and
+/// positioned relative to the batch, so unlike the regular sprite pipeline there are no
+/// per-sprite size, flip or anchor uniforms.
+pub fn build_sprite_batch_pipeline(
+    shaders: &mut Assets<Shader>,
+    blend_mode: BlendMode,
+) -> PipelineDescriptor {
+    let (color_blend, alpha_blend) = blend_mode.blend_descriptors();
+    PipelineDescriptor {
+        rasterization_state: Some(RasterizationStateDescriptor {
+            front_face: FrontFace::Ccw,
+            cull_mode: CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+            clamp_depth: false,
+        }),
+        depth_stencil_state: Some(sprite_depth_stencil_state()),
+        color_states: vec![ColorStateDescriptor {
+            format: TextureFormat::default(),
+            color_blend,
+            alpha_blend,
+            write_mask: ColorWrite::ALL,
+        }],
+        ..PipelineDescriptor::new(ShaderStages {
+            vertex: shaders.add(Shader::from_glsl(
+                ShaderStage::Vertex,
+                include_str!("sprite_batch.vert"),
+            )),
+            fragment: Some(shaders.add(Shader::from_glsl(
+                ShaderStage::Fragment,
+                include_str!("sprite.frag"),
+            ))),
+        })
+    }
+}
+
 pub mod node {
     pub const COLOR_MATERIAL: &str = "color_material";
     pub const SPRITE: &str = "sprite";
@@ -151,11 +250,20 @@ impl SpriteRenderGraphBuilder for RenderGraph {
 
         let mut pipelines = resources.get_mut::<Assets<PipelineDescriptor>>().unwrap();
         let mut shaders = resources.get_mut::<Assets<Shader>>().unwrap();
-        pipelines.set_untracked(SPRITE_PIPELINE_HANDLE, build_sprite_pipeline(&mut shaders));
         pipelines.set_untracked(
             SPRITE_SHEET_PIPELINE_HANDLE,
             build_sprite_sheet_pipeline(&mut shaders),
         );
+        for blend_mode in BLEND_MODES.iter() {
+            pipelines.set_untracked(
+                blend_mode.sprite_pipeline(),
+                build_sprite_pipeline(&mut shaders, *blend_mode),
+            );
+            pipelines.set_untracked(
+                blend_mode.sprite_batch_pipeline(),
+                build_sprite_batch_pipeline(&mut shaders, *blend_mode),
+            );
+        }
         self
     }
 }
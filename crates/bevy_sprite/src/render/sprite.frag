@@ -1,6 +1,7 @@
 #version 450
 
 layout(location = 0) in vec2 v_Uv;
+layout(location = 1) in vec4 v_Color;
 
 layout(location = 0) out vec4 o_Target;
 
@@ -14,11 +15,12 @@ layout(set = 1, binding = 2) uniform sampler ColorMaterial_texture_sampler;
 # endif
 
 void main() {
-    vec4 color = Color;
+    vec4 color = Color * v_Color;
 # ifdef COLORMATERIAL_TEXTURE
     color *= texture(
         sampler2D(ColorMaterial_texture, ColorMaterial_texture_sampler),
         v_Uv);
 # endif
-    o_Target = color;
+    // output premultiplied alpha, which every sprite blend mode expects
+    o_Target = vec4(color.rgb * color.a, color.a);
 }
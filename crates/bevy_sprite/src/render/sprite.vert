@@ -5,6 +5,7 @@ layout(location = 1) in vec3 Vertex_Normal;
 layout(location = 2) in vec2 Vertex_Uv;
 
 layout(location = 0) out vec2 v_Uv;
+layout(location = 1) out vec4 v_Color;
 
 layout(set = 0, binding = 0) uniform Camera {
     mat4 ViewProj;
@@ -16,9 +17,29 @@ layout(set = 2, binding = 0) uniform Transform {
 layout(set = 2, binding = 1) uniform Sprite_size {
     vec2 size;
 };
+layout(set = 2, binding = 2) uniform Sprite_flip_x {
+    uint flip_x;
+};
+layout(set = 2, binding = 3) uniform Sprite_flip_y {
+    uint flip_y;
+};
+layout(set = 2, binding = 4) uniform Sprite_anchor {
+    vec2 anchor;
+};
+layout(set = 2, binding = 5) uniform Sprite_color {
+    vec4 color;
+};
 
 void main() {
-    v_Uv = Vertex_Uv;
-    vec3 position = Vertex_Position * vec3(size, 1.0);
+    vec2 uv = Vertex_Uv;
+    if (flip_x != 0) {
+        uv.x = 1.0 - uv.x;
+    }
+    if (flip_y != 0) {
+        uv.y = 1.0 - uv.y;
+    }
+    v_Uv = uv;
+    v_Color = color;
+    vec3 position = (Vertex_Position - vec3(anchor, 0.0)) * vec3(size, 1.0);
     gl_Position = ViewProj * Model * vec4(position, 1.0);
-}
\ No newline at end of file
+}
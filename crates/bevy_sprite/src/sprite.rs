@@ -1,18 +1,95 @@
-use crate::ColorMaterial;
+use crate::{BlendMode, ColorMaterial, TextureAtlas, TextureAtlasSprite};
 use bevy_asset::{Assets, Handle};
-use bevy_ecs::{Query, Res};
+use bevy_core::Bytes;
+use bevy_ecs::{Mut, Query, QuerySet, Res, With};
 use bevy_math::Vec2;
-use bevy_render::{renderer::RenderResources, texture::Texture};
+use bevy_render::{
+    camera::OrthographicProjection,
+    color::Color,
+    pipeline::{RenderPipeline, RenderPipelines},
+    renderer::{RenderResource, RenderResourceType, RenderResources},
+    texture::Texture,
+};
+use bevy_transform::components::GlobalTransform;
 use bevy_type_registry::TypeUuid;
 
 #[derive(Debug, Default, RenderResources, TypeUuid)]
 #[uuid = "7233c597-ccfa-411f-bd59-9af349432ada"]
 pub struct Sprite {
     pub size: Vec2,
+    /// Multiplied with the sprite's material color. Use this to tint or fade out individual
+    /// sprites that share a material.
+    pub color: Color,
+    /// Mirrors the sprite's texture horizontally
+    pub flip_x: bool,
+    /// Mirrors the sprite's texture vertically
+    pub flip_y: bool,
+    /// The point of the sprite that sits at its `Transform`'s translation
+    pub anchor: SpriteAnchor,
     #[render_resources(ignore)]
     pub resize_mode: SpriteResizeMode,
 }
 
+/// The point of a [Sprite] that is placed at the sprite's position. Flipping a sprite does not
+/// move its anchor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpriteAnchor {
+    Center,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+    CenterLeft,
+    CenterRight,
+    TopLeft,
+    TopCenter,
+    TopRight,
+    /// A custom anchor point, relative to the sprite's size. `(-0.5, -0.5)` is the bottom left
+    /// corner and `(0.5, 0.5)` is the top right corner.
+    Custom(Vec2),
+}
+
+impl Default for SpriteAnchor {
+    fn default() -> Self {
+        SpriteAnchor::Center
+    }
+}
+
+impl SpriteAnchor {
+    /// Returns the anchor point relative to the sprite's size, where `(0.0, 0.0)` is the center
+    pub fn as_vec(&self) -> Vec2 {
+        match self {
+            SpriteAnchor::Center => Vec2::new(0.0, 0.0),
+            SpriteAnchor::BottomLeft => Vec2::new(-0.5, -0.5),
+            SpriteAnchor::BottomCenter => Vec2::new(0.0, -0.5),
+            SpriteAnchor::BottomRight => Vec2::new(0.5, -0.5),
+            SpriteAnchor::CenterLeft => Vec2::new(-0.5, 0.0),
+            SpriteAnchor::CenterRight => Vec2::new(0.5, 0.0),
+            SpriteAnchor::TopLeft => Vec2::new(-0.5, 0.5),
+            SpriteAnchor::TopCenter => Vec2::new(0.0, 0.5),
+            SpriteAnchor::TopRight => Vec2::new(0.5, 0.5),
+            SpriteAnchor::Custom(point) => *point,
+        }
+    }
+}
+
+impl RenderResource for SpriteAnchor {
+    fn resource_type(&self) -> Option<RenderResourceType> {
+        Some(RenderResourceType::Buffer)
+    }
+
+    fn write_buffer_bytes(&self, buffer: &mut [u8]) {
+        self.as_vec().write_bytes(buffer);
+    }
+
+    fn buffer_byte_len(&self) -> Option<usize> {
+        Some(std::mem::size_of::<[f32; 2]>())
+    }
+
+    fn texture(&self) -> Option<&Handle<Texture>> {
+        None
+    }
+}
+
 /// Determines how `Sprite` resize should be handled
 #[derive(Debug)]
 pub enum SpriteResizeMode {
@@ -32,8 +109,15 @@ impl Sprite {
         Self {
             size,
             resize_mode: SpriteResizeMode::Manual,
+            ..Default::default()
         }
     }
+
+    /// Returns this sprite with the given anchor
+    pub fn with_anchor(mut self, anchor: SpriteAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
 }
 
 pub fn sprite_system(
@@ -48,10 +132,93 @@ pub fn sprite_system(
                 let material = materials.get(handle).unwrap();
                 if let Some(ref texture_handle) = material.texture {
                     if let Some(texture) = textures.get(texture_handle) {
-                        sprite.size = texture.size.as_vec3().truncate();
+                        let texture_size = texture.size.as_vec3().truncate();
+                        // only write when the size changes so the sprite isn't flagged as changed
+                        if sprite.size != texture_size {
+                            sprite.size = texture_size;
+                        }
                     }
                 }
             }
         }
     }
 }
+
+/// Switches sprites that use one of the built in sprite pipelines to the pipeline matching their
+/// material's [BlendMode]
+pub fn sprite_blend_mode_system(
+    materials: Res<Assets<ColorMaterial>>,
+    mut query: Query<(&Handle<ColorMaterial>, &mut RenderPipelines), With<Sprite>>,
+) {
+    for (handle, mut render_pipelines) in query.iter_mut() {
+        let material = if let Some(material) = materials.get(handle) {
+            material
+        } else {
+            continue;
+        };
+
+        if render_pipelines.pipelines.len() != 1 {
+            continue;
+        }
+        let pipeline = &render_pipelines.pipelines[0].pipeline;
+        match BlendMode::from_sprite_pipeline(pipeline) {
+            Some(blend_mode) if blend_mode != material.blend_mode => {
+                render_pipelines.pipelines[0] =
+                    RenderPipeline::new(material.blend_mode.sprite_pipeline());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Snaps sprites and sprite sheet sprites to whole world units while a camera uses
+/// [ScalingMode::PixelPerfect](bevy_render::camera::ScalingMode::PixelPerfect). The bottom left
+/// corner of each sprite is snapped rather than its anchor, so sprites with odd sizes line up with
+/// the pixel grid too.
+pub fn sprite_pixel_snap_system(
+    projection_query: Query<&OrthographicProjection>,
+    texture_atlases: Res<Assets<TextureAtlas>>,
+    mut queries: QuerySet<(
+        Query<(&Sprite, &mut GlobalTransform)>,
+        Query<(
+            &TextureAtlasSprite,
+            &Handle<TextureAtlas>,
+            &mut GlobalTransform,
+        )>,
+    )>,
+) {
+    if !projection_query
+        .iter()
+        .any(|projection| projection.scaling_mode.is_pixel_perfect())
+    {
+        return;
+    }
+
+    for (sprite, mut global_transform) in queries.q0_mut().iter_mut() {
+        snap_to_pixels(&mut global_transform, sprite.size, sprite.anchor.as_vec());
+    }
+
+    for (sprite, texture_atlas_handle, mut global_transform) in queries.q1_mut().iter_mut() {
+        // sprite sheet sprites are centered on their position and as large as their atlas rect
+        let rect = texture_atlases
+            .get(texture_atlas_handle)
+            .and_then(|texture_atlas| texture_atlas.textures.get(sprite.index as usize));
+        if let Some(rect) = rect {
+            snap_to_pixels(
+                &mut global_transform,
+                Vec2::new(rect.width(), rect.height()),
+                Vec2::zero(),
+            );
+        }
+    }
+}
+
+fn snap_to_pixels(global_transform: &mut Mut<GlobalTransform>, size: Vec2, anchor: Vec2) {
+    let size = size * global_transform.scale.truncate();
+    let corner = global_transform.translation.truncate() + (Vec2::new(-0.5, -0.5) - anchor) * size;
+    let offset = Vec2::new(corner.x.round() - corner.x, corner.y.round() - corner.y);
+    // only write when the sprite moves so it isn't flagged as changed every frame
+    if offset != Vec2::zero() {
+        global_transform.translation += offset.extend(0.0);
+    }
+}
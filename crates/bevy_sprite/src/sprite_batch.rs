@@ -0,0 +1,660 @@
+use crate::{render::SPRITE_BATCH_PIPELINE_HANDLE, BlendMode, ColorMaterial, Sprite, QUAD_HANDLE};
+use bevy_asset::{Assets, Handle};
+use bevy_core::FloatOrd;
+use bevy_ecs::{
+    Bundle, Changed, Commands, Entity, Or, Query, QuerySet, Res, ResMut, With, Without,
+};
+use bevy_math::{Vec2, Vec3};
+use bevy_render::{
+    camera::{ComputedVisibility, SortingLayer, SortingLayers},
+    draw::Draw,
+    mesh::{Indices, Mesh},
+    pipeline::{PrimitiveTopology, RenderPipeline, RenderPipelines},
+    render_graph::base::MainPass,
+};
+use bevy_transform::prelude::{GlobalTransform, Transform};
+use bevy_utils::HashMap;
+use std::ops::Range;
+
+/// Controls how adjacent sprites that share a [ColorMaterial] are merged into a single draw call.
+#[derive(Debug, Clone)]
+pub struct SpriteBatching {
+    /// Whether sprites should be batched at all
+    pub enabled: bool,
+    /// The minimum number of adjacent sprites sharing a material before they are batched
+    pub min_sprites: usize,
+}
+
+impl Default for SpriteBatching {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_sprites: 2,
+        }
+    }
+}
+
+/// An entity that draws a run of sprites using `material` with a single merged mesh.
+///
+/// Batches are spawned and despawned automatically by [sprite_batch_system]. A batch only holds
+/// sprites that are adjacent in the back-to-front order of transparent draws, so no other draw
+/// lands between them. The batch is drawn in place of its first visible sprite, which keeps the
+/// draw order of the scene the same as when every sprite is drawn on its own.
+#[derive(Debug, Default)]
+pub struct SpriteBatch {
+    material: Handle<ColorMaterial>,
+    sprites: Vec<Entity>,
+}
+
+impl SpriteBatch {
+    /// The material shared by every sprite in this batch
+    pub fn material(&self) -> &Handle<ColorMaterial> {
+        &self.material
+    }
+
+    /// The sprites drawn by this batch, ordered back-to-front
+    pub fn sprites(&self) -> &[Entity] {
+        &self.sprites
+    }
+}
+
+#[derive(Bundle)]
+pub struct SpriteBatchBundle {
+    pub sprite_batch: SpriteBatch,
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<ColorMaterial>,
+    pub main_pass: MainPass,
+    pub draw: Draw,
+    pub render_pipelines: RenderPipelines,
+    pub transform: Transform,
+    pub global_transform: GlobalTransform,
+}
+
+impl Default for SpriteBatchBundle {
+    fn default() -> Self {
+        Self {
+            render_pipelines: RenderPipelines::from_pipelines(vec![RenderPipeline::new(
+                SPRITE_BATCH_PIPELINE_HANDLE,
+            )]),
+            draw: Draw {
+                is_transparent: true,
+                ..Default::default()
+            },
+            sprite_batch: Default::default(),
+            mesh: Default::default(),
+            material: Default::default(),
+            main_pass: MainPass,
+            transform: Default::default(),
+            global_transform: Default::default(),
+        }
+    }
+}
+
+/// Maps each batched material to the entities that draw its runs of sprites
+#[derive(Debug, Default)]
+pub struct SpriteBatches {
+    batches: HashMap<Handle<ColorMaterial>, Vec<Entity>>,
+}
+
+impl SpriteBatches {
+    /// The batches that draw sprites using `material`, ordered back-to-front
+    pub fn get(&self, material: &Handle<ColorMaterial>) -> &[Entity] {
+        self.batches
+            .get(material)
+            .map(|batches| batches.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+struct BatchedSprite {
+    entity: Entity,
+    size: Vec2,
+    anchor: Vec2,
+    flip_x: bool,
+    flip_y: bool,
+    color: [f32; 4],
+    transform: GlobalTransform,
+}
+
+/// The position of a transparent draw in the order of
+/// [visible_entities_system](bevy_render::camera::visible_entities_system) for 2D cameras:
+/// sorting layer, order in layer, then back-to-front by depth, with ties broken by entity
+type DrawOrder = (usize, i32, FloatOrd, Entity);
+
+/// A transparent draw, which can be merged with its neighbours if it is a sprite that uses the
+/// built in sprite pipelines
+struct TransparentDraw {
+    order: DrawOrder,
+    sprite: Option<(Handle<ColorMaterial>, BlendMode, BatchedSprite)>,
+}
+
+/// Sorts `draws` back-to-front and returns the runs of adjacent sprites that share a material and
+/// blend mode, skipping runs shorter than `min_sprites`
+fn batch_runs(draws: &mut [TransparentDraw], min_sprites: usize) -> Vec<Range<usize>> {
+    draws.sort_by_key(|draw| draw.order);
+
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < draws.len() {
+        let (material, blend_mode) = match &draws[start].sprite {
+            Some((material, blend_mode, _)) => (material, blend_mode),
+            None => {
+                start += 1;
+                continue;
+            }
+        };
+        let len = draws[start..]
+            .iter()
+            .take_while(|draw| match &draw.sprite {
+                Some((other_material, other_blend_mode, _)) => {
+                    other_material == material && other_blend_mode == blend_mode
+                }
+                None => false,
+            })
+            .count();
+        if len >= min_sprites.max(1) {
+            runs.push(start..start + len);
+        }
+        start += len;
+    }
+
+    runs
+}
+
+/// Merges runs of visible sprites that use the built in sprite pipelines and share a material into
+/// batches. Only sprites that are adjacent in the back-to-front order of transparent draws are
+/// merged, so batching never changes which sprite ends up on top. Batch meshes are only rebuilt
+/// when a batch's members, transforms or sprites change.
+///
+/// The order is computed by depth like for 2D cameras. Cameras that sort by distance may see a
+/// different order, so sprites drawn by those cameras should not be batched.
+#[allow(clippy::type_complexity)]
+pub fn sprite_batch_system(
+    commands: &mut Commands,
+    settings: Res<SpriteBatching>,
+    sorting_layers: Res<SortingLayers>,
+    mut sprite_batches: ResMut<SpriteBatches>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut queries: QuerySet<(
+        Query<
+            (
+                Entity,
+                &Draw,
+                &GlobalTransform,
+                Option<&SortingLayer>,
+                Option<&Sprite>,
+                Option<&Handle<ColorMaterial>>,
+                Option<&Handle<Mesh>>,
+                Option<&RenderPipelines>,
+            ),
+            (With<MainPass>, Without<SpriteBatch>),
+        >,
+        Query<
+            Entity,
+            (
+                With<Sprite>,
+                Or<(Changed<GlobalTransform>, Changed<Sprite>)>,
+            ),
+        >,
+        Query<(
+            &mut SpriteBatch,
+            &Handle<Mesh>,
+            &mut Transform,
+            &mut GlobalTransform,
+            &mut RenderPipelines,
+        )>,
+    )>,
+) {
+    let mut draws = Vec::new();
+    if settings.enabled {
+        let default_layer = sorting_layers.order_or_default(SortingLayers::DEFAULT);
+        for (
+            entity,
+            draw,
+            global_transform,
+            sorting_layer,
+            sprite,
+            material,
+            mesh,
+            render_pipelines,
+        ) in queries.q0().iter()
+        {
+            if !draw.is_visible || !draw.is_transparent {
+                continue;
+            }
+
+            let (layer, order_in_layer) = sorting_layer
+                .map(|sorting_layer| {
+                    (
+                        sorting_layers.order_or_default(&sorting_layer.layer),
+                        sorting_layer.order,
+                    )
+                })
+                .unwrap_or((default_layer, 0));
+            let order = (
+                layer,
+                order_in_layer,
+                FloatOrd(global_transform.translation.z),
+                entity,
+            );
+
+            let sprite = match (sprite, material, mesh, render_pipelines) {
+                // batches are made of plain quads, so sprites with custom meshes are drawn on
+                // their own
+                (Some(sprite), Some(material), Some(mesh), Some(render_pipelines))
+                    if *mesh == QUAD_HANDLE && render_pipelines.pipelines.len() == 1 =>
+                {
+                    BlendMode::from_sprite_pipeline(&render_pipelines.pipelines[0].pipeline).map(
+                        |blend_mode| {
+                            (
+                                material.clone_weak(),
+                                blend_mode,
+                                BatchedSprite {
+                                    entity,
+                                    size: sprite.size,
+                                    anchor: sprite.anchor.as_vec(),
+                                    flip_x: sprite.flip_x,
+                                    flip_y: sprite.flip_y,
+                                    color: sprite.color.as_linear_rgba_f32(),
+                                    transform: *global_transform,
+                                },
+                            )
+                        },
+                    )
+                }
+                _ => None,
+            };
+            draws.push(TransparentDraw { order, sprite });
+        }
+    }
+
+    let runs = batch_runs(&mut draws, settings.min_sprites);
+
+    // the runs of each material reuse that material's batches in back-to-front order
+    let mut used_batches = HashMap::<Handle<ColorMaterial>, usize>::default();
+    for run in runs {
+        let sprites = draws[run]
+            .iter_mut()
+            .filter_map(|draw| draw.sprite.take())
+            .collect::<Vec<_>>();
+        let (material, blend_mode, _) = &sprites[0];
+        let material = material.clone_weak();
+        let pipeline = blend_mode.sprite_batch_pipeline();
+        let sprites = sprites
+            .into_iter()
+            .map(|(_, _, sprite)| sprite)
+            .collect::<Vec<_>>();
+        let members = sprites
+            .iter()
+            .map(|sprite| sprite.entity)
+            .collect::<Vec<Entity>>();
+        let front_z = sprites.last().unwrap().transform.translation.z;
+        let origin = Vec3::new(0.0, 0.0, front_z);
+
+        let index = used_batches.entry(material.clone_weak()).or_insert(0);
+        let batch_entity = sprite_batches
+            .batches
+            .get(&material)
+            .and_then(|batches| batches.get(*index))
+            .cloned();
+        *index += 1;
+
+        let existing_batch = batch_entity.and_then(|batch_entity| {
+            queries.q2_mut().get_mut(batch_entity).ok().map(
+                |(batch, mesh, _, _, mut render_pipelines)| {
+                    // the material's blend mode changed
+                    if render_pipelines.pipelines[0].pipeline != pipeline {
+                        render_pipelines.pipelines[0] = RenderPipeline::new(pipeline.clone_weak());
+                    }
+                    (batch_entity, batch.sprites != members, mesh.clone_weak())
+                },
+            )
+        });
+
+        match existing_batch {
+            Some((batch_entity, members_changed, mesh_handle)) => {
+                let sprites_changed = members_changed
+                    || members
+                        .iter()
+                        .any(|entity| queries.q1().get(*entity).is_ok());
+                if !sprites_changed {
+                    continue;
+                }
+
+                if let Some(mesh) = meshes.get_mut(&mesh_handle) {
+                    *mesh = build_batch_mesh(&sprites, origin);
+                }
+
+                let (mut batch, _, mut transform, mut global_transform, _) =
+                    queries.q2_mut().get_mut(batch_entity).unwrap();
+                batch.sprites = members;
+                transform.translation = origin;
+                global_transform.translation = origin;
+            }
+            None => {
+                let mesh = meshes.add(build_batch_mesh(&sprites, origin));
+                commands.spawn(SpriteBatchBundle {
+                    sprite_batch: SpriteBatch {
+                        material: material.clone_weak(),
+                        sprites: members,
+                    },
+                    mesh,
+                    material: material.clone_weak(),
+                    render_pipelines: RenderPipelines::from_pipelines(vec![RenderPipeline::new(
+                        pipeline,
+                    )]),
+                    transform: Transform::from_translation(origin),
+                    global_transform: GlobalTransform::from_translation(origin),
+                    ..Default::default()
+                });
+                sprite_batches
+                    .batches
+                    .entry(material)
+                    .or_insert_with(Vec::new)
+                    .push(commands.current_entity().unwrap());
+            }
+        }
+    }
+
+    // despawn the batches that are left over because their material has fewer runs now
+    sprite_batches.batches.retain(|material, batches| {
+        let used = used_batches.get(material).cloned().unwrap_or(0);
+        for batch_entity in batches.drain(used.min(batches.len())..) {
+            commands.despawn(batch_entity);
+        }
+        !batches.is_empty()
+    });
+}
+
+/// Draws each batch in place of its first visible sprite and removes the render commands of the
+/// other sprites in the batch. This runs after
+/// [draw_render_pipelines_system](bevy_render::pipeline::draw_render_pipelines_system), so the
+/// batch is drawn at the position of its sprites in the draw order rather than its own.
+pub fn sprite_batch_draw_system(
+    batch_query: Query<(Entity, &SpriteBatch)>,
+    visibility_query: Query<&ComputedVisibility>,
+    mut draw_query: Query<&mut Draw>,
+) {
+    for (batch_entity, batch) in batch_query.iter() {
+        // keep drawing the sprites individually until the batch itself is ready to draw
+        let mut render_commands = match draw_query.get_mut(batch_entity) {
+            Ok(mut draw) if !draw.render_commands.is_empty() => {
+                std::mem::take(&mut draw.render_commands)
+            }
+            _ => continue,
+        };
+
+        let host = batch.sprites.iter().find(|sprite| {
+            visibility_query
+                .get(**sprite)
+                .map_or(true, |visibility| visibility.is_visible)
+        });
+        for sprite in batch.sprites.iter() {
+            if let Ok(mut draw) = draw_query.get_mut(*sprite) {
+                if Some(sprite) == host {
+                    draw.render_commands = std::mem::take(&mut render_commands);
+                } else {
+                    draw.clear_render_commands();
+                }
+            }
+        }
+    }
+}
+
+/// Per-vertex sprite colors, which take the place of the `Sprite_color` uniform in batches
+const ATTRIBUTE_COLOR: &str = "Vertex_Color";
+
+fn build_batch_mesh(sprites: &[BatchedSprite], origin: Vec3) -> Mesh {
+    // corners match the vertex order of the sprite quad mesh
+    const CORNERS: [([f32; 2], [f32; 2]); 4] = [
+        ([-0.5, -0.5], [0.0, 1.0]),
+        ([-0.5, 0.5], [0.0, 0.0]),
+        ([0.5, 0.5], [1.0, 0.0]),
+        ([0.5, -0.5], [1.0, 1.0]),
+    ];
+
+    let mut positions = Vec::<[f32; 3]>::with_capacity(sprites.len() * 4);
+    let mut uvs = Vec::<[f32; 2]>::with_capacity(sprites.len() * 4);
+    let mut colors = Vec::<[f32; 4]>::with_capacity(sprites.len() * 4);
+    let mut indices = Vec::<u32>::with_capacity(sprites.len() * 6);
+    for sprite in sprites.iter() {
+        let base = positions.len() as u32;
+        for (corner, uv) in CORNERS.iter() {
+            let local = (Vec2::new(corner[0], corner[1]) - sprite.anchor) * sprite.size;
+            let position = sprite.transform.mul_vec3(local.extend(0.0)) - origin;
+            positions.push([position.x, position.y, position.z]);
+            uvs.push([
+                if sprite.flip_x { 1.0 - uv[0] } else { uv[0] },
+                if sprite.flip_y { 1.0 - uv[1] } else { uv[1] },
+            ]);
+            colors.push(sprite.color);
+        }
+        indices.extend([0, 2, 1, 0, 3, 2].iter().map(|index| base + index));
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_attribute(ATTRIBUTE_COLOR, colors);
+    mesh
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SpriteAnchor;
+    use bevy_math::Quat;
+    use bevy_type_registry::TypeUuid;
+
+    fn material(id: u64) -> Handle<ColorMaterial> {
+        Handle::weak_from_u64(ColorMaterial::TYPE_UUID, id)
+    }
+
+    fn sprite_draw(
+        entity: u32,
+        material: &Handle<ColorMaterial>,
+        order_in_layer: i32,
+        z: f32,
+    ) -> TransparentDraw {
+        let entity = Entity::new(entity);
+        TransparentDraw {
+            order: (0, order_in_layer, FloatOrd(z), entity),
+            sprite: Some((
+                material.clone_weak(),
+                BlendMode::Alpha,
+                BatchedSprite {
+                    entity,
+                    size: Vec2::one(),
+                    anchor: Vec2::zero(),
+                    flip_x: false,
+                    flip_y: false,
+                    color: [1.0, 1.0, 1.0, 1.0],
+                    transform: GlobalTransform::from_translation(Vec3::new(0.0, 0.0, z)),
+                },
+            )),
+        }
+    }
+
+    fn other_draw(entity: u32, z: f32) -> TransparentDraw {
+        TransparentDraw {
+            order: (0, 0, FloatOrd(z), Entity::new(entity)),
+            sprite: None,
+        }
+    }
+
+    /// The entities drawn by each draw call, in the order the calls are made
+    fn draw_calls(draws: &mut [TransparentDraw], min_sprites: usize) -> Vec<Vec<Entity>> {
+        let runs = batch_runs(draws, min_sprites);
+        let mut calls = Vec::new();
+        let mut index = 0;
+        while index < draws.len() {
+            match runs.iter().find(|run| run.start == index) {
+                Some(run) => {
+                    calls.push(draws[run.clone()].iter().map(|d| d.order.3).collect());
+                    index = run.end;
+                }
+                None => {
+                    calls.push(vec![draws[index].order.3]);
+                    index += 1;
+                }
+            }
+        }
+        calls
+    }
+
+    #[test]
+    fn batching_keeps_draw_order() {
+        let a = material(1);
+        let b = material(2);
+        // entity ids match the back-to-front order
+        let mut draws = vec![
+            sprite_draw(7, &a, 0, 7.0),
+            sprite_draw(4, &a, 0, 4.0),
+            other_draw(3, 3.0),
+            sprite_draw(0, &a, 0, 0.0),
+            sprite_draw(6, &b, 0, 6.0),
+            sprite_draw(2, &a, 0, 2.0),
+            sprite_draw(5, &a, 0, 5.0),
+            sprite_draw(1, &a, 0, 1.0),
+        ];
+
+        let batched = draw_calls(&mut draws, 2);
+        let unbatched = draw_calls(&mut draws, usize::MAX);
+        assert_eq!(
+            batched.iter().flatten().collect::<Vec<_>>(),
+            unbatched.iter().flatten().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            unbatched.concat(),
+            (0..8).map(Entity::new).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            batched,
+            vec![
+                vec![Entity::new(0), Entity::new(1), Entity::new(2)],
+                vec![Entity::new(3)],
+                vec![Entity::new(4), Entity::new(5)],
+                vec![Entity::new(6)],
+                vec![Entity::new(7)],
+            ]
+        );
+    }
+
+    #[test]
+    fn other_materials_split_batches() {
+        let a = material(1);
+        let b = material(2);
+        let mut draws = vec![
+            sprite_draw(0, &a, 0, 0.0),
+            sprite_draw(1, &a, 0, 10.0),
+            sprite_draw(2, &b, 0, 5.0),
+        ];
+        assert_eq!(
+            draw_calls(&mut draws, 2),
+            vec![
+                vec![Entity::new(0)],
+                vec![Entity::new(2)],
+                vec![Entity::new(1)],
+            ]
+        );
+    }
+
+    #[test]
+    fn batches_follow_order_in_layer() {
+        let a = material(1);
+        let b = material(2);
+        // the order in layer takes precedence over depth
+        let mut draws = vec![
+            sprite_draw(0, &a, 0, 10.0),
+            sprite_draw(1, &b, 1, 0.0),
+            sprite_draw(2, &a, 2, 0.0),
+            sprite_draw(3, &a, 0, 20.0),
+            sprite_draw(4, &a, 2, 5.0),
+        ];
+        assert_eq!(
+            draw_calls(&mut draws, 2),
+            vec![
+                vec![Entity::new(0), Entity::new(3)],
+                vec![Entity::new(1)],
+                vec![Entity::new(2), Entity::new(4)],
+            ]
+        );
+    }
+
+    #[test]
+    fn batch_mesh_merges_sprite_quads() {
+        let sprites = vec![
+            BatchedSprite {
+                entity: Entity::new(0),
+                size: Vec2::new(2.0, 4.0),
+                anchor: Vec2::new(0.0, 0.0),
+                flip_x: false,
+                flip_y: false,
+                color: [1.0, 1.0, 1.0, 1.0],
+                transform: GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 1.0)),
+            },
+            BatchedSprite {
+                entity: Entity::new(1),
+                size: Vec2::new(2.0, 2.0),
+                anchor: Vec2::new(0.0, 0.0),
+                flip_x: false,
+                flip_y: false,
+                color: [1.0, 1.0, 1.0, 1.0],
+                transform: GlobalTransform::from_translation(Vec3::new(0.0, 0.0, 2.0))
+                    .mul_transform(Transform::from_rotation(Quat::from_rotation_z(
+                        std::f32::consts::FRAC_PI_2,
+                    ))),
+            },
+        ];
+
+        let mesh = build_batch_mesh(&sprites, Vec3::new(0.0, 0.0, 2.0));
+        match mesh.indices() {
+            Some(Indices::U32(indices)) => {
+                assert_eq!(indices, &vec![0, 2, 1, 0, 3, 2, 4, 6, 5, 4, 7, 6])
+            }
+            _ => panic!("batch mesh should use u32 indices"),
+        }
+
+        let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(bevy_render::mesh::VertexAttributeValues::Float3(positions)) => positions,
+            _ => panic!("batch mesh should have Float3 positions"),
+        };
+        assert_eq!(positions.len(), 8);
+        // the first sprite's bottom left corner, relative to the batch origin
+        assert_eq!(positions[0], [9.0, -2.0, -1.0]);
+        // the second sprite is rotated a quarter turn, so its bottom left corner is now bottom right
+        let rotated = Vec3::from(positions[4]);
+        assert!((rotated - Vec3::new(1.0, -1.0, 0.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn batch_mesh_applies_anchor_and_flip() {
+        let sprites = vec![BatchedSprite {
+            entity: Entity::new(0),
+            size: Vec2::new(2.0, 2.0),
+            anchor: SpriteAnchor::BottomLeft.as_vec(),
+            flip_x: true,
+            flip_y: false,
+            color: [1.0, 1.0, 1.0, 1.0],
+            transform: GlobalTransform::identity(),
+        }];
+
+        let mesh = build_batch_mesh(&sprites, Vec3::zero());
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(bevy_render::mesh::VertexAttributeValues::Float3(positions)) => {
+                // the bottom left corner sits on the sprite's position
+                assert_eq!(positions[0], [0.0, 0.0, 0.0]);
+                assert_eq!(positions[2], [2.0, 2.0, 0.0]);
+            }
+            _ => panic!("batch mesh should have Float3 positions"),
+        }
+        match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(bevy_render::mesh::VertexAttributeValues::Float2(uvs)) => {
+                assert_eq!(uvs[0], [1.0, 1.0]);
+                assert_eq!(uvs[2], [0.0, 0.0]);
+            }
+            _ => panic!("batch mesh should have Float2 uvs"),
+        }
+    }
+}
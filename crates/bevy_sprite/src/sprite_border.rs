@@ -0,0 +1,253 @@
+use crate::{ColorMaterial, Sprite, QUAD_HANDLE};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{Entity, Local, Query, QuerySet, Res, ResMut, With};
+use bevy_math::Vec2;
+use bevy_render::{
+    mesh::{Indices, Mesh},
+    pipeline::PrimitiveTopology,
+    texture::Texture,
+};
+use bevy_utils::HashMap;
+
+/// Border insets, in texels, for 9-slice scaling. Adding this component to a sprite keeps the
+/// corners of its texture at their original size and only stretches the edges and center, so
+/// panels and speech bubbles can be resized without distorting their corners.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SpriteBorder {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl SpriteBorder {
+    /// Creates a border with the same inset on every side
+    pub fn all(inset: f32) -> Self {
+        Self {
+            left: inset,
+            right: inset,
+            top: inset,
+            bottom: inset,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct SlicedMeshKey {
+    size: Vec2,
+    texture_size: Vec2,
+    border: SpriteBorder,
+}
+
+#[derive(Default)]
+pub struct SpriteBorderSystemState {
+    sliced_meshes: HashMap<Entity, (SlicedMeshKey, Handle<Mesh>)>,
+}
+
+/// Replaces the quad mesh of sprites that have a [SpriteBorder] with a 9-slice mesh, and keeps that
+/// mesh in sync with the sprite's size and texture. Sprites whose [SpriteBorder] is removed get
+/// their quad mesh back.
+#[allow(clippy::type_complexity)]
+pub fn sprite_border_system(
+    mut state: Local<SpriteBorderSystemState>,
+    materials: Res<Assets<ColorMaterial>>,
+    textures: Res<Assets<Texture>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut queries: QuerySet<(
+        Query<(
+            Entity,
+            &Sprite,
+            &SpriteBorder,
+            &Handle<ColorMaterial>,
+            &mut Handle<Mesh>,
+        )>,
+        Query<&mut Handle<Mesh>, With<Sprite>>,
+    )>,
+) {
+    let mut sliced_meshes = HashMap::default();
+    for (entity, sprite, border, material, mut mesh) in queries.q0_mut().iter_mut() {
+        let texture_size = if let Some(texture) = materials
+            .get(material)
+            .and_then(|material| material.texture.as_ref())
+            .and_then(|texture| textures.get(texture))
+        {
+            texture.size.as_vec3().truncate()
+        } else {
+            // wait until the texture has loaded, keeping the current sliced mesh until then
+            if let Some(sliced_mesh) = state.sliced_meshes.remove(&entity) {
+                sliced_meshes.insert(entity, sliced_mesh);
+            }
+            continue;
+        };
+
+        let key = SlicedMeshKey {
+            size: sprite.size,
+            texture_size,
+            border: *border,
+        };
+        let sliced_mesh = match state.sliced_meshes.remove(&entity) {
+            Some((old_key, sliced_mesh)) if *mesh == sliced_mesh => {
+                if old_key != key {
+                    if let Some(sliced) = meshes.get_mut(&sliced_mesh) {
+                        *sliced = build_sliced_mesh(&key);
+                    }
+                }
+                sliced_mesh
+            }
+            // only replace the default quad so custom meshes are left alone
+            _ if *mesh == QUAD_HANDLE => {
+                let sliced_mesh = meshes.add(build_sliced_mesh(&key));
+                *mesh = sliced_mesh.clone();
+                sliced_mesh
+            }
+            _ => continue,
+        };
+        sliced_meshes.insert(entity, (key, sliced_mesh));
+    }
+
+    // the sprites that are left lost their border or were despawned
+    for (entity, (_, sliced_mesh)) in state.sliced_meshes.drain() {
+        if let Ok(mut mesh) = queries.q1_mut().get_mut(entity) {
+            if *mesh == sliced_mesh {
+                *mesh = QUAD_HANDLE;
+            }
+        }
+    }
+
+    // dropping the handles of sprites without a border frees their meshes
+    state.sliced_meshes = sliced_meshes;
+}
+
+fn build_sliced_mesh(key: &SlicedMeshKey) -> Mesh {
+    // shrink the borders if the sprite is too small to fit them
+    let scale_x = (key.size.x / (key.border.left + key.border.right)).min(1.0);
+    let scale_y = (key.size.y / (key.border.bottom + key.border.top)).min(1.0);
+    // positions are relative to the sprite's size, which is applied in the sprite shader. a sprite
+    // without a size collapses to a point no matter where its slices start
+    let relative = |inset: f32, scale: f32, size: f32| {
+        if size > 0.0 {
+            inset * scale / size
+        } else {
+            0.0
+        }
+    };
+
+    let xs = [
+        -0.5,
+        -0.5 + relative(key.border.left, scale_x, key.size.x),
+        0.5 - relative(key.border.right, scale_x, key.size.x),
+        0.5,
+    ];
+    let ys = [
+        -0.5,
+        -0.5 + relative(key.border.bottom, scale_y, key.size.y),
+        0.5 - relative(key.border.top, scale_y, key.size.y),
+        0.5,
+    ];
+    let us = [
+        0.0,
+        key.border.left / key.texture_size.x,
+        1.0 - key.border.right / key.texture_size.x,
+        1.0,
+    ];
+    // texture coordinates start at the top
+    let vs = [
+        1.0,
+        1.0 - key.border.bottom / key.texture_size.y,
+        key.border.top / key.texture_size.y,
+        0.0,
+    ];
+
+    let mut positions = Vec::<[f32; 3]>::with_capacity(16);
+    let mut normals = Vec::<[f32; 3]>::with_capacity(16);
+    let mut uvs = Vec::<[f32; 2]>::with_capacity(16);
+    for (x, u) in xs.iter().zip(us.iter()) {
+        for (y, v) in ys.iter().zip(vs.iter()) {
+            positions.push([*x, *y, 0.0]);
+            normals.push([0.0, 0.0, 1.0]);
+            uvs.push([*u, *v]);
+        }
+    }
+
+    let mut indices = Vec::<u32>::with_capacity(54);
+    for column in 0..3 {
+        for row in 0..3 {
+            let south_west = column * 4 + row;
+            let north_west = south_west + 1;
+            let south_east = south_west + 4;
+            let north_east = south_east + 1;
+            indices.extend_from_slice(&[
+                south_west, north_east, north_west, south_west, south_east, north_east,
+            ]);
+        }
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy_render::mesh::VertexAttributeValues;
+
+    #[test]
+    fn sliced_mesh_keeps_border_size() {
+        let mesh = build_sliced_mesh(&SlicedMeshKey {
+            size: Vec2::new(100.0, 40.0),
+            texture_size: Vec2::new(32.0, 32.0),
+            border: SpriteBorder::all(8.0),
+        });
+
+        let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float3(positions)) => positions,
+            _ => panic!("sliced mesh should have Float3 positions"),
+        };
+        assert_eq!(positions.len(), 16);
+        // the inner corner of the bottom left slice is 8 units in from the sprite's corner
+        assert_eq!(positions[5], [-0.5 + 8.0 / 100.0, -0.5 + 8.0 / 40.0, 0.0]);
+
+        let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float2(uvs)) => uvs,
+            _ => panic!("sliced mesh should have Float2 uvs"),
+        };
+        assert_eq!(uvs[5], [0.25, 0.75]);
+    }
+
+    #[test]
+    fn sliced_mesh_shrinks_borders_on_small_sprites() {
+        let mesh = build_sliced_mesh(&SlicedMeshKey {
+            size: Vec2::new(8.0, 8.0),
+            texture_size: Vec2::new(32.0, 32.0),
+            border: SpriteBorder::all(8.0),
+        });
+
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float3(positions)) => {
+                // both borders meet in the middle of the sprite
+                assert_eq!(positions[5], [0.0, 0.0, 0.0]);
+            }
+            _ => panic!("sliced mesh should have Float3 positions"),
+        }
+    }
+
+    #[test]
+    fn sliced_mesh_handles_zero_size() {
+        let mesh = build_sliced_mesh(&SlicedMeshKey {
+            size: Vec2::new(0.0, 0.0),
+            texture_size: Vec2::new(32.0, 32.0),
+            border: SpriteBorder::all(8.0),
+        });
+
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float3(positions)) => {
+                assert!(positions.iter().flatten().all(|value| value.is_finite()));
+            }
+            _ => panic!("sliced mesh should have Float3 positions"),
+        }
+    }
+}
@@ -64,6 +64,25 @@ impl TextureAtlas {
         }
     }
 
+    /// Create a `TextureAtlas` that will be packed from the given `textures` by
+    /// [texture_atlas_packing_system](crate::texture_atlas_packing_system) once all of them
+    /// have loaded. Until then the atlas has no texture and no sprites, so check
+    /// [TextureAtlas::is_ready] before indexing into `textures`.
+    pub fn new_packed(textures: Vec<Handle<Texture>>) -> Self {
+        Self {
+            texture: Default::default(),
+            size: Vec2::new(0.0, 0.0),
+            textures: Vec::new(),
+            texture_handles: Some(
+                textures
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, handle)| (handle, index))
+                    .collect(),
+            ),
+        }
+    }
+
     /// Generate a `TextureAtlas` by splitting a texture into a grid where each
     /// cell of the grid  of `tile_size` is one of the textures in the atlas
     pub fn from_grid(
@@ -140,6 +159,22 @@ impl TextureAtlas {
         self.textures.is_empty()
     }
 
+    /// Returns true if this atlas was created with [TextureAtlas::new_packed] and its
+    /// textures have not been packed yet
+    pub fn needs_packing(&self) -> bool {
+        self.textures.is_empty()
+            && self
+                .texture_handles
+                .as_ref()
+                .map_or(false, |texture_handles| !texture_handles.is_empty())
+    }
+
+    /// Returns true once the atlas has sprites. Packed atlases aren't ready until all of their
+    /// textures have loaded and been packed.
+    pub fn is_ready(&self) -> bool {
+        !self.textures.is_empty()
+    }
+
     pub fn get_texture_index(&self, texture: &Handle<Texture>) -> Option<usize> {
         self.texture_handles
             .as_ref()
@@ -0,0 +1,139 @@
+use crate::{TextureAtlas, TextureAtlasBuilder};
+use anyhow::Result;
+use bevy_asset::{AssetLoader, AssetPath, Assets, HandleId, LoadContext, LoadedAsset};
+use bevy_ecs::ResMut;
+use bevy_math::Vec2;
+use bevy_render::texture::Texture;
+use bevy_utils::{tracing::warn, BoxedFuture};
+use serde::Deserialize;
+
+/// Describes a [TextureAtlas] in a `.atlas` file. Texture paths are relative to the `.atlas` file.
+///
+/// ```ron
+/// Grid(
+///     texture: "gabe-idle-run.png",
+///     tile_size: (24.0, 24.0),
+///     columns: 7,
+///     rows: 1,
+/// )
+/// ```
+#[derive(Debug, Deserialize)]
+pub enum TextureAtlasDescriptor {
+    /// Splits a single texture into a grid of equally sized cells
+    Grid {
+        texture: String,
+        tile_size: Vec2,
+        columns: usize,
+        rows: usize,
+        #[serde(default)]
+        padding: Vec2,
+    },
+    /// Packs individual textures into a new atlas texture once all of them have loaded
+    Packed { textures: Vec<String> },
+}
+
+/// Loads [TextureAtlas] assets from `.atlas` files
+#[derive(Debug, Default)]
+pub struct TextureAtlasLoader;
+
+impl AssetLoader for TextureAtlasLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let descriptor = ron::de::from_bytes::<TextureAtlasDescriptor>(bytes)?;
+            let parent = load_context.path().parent().unwrap().to_owned();
+            let texture_path = |path: &str| AssetPath::new(parent.join(path), None);
+
+            let (texture_atlas, dependencies) = match descriptor {
+                TextureAtlasDescriptor::Grid {
+                    texture,
+                    tile_size,
+                    columns,
+                    rows,
+                    padding,
+                } => {
+                    let path = texture_path(&texture);
+                    let texture_atlas = TextureAtlas::from_grid_with_padding(
+                        load_context.get_handle(path.clone()),
+                        tile_size,
+                        columns,
+                        rows,
+                        padding,
+                    );
+                    (texture_atlas, vec![path])
+                }
+                TextureAtlasDescriptor::Packed { textures } => {
+                    let paths = textures
+                        .iter()
+                        .map(|texture| texture_path(texture))
+                        .collect::<Vec<AssetPath>>();
+                    let texture_atlas = TextureAtlas::new_packed(
+                        paths
+                            .iter()
+                            .map(|path| load_context.get_handle(path.clone()))
+                            .collect(),
+                    );
+                    (texture_atlas, paths)
+                }
+            };
+
+            load_context
+                .set_default_asset(LoadedAsset::new(texture_atlas).with_dependencies(dependencies));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas"]
+    }
+}
+
+/// Packs [TextureAtlas]es created with [TextureAtlas::new_packed] once all of their textures
+/// have loaded
+pub fn texture_atlas_packing_system(
+    mut textures: ResMut<Assets<Texture>>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+) {
+    let pending = texture_atlases
+        .iter()
+        .filter(|(_, texture_atlas)| texture_atlas.needs_packing())
+        .map(|(id, _)| id)
+        .collect::<Vec<HandleId>>();
+
+    for id in pending {
+        let texture_atlas = texture_atlases.get(id).unwrap();
+        let mut texture_handles = texture_atlas
+            .texture_handles
+            .as_ref()
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+        if texture_handles
+            .iter()
+            .any(|(handle, _)| textures.get(*handle).is_none())
+        {
+            continue;
+        }
+
+        // pack textures in the order they were listed in
+        texture_handles.sort_by_key(|(_, index)| **index);
+        let mut texture_atlas_builder = TextureAtlasBuilder::default();
+        for (handle, _) in texture_handles {
+            texture_atlas_builder.add_texture(handle.clone(), textures.get(handle).unwrap());
+        }
+
+        let result = texture_atlas_builder.finish(&mut textures);
+        let texture_atlas = texture_atlases.get_mut(id).unwrap();
+        match result {
+            Ok(packed_texture_atlas) => *texture_atlas = packed_texture_atlas,
+            Err(err) => {
+                warn!("Failed to pack texture atlas: {}", err);
+                // don't try to pack this atlas again
+                texture_atlas.texture_handles = None;
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 mod slice;
 pub use slice::{ParallelSlice, ParallelSliceMut};
 
+#[cfg(not(target_arch = "wasm32"))]
 mod task;
+#[cfg(not(target_arch = "wasm32"))]
 pub use task::Task;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -12,7 +14,7 @@ pub use task_pool::{Scope, TaskPool, TaskPoolBuilder};
 #[cfg(target_arch = "wasm32")]
 mod single_threaded_task_pool;
 #[cfg(target_arch = "wasm32")]
-pub use single_threaded_task_pool::{Scope, TaskPool, TaskPoolBuilder};
+pub use single_threaded_task_pool::{Scope, Task, TaskPool, TaskPoolBuilder};
 
 mod usages;
 pub use usages::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool};
@@ -1,7 +1,10 @@
+use futures_lite::stream::Stream;
 use std::{
     future::Future,
     mem,
+    pin::Pin,
     sync::{Arc, Mutex},
+    task::{Context, Poll},
 };
 
 /// Used to create a TaskPool
@@ -60,7 +63,7 @@ impl TaskPool {
     pub fn scope<'scope, F, T>(&self, f: F) -> Vec<T>
     where
         F: FnOnce(&mut Scope<'scope, T>) + 'scope + Send,
-        T: Send + 'static,
+        T: Send + 'scope,
     {
         let executor = &async_executor::LocalExecutor::new();
         let executor: &'scope async_executor::LocalExecutor<'scope> =
@@ -83,32 +86,60 @@ impl TaskPool {
             .collect()
     }
 
-    // Spawns a static future onto the JS event loop. For now it is returning FakeTask
-    // instance with no-op detach method. Returning real Task is possible here, but tricky:
-    // future is running on JS event loop, Task is running on async_executor::LocalExecutor
-    // so some proxy future is needed. Moreover currently we don't have long-living
-    // LocalExecutor here (above `spawn` implementation creates temporary one)
-    // But for typical use cases it seems that current implementation should be sufficient:
-    // caller can spawn long-running future writing results to some channel / event queue
-    // and simply call detach on returned Task (like AssetServer does) - spawned future
-    // can write results to some channel / event queue.
-
-    pub fn spawn<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask
+    /// Spawns a static future onto the JS event loop and returns a [Task] that yields its output.
+    ///
+    /// Unlike tasks on multi-threaded pools, futures spawned here keep running when their [Task] is
+    /// dropped, because the JS event loop has no way to cancel them.
+    pub fn spawn<T>(&self, future: impl Future<Output = T> + 'static) -> Task<T>
     where
         T: 'static,
     {
+        let (sender, receiver) = async_channel::bounded(1);
         wasm_bindgen_futures::spawn_local(async move {
-            future.await;
+            // the task may have been dropped, in which case nobody wants the output
+            let _ = sender.send(future.await).await;
         });
-        FakeTask
+        Task(receiver)
     }
 }
 
+/// A future spawned onto the JS event loop, which has the same API as the multi-threaded `Task`
+/// so code that spawns tasks compiles for wasm unchanged.
+///
+/// Tasks are also futures themselves and yield the output of the spawned future. Awaiting a
+/// canceled task panics.
 #[derive(Debug)]
-pub struct FakeTask;
+pub struct Task<T>(async_channel::Receiver<T>);
 
-impl FakeTask {
+impl<T> Task<T> {
+    /// Lets the task keep running in the background
     pub fn detach(self) {}
+
+    /// Stops listening for the task's output. Returns the output if the task already completed.
+    pub async fn cancel(self) -> Option<T> {
+        self.0.close();
+        self.0.try_recv().ok()
+    }
+
+    /// Returns the task's output if it has completed, without blocking. This is how systems can
+    /// check on tasks that are stored in components or resources once per frame.
+    ///
+    /// Once the output has been returned, the task is done and must not be checked again.
+    pub fn check_ready(&mut self) -> Option<T> {
+        self.0.try_recv().ok()
+    }
+}
+
+impl<T> Future for Task<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match Pin::new(&mut self.0).poll_next(cx) {
+            Poll::Ready(Some(output)) => Poll::Ready(output),
+            Poll::Ready(None) => panic!("awaited a canceled task"),
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -1,3 +1,4 @@
+use futures_lite::future;
 use std::{
     future::Future,
     pin::Pin,
@@ -39,6 +40,14 @@ impl<T> Task<T> {
     pub async fn cancel(self) -> Option<T> {
         self.0.cancel().await
     }
+
+    /// Returns the task's output if it has completed, without blocking. This is how systems can
+    /// check on tasks that are stored in components or resources once per frame.
+    ///
+    /// Once the output has been returned, the task is done and must not be checked again.
+    pub fn check_ready(&mut self) -> Option<T> {
+        future::block_on(future::poll_once(&mut self.0))
+    }
 }
 
 impl<T> Future for Task<T> {
@@ -48,3 +57,26 @@ impl<T> Future for Task<T> {
         Pin::new(&mut self.0).poll(cx)
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use crate::TaskPool;
+
+    #[test]
+    fn check_ready_returns_output_once_done() {
+        let pool = TaskPool::new();
+        let (sender, receiver) = async_channel::bounded(1);
+        let mut task = pool.spawn(async move { receiver.recv().await.unwrap() * 2 });
+
+        assert_eq!(task.check_ready(), None);
+
+        sender.try_send(21).unwrap();
+        let output = loop {
+            if let Some(output) = task.check_ready() {
+                break output;
+            }
+            std::thread::yield_now();
+        };
+        assert_eq!(output, 42);
+    }
+}
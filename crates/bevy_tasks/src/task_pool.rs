@@ -2,7 +2,7 @@ use std::{
     future::Future,
     mem,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Mutex},
     thread::{self, JoinHandle},
 };
 
@@ -152,13 +152,38 @@ impl TaskPool {
 
     /// Allows spawning non-`static futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
-    /// to spawn tasks. This function will await the completion of all tasks before returning.
+    /// to spawn tasks. This function will await the completion of all tasks before returning, and
+    /// returns the outputs of the tasks in the order they were spawned.
+    ///
+    /// Because the tasks are done when this returns, they can borrow data from the caller, and
+    /// their outputs can borrow from it too.
     ///
     /// This is similar to `rayon::scope` and `crossbeam::scope`
+    ///
+    /// ```
+    /// use bevy_tasks::TaskPool;
+    ///
+    /// let pool = TaskPool::new();
+    /// let mut chunks = vec![vec![3, 1, 2], vec![6, 5, 4]];
+    ///
+    /// pool.scope(|scope| {
+    ///     for chunk in chunks.iter_mut() {
+    ///         scope.spawn(async move { chunk.sort_unstable() });
+    ///     }
+    /// });
+    ///
+    /// let largest = pool.scope(|scope| {
+    ///     for chunk in chunks.iter() {
+    ///         scope.spawn(async move { chunk.iter().max().unwrap() });
+    ///     }
+    /// });
+    /// assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(largest, vec![&3, &6]);
+    /// ```
     pub fn scope<'scope, F, T>(&self, f: F) -> Vec<T>
     where
         F: FnOnce(&mut Scope<'scope, T>) + 'scope + Send,
-        T: Send + 'static,
+        T: Send + 'scope,
     {
         // SAFETY: This function blocks until all futures complete, so this future must return
         // before this function returns. However, rust has no way of knowing
@@ -167,45 +192,53 @@ impl TaskPool {
         let executor: &async_executor::Executor = &*self.executor;
         let executor: &'scope async_executor::Executor = unsafe { mem::transmute(executor) };
 
-        let fut = async move {
-            let mut scope = Scope {
-                executor,
-                spawned: Vec::new(),
-            };
+        // The results are written to this function's stack rather than returned by the future, so
+        // the future has no output and the results don't have to be 'static
+        let results = Mutex::new(Vec::new());
+        {
+            let results = &results;
+            let fut = async move {
+                let mut scope = Scope {
+                    executor,
+                    spawned: Vec::new(),
+                };
 
-            f(&mut scope);
+                f(&mut scope);
 
-            let mut results = Vec::with_capacity(scope.spawned.len());
-            for task in scope.spawned {
-                results.push(task.await);
-            }
+                let mut scope_results = Vec::with_capacity(scope.spawned.len());
+                for task in scope.spawned {
+                    scope_results.push(task.await);
+                }
 
-            results
-        };
+                *results.lock().unwrap() = scope_results;
+            };
 
-        // Pin the future on the stack.
-        pin!(fut);
+            // Pin the future on the stack.
+            pin!(fut);
+
+            // SAFETY: This function blocks until all futures complete, so we do not read/write the
+            // data from futures outside of the 'scope lifetime. However, rust has no way of knowing
+            // this so we must convert to 'static here to appease the compiler as it is unable to
+            // validate safety.
+            let fut: Pin<&mut (dyn Future<Output = ()> + Send)> = fut;
+            let fut: Pin<&'static mut (dyn Future<Output = ()> + Send + 'static)> =
+                unsafe { mem::transmute(fut) };
+
+            // The thread that calls scope() will participate in driving tasks in the pool forward
+            // until the tasks that are spawned by this scope() call complete. (If the caller of
+            // scope() happens to be a thread in this thread pool, and we only have one thread in the
+            // pool, then simply calling future::block_on(spawned) would deadlock.)
+            let mut spawned = self.executor.spawn(fut);
+            loop {
+                if future::block_on(future::poll_once(&mut spawned)).is_some() {
+                    break;
+                }
 
-        // SAFETY: This function blocks until all futures complete, so we do not read/write the
-        // data from futures outside of the 'scope lifetime. However, rust has no way of knowing
-        // this so we must convert to 'static here to appease the compiler as it is unable to
-        // validate safety.
-        let fut: Pin<&mut (dyn Future<Output = Vec<T>> + Send)> = fut;
-        let fut: Pin<&'static mut (dyn Future<Output = Vec<T>> + Send + 'static)> =
-            unsafe { mem::transmute(fut) };
-
-        // The thread that calls scope() will participate in driving tasks in the pool forward
-        // until the tasks that are spawned by this scope() call complete. (If the caller of scope()
-        // happens to be a thread in this thread pool, and we only have one thread in the pool, then
-        // simply calling future::block_on(spawned) would deadlock.)
-        let mut spawned = self.executor.spawn(fut);
-        loop {
-            if let Some(result) = future::block_on(future::poll_once(&mut spawned)) {
-                break result;
+                self.executor.try_tick();
             }
-
-            self.executor.try_tick();
         }
+
+        results.into_inner().unwrap()
     }
 
     /// Spawns a static future onto the thread pool. The returned Task is a future. It can also be
@@ -273,4 +306,32 @@ mod tests {
         assert_eq!(outputs.len(), 100);
         assert_eq!(count.load(Ordering::Relaxed), 100);
     }
+
+    #[test]
+    pub fn test_borrowed_data() {
+        let pool = TaskPool::new();
+
+        let mut chunks = (0..8)
+            .map(|i| (i * 10..i * 10 + 10).rev().collect::<Vec<i32>>())
+            .collect::<Vec<_>>();
+
+        // tasks can mutate disjoint parts of the caller's data...
+        pool.scope(|scope| {
+            for chunk in chunks.iter_mut() {
+                scope.spawn(async move { chunk.sort_unstable() });
+            }
+        });
+
+        // ...and return references into it
+        let smallest: Vec<&i32> = pool.scope(|scope| {
+            for chunk in chunks.iter() {
+                scope.spawn(async move { &chunk[0] });
+            }
+        });
+
+        assert_eq!(
+            smallest.into_iter().copied().collect::<Vec<i32>>(),
+            (0..8).map(|i| i * 10).collect::<Vec<i32>>()
+        );
+    }
 }
@@ -11,6 +11,7 @@ use bevy_render::{
     },
 };
 use bevy_sprite::TextureAtlasSprite;
+use bevy_utils::tracing::warn;
 use glyph_brush_layout::{HorizontalAlign, VerticalAlign};
 
 use crate::PositionedGlyph;
@@ -76,7 +77,7 @@ impl<'a> Drawable for DrawableText<'a> {
         {
             draw.set_vertex_buffer(0, vertex_attribute_buffer_id, 0);
         } else {
-            println!("could not find vertex buffer for bevy_sprite::QUAD_HANDLE")
+            warn!("could not find vertex buffer for bevy_sprite::QUAD_HANDLE")
         }
 
         let mut indices = 0..0;
@@ -5,6 +5,7 @@ mod font_atlas;
 mod font_atlas_set;
 mod font_loader;
 mod glyph_brush;
+mod localization;
 mod pipeline;
 
 pub use draw::*;
@@ -14,10 +15,13 @@ pub use font_atlas::*;
 pub use font_atlas_set::*;
 pub use font_loader::*;
 pub use glyph_brush::*;
+pub use localization::*;
 pub use pipeline::*;
 
 pub mod prelude {
-    pub use crate::{Font, TextAlignment, TextError, TextStyle};
+    pub use crate::{
+        Font, Localization, LocalizedText, TextAlignment, TextError, TextStyle, Translations,
+    };
     pub use glyph_brush_layout::{HorizontalAlign, VerticalAlign};
 }
 
@@ -34,7 +38,10 @@ impl Plugin for TextPlugin {
     fn build(&self, app: &mut AppBuilder) {
         app.add_asset::<Font>()
             .add_asset::<FontAtlasSet>()
+            .add_asset::<Translations>()
             .init_asset_loader::<FontLoader>()
+            .init_asset_loader::<TranslationsLoader>()
+            .init_resource::<Localization>()
             .add_resource(DefaultTextPipeline::default());
     }
 }
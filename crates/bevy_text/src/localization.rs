@@ -0,0 +1,340 @@
+use anyhow::Result;
+use bevy_asset::{AssetLoader, Assets, Handle, LoadContext, LoadedAsset};
+use bevy_type_registry::TypeUuid;
+use bevy_utils::{BoxedFuture, HashMap};
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum TranslationsError {
+    #[error("Line {line} is not a message of the form `key = value`")]
+    InvalidMessage { line: usize },
+    #[error("Line {line} continues a message, but no message came before it")]
+    UnexpectedContinuation { line: usize },
+}
+
+/// The translated messages of one locale.
+///
+/// Translations are usually loaded from `.ftl` files, which support the simple messages of
+/// [Fluent](https://projectfluent.org): `key = value` lines, values continued on indented lines,
+/// `#` comments, `{ $argument }` placeables, and terms like `-brand = Bevy` that other messages
+/// can reference with `{ -brand }`. Attributes (indented `.name = value` lines) are rejected.
+#[derive(Debug, Default, Clone, TypeUuid)]
+#[uuid = "f4ad6f6f-37ce-4256-88c0-9d5bacef79f9"]
+pub struct Translations {
+    messages: HashMap<String, String>,
+}
+
+impl Translations {
+    pub fn from_ftl(source: &str) -> Result<Self, TranslationsError> {
+        let mut translations = Translations::default();
+        let mut current: Option<String> = None;
+        // blank lines only belong to a value if it continues after them
+        let mut blank_lines = 0;
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+            let skipped_lines = std::mem::take(&mut blank_lines);
+            if line.starts_with('#') {
+                current = None;
+                continue;
+            }
+
+            if line.starts_with(char::is_whitespace) {
+                // attributes aren't supported
+                if line.trim_start().starts_with('.') {
+                    return Err(TranslationsError::InvalidMessage { line: line_number });
+                }
+                let key = current
+                    .as_ref()
+                    .ok_or(TranslationsError::UnexpectedContinuation { line: line_number })?;
+                let value = translations.messages.get_mut(key).unwrap();
+                if !value.is_empty() {
+                    for _ in 0..=skipped_lines {
+                        value.push('\n');
+                    }
+                }
+                value.push_str(line.trim());
+                continue;
+            }
+
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap().trim();
+            let value = parts
+                .next()
+                .ok_or(TranslationsError::InvalidMessage { line: line_number })?;
+            if !is_identifier(key.strip_prefix('-').unwrap_or(key)) {
+                return Err(TranslationsError::InvalidMessage { line: line_number });
+            }
+            translations.insert(key, value.trim());
+            current = Some(key.to_string());
+        }
+
+        Ok(translations)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(|value| value.as_str())
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.messages.insert(key.into(), value.into());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.messages
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+#[derive(Default)]
+pub struct TranslationsLoader;
+
+impl AssetLoader for TranslationsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let translations = Translations::from_ftl(std::str::from_utf8(bytes)?)?;
+            load_context.set_default_asset(LoadedAsset::new(translations));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+/// A text that is looked up in the [Translations] of the current locale. UI text entities with
+/// this component have their text replaced with the translation, which is updated when the locale
+/// or the translations change.
+#[derive(Debug, Default, Clone)]
+pub struct LocalizedText {
+    pub key: String,
+    /// The values of the `{ $argument }` placeables of the translation
+    pub args: HashMap<String, String>,
+}
+
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> Self {
+        LocalizedText {
+            key: key.into(),
+            args: Default::default(),
+        }
+    }
+
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.args.insert(name.into(), value.to_string());
+        self
+    }
+}
+
+/// The current locale and the [Translations] of every locale.
+///
+/// Messages that are missing from the current locale's translations are looked up in the
+/// fallback locale's translations, and show their key if they are missing there as well.
+#[derive(Debug)]
+pub struct Localization {
+    locale: String,
+    fallback_locale: Option<String>,
+    translations: HashMap<String, Vec<Handle<Translations>>>,
+    version: u64,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Localization::new("en-US")
+    }
+}
+
+impl Localization {
+    pub fn new(locale: impl Into<String>) -> Self {
+        Localization {
+            locale: locale.into(),
+            fallback_locale: None,
+            translations: Default::default(),
+            version: 0,
+        }
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Switches to another locale, which updates all [LocalizedText]s
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale = locale.into();
+        self.version += 1;
+    }
+
+    pub fn fallback_locale(&self) -> Option<&str> {
+        self.fallback_locale.as_deref()
+    }
+
+    pub fn set_fallback_locale(&mut self, locale: Option<String>) {
+        self.fallback_locale = locale;
+        self.version += 1;
+    }
+
+    /// Adds translations for `locale`. A locale can have several translations, which are searched
+    /// in the order they were added.
+    pub fn add_translations(&mut self, locale: impl Into<String>, handle: Handle<Translations>) {
+        self.translations
+            .entry(locale.into())
+            .or_insert_with(Vec::new)
+            .push(handle);
+        self.version += 1;
+    }
+
+    /// The locales that have translations
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.translations.keys().map(|locale| locale.as_str())
+    }
+
+    /// Increases every time the locale, the fallback locale or the translations of a locale change
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns whether `handle` is one of the translations of any locale
+    pub fn uses_translations(&self, handle: &Handle<Translations>) -> bool {
+        self.translations
+            .values()
+            .any(|handles| handles.contains(handle))
+    }
+
+    /// Looks up the message `key` in the current and then the fallback locale, without filling in
+    /// its placeables
+    pub fn get<'a>(&self, key: &str, translations: &'a Assets<Translations>) -> Option<&'a str> {
+        std::iter::once(self.locale.as_str())
+            .chain(self.fallback_locale.as_deref())
+            .filter_map(|locale| self.translations.get(locale))
+            .flatten()
+            .filter_map(|handle| translations.get(handle))
+            .find_map(|translations| translations.get(key))
+    }
+
+    /// Translates `text`, filling in its arguments and the terms it references
+    pub fn format(&self, text: &LocalizedText, translations: &Assets<Translations>) -> String {
+        let message = match self.get(&text.key, translations) {
+            Some(message) => message,
+            None => return text.key.clone(),
+        };
+
+        format_message(message, &text.args, |term| self.get(term, translations))
+    }
+}
+
+fn format_message<'a>(
+    message: &'a str,
+    args: &HashMap<String, String>,
+    get_term: impl Fn(&str) -> Option<&'a str>,
+) -> String {
+    let mut formatted = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        formatted.push_str(&rest[..start]);
+        let placeable = rest[start + 1..end].trim();
+        if let Some(name) = placeable.strip_prefix('$') {
+            match args.get(name) {
+                Some(value) => formatted.push_str(value),
+                None => formatted.push_str(&rest[start..=end]),
+            }
+        } else if placeable.starts_with('-') {
+            formatted.push_str(get_term(placeable).unwrap_or(placeable));
+        } else if placeable.len() >= 2 && placeable.starts_with('"') && placeable.ends_with('"') {
+            formatted.push_str(&placeable[1..placeable.len() - 1]);
+        } else {
+            formatted.push_str(&rest[start..=end]);
+        }
+        rest = &rest[end + 1..];
+    }
+    formatted.push_str(rest);
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_message, LocalizedText, Translations, TranslationsError};
+
+    #[test]
+    fn parse_ftl() {
+        let translations = Translations::from_ftl(
+            "# a comment\n\
+             hello = Hello, { $name }!\n\
+             \n\
+             multiline =\n    first line\n    second line\n\
+             -brand = Bevy\n",
+        )
+        .unwrap();
+        assert_eq!(translations.get("hello"), Some("Hello, { $name }!"));
+        assert_eq!(
+            translations.get("multiline"),
+            Some("first line\nsecond line")
+        );
+        assert_eq!(translations.get("-brand"), Some("Bevy"));
+
+        assert_eq!(
+            Translations::from_ftl("hello world").unwrap_err(),
+            TranslationsError::InvalidMessage { line: 1 }
+        );
+        assert_eq!(
+            Translations::from_ftl("  indented").unwrap_err(),
+            TranslationsError::UnexpectedContinuation { line: 1 }
+        );
+    }
+
+    #[test]
+    fn parse_ftl_multiline_values_and_attributes() {
+        let translations = Translations::from_ftl(
+            "multiline =\n    first paragraph\n\n    second paragraph\n\n\
+             next = Next\n",
+        )
+        .unwrap();
+        assert_eq!(
+            translations.get("multiline"),
+            Some("first paragraph\n\nsecond paragraph")
+        );
+        assert_eq!(translations.get("next"), Some("Next"));
+
+        assert_eq!(
+            Translations::from_ftl("login = Log in\n    .title = Log in to your account")
+                .unwrap_err(),
+            TranslationsError::InvalidMessage { line: 2 }
+        );
+    }
+
+    #[test]
+    fn format_placeables() {
+        let translations = Translations::from_ftl(
+            "-brand = Bevy\n\
+             welcome = Welcome to { -brand }, { $name }! { \"{\" } { $missing }\n",
+        )
+        .unwrap();
+        let text = LocalizedText::new("welcome").with_arg("name", "Ferris");
+        assert_eq!(
+            format_message(translations.get("welcome").unwrap(), &text.args, |term| {
+                translations.get(term)
+            }),
+            "Welcome to Bevy, Ferris! { { $missing }"
+        );
+    }
+}
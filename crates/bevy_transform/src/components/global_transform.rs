@@ -32,6 +32,13 @@ impl GlobalTransform {
         }
     }
 
+    /// Creates a new [GlobalTransform] at the position `(x, y, z)`. In 2d, the `z` component determines
+    /// the draw order: entities with a greater `z` are drawn on top.
+    #[inline]
+    pub fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        Self::from_translation(Vec3::new(x, y, z))
+    }
+
     #[inline]
     pub fn from_translation(translation: Vec3) -> Self {
         GlobalTransform {
@@ -68,17 +75,68 @@ impl GlobalTransform {
         Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
     }
 
+    /// Get the unit vector in the local x direction
     #[inline]
-    pub fn forward(&self) -> Vec3 {
+    pub fn local_x(&self) -> Vec3 {
+        self.rotation * Vec3::unit_x()
+    }
+
+    /// Get the unit vector in the local y direction
+    #[inline]
+    pub fn local_y(&self) -> Vec3 {
+        self.rotation * Vec3::unit_y()
+    }
+
+    /// Get the unit vector in the local z direction
+    #[inline]
+    pub fn local_z(&self) -> Vec3 {
         self.rotation * Vec3::unit_z()
     }
 
+    /// Get the unit vector pointing "forward" (the local -z direction). This is the direction
+    /// cameras look in and the direction [looking_at](Self::looking_at) points at the target.
+    #[inline]
+    pub fn forward(&self) -> Vec3 {
+        -self.local_z()
+    }
+
+    /// Get the unit vector pointing "right" (the local x direction)
+    #[inline]
+    pub fn right(&self) -> Vec3 {
+        self.local_x()
+    }
+
+    /// Get the unit vector pointing "up" (the local y direction)
+    #[inline]
+    pub fn up(&self) -> Vec3 {
+        self.local_y()
+    }
+
     #[inline]
     /// Rotate the transform by the given rotation
     pub fn rotate(&mut self, rotation: Quat) {
         self.rotation *= rotation;
     }
 
+    /// Rotate the transform around a world-space `point` by the given rotation. Both the
+    /// translation and the orientation are affected, so an orbiting object keeps facing the
+    /// same way relative to the point.
+    #[inline]
+    pub fn rotate_around(&mut self, point: Vec3, rotation: Quat) {
+        self.translate_around(point, rotation);
+        self.rotation = rotation * self.rotation;
+    }
+
+    /// Move the translation around a world-space `point` by the given rotation, leaving the
+    /// orientation untouched.
+    #[inline]
+    pub fn translate_around(&mut self, point: Vec3, rotation: Quat) {
+        self.translation = point + rotation * (self.translation - point);
+    }
+
+    /// Composes `self` (the parent) with a child `transform`. Scale is combined per axis, which is
+    /// exact as long as the child is not rotated relative to a non-uniformly scaled parent (that
+    /// combination would require shear, which a translation/rotation/scale triple can't express).
     #[inline]
     pub fn mul_transform(&self, transform: Transform) -> GlobalTransform {
         let translation = self.mul_vec3(transform.translation);
@@ -91,10 +149,13 @@ impl GlobalTransform {
         }
     }
 
+    /// Transforms a point: scale is applied first, then rotation, then translation. This matches
+    /// [compute_matrix](Self::compute_matrix), so non-uniform scale behaves the same on the CPU
+    /// and on the GPU.
     #[inline]
     pub fn mul_vec3(&self, mut value: Vec3) -> Vec3 {
-        value = self.rotation * value;
         value = self.scale * value;
+        value = self.rotation * value;
         value += self.translation;
         value
     }
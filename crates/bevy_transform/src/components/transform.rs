@@ -1,4 +1,4 @@
-use bevy_math::{Mat3, Mat4, Quat, Vec3};
+use bevy_math::{Mat3, Mat4, Quat, Vec2, Vec3};
 use bevy_property::Properties;
 use std::ops::Mul;
 
@@ -21,6 +21,17 @@ impl Transform {
         }
     }
 
+    /// Creates a 2d transform at `translation` rotated counter-clockwise by `angle` radians.
+    /// `z` selects the layer the entity is drawn on.
+    #[inline]
+    pub fn from_translation_2d(translation: Vec2, z: f32, angle: f32) -> Self {
+        Transform {
+            translation: translation.extend(z),
+            rotation: Quat::from_rotation_z(angle),
+            ..Default::default()
+        }
+    }
+
     #[inline]
     pub fn from_matrix(matrix: Mat4) -> Self {
         let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
@@ -32,6 +43,13 @@ impl Transform {
         }
     }
 
+    /// Creates a new [Transform] at the position `(x, y, z)`. In 2d, the `z` component determines
+    /// the draw order: entities with a greater `z` are drawn on top.
+    #[inline]
+    pub fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        Self::from_translation(Vec3::new(x, y, z))
+    }
+
     #[inline]
     pub fn from_translation(translation: Vec3) -> Self {
         Transform {
@@ -68,17 +86,99 @@ impl Transform {
         Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
     }
 
+    /// Get the unit vector in the local x direction
     #[inline]
-    pub fn forward(&self) -> Vec3 {
+    pub fn local_x(&self) -> Vec3 {
+        self.rotation * Vec3::unit_x()
+    }
+
+    /// Get the unit vector in the local y direction
+    #[inline]
+    pub fn local_y(&self) -> Vec3 {
+        self.rotation * Vec3::unit_y()
+    }
+
+    /// Get the unit vector in the local z direction
+    #[inline]
+    pub fn local_z(&self) -> Vec3 {
         self.rotation * Vec3::unit_z()
     }
 
+    /// Get the unit vector pointing "forward" (the local -z direction). This is the direction
+    /// cameras look in and the direction [looking_at](Self::looking_at) points at the target.
+    #[inline]
+    pub fn forward(&self) -> Vec3 {
+        -self.local_z()
+    }
+
+    /// Get the unit vector pointing "right" (the local x direction)
+    #[inline]
+    pub fn right(&self) -> Vec3 {
+        self.local_x()
+    }
+
+    /// Get the unit vector pointing "up" (the local y direction)
+    #[inline]
+    pub fn up(&self) -> Vec3 {
+        self.local_y()
+    }
+
+    /// The x and y components of the translation
+    #[inline]
+    pub fn translation_2d(&self) -> Vec2 {
+        self.translation.truncate()
+    }
+
+    /// Sets the x and y components of the translation, keeping `z` (the layer) unchanged
+    #[inline]
+    pub fn set_translation_2d(&mut self, translation: Vec2) {
+        self.translation = translation.extend(self.translation.z);
+    }
+
+    /// The counter-clockwise rotation around the z axis in radians, in `-PI..=PI`
+    #[inline]
+    pub fn rotation_2d(&self) -> f32 {
+        let local_x = self.local_x();
+        local_x.y.atan2(local_x.x)
+    }
+
+    /// Replaces the rotation with a counter-clockwise rotation of `angle` radians around the z axis
+    #[inline]
+    pub fn set_rotation_2d(&mut self, angle: f32) {
+        self.rotation = Quat::from_rotation_z(angle);
+    }
+
+    /// Rotates counter-clockwise by `angle` radians around the z axis
+    #[inline]
+    pub fn rotate_2d(&mut self, angle: f32) {
+        self.rotate(Quat::from_rotation_z(angle));
+    }
+
     #[inline]
     /// Rotate the transform by the given rotation
     pub fn rotate(&mut self, rotation: Quat) {
         self.rotation *= rotation;
     }
 
+    /// Rotate the transform around a world-space `point` by the given rotation. Both the
+    /// translation and the orientation are affected, so an orbiting object keeps facing the
+    /// same way relative to the point.
+    #[inline]
+    pub fn rotate_around(&mut self, point: Vec3, rotation: Quat) {
+        self.translate_around(point, rotation);
+        self.rotation = rotation * self.rotation;
+    }
+
+    /// Move the translation around a world-space `point` by the given rotation, leaving the
+    /// orientation untouched.
+    #[inline]
+    pub fn translate_around(&mut self, point: Vec3, rotation: Quat) {
+        self.translation = point + rotation * (self.translation - point);
+    }
+
+    /// Composes `self` (the parent) with a child `transform`. Scale is combined per axis, which is
+    /// exact as long as the child is not rotated relative to a non-uniformly scaled parent (that
+    /// combination would require shear, which a translation/rotation/scale triple can't express).
     #[inline]
     pub fn mul_transform(&self, transform: Transform) -> Self {
         let translation = self.mul_vec3(transform.translation);
@@ -91,10 +191,13 @@ impl Transform {
         }
     }
 
+    /// Transforms a point: scale is applied first, then rotation, then translation. This matches
+    /// [compute_matrix](Self::compute_matrix), so non-uniform scale behaves the same on the CPU
+    /// and on the GPU.
     #[inline]
     pub fn mul_vec3(&self, mut value: Vec3) -> Vec3 {
-        value = self.rotation * value;
         value = self.scale * value;
+        value = self.rotation * value;
         value += self.translation;
         value
     }
@@ -144,3 +247,60 @@ impl Mul<Vec3> for Transform {
         self.mul_vec3(value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_approx_eq(a: Vec3, b: Vec3) {
+        assert!((a - b).length() < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn looking_at_points_forward_at_target() {
+        let transform = Transform::from_translation(Vec3::new(0.0, 0.0, 5.0))
+            .looking_at(Vec3::new(5.0, 0.0, 5.0), Vec3::unit_y());
+        assert_approx_eq(transform.forward(), Vec3::unit_x());
+        assert_approx_eq(transform.up(), Vec3::unit_y());
+        assert_approx_eq(transform.right(), Vec3::unit_z());
+    }
+
+    #[test]
+    fn rotate_around_point() {
+        let mut transform = Transform::from_translation(Vec3::new(2.0, 0.0, 0.0));
+        let rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        transform.rotate_around(Vec3::new(1.0, 0.0, 0.0), rotation);
+        assert_approx_eq(transform.translation, Vec3::new(1.0, 0.0, -1.0));
+        assert_approx_eq(transform.local_x(), rotation * Vec3::unit_x());
+    }
+
+    #[test]
+    fn non_uniform_scale_matches_matrix() {
+        let parent = Transform {
+            translation: Vec3::new(1.0, 2.0, 3.0),
+            rotation: Quat::from_rotation_z(0.5),
+            scale: Vec3::new(2.0, 1.0, 0.5),
+        };
+        let child = Transform::from_translation(Vec3::new(1.0, 1.0, 1.0));
+        let point = Vec3::new(0.5, -1.0, 2.0);
+        assert_approx_eq(
+            parent.mul_vec3(point),
+            parent.compute_matrix().transform_point3(point),
+        );
+        assert_approx_eq(
+            parent.mul_transform(child).translation,
+            parent.compute_matrix().transform_point3(child.translation),
+        );
+    }
+
+    #[test]
+    fn transform_2d() {
+        let mut transform = Transform::from_translation_2d(Vec2::new(1.0, 2.0), 3.0, 0.5);
+        assert!((transform.rotation_2d() - 0.5).abs() < 1e-5);
+        transform.rotate_2d(0.25);
+        assert!((transform.rotation_2d() - 0.75).abs() < 1e-5);
+        transform.set_translation_2d(Vec2::new(4.0, 5.0));
+        assert_eq!(transform.translation, Vec3::new(4.0, 5.0, 3.0));
+        assert_eq!(transform.translation_2d(), Vec2::new(4.0, 5.0));
+    }
+}
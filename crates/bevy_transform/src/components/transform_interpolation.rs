@@ -0,0 +1,50 @@
+use super::Transform;
+use bevy_property::Properties;
+
+/// Smooths the rendered motion of an entity whose [Transform] is only updated on a fixed
+/// timestep.
+///
+/// The last two simulated transforms are recorded whenever a fixed step has run, and the entity's
+/// [GlobalTransform](super::GlobalTransform) is set to a blend of the two based on how far the
+/// current frame is into the next step. The [Transform] itself always holds the simulated value.
+#[derive(Debug, Clone, Copy, Properties)]
+pub struct TransformInterpolation {
+    pub previous: Transform,
+    pub current: Transform,
+    #[property(ignore)]
+    pub(crate) last_step: Option<u64>,
+}
+
+impl TransformInterpolation {
+    pub fn new(transform: Transform) -> Self {
+        TransformInterpolation {
+            previous: transform,
+            current: transform,
+            last_step: None,
+        }
+    }
+
+    /// Records a newly simulated transform, shifting the current one into `previous`.
+    pub fn push(&mut self, transform: Transform) {
+        self.previous = self.current;
+        self.current = transform;
+    }
+
+    /// Blends `previous` and `current`. `alpha` of 0.0 yields `previous`, 1.0 yields `current`.
+    pub fn interpolate(&self, alpha: f32) -> Transform {
+        Transform {
+            translation: self
+                .previous
+                .translation
+                .lerp(self.current.translation, alpha),
+            rotation: self.previous.rotation.lerp(self.current.rotation, alpha),
+            scale: self.previous.scale.lerp(self.current.scale, alpha),
+        }
+    }
+}
+
+impl Default for TransformInterpolation {
+    fn default() -> Self {
+        TransformInterpolation::new(Transform::identity())
+    }
+}
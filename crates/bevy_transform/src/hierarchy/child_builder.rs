@@ -1,5 +1,6 @@
-use crate::prelude::{Children, Parent, PreviousParent};
+use crate::prelude::{Children, GlobalTransform, Parent, PreviousParent, Transform};
 use bevy_ecs::{Command, Commands, Component, DynamicBundle, Entity, Resources, World};
+use bevy_utils::tracing::debug;
 use smallvec::SmallVec;
 
 #[derive(Debug)]
@@ -68,6 +69,90 @@ impl Command for PushChildren {
     }
 }
 
+/// Moves `entity` under `parent` while keeping its current world-space transform
+#[derive(Debug)]
+pub struct SetParentInPlace {
+    entity: Entity,
+    parent: Entity,
+}
+
+impl Command for SetParentInPlace {
+    fn write(self: Box<Self>, world: &mut World, _resources: &mut Resources) {
+        if !world.contains(self.parent) {
+            debug!(
+                "Failed to set the parent of entity {:?}: parent {:?} does not exist",
+                self.entity, self.parent
+            );
+            return;
+        }
+
+        // compute the local transform that reproduces the current world transform under the new
+        // parent
+        let local_transform = match (
+            world.get::<GlobalTransform>(self.entity),
+            world.get::<GlobalTransform>(self.parent),
+        ) {
+            (Ok(global_transform), Ok(parent_global_transform)) => Some(Transform::from_matrix(
+                parent_global_transform.compute_matrix().inverse()
+                    * global_transform.compute_matrix(),
+            )),
+            _ => None,
+        };
+        let previous_parent = world.get::<Parent>(self.entity).map(|parent| parent.0).ok();
+
+        if let Err(e) = world.insert(
+            self.entity,
+            (Parent(self.parent), PreviousParent(self.parent)),
+        ) {
+            debug!(
+                "Failed to set the parent of entity {:?}: {}",
+                self.entity, e
+            );
+            return;
+        }
+
+        if let Some(previous_parent) = previous_parent {
+            if let Ok(mut previous_children) = world.get_mut::<Children>(previous_parent) {
+                previous_children.0.retain(|e| *e != self.entity);
+            }
+        }
+
+        if let Some(local_transform) = local_transform {
+            if let Ok(mut transform) = world.get_mut::<Transform>(self.entity) {
+                *transform = local_transform;
+            }
+        }
+
+        {
+            let mut added = false;
+            if let Ok(mut children) = world.get_mut::<Children>(self.parent) {
+                children.0.push(self.entity);
+                added = true;
+            }
+
+            // NOTE: ideally this is just an else statement, but currently that _incorrectly_ fails borrow-checking
+            if !added {
+                if let Err(e) = world.insert_one(self.parent, Children::with(&[self.entity])) {
+                    debug!("Failed to add children to entity {:?}: {}", self.parent, e);
+                }
+            }
+        }
+    }
+}
+
+pub trait SetParentInPlaceExt {
+    /// Makes `entity` a child of `parent`, recomputing its local [Transform] so that it keeps the
+    /// world-space position, rotation and scale it had before being moved. This uses the
+    /// [GlobalTransform]s computed during the last transform propagation.
+    fn set_parent_in_place(&mut self, entity: Entity, parent: Entity) -> &mut Self;
+}
+
+impl SetParentInPlaceExt for Commands {
+    fn set_parent_in_place(&mut self, entity: Entity, parent: Entity) -> &mut Self {
+        self.add_command(SetParentInPlace { entity, parent })
+    }
+}
+
 impl<'a> ChildBuilder<'a> {
     pub fn spawn(&mut self, components: impl DynamicBundle + Send + Sync + 'static) -> &mut Self {
         self.commands.spawn(components);
@@ -191,9 +276,10 @@ impl<'a> BuildChildren for ChildBuilder<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::BuildChildren;
-    use crate::prelude::{Children, Parent, PreviousParent};
+    use super::{BuildChildren, SetParentInPlaceExt};
+    use crate::prelude::{Children, GlobalTransform, Parent, PreviousParent, Transform};
     use bevy_ecs::{Commands, Entity, Resources, World};
+    use bevy_math::{Quat, Vec3};
     use smallvec::{smallvec, SmallVec};
 
     #[test]
@@ -300,4 +386,49 @@ mod tests {
             PreviousParent(parent)
         );
     }
+
+    #[test]
+    fn set_parent_in_place() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        let mut commands = Commands::default();
+
+        let parent_transform = Transform {
+            translation: Vec3::new(1.0, 0.0, 0.0),
+            rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+            scale: Vec3::new(2.0, 2.0, 2.0),
+        };
+        let child_transform = Transform::from_translation(Vec3::new(3.0, 4.0, 5.0));
+        let parent = world.spawn((parent_transform, GlobalTransform::from(parent_transform)));
+        let child = world.spawn((child_transform, GlobalTransform::from(child_transform)));
+
+        commands.set_parent_in_place(child, parent);
+        commands.apply(&mut world, &mut resources);
+
+        assert_eq!(*world.get::<Parent>(child).unwrap(), Parent(parent));
+        assert_eq!(
+            world.get::<Children>(parent).unwrap().0.as_slice(),
+            &[child]
+        );
+
+        let local_transform = *world.get::<Transform>(child).unwrap();
+        let world_transform = parent_transform.mul_transform(local_transform);
+        assert!((world_transform.translation - child_transform.translation).length() < 1e-4);
+    }
+
+    #[test]
+    fn set_parent_in_place_missing_parent() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        let mut commands = Commands::default();
+
+        let parent = world.spawn((Transform::identity(), GlobalTransform::identity()));
+        let child = world.spawn((Transform::identity(), GlobalTransform::identity()));
+        world.despawn(parent).unwrap();
+
+        commands.set_parent_in_place(child, parent);
+        commands.apply(&mut world, &mut resources);
+
+        assert!(world.get::<Parent>(child).is_err());
+    }
 }
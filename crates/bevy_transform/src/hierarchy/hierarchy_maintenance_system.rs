@@ -70,7 +70,10 @@ pub fn parent_update_system(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{hierarchy::BuildChildren, transform_propagate_system::transform_propagate_system};
+    use crate::{
+        hierarchy::BuildChildren,
+        transform_propagate_system::{transform_propagate_system, TransformPropagationState},
+    };
     use bevy_ecs::{Resources, Schedule, World};
     use bevy_math::Vec3;
 
@@ -78,6 +81,7 @@ mod test {
     fn correct_children() {
         let mut world = World::default();
         let mut resources = Resources::default();
+        resources.insert(TransformPropagationState::default());
 
         let mut schedule = Schedule::default();
         schedule.add_stage("update");
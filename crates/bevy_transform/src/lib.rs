@@ -1,5 +1,6 @@
 pub mod components;
 pub mod hierarchy;
+pub mod transform_interpolation_system;
 pub mod transform_propagate_system;
 
 pub mod prelude {
@@ -8,7 +9,11 @@ pub mod prelude {
 
 use bevy_app::prelude::*;
 use bevy_type_registry::RegisterType;
-use prelude::{parent_update_system, Children, GlobalTransform, Parent, PreviousParent, Transform};
+use transform_propagate_system::TransformPropagationState;
+use prelude::{
+    parent_update_system, Children, GlobalTransform, Parent, PreviousParent, Transform,
+    TransformInterpolation,
+};
 
 #[derive(Default)]
 pub struct TransformPlugin;
@@ -20,6 +25,8 @@ impl Plugin for TransformPlugin {
             .register_component_with::<PreviousParent>(|reg| reg.map_entities())
             .register_component::<Transform>()
             .register_component::<GlobalTransform>()
+            .register_component::<TransformInterpolation>()
+            .init_resource::<TransformPropagationState>()
             // add transform systems to startup so the first update is "correct"
             .add_startup_system(parent_update_system)
             .add_startup_system(transform_propagate_system::transform_propagate_system)
@@ -27,6 +34,14 @@ impl Plugin for TransformPlugin {
             .add_system_to_stage(
                 stage::POST_UPDATE,
                 transform_propagate_system::transform_propagate_system,
+            )
+            .add_system_to_stage(
+                stage::POST_UPDATE,
+                transform_interpolation_system::transform_interpolation_system,
+            )
+            .add_system_to_stage(
+                stage::LAST,
+                transform_propagate_system::mark_late_transform_changes_system,
             );
     }
 }
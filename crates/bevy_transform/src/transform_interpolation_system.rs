@@ -0,0 +1,43 @@
+use crate::components::*;
+use bevy_core::FixedTime;
+use bevy_ecs::prelude::*;
+
+/// Records new simulated transforms for entities with a [TransformInterpolation] and writes the
+/// interpolated result into their [GlobalTransform]. Runs after `transform_propagate_system` so
+/// it overrides the propagated value.
+///
+/// Only the entity's own [GlobalTransform] is interpolated: children keep following the simulated
+/// transform of their parent.
+pub fn transform_interpolation_system(
+    fixed_time: Res<FixedTime>,
+    mut interpolation_query: Query<(
+        Entity,
+        &Transform,
+        &mut TransformInterpolation,
+        Option<&Parent>,
+    )>,
+    mut global_transform_query: Query<&mut GlobalTransform>,
+) {
+    let alpha = fixed_time.alpha().min(1.0);
+    for (entity, transform, mut interpolation, parent) in interpolation_query.iter_mut() {
+        match interpolation.last_step {
+            None => *interpolation = TransformInterpolation::new(*transform),
+            Some(step) if step != fixed_time.steps() => interpolation.push(*transform),
+            _ => {}
+        }
+        interpolation.last_step = Some(fixed_time.steps());
+
+        let local = interpolation.interpolate(alpha);
+        let global = match parent {
+            Some(parent) => match global_transform_query.get_mut(parent.0) {
+                Ok(parent_global) => parent_global.mul_transform(local),
+                Err(_) => continue,
+            },
+            None => GlobalTransform::from(local),
+        };
+
+        if let Ok(mut global_transform) = global_transform_query.get_mut(entity) {
+            *global_transform = global;
+        }
+    }
+}
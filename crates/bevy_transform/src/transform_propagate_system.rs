@@ -1,39 +1,153 @@
 use crate::components::*;
 use bevy_ecs::prelude::*;
+use bevy_utils::{HashMap, HashSet};
 
+/// Bookkeeping that lets [transform_propagate_system] pick up [Transform] changes made after it
+/// ran. Change trackers are cleared at the end of every frame, so a [Transform] written later in
+/// the frame would otherwise never reach its [GlobalTransform].
+#[derive(Debug, Default)]
+pub struct TransformPropagationState {
+    propagated: HashMap<Entity, Transform>,
+    dirty: HashSet<Entity>,
+}
+
+/// Updates [GlobalTransform] for every entity whose [Transform] changed since the last
+/// propagation, along with all of its descendants. Entities that were (re)parented this frame are
+/// recomputed relative to their new parent's [GlobalTransform], and entities whose [Parent] was
+/// removed become roots again. Subtrees without changes are skipped.
 pub fn transform_propagate_system(
+    mut state: ResMut<TransformPropagationState>,
     mut root_query: Query<
-        (Option<&Children>, &Transform, &mut GlobalTransform),
+        (Entity, Option<&Children>, &Transform, &mut GlobalTransform),
         (Without<Parent>, With<GlobalTransform>),
     >,
     mut transform_query: Query<(&Transform, &mut GlobalTransform), With<Parent>>,
+    changed_transform_query: Query<Entity, Changed<Transform>>,
+    reparented_query: Query<(Entity, &Parent), Changed<Parent>>,
     children_query: Query<Option<&Children>, (With<Parent>, With<GlobalTransform>)>,
 ) {
-    for (children, transform, mut global_transform) in root_query.iter_mut() {
+    let state = &mut *state;
+    let dirty = std::mem::take(&mut state.dirty);
+    let changes = Changes {
+        changed_transform_query: &changed_transform_query,
+        dirty: &dirty,
+    };
+
+    for (entity, children, transform, mut global_transform) in root_query.iter_mut() {
+        let mut changed = false;
+        if changes.contains(entity) {
+            *global_transform = GlobalTransform::from(*transform);
+            state.propagated.insert(entity, *transform);
+            changed = true;
+        }
+
+        if let Some(children) = children {
+            for child in children.0.iter() {
+                propagate_recursive(
+                    &global_transform,
+                    &changes,
+                    &mut state.propagated,
+                    &mut transform_query,
+                    &children_query,
+                    *child,
+                    changed,
+                );
+            }
+        }
+    }
+
+    // entities that were detached from their parent are roots now, so their global transform is
+    // their local transform again
+    for entity in root_query.removed::<Parent>().to_vec() {
+        let (_, children, transform, mut global_transform) = match root_query.get_mut(entity) {
+            Ok(root) => root,
+            Err(_) => continue,
+        };
         *global_transform = GlobalTransform::from(*transform);
+        state.propagated.insert(entity, *transform);
 
         if let Some(children) = children {
             for child in children.0.iter() {
                 propagate_recursive(
                     &global_transform,
+                    &changes,
+                    &mut state.propagated,
                     &mut transform_query,
                     &children_query,
                     *child,
+                    true,
                 );
             }
         }
     }
+
+    // the parent's `Children` may not have been updated yet for entities that were just
+    // parented, so walk those subtrees starting from the child instead
+    for (entity, parent) in reparented_query.iter() {
+        let parent_global_transform =
+            if let Ok((_, _, _, global_transform)) = root_query.get_mut(parent.0) {
+                *global_transform
+            } else if let Ok((_, global_transform)) = transform_query.get_mut(parent.0) {
+                *global_transform
+            } else {
+                continue;
+            };
+
+        propagate_recursive(
+            &parent_global_transform,
+            &changes,
+            &mut state.propagated,
+            &mut transform_query,
+            &children_query,
+            entity,
+            true,
+        );
+    }
+}
+
+/// Runs at the end of the frame and remembers every [Transform] that changed after
+/// [transform_propagate_system] ran, so the next propagation still treats it as changed.
+pub fn mark_late_transform_changes_system(
+    mut state: ResMut<TransformPropagationState>,
+    changed_transform_query: Query<(Entity, &Transform), Changed<Transform>>,
+) {
+    let state = &mut *state;
+    for (entity, transform) in changed_transform_query.iter() {
+        if state.propagated.get(&entity) != Some(transform) {
+            state.dirty.insert(entity);
+        }
+    }
+    state.propagated.clear();
+}
+
+struct Changes<'a, 'b> {
+    changed_transform_query: &'a Query<'b, Entity, Changed<Transform>>,
+    dirty: &'a HashSet<Entity>,
+}
+
+impl<'a, 'b> Changes<'a, 'b> {
+    fn contains(&self, entity: Entity) -> bool {
+        self.dirty.contains(&entity) || self.changed_transform_query.get(entity).is_ok()
+    }
 }
 
 fn propagate_recursive(
     parent: &GlobalTransform,
+    changes: &Changes,
+    propagated: &mut HashMap<Entity, Transform>,
     transform_query: &mut Query<(&Transform, &mut GlobalTransform), With<Parent>>,
     children_query: &Query<Option<&Children>, (With<Parent>, With<GlobalTransform>)>,
     entity: Entity,
+    mut changed: bool,
 ) {
+    changed |= changes.contains(entity);
+
     let global_matrix = {
         if let Ok((transform, mut global_transform)) = transform_query.get_mut(entity) {
-            *global_transform = parent.mul_transform(*transform);
+            if changed {
+                *global_transform = parent.mul_transform(*transform);
+                propagated.insert(entity, *transform);
+            }
             *global_transform
         } else {
             return;
@@ -42,7 +156,15 @@ fn propagate_recursive(
 
     if let Ok(Some(children)) = children_query.get(entity) {
         for child in children.0.iter() {
-            propagate_recursive(&global_matrix, transform_query, children_query, *child);
+            propagate_recursive(
+                &global_matrix,
+                changes,
+                propagated,
+                transform_query,
+                children_query,
+                *child,
+                changed,
+            );
         }
     }
 }
@@ -58,6 +180,7 @@ mod test {
     fn did_propagate() {
         let mut world = World::default();
         let mut resources = Resources::default();
+        resources.insert(TransformPropagationState::default());
 
         let mut schedule = Schedule::default();
         schedule.add_stage("update");
@@ -107,6 +230,7 @@ mod test {
     fn did_propagate_command_buffer() {
         let mut world = World::default();
         let mut resources = Resources::default();
+        resources.insert(TransformPropagationState::default());
 
         let mut schedule = Schedule::default();
         schedule.add_stage("update");
@@ -151,4 +275,140 @@ mod test {
                 * Transform::from_translation(Vec3::new(0.0, 0.0, 3.0))
         );
     }
+
+    #[test]
+    fn detached_child_becomes_root() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        resources.insert(TransformPropagationState::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("update");
+        schedule.add_system_to_stage("update", parent_update_system);
+        schedule.add_system_to_stage("update", transform_propagate_system);
+
+        let mut commands = Commands::default();
+        commands.set_entity_reserver(world.get_entity_reserver());
+        let mut children = Vec::new();
+        commands
+            .spawn((
+                Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+                GlobalTransform::identity(),
+            ))
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        Transform::from_translation(Vec3::new(0.0, 2.0, 0.0)),
+                        GlobalTransform::identity(),
+                    ))
+                    .for_current_entity(|entity| children.push(entity));
+            });
+        commands.apply(&mut world, &mut resources);
+        schedule.initialize(&mut world, &mut resources);
+        schedule.run(&mut world, &mut resources);
+
+        world.remove_one::<Parent>(children[0]).unwrap();
+        schedule.run(&mut world, &mut resources);
+
+        assert_eq!(
+            *world.get::<GlobalTransform>(children[0]).unwrap(),
+            GlobalTransform::from_translation(Vec3::new(0.0, 2.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn only_propagates_changed_subtrees() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        resources.insert(TransformPropagationState::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("update");
+        schedule.add_system_to_stage("update", parent_update_system);
+        schedule.add_system_to_stage("update", transform_propagate_system);
+
+        let mut commands = Commands::default();
+        commands.set_entity_reserver(world.get_entity_reserver());
+        let mut parent = None;
+        let mut children = Vec::new();
+        commands
+            .spawn((
+                Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+                GlobalTransform::identity(),
+            ))
+            .for_current_entity(|entity| parent = Some(entity))
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        Transform::from_translation(Vec3::new(0.0, 2.0, 0.0)),
+                        GlobalTransform::identity(),
+                    ))
+                    .for_current_entity(|entity| children.push(entity));
+            });
+        commands.apply(&mut world, &mut resources);
+        schedule.initialize(&mut world, &mut resources);
+        schedule.run(&mut world, &mut resources);
+
+        // nothing changed, so the (bogus) global transform is left alone
+        let marker = GlobalTransform::from_translation(Vec3::new(9.0, 9.0, 9.0));
+        *world.get_mut::<GlobalTransform>(children[0]).unwrap() = marker;
+        schedule.run(&mut world, &mut resources);
+        assert_eq!(*world.get::<GlobalTransform>(children[0]).unwrap(), marker);
+
+        // moving the parent recomputes the whole subtree
+        world
+            .get_mut::<Transform>(parent.unwrap())
+            .unwrap()
+            .translation = Vec3::new(3.0, 0.0, 0.0);
+        schedule.run(&mut world, &mut resources);
+        assert_eq!(
+            *world.get::<GlobalTransform>(children[0]).unwrap(),
+            GlobalTransform::from_translation(Vec3::new(3.0, 0.0, 0.0))
+                * Transform::from_translation(Vec3::new(0.0, 2.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn propagates_transforms_changed_after_propagation() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        resources.insert(TransformPropagationState::default());
+
+        fn late_move_system(mut moved: Local<bool>, mut query: Query<&mut Transform>) {
+            if !*moved {
+                for mut transform in query.iter_mut() {
+                    transform.translation = Vec3::new(5.0, 0.0, 0.0);
+                }
+                *moved = true;
+            }
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("update");
+        schedule.add_stage("late");
+        schedule.add_stage("last");
+        schedule.add_system_to_stage("update", parent_update_system);
+        schedule.add_system_to_stage("update", transform_propagate_system);
+        schedule.add_system_to_stage("late", late_move_system);
+        schedule.add_system_to_stage("last", mark_late_transform_changes_system);
+
+        let entity = world.spawn((
+            Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+            GlobalTransform::identity(),
+        ));
+        schedule.initialize(&mut world, &mut resources);
+
+        // the move happens after propagation, so it only shows up on the next frame
+        schedule.run(&mut world, &mut resources);
+        assert_eq!(
+            *world.get::<GlobalTransform>(entity).unwrap(),
+            GlobalTransform::from_translation(Vec3::new(1.0, 0.0, 0.0))
+        );
+
+        schedule.run(&mut world, &mut resources);
+        assert_eq!(
+            *world.get::<GlobalTransform>(entity).unwrap(),
+            GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0))
+        );
+    }
 }
@@ -3,7 +3,8 @@ use bevy_ecs::{
     Resources, World,
 };
 use bevy_property::{
-    DeserializeProperty, Properties, Property, PropertyTypeRegistration, PropertyTypeRegistry,
+    DeserializeProperty, DynamicProperties, Properties, Property, PropertyTypeRegistration,
+    PropertyTypeRegistry,
 };
 use bevy_utils::{HashMap, HashSet};
 use parking_lot::RwLock;
@@ -78,6 +79,8 @@ impl ComponentRegistry {
     }
 }
 
+/// Type-erased operations on a component type, which let scenes, save games and tools create,
+/// read, modify and remove components they only know the name of
 #[derive(Clone)]
 pub struct ComponentRegistration {
     pub ty: TypeId,
@@ -86,6 +89,10 @@ pub struct ComponentRegistration {
     component_add_fn: fn(&mut World, resources: &Resources, Entity, &dyn Property),
     component_apply_fn: fn(&mut World, Entity, &dyn Property),
     component_properties_fn: fn(&Archetype, usize) -> &dyn Properties,
+    component_default_fn: fn(&Resources) -> Box<dyn Property>,
+    entity_properties_fn: fn(&World, Entity) -> Option<&dyn Properties>,
+    entity_properties_mut_fn: fn(&mut World, Entity, &mut dyn FnMut(&mut dyn Properties)) -> bool,
+    component_remove_fn: fn(&mut World, Entity),
     component_copy_fn: fn(&World, &mut World, &Resources, Entity, Entity),
     copy_to_scene_fn: fn(&World, &mut World, &Resources, Entity, Entity),
     copy_from_scene_fn: fn(&World, &mut World, &Resources, Entity, Entity),
@@ -141,6 +148,40 @@ impl ComponentRegistrationDefaults {
         }
     }
 
+    fn component_default<T: Component + Properties + FromResources>(
+        resources: &Resources,
+    ) -> Box<dyn Property> {
+        Box::new(T::from_resources(resources))
+    }
+
+    fn entity_properties<T: Component + Properties>(
+        world: &World,
+        entity: Entity,
+    ) -> Option<&dyn Properties> {
+        world
+            .get::<T>(entity)
+            .ok()
+            .map(|component| component as &dyn Properties)
+    }
+
+    fn entity_properties_mut<T: Component + Properties>(
+        world: &mut World,
+        entity: Entity,
+        f: &mut dyn FnMut(&mut dyn Properties),
+    ) -> bool {
+        match world.get_mut::<T>(entity) {
+            Ok(mut component) => {
+                f(&mut *component);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn component_remove<T: Component>(world: &mut World, entity: Entity) {
+        let _ = world.remove_one::<T>(entity);
+    }
+
     // NOTE: this code will be removed soon, so we're just suppressing the new clippy lint
     #[allow(clippy::clippy::unnecessary_wraps)]
     fn map_entities(_world: &mut World, _entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
@@ -167,6 +208,10 @@ impl ComponentRegistration {
             component_apply_fn: ComponentRegistrationDefaults::component_apply::<T>,
             component_copy_fn: ComponentRegistrationDefaults::component_copy::<T>,
             component_properties_fn: ComponentRegistrationDefaults::component_properties::<T>,
+            component_default_fn: ComponentRegistrationDefaults::component_default::<T>,
+            entity_properties_fn: ComponentRegistrationDefaults::entity_properties::<T>,
+            entity_properties_mut_fn: ComponentRegistrationDefaults::entity_properties_mut::<T>,
+            component_remove_fn: ComponentRegistrationDefaults::component_remove::<T>,
             copy_from_scene_fn: ComponentRegistrationDefaults::component_copy::<T>,
             copy_to_scene_fn: ComponentRegistrationDefaults::component_copy::<T>,
             map_entities_fn: ComponentRegistrationDefaults::map_entities,
@@ -202,6 +247,100 @@ impl ComponentRegistration {
         (self.component_properties_fn)(archetype, entity_index)
     }
 
+    /// Creates the component with its [FromResources] (or [Default]) value
+    pub fn create_default(&self, resources: &Resources) -> Box<dyn Property> {
+        (self.component_default_fn)(resources)
+    }
+
+    /// Adds the component with its [FromResources] (or [Default]) value to `entity`
+    pub fn add_default_to_entity(&self, world: &mut World, resources: &Resources, entity: Entity) {
+        let component = self.create_default(resources);
+        self.add_property_to_entity(world, resources, entity, &*component);
+    }
+
+    /// Returns the component of `entity`, or `None` if it doesn't have one
+    pub fn get_entity_properties<'a>(
+        &self,
+        world: &'a World,
+        entity: Entity,
+    ) -> Option<&'a dyn Properties> {
+        (self.entity_properties_fn)(world, entity)
+    }
+
+    /// Calls `f` with the component of `entity`, which marks it as mutated. Returns `false` if the
+    /// entity doesn't have the component.
+    pub fn with_entity_properties_mut(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        f: &mut dyn FnMut(&mut dyn Properties),
+    ) -> bool {
+        (self.entity_properties_mut_fn)(world, entity, f)
+    }
+
+    /// Returns a copy of the field called `name` of the component of `entity`
+    pub fn get_entity_field(
+        &self,
+        world: &World,
+        entity: Entity,
+        name: &str,
+    ) -> Option<Box<dyn Property>> {
+        self.get_entity_properties(world, entity)?
+            .prop(name)
+            .map(|field| field.clone_prop())
+    }
+
+    /// Sets the field called `name` of the component of `entity`. Returns `false` if the entity
+    /// doesn't have the component, the component doesn't have the field, or `value` doesn't have
+    /// the field's type.
+    pub fn set_entity_field(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        name: &str,
+        value: &dyn Property,
+    ) -> bool {
+        // check the field without borrowing the component mutably, which would mark it as
+        // changed even if nothing is set
+        let can_set = match self
+            .get_entity_properties(world, entity)
+            .and_then(|properties| properties.prop(name))
+        {
+            // Property::set panics on values of another type
+            Some(field) => {
+                field.any().type_id() == value.any().type_id()
+                    || (value.as_properties().is_some()
+                        && field.property_type() == value.property_type())
+            }
+            None => false,
+        };
+        if !can_set {
+            return false;
+        }
+
+        self.with_entity_properties_mut(world, entity, &mut |properties| {
+            if let Some(field) = properties.prop_mut(name) {
+                field.set(value);
+            }
+        })
+    }
+
+    /// Copies the component of `entity` into [DynamicProperties], which can be serialized with
+    /// [Property::serializable] and added back with [ComponentRegistration::add_property_to_entity]
+    pub fn to_dynamic_properties(
+        &self,
+        world: &World,
+        entity: Entity,
+    ) -> Option<DynamicProperties> {
+        self.get_entity_properties(world, entity)
+            .map(|properties| properties.to_dynamic())
+    }
+
+    /// Removes the component from `entity`, if it has one
+    pub fn remove_from_entity(&self, world: &mut World, entity: Entity) {
+        (self.component_remove_fn)(world, entity);
+    }
+
     pub fn component_copy(
         &self,
         source_world: &World,
@@ -334,3 +473,68 @@ where
 pub trait IntoComponent<ToComponent: Component> {
     fn into_component(&self, resources: &Resources) -> ToComponent;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentRegistration;
+    use bevy_ecs::{Entity, Mutated, Resources, World};
+    use bevy_property::{Properties, PropertiesVal, Property};
+
+    #[derive(Properties, Default)]
+    struct Health {
+        value: f32,
+        max: f32,
+    }
+
+    #[test]
+    fn entity_components_by_registration() {
+        let registration = ComponentRegistration::of::<Health>();
+        let resources = Resources::default();
+        let mut world = World::new();
+        let entity = world.spawn(());
+
+        // add a default component, then patch it
+        registration.add_default_to_entity(&mut world, &resources, entity);
+        assert!(registration.set_entity_field(&mut world, entity, "max", &100.0f32));
+        assert!(!registration.set_entity_field(&mut world, entity, "missing", &1.0f32));
+        assert!(!registration.set_entity_field(&mut world, entity, "max", &1.0f64));
+        assert_eq!(world.get::<Health>(entity).unwrap().max, 100.0);
+
+        let max = registration
+            .get_entity_field(&world, entity, "max")
+            .unwrap();
+        assert_eq!(max.any().downcast_ref::<f32>(), Some(&100.0));
+
+        let dynamic = registration.to_dynamic_properties(&world, entity).unwrap();
+        assert_eq!(dynamic.prop_val::<f32>("max"), Some(&100.0));
+        assert_eq!(dynamic.prop_len(), 2);
+
+        registration.remove_from_entity(&mut world, entity);
+        assert!(registration.get_entity_properties(&world, entity).is_none());
+    }
+
+    #[test]
+    fn failed_field_sets_leave_the_component_unchanged() {
+        let registration = ComponentRegistration::of::<Health>();
+        let mut world = World::new();
+        let entity = world.spawn((Health::default(),));
+        world.clear_trackers();
+
+        assert!(!registration.set_entity_field(&mut world, entity, "missing", &1.0f32));
+        assert!(!registration.set_entity_field(&mut world, entity, "max", &1.0f64));
+        assert_eq!(
+            world
+                .query_filtered::<Entity, Mutated<Health>>()
+                .collect::<Vec<_>>(),
+            vec![]
+        );
+
+        assert!(registration.set_entity_field(&mut world, entity, "max", &1.0f32));
+        assert_eq!(
+            world
+                .query_filtered::<Entity, Mutated<Health>>()
+                .collect::<Vec<_>>(),
+            vec![entity]
+        );
+    }
+}
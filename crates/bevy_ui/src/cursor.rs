@@ -0,0 +1,269 @@
+use crate::{entity::ImageBundle, FocusPolicy, Node, PositionType, Style};
+use bevy_app::{EventReader, Events};
+use bevy_asset::Handle;
+use bevy_ecs::{Commands, Entity, Local, Query, Res, ResMut};
+use bevy_math::Vec2;
+use bevy_render::draw::Draw;
+use bevy_sprite::ColorMaterial;
+use bevy_transform::components::Transform;
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+use bevy_window::{CursorEntered, CursorLeft, CursorMoved, WindowId, Windows};
+
+/// The z of cursor images, in front of all other nodes but behind the ui camera
+const CURSOR_Z: f32 = 999.0;
+
+/// An image that is drawn in place of the OS cursor
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorImage {
+    pub material: Handle<ColorMaterial>,
+    /// The point of the image that is at the cursor position, in pixels from its top left corner
+    pub hotspot: Vec2,
+}
+
+/// Replaces the cursors of windows with custom images, which can be changed at any time.
+///
+/// The OS cursor is hidden while a window has a cursor image, which is then drawn by the UI
+/// instead, and the image is hidden while the cursor is outside of the window. Standard cursor
+/// shapes can be set with `Window::set_cursor_icon`.
+///
+/// The image is a UI node, so it is only drawn while there is a UI camera, and it follows the
+/// cursor with a frame of lag. UI is currently only drawn in the primary window, so only the
+/// primary window's cursor image is drawn. Other windows keep their OS cursor even if they have a
+/// cursor image, and a warning is logged for them.
+#[derive(Debug, Default)]
+pub struct CursorImages {
+    images: HashMap<WindowId, CursorImage>,
+}
+
+impl CursorImages {
+    pub fn set(&mut self, window_id: WindowId, image: CursorImage) {
+        self.images.insert(window_id, image);
+    }
+
+    /// Removes the window's cursor image, showing the OS cursor again
+    pub fn remove(&mut self, window_id: WindowId) -> Option<CursorImage> {
+        self.images.remove(&window_id)
+    }
+
+    pub fn get(&self, window_id: WindowId) -> Option<&CursorImage> {
+        self.images.get(&window_id)
+    }
+}
+
+#[derive(Debug)]
+struct CursorImageNode {
+    entity: Entity,
+    image: Option<CursorImage>,
+    /// Whether the OS cursor was visible before it was replaced by the image
+    os_cursor_visible: bool,
+}
+
+#[derive(Default)]
+pub struct CursorImageState {
+    cursor_moved_event_reader: EventReader<CursorMoved>,
+    cursor_entered_event_reader: EventReader<CursorEntered>,
+    cursor_left_event_reader: EventReader<CursorLeft>,
+    /// The cursor position in each window the cursor is in
+    cursor_positions: HashMap<WindowId, Vec2>,
+    nodes: HashMap<WindowId, CursorImageNode>,
+    /// Windows other than the primary window that a warning was logged for
+    warned_windows: HashSet<WindowId>,
+}
+
+/// Draws the [CursorImages] at the cursor positions. Runs after the layout, which positions
+/// nodes itself.
+pub fn cursor_image_system(
+    commands: &mut Commands,
+    mut state: Local<CursorImageState>,
+    cursor_images: Res<CursorImages>,
+    cursor_moved_events: Res<Events<CursorMoved>>,
+    cursor_entered_events: Res<Events<CursorEntered>>,
+    cursor_left_events: Res<Events<CursorLeft>>,
+    mut windows: ResMut<Windows>,
+    mut node_query: Query<(&Node, &mut Transform, &mut Draw, &mut Handle<ColorMaterial>)>,
+) {
+    let state = &mut *state;
+    for event in state.cursor_moved_event_reader.iter(&cursor_moved_events) {
+        state.cursor_positions.insert(event.id, event.position);
+    }
+    // the cursor enters and leaves a window in turns, so it ends up outside of the windows it left
+    // more often than it entered
+    let mut crossings = HashMap::<WindowId, i32>::default();
+    for event in state
+        .cursor_entered_event_reader
+        .iter(&cursor_entered_events)
+    {
+        *crossings.entry(event.id).or_default() += 1;
+    }
+    for event in state.cursor_left_event_reader.iter(&cursor_left_events) {
+        *crossings.entry(event.id).or_default() -= 1;
+    }
+    for (window_id, crossings) in crossings {
+        if crossings < 0 {
+            state.cursor_positions.remove(&window_id);
+        }
+    }
+
+    for window_id in cursor_images.images.keys() {
+        if !window_id.is_primary() && state.warned_windows.insert(*window_id) {
+            warn!(
+                "Cursor images are only drawn in the primary window, window {:?} keeps its OS cursor",
+                window_id
+            );
+        }
+    }
+
+    let window = match windows.get_primary_mut() {
+        Some(window) => window,
+        None => return,
+    };
+    let image = cursor_images.get(window.id());
+    let node = match state.nodes.get_mut(&window.id()) {
+        Some(node) => node,
+        None if image.is_none() => return,
+        None => {
+            let entity = commands
+                .spawn(ImageBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..Default::default()
+                    },
+                    draw: Draw {
+                        is_visible: false,
+                        is_transparent: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .with(FocusPolicy::Pass)
+                .current_entity()
+                .unwrap();
+            // the node can be updated once it has been spawned and laid out
+            state.nodes.insert(
+                window.id(),
+                CursorImageNode {
+                    entity,
+                    image: None,
+                    os_cursor_visible: window.cursor_visible(),
+                },
+            );
+            return;
+        }
+    };
+
+    if node.image.as_ref() != image {
+        match (&node.image, image) {
+            (None, Some(_)) => {
+                node.os_cursor_visible = window.cursor_visible();
+                window.set_cursor_visibility(false);
+            }
+            (Some(_), None) => window.set_cursor_visibility(node.os_cursor_visible),
+            _ => {}
+        }
+        node.image = image.cloned();
+    }
+
+    let (ui_node, mut transform, mut draw, mut material) = match node_query.get_mut(node.entity) {
+        Ok(components) => components,
+        Err(_) => return,
+    };
+    let (image, position) = match (&node.image, state.cursor_positions.get(&window.id())) {
+        (Some(image), Some(position)) => (image, *position),
+        _ => {
+            draw.is_visible = false;
+            return;
+        }
+    };
+    if *material != image.material {
+        *material = image.material.clone();
+    }
+    draw.is_visible = true;
+    // ui coordinates start at the bottom left, but the hotspot is relative to the top left
+    let size = ui_node.size;
+    transform.translation.x = position.x - image.hotspot.x + size.x / 2.0;
+    transform.translation.y = position.y + image.hotspot.y - size.y / 2.0;
+    transform.translation.z = CURSOR_Z;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{IntoSystem, Resources, Schedule, World};
+    use bevy_window::{Window, WindowDescriptor};
+
+    fn send<T: bevy_ecs::Resource>(resources: &Resources, event: T) {
+        resources.get_mut::<Events<T>>().unwrap().send(event);
+    }
+
+    fn is_cursor_image_visible(world: &World) -> bool {
+        world.query::<&Draw>().next().unwrap().is_visible
+    }
+
+    #[test]
+    fn cursor_image_is_hidden_outside_of_the_window() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+            800,
+            600,
+            1.0,
+        ));
+        resources.insert(windows);
+        let mut cursor_images = CursorImages::default();
+        cursor_images.set(
+            WindowId::primary(),
+            CursorImage {
+                material: Default::default(),
+                hotspot: Vec2::zero(),
+            },
+        );
+        resources.insert(cursor_images);
+        resources.insert(Events::<CursorMoved>::default());
+        resources.insert(Events::<CursorEntered>::default());
+        resources.insert(Events::<CursorLeft>::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("ui");
+        schedule.add_system_to_stage("ui", cursor_image_system.system());
+        schedule.initialize(&mut world, &mut resources);
+        // the first update spawns the node of the cursor image
+        schedule.run(&mut world, &mut resources);
+
+        let moved = CursorMoved {
+            id: WindowId::primary(),
+            position: Vec2::new(100.0, 100.0),
+        };
+        let entered = CursorEntered {
+            id: WindowId::primary(),
+        };
+        let left = CursorLeft {
+            id: WindowId::primary(),
+        };
+        send(&resources, moved.clone());
+        schedule.run(&mut world, &mut resources);
+        assert!(is_cursor_image_visible(&world));
+
+        send(&resources, left.clone());
+        schedule.run(&mut world, &mut resources);
+        assert!(!is_cursor_image_visible(&world));
+
+        send(&resources, entered.clone());
+        send(&resources, moved.clone());
+        schedule.run(&mut world, &mut resources);
+        assert!(is_cursor_image_visible(&world));
+
+        // leaving and entering again in the same frame keeps the image visible
+        send(&resources, left.clone());
+        send(&resources, entered);
+        send(&resources, moved);
+        schedule.run(&mut world, &mut resources);
+        assert!(is_cursor_image_visible(&world));
+
+        send(&resources, left);
+        schedule.run(&mut world, &mut resources);
+        assert!(!is_cursor_image_visible(&world));
+    }
+}
@@ -1,4 +1,5 @@
 mod anchors;
+mod cursor;
 pub mod entity;
 mod flex;
 mod focus;
@@ -9,6 +10,7 @@ pub mod update;
 pub mod widget;
 
 pub use anchors::*;
+pub use cursor::*;
 pub use flex::*;
 pub use focus::*;
 pub use margins::*;
@@ -20,7 +22,7 @@ pub mod prelude {
         entity::*,
         node::*,
         widget::{Button, Text},
-        Anchors, Interaction, Margins,
+        Anchors, CursorImage, CursorImages, Interaction, Margins,
     };
 }
 
@@ -38,13 +40,16 @@ pub mod stage {
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut AppBuilder) {
         app.init_resource::<FlexSurface>()
+            .init_resource::<CursorImages>()
             .add_stage_before(bevy_app::stage::POST_UPDATE, stage::UI)
             .add_system_to_stage(bevy_app::stage::PRE_UPDATE, ui_focus_system)
             // add these stages to front because these must run before transform update systems
+            .add_system_to_stage(stage::UI, widget::localized_text_system)
             .add_system_to_stage(stage::UI, widget::text_system)
             .add_system_to_stage(stage::UI, widget::image_node_system)
             .add_system_to_stage(stage::UI, ui_z_system)
             .add_system_to_stage(stage::UI, flex_node_system)
+            .add_system_to_stage(stage::UI, cursor_image_system)
             .add_system_to_stage(bevy_render::stage::DRAW, widget::draw_text_system);
 
         let resources = app.resources();
@@ -147,6 +147,13 @@ impl UiRenderGraphBuilder for RenderGraph {
         self.add_node_edge(base::node::MAIN_PASS, node::UI_PASS)
             .unwrap();
 
+        // capture offscreen frames and screenshots after the ui has been drawn
+        for capture_node in [base::node::FRAME_CAPTURE, base::node::SCREENSHOT].iter() {
+            if self.get_node_id(*capture_node).is_ok() {
+                self.add_node_edge(node::UI_PASS, *capture_node).unwrap();
+            }
+        }
+
         // setup ui camera
         self.add_system_node(node::UI_CAMERA, CameraNode::new(camera::UI_CAMERA));
         self.add_node_edge(node::UI_CAMERA, node::UI_PASS).unwrap();
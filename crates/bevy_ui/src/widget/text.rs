@@ -1,6 +1,7 @@
 use crate::{CalculatedSize, Node, Style, Val};
-use bevy_asset::{Assets, Handle};
-use bevy_ecs::{Changed, Entity, Local, Or, Query, QuerySet, Res, ResMut};
+use bevy_app::{EventReader, Events};
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_ecs::{Changed, Entity, Local, Mut, Or, Query, QuerySet, Res, ResMut};
 use bevy_math::Size;
 use bevy_render::{
     draw::{Draw, DrawContext, Drawable},
@@ -10,7 +11,10 @@ use bevy_render::{
     texture::Texture,
 };
 use bevy_sprite::{TextureAtlas, QUAD_HANDLE};
-use bevy_text::{DefaultTextPipeline, DrawableText, Font, FontAtlasSet, TextError, TextStyle};
+use bevy_text::{
+    DefaultTextPipeline, DrawableText, Font, FontAtlasSet, Localization, LocalizedText, TextError,
+    TextStyle, Translations,
+};
 use bevy_transform::prelude::GlobalTransform;
 
 #[derive(Debug, Default)]
@@ -38,6 +42,54 @@ pub fn text_constraint(min_size: Val, size: Val, max_size: Val) -> f32 {
     }
 }
 
+#[derive(Default)]
+pub struct LocalizedTextState {
+    translations_event_reader: EventReader<AssetEvent<Translations>>,
+    localization_version: Option<u64>,
+}
+
+/// Replaces the value of [Text]s with their [LocalizedText] translation. All texts are translated
+/// again when the locale or the translations change, otherwise only the [LocalizedText]s that
+/// changed are.
+pub fn localized_text_system(
+    mut state: Local<LocalizedTextState>,
+    localization: Res<Localization>,
+    translations: Res<Assets<Translations>>,
+    translations_events: Res<Events<AssetEvent<Translations>>>,
+    mut text_queries: QuerySet<(
+        Query<(&LocalizedText, &mut Text), Changed<LocalizedText>>,
+        Query<(&LocalizedText, &mut Text)>,
+    )>,
+) {
+    let mut translations_changed = state.localization_version != Some(localization.version());
+    state.localization_version = Some(localization.version());
+    for event in state.translations_event_reader.iter(&translations_events) {
+        let handle = match event {
+            AssetEvent::Created { handle }
+            | AssetEvent::Modified { handle }
+            | AssetEvent::Removed { handle } => handle,
+        };
+        translations_changed |= localization.uses_translations(handle);
+    }
+
+    let update_text = |localized_text: &LocalizedText, text: &mut Mut<Text>| {
+        let value = localization.format(localized_text, &translations);
+        // only changed texts are laid out again
+        if text.value != value {
+            text.value = value;
+        }
+    };
+    if translations_changed {
+        for (localized_text, mut text) in text_queries.q1_mut().iter_mut() {
+            update_text(localized_text, &mut text);
+        }
+    } else {
+        for (localized_text, mut text) in text_queries.q0_mut().iter_mut() {
+            update_text(localized_text, &mut text);
+        }
+    }
+}
+
 /// Computes the size of a text block and updates the TextGlyphs with the
 /// new computed glyphs from the layout
 pub fn text_system(
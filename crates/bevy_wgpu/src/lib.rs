@@ -12,17 +12,23 @@ pub use wgpu_resources::*;
 
 use bevy_app::prelude::*;
 use bevy_ecs::{Resources, World};
-use bevy_render::renderer::{free_shared_buffers_system, RenderResourceContext, SharedBuffers};
+use bevy_render::renderer::{RenderResourceContext, SharedBuffers};
 use renderer::WgpuRenderResourceContext;
 
+/// Renders Apps with wgpu.
+///
+/// A [WgpuOptions] resource inserted before this plugin is added takes precedence over `options`.
 #[derive(Default)]
-pub struct WgpuPlugin;
+pub struct WgpuPlugin {
+    pub options: WgpuOptions,
+}
 
 impl Plugin for WgpuPlugin {
     fn build(&self, app: &mut AppBuilder) {
+        let options = self.options.clone();
+        app.resources_mut().get_or_insert_with(|| options);
         let render_system = get_wgpu_render_system(app.resources_mut());
-        app.add_system_to_stage(bevy_render::stage::RENDER, render_system)
-            .add_system_to_stage(bevy_render::stage::POST_RENDER, free_shared_buffers_system);
+        app.add_system_to_stage(bevy_render::stage::RENDER, render_system);
     }
 }
 
@@ -41,7 +47,43 @@ pub fn get_wgpu_render_system(resources: &mut Resources) -> impl FnMut(&mut Worl
 
 #[derive(Default, Clone)]
 pub struct WgpuOptions {
-    power_pref: WgpuPowerOptions,
+    pub backend: WgpuBackend,
+    pub power_pref: WgpuPowerOptions,
+}
+
+/// The graphics APIs wgpu may pick an adapter from.
+///
+/// Software rasterizers such as lavapipe or SwiftShader are exposed as Vulkan drivers. To render
+/// on a machine without a GPU, pick [WgpuBackend::Vulkan] and point `VK_ICD_FILENAMES` at the
+/// rasterizer's ICD manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WgpuBackend {
+    /// The first-class backends of the platform: Vulkan, Metal, DX12 or WebGPU
+    Auto,
+    Vulkan,
+    Metal,
+    Dx12,
+    Dx11,
+    Gl,
+}
+
+impl WgpuBackend {
+    fn backend_bits(self) -> wgpu::BackendBit {
+        match self {
+            WgpuBackend::Auto => wgpu::BackendBit::PRIMARY,
+            WgpuBackend::Vulkan => wgpu::BackendBit::VULKAN,
+            WgpuBackend::Metal => wgpu::BackendBit::METAL,
+            WgpuBackend::Dx12 => wgpu::BackendBit::DX12,
+            WgpuBackend::Dx11 => wgpu::BackendBit::DX11,
+            WgpuBackend::Gl => wgpu::BackendBit::GL,
+        }
+    }
+}
+
+impl Default for WgpuBackend {
+    fn default() -> Self {
+        WgpuBackend::Auto
+    }
 }
 
 #[derive(Clone)]
@@ -4,6 +4,8 @@ use bevy_render::{
     render_graph::{Edge, NodeId, ResourceSlots, StageBorrow},
     renderer::RenderResourceContext,
 };
+#[cfg(feature = "trace")]
+use bevy_utils::tracing::info_span;
 use bevy_utils::HashMap;
 use parking_lot::RwLock;
 use std::sync::Arc;
@@ -22,6 +24,11 @@ impl WgpuRenderGraphExecutor {
         queue: &mut wgpu::Queue,
         stages: &mut [StageBorrow],
     ) {
+        #[cfg(feature = "trace")]
+        let render_graph_span = info_span!("render_graph");
+        #[cfg(feature = "trace")]
+        let _render_graph_guard = render_graph_span.enter();
+
         let mut render_resource_context = resources
             .get_mut::<Box<dyn RenderResourceContext>>()
             .unwrap();
@@ -48,6 +55,14 @@ impl WgpuRenderGraphExecutor {
                 let mut render_context = WgpuRenderContext::new(device, render_resource_context);
                 for job in jobs_chunk.iter_mut() {
                     for node_state in job.node_states.iter_mut() {
+                        #[cfg(feature = "trace")]
+                        let node_span = info_span!(
+                            "render_graph_node",
+                            name = node_state.name.as_deref().unwrap_or("unnamed")
+                        );
+                        #[cfg(feature = "trace")]
+                        let _node_guard = node_span.enter();
+
                         // bind inputs from connected node outputs
                         for (i, mut input_slot) in node_state.input_slots.iter_mut().enumerate() {
                             if let Edge::SlotEdge {
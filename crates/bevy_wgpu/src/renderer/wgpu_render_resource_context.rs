@@ -1,6 +1,6 @@
 use crate::{
     wgpu_type_converter::{OwnedWgpuVertexBufferDescriptor, WgpuInto},
-    WgpuBindGroupInfo, WgpuResources,
+    BufferMapFuture, WgpuBindGroupInfo, WgpuResources,
 };
 
 use bevy_asset::{Assets, Handle, HandleUntyped};
@@ -9,8 +9,8 @@ use bevy_render::{
         BindGroupDescriptor, BindGroupDescriptorId, BindingShaderStage, PipelineDescriptor,
     },
     renderer::{
-        BindGroup, BufferId, BufferInfo, RenderResourceBinding, RenderResourceContext,
-        RenderResourceId, SamplerId, TextureId,
+        BindGroup, BufferId, BufferInfo, BufferMapError, BufferMapMode, RenderResourceBinding,
+        RenderResourceContext, RenderResourceId, SamplerId, TextureId,
     },
     shader::{glsl_to_spirv, Shader, ShaderSource},
     texture::{Extent3d, SamplerDescriptor, TextureDescriptor},
@@ -43,6 +43,13 @@ impl WgpuRenderResourceContext {
         window_surfaces.insert(window_id, surface);
     }
 
+    /// Drops the surface and swap chain of a window, for example because its native window was
+    /// destroyed
+    pub fn remove_window_surface(&self, window_id: WindowId) {
+        self.resources.window_swap_chains.write().remove(&window_id);
+        self.resources.window_surfaces.write().remove(&window_id);
+    }
+
     pub fn copy_buffer_to_buffer(
         &self,
         command_encoder: &mut wgpu::CommandEncoder,
@@ -104,6 +111,45 @@ impl WgpuRenderResourceContext {
         );
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_texture_to_buffer(
+        &self,
+        command_encoder: &mut wgpu::CommandEncoder,
+        source_texture: TextureId,
+        source_origin: [u32; 3], // TODO: replace with math type
+        source_mip_level: u32,
+        destination_buffer: BufferId,
+        destination_offset: u64,
+        destination_bytes_per_row: u32,
+        size: Extent3d,
+    ) {
+        let buffers = self.resources.buffers.read();
+        let textures = self.resources.textures.read();
+
+        let source = textures.get(&source_texture).unwrap();
+        let destination = buffers.get(&destination_buffer).unwrap();
+        command_encoder.copy_texture_to_buffer(
+            wgpu::TextureCopyView {
+                texture: source,
+                mip_level: source_mip_level,
+                origin: wgpu::Origin3d {
+                    x: source_origin[0],
+                    y: source_origin[1],
+                    z: source_origin[2],
+                },
+            },
+            wgpu::BufferCopyView {
+                buffer: destination,
+                layout: wgpu::TextureDataLayout {
+                    offset: destination_offset,
+                    bytes_per_row: destination_bytes_per_row,
+                    rows_per_image: size.height,
+                },
+            },
+            size.wgpu_into(),
+        );
+    }
+
     pub fn create_bind_group_layout(&self, descriptor: &BindGroupDescriptor) {
         if self
             .resources
@@ -232,6 +278,7 @@ impl RenderResourceContext for WgpuRenderResourceContext {
 
         buffers.remove(&buffer);
         buffer_infos.remove(&buffer);
+        self.resources.buffer_map_futures.lock().remove(&buffer);
     }
 
     fn remove_texture(&self, texture: TextureId) {
@@ -541,17 +588,63 @@ impl RenderResourceContext for WgpuRenderResourceContext {
         write(&mut data, self);
     }
 
-    fn map_buffer(&self, id: BufferId) {
+    fn read_mapped_buffer(
+        &self,
+        id: BufferId,
+        range: Range<u64>,
+        read: &mut dyn FnMut(&[u8], &dyn RenderResourceContext),
+    ) {
+        let buffer = {
+            let buffers = self.resources.buffers.read();
+            buffers.get(&id).unwrap().clone()
+        };
+        let buffer_slice = buffer.slice(range);
+        let data = buffer_slice.get_mapped_range();
+        read(&data, self);
+    }
+
+    fn map_buffer(&self, id: BufferId, mode: BufferMapMode) {
         let buffers = self.resources.buffers.read();
         let buffer = buffers.get(&id).unwrap();
         let buffer_slice = buffer.slice(..);
-        let data = buffer_slice.map_async(wgpu::MapMode::Write);
+        let data = buffer_slice.map_async(mode.wgpu_into());
         self.device.poll(wgpu::Maintain::Wait);
         if future::block_on(data).is_err() {
             panic!("failed to map buffer to host");
         }
     }
 
+    fn map_buffer_async(&self, id: BufferId, mode: BufferMapMode) {
+        let buffers = self.resources.buffers.read();
+        let buffer = buffers.get(&id).unwrap();
+        let data = buffer.slice(..).map_async(mode.wgpu_into());
+        self.resources
+            .buffer_map_futures
+            .lock()
+            .insert(id, BufferMapFuture(Box::pin(data)));
+    }
+
+    fn is_buffer_mapped(&self, id: BufferId) -> Result<bool, BufferMapError> {
+        let mut buffer_map_futures = self.resources.buffer_map_futures.lock();
+        let data = match buffer_map_futures.get_mut(&id) {
+            Some(data) => data,
+            None => return Err(BufferMapError::NotMapping(id)),
+        };
+        // runs the callbacks of finished mappings, without waiting for the rest
+        self.device.poll(wgpu::Maintain::Poll);
+        match future::block_on(future::poll_once(&mut data.0)) {
+            Some(Ok(())) => {
+                buffer_map_futures.remove(&id);
+                Ok(true)
+            }
+            Some(Err(_)) => {
+                buffer_map_futures.remove(&id);
+                Err(BufferMapError::MapFailed(id))
+            }
+            None => Ok(false),
+        }
+    }
+
     fn unmap_buffer(&self, id: BufferId) {
         let buffers = self.resources.buffers.read();
         let buffer = buffers.get(&id).unwrap();
@@ -8,7 +8,7 @@ use bevy_render::{
     render_graph::{DependentNodeStager, RenderGraph, RenderGraphStager},
     renderer::RenderResourceContext,
 };
-use bevy_window::{WindowCreated, WindowResized, Windows};
+use bevy_window::{AppLifecycle, WindowClosed, WindowCreated, WindowId, WindowResized, Windows};
 use std::{ops::Deref, sync::Arc};
 
 pub struct WgpuRenderer {
@@ -17,12 +17,16 @@ pub struct WgpuRenderer {
     pub queue: wgpu::Queue,
     pub window_resized_event_reader: EventReader<WindowResized>,
     pub window_created_event_reader: EventReader<WindowCreated>,
+    pub window_closed_event_reader: EventReader<WindowClosed>,
+    pub app_lifecycle_event_reader: EventReader<AppLifecycle>,
+    /// Whether the app is suspended, in which case there are no window surfaces to render to
+    pub suspended: bool,
     pub initialized: bool,
 }
 
 impl WgpuRenderer {
     pub async fn new(options: WgpuOptions) -> Self {
-        let instance = wgpu::Instance::new(wgpu::BackendBit::PRIMARY);
+        let instance = wgpu::Instance::new(options.backend.backend_bits());
 
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
@@ -36,9 +40,9 @@ impl WgpuRenderer {
             .await
             .expect("Unable to find a GPU! Make sure you have installed required drivers!");
 
-        #[cfg(feature = "trace")]
+        #[cfg(feature = "wgpu_trace")]
         let trace_path = Some(std::path::Path::new("wgpu_trace"));
-        #[cfg(not(feature = "trace"))]
+        #[cfg(not(feature = "wgpu_trace"))]
         let trace_path = None;
 
         let (device, queue) = adapter
@@ -59,17 +63,14 @@ impl WgpuRenderer {
             queue,
             window_resized_event_reader: Default::default(),
             window_created_event_reader: Default::default(),
+            window_closed_event_reader: Default::default(),
+            app_lifecycle_event_reader: Default::default(),
+            suspended: false,
             initialized: false,
         }
     }
 
     pub fn handle_window_created_events(&mut self, resources: &Resources) {
-        let mut render_resource_context = resources
-            .get_mut::<Box<dyn RenderResourceContext>>()
-            .unwrap();
-        let render_resource_context = render_resource_context
-            .downcast_mut::<WgpuRenderResourceContext>()
-            .unwrap();
         let windows = resources.get::<Windows>().unwrap();
         let window_created_events = resources.get::<Events<WindowCreated>>().unwrap();
         for window_created_event in self
@@ -79,16 +80,86 @@ impl WgpuRenderer {
             let window = windows
                 .get(window_created_event.id)
                 .expect("Received window created event for non-existent window");
-            #[cfg(feature = "bevy_winit")]
-            {
-                let winit_windows = resources.get::<bevy_winit::WinitWindows>().unwrap();
-                let winit_window = winit_windows.get_window(window.id()).unwrap();
-                let surface = unsafe { self.instance.create_surface(winit_window.deref()) };
-                render_resource_context.set_window_surface(window.id(), surface);
+            self.create_window_surface(resources, window.id());
+        }
+    }
+
+    pub fn handle_window_closed_events(&mut self, resources: &Resources) {
+        let window_closed_events = resources.get::<Events<WindowClosed>>().unwrap();
+        let render_resource_context = resources.get::<Box<dyn RenderResourceContext>>().unwrap();
+        let render_resource_context = render_resource_context
+            .downcast_ref::<WgpuRenderResourceContext>()
+            .unwrap();
+        for window_closed_event in self.window_closed_event_reader.iter(&window_closed_events) {
+            render_resource_context.remove_window_surface(window_closed_event.id);
+        }
+    }
+
+    /// Drops window surfaces when the app is suspended and recreates them when it is resumed,
+    /// because the OS destroys the native windows of suspended apps on some platforms
+    pub fn handle_app_lifecycle_events(&mut self, resources: &Resources) {
+        let app_lifecycle_events = match resources.get::<Events<AppLifecycle>>() {
+            Some(app_lifecycle_events) => app_lifecycle_events,
+            None => return,
+        };
+        let app_lifecycle_events = self
+            .app_lifecycle_event_reader
+            .iter(&app_lifecycle_events)
+            .copied()
+            .collect::<Vec<_>>();
+        let render_resource_context = resources.get::<Box<dyn RenderResourceContext>>().unwrap();
+        let windows = resources.get::<Windows>().unwrap();
+        for app_lifecycle_event in app_lifecycle_events {
+            match app_lifecycle_event {
+                AppLifecycle::Suspended if !self.suspended => {
+                    let render_resource_context = render_resource_context
+                        .downcast_ref::<WgpuRenderResourceContext>()
+                        .unwrap();
+                    for window in windows.iter() {
+                        render_resource_context.remove_window_surface(window.id());
+                    }
+                    self.suspended = true;
+                }
+                AppLifecycle::Resumed if self.suspended => {
+                    for window in windows.iter() {
+                        if self.create_window_surface(resources, window.id()) {
+                            render_resource_context.create_swap_chain(window);
+                        }
+                    }
+                    self.suspended = false;
+                }
+                _ => {}
             }
         }
     }
 
+    /// Creates a surface for the window's native window. Returns false for windows without one,
+    /// such as the windows of apps that render offscreen.
+    #[allow(unused_variables)]
+    fn create_window_surface(&self, resources: &Resources, window_id: WindowId) -> bool {
+        #[cfg(feature = "bevy_winit")]
+        {
+            let winit_windows = match resources.get::<bevy_winit::WinitWindows>() {
+                Some(winit_windows) => winit_windows,
+                None => return false,
+            };
+            let winit_window = match winit_windows.get_window(window_id) {
+                Some(winit_window) => winit_window,
+                None => return false,
+            };
+            let render_resource_context =
+                resources.get::<Box<dyn RenderResourceContext>>().unwrap();
+            let render_resource_context = render_resource_context
+                .downcast_ref::<WgpuRenderResourceContext>()
+                .unwrap();
+            let surface = unsafe { self.instance.create_surface(winit_window.deref()) };
+            render_resource_context.set_window_surface(window_id, surface);
+            true
+        }
+        #[cfg(not(feature = "bevy_winit"))]
+        false
+    }
+
     pub fn run_graph(&mut self, world: &mut World, resources: &mut Resources) {
         let mut render_graph = resources.get_mut::<RenderGraph>().unwrap();
         // stage nodes
@@ -110,6 +181,11 @@ impl WgpuRenderer {
     }
 
     pub fn update(&mut self, world: &mut World, resources: &mut Resources) {
+        self.handle_app_lifecycle_events(resources);
+        if self.suspended {
+            return;
+        }
+        self.handle_window_closed_events(resources);
         self.handle_window_created_events(resources);
         self.run_graph(world, resources);
 
@@ -7,8 +7,8 @@ use bevy_render::{
 };
 use bevy_utils::HashMap;
 use bevy_window::WindowId;
-use parking_lot::{RwLock, RwLockReadGuard};
-use std::sync::Arc;
+use parking_lot::{Mutex, RwLock, RwLockReadGuard};
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
 
 #[derive(Debug, Default)]
 pub struct WgpuBindGroupInfo {
@@ -69,6 +69,17 @@ pub struct WgpuResourceRefs<'a> {
     pub bind_groups: &'a HashMap<BindGroupDescriptorId, WgpuBindGroupInfo>,
 }
 
+/// Resolves once a buffer that is being mapped without blocking can be used
+pub struct BufferMapFuture(
+    pub Pin<Box<dyn Future<Output = Result<(), wgpu::BufferAsyncError>> + Send>>,
+);
+
+impl fmt::Debug for BufferMapFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BufferMapFuture").finish()
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct WgpuResources {
     pub buffer_infos: Arc<RwLock<HashMap<BufferId, BufferInfo>>>,
@@ -85,6 +96,7 @@ pub struct WgpuResources {
     pub bind_groups: Arc<RwLock<HashMap<BindGroupDescriptorId, WgpuBindGroupInfo>>>,
     pub bind_group_layouts: Arc<RwLock<HashMap<BindGroupDescriptorId, wgpu::BindGroupLayout>>>,
     pub asset_resources: Arc<RwLock<HashMap<(HandleUntyped, u64), RenderResourceId>>>,
+    pub buffer_map_futures: Arc<Mutex<HashMap<BufferId, BufferMapFuture>>>,
 }
 
 impl WgpuResources {
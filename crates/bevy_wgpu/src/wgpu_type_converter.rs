@@ -8,7 +8,7 @@ use bevy_render::{
         StencilStateDescriptor, StencilStateFaceDescriptor, VertexAttributeDescriptor,
         VertexBufferDescriptor, VertexFormat,
     },
-    renderer::BufferUsage,
+    renderer::{BufferMapMode, BufferUsage},
     texture::{
         AddressMode, Extent3d, FilterMode, SamplerDescriptor, TextureComponentType,
         TextureDescriptor, TextureDimension, TextureFormat, TextureUsage, TextureViewDimension,
@@ -139,6 +139,15 @@ impl WgpuFrom<BufferUsage> for wgpu::BufferUsage {
     }
 }
 
+impl WgpuFrom<BufferMapMode> for wgpu::MapMode {
+    fn from(val: BufferMapMode) -> Self {
+        match val {
+            BufferMapMode::Read => wgpu::MapMode::Read,
+            BufferMapMode::Write => wgpu::MapMode::Write,
+        }
+    }
+}
+
 impl WgpuFrom<&LoadOp<Color>> for wgpu::LoadOp<wgpu::Color> {
     fn from(val: &LoadOp<Color>) -> Self {
         match val {
@@ -562,10 +571,11 @@ impl WgpuFrom<FilterMode> for wgpu::FilterMode {
 impl WgpuFrom<&Window> for wgpu::SwapChainDescriptor {
     fn from(window: &Window) -> Self {
         wgpu::SwapChainDescriptor {
-            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+            // screenshots copy the swap chain texture
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT | wgpu::TextureUsage::COPY_SRC,
             format: TextureFormat::default().wgpu_into(),
-            width: window.width(),
-            height: window.height(),
+            width: window.physical_width(),
+            height: window.physical_height(),
             present_mode: if window.vsync() {
                 wgpu::PresentMode::Mailbox
             } else {
@@ -0,0 +1,111 @@
+use bevy_app::Events;
+use bevy_ecs::ResMut;
+
+/// A request to the windowing backend's clipboard, queued by [Clipboard]
+#[derive(Debug)]
+pub enum ClipboardCommand {
+    SetText { text: String },
+    RequestText,
+}
+
+/// Sent with the contents of the clipboard after they were requested with
+/// [Clipboard::request_text]
+#[derive(Debug, Clone)]
+pub struct ClipboardText {
+    /// The text on the clipboard, or `None` if it holds something else or couldn't be read
+    pub text: Option<String>,
+}
+
+/// Copies text to and pastes text from the system clipboard.
+///
+/// Reading the clipboard is asynchronous on the web, so pasted text is sent as a [ClipboardText]
+/// event. Both operations are carried out by the windowing backend. Without a backend, for
+/// example when the [CLIPBOARD_CAPABILITY](crate::CLIPBOARD_CAPABILITY) is unavailable or the app
+/// is headless, copied text is dropped and requested text is answered with `None`.
+#[derive(Debug, Default)]
+pub struct Clipboard {
+    command_queue: Vec<ClipboardCommand>,
+    has_backend: bool,
+}
+
+impl Clipboard {
+    /// Replaces the contents of the clipboard with `text`
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.command_queue
+            .push(ClipboardCommand::SetText { text: text.into() });
+    }
+
+    /// Reads the text on the clipboard, which is sent as a [ClipboardText] event
+    pub fn request_text(&mut self) {
+        self.command_queue.push(ClipboardCommand::RequestText);
+    }
+
+    #[inline]
+    pub fn drain_commands(&mut self) -> impl Iterator<Item = ClipboardCommand> + '_ {
+        self.command_queue.drain(..)
+    }
+
+    /// Called by windowing backends that carry out the clipboard commands
+    pub fn set_has_backend(&mut self, has_backend: bool) {
+        self.has_backend = has_backend;
+    }
+
+    pub fn has_backend(&self) -> bool {
+        self.has_backend
+    }
+}
+
+/// Answers the [Clipboard] commands when there is no windowing backend to carry them out, so they
+/// don't pile up and requests for text don't go unanswered
+pub fn clipboard_fallback_system(
+    mut clipboard: ResMut<Clipboard>,
+    mut clipboard_text_events: ResMut<Events<ClipboardText>>,
+) {
+    if clipboard.has_backend() {
+        return;
+    }
+    for command in clipboard.drain_commands() {
+        if let ClipboardCommand::RequestText = command {
+            clipboard_text_events.send(ClipboardText { text: None });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{IntoSystem, Resources, Schedule, World};
+
+    #[test]
+    fn commands_are_answered_without_backend() {
+        let mut world = World::default();
+        let mut resources = Resources::default();
+        let mut clipboard = Clipboard::default();
+        clipboard.set_text("copied");
+        clipboard.request_text();
+        resources.insert(clipboard);
+        resources.insert(Events::<ClipboardText>::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("update");
+        schedule.add_system_to_stage("update", clipboard_fallback_system.system());
+        schedule.initialize(&mut world, &mut resources);
+        schedule.run(&mut world, &mut resources);
+
+        assert_eq!(
+            resources
+                .get_mut::<Clipboard>()
+                .unwrap()
+                .drain_commands()
+                .count(),
+            0
+        );
+        let events = resources.get::<Events<ClipboardText>>().unwrap();
+        let texts = events
+            .get_reader()
+            .iter(&events)
+            .map(|event| event.text.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec![None]);
+    }
+}
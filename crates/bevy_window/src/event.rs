@@ -5,8 +5,8 @@ use bevy_math::Vec2;
 #[derive(Debug, Clone)]
 pub struct WindowResized {
     pub id: WindowId,
-    pub width: usize,
-    pub height: usize,
+    pub width: f32,
+    pub height: f32,
 }
 
 /// An event that indicates that a new window should be created.
@@ -22,6 +22,13 @@ pub struct CloseWindow {
     pub id: WindowId,
 }
 
+/// An event that is sent whenever a window has been closed and removed from [Windows](crate::Windows).
+/// It is sent by the windowing backend once it has dropped the native window.
+#[derive(Debug, Clone)]
+pub struct WindowClosed {
+    pub id: WindowId,
+}
+
 /// An event that is sent whenever a new window is created.
 #[derive(Debug, Clone)]
 pub struct WindowCreated {
@@ -41,9 +48,46 @@ pub struct CursorMoved {
     pub position: Vec2,
 }
 
+/// An event that is sent whenever the cursor enters a window
+#[derive(Debug, Clone)]
+pub struct CursorEntered {
+    pub id: WindowId,
+}
+
+/// An event that is sent whenever the cursor leaves a window
+#[derive(Debug, Clone)]
+pub struct CursorLeft {
+    pub id: WindowId,
+}
+
 /// An event that is sent whenever a window receives a character from the OS or underlying system.
 #[derive(Debug, Clone)]
 pub struct ReceivedCharacter {
     pub id: WindowId,
     pub char: char,
 }
+
+/// An event that indicates a window's scale factor has changed.
+#[derive(Debug, Clone)]
+pub struct WindowScaleFactorChanged {
+    pub id: WindowId,
+    pub scale_factor: f64,
+}
+
+/// An event that indicates a window's OS-reported scale factor has changed.
+#[derive(Debug, Clone)]
+pub struct WindowBackendScaleFactorChanged {
+    pub id: WindowId,
+    pub scale_factor: f64,
+}
+
+/// An event that is sent when the OS suspends or resumes the app, for example when a mobile app is
+/// moved to the background and back. While the app is suspended its window surfaces are lost and the
+/// app isn't updated.
+///
+/// Some platforms, like Android, also send [AppLifecycle::Resumed] once the app has started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppLifecycle {
+    Suspended,
+    Resumed,
+}
@@ -1,28 +1,45 @@
+mod clipboard;
+mod cursor;
 mod event;
 mod system;
 mod window;
 mod windows;
 
+pub use clipboard::*;
+pub use cursor::*;
 pub use event::*;
 pub use system::*;
 pub use window::*;
 pub use windows::*;
 
 pub mod prelude {
-    pub use crate::{CursorMoved, ReceivedCharacter, Window, WindowDescriptor, Windows};
+    pub use crate::{
+        AppLifecycle, Clipboard, ClipboardText, CursorEntered, CursorIcon, CursorLeft, CursorMoved,
+        ReceivedCharacter, Window, WindowCloseRequested, WindowCloseRequests, WindowDescriptor,
+        Windows,
+    };
 }
 
 use bevy_app::prelude::*;
 
+/// The name of the clipboard [Capabilities](bevy_app::Capabilities). When it is unavailable,
+/// [Clipboard] requests are answered as if the clipboard was empty.
+pub const CLIPBOARD_CAPABILITY: &str = "clipboard";
+
+/// Adds window support to Apps.
+///
+/// A [WindowDescriptor] resource inserted before this plugin is added takes precedence over
+/// `primary_window`.
 pub struct WindowPlugin {
-    pub add_primary_window: bool,
+    /// The settings of the window that is created on startup, or `None` to start without a window
+    pub primary_window: Option<WindowDescriptor>,
     pub exit_on_close: bool,
 }
 
 impl Default for WindowPlugin {
     fn default() -> Self {
         WindowPlugin {
-            add_primary_window: true,
+            primary_window: Some(WindowDescriptor::default()),
             exit_on_close: true,
         }
     }
@@ -35,16 +52,35 @@ impl Plugin for WindowPlugin {
             .add_event::<WindowCreated>()
             .add_event::<WindowCloseRequested>()
             .add_event::<CloseWindow>()
+            .add_event::<WindowClosed>()
             .add_event::<CursorMoved>()
+            .add_event::<CursorEntered>()
+            .add_event::<CursorLeft>()
             .add_event::<ReceivedCharacter>()
-            .init_resource::<Windows>();
+            .add_event::<WindowScaleFactorChanged>()
+            .add_event::<WindowBackendScaleFactorChanged>()
+            .add_event::<AppLifecycle>()
+            .add_event::<ClipboardText>()
+            .init_resource::<Windows>()
+            .init_resource::<Clipboard>()
+            .init_resource::<WindowClosePolicy>()
+            .init_resource::<WindowCloseRequests>()
+            .add_system_to_stage(
+                bevy_app::stage::PRE_UPDATE,
+                collect_window_close_requests_system,
+            )
+            .add_system_to_stage(
+                bevy_app::stage::POST_UPDATE,
+                resolve_window_close_requests_system,
+            )
+            .add_system_to_stage(bevy_app::stage::LAST, clipboard_fallback_system);
 
-        if self.add_primary_window {
+        if let Some(ref primary_window) = self.primary_window {
             let resources = app.resources();
             let window_descriptor = resources
                 .get::<WindowDescriptor>()
                 .map(|descriptor| (*descriptor).clone())
-                .unwrap_or_else(WindowDescriptor::default);
+                .unwrap_or_else(|| primary_window.clone());
             let mut create_window_event = resources.get_mut::<Events<CreateWindow>>().unwrap();
             create_window_event.send(CreateWindow {
                 id: WindowId::primary(),
@@ -53,7 +89,7 @@ impl Plugin for WindowPlugin {
         }
 
         if self.exit_on_close {
-            app.add_system(exit_on_window_close_system);
+            app.add_system_to_stage(bevy_app::stage::LAST, exit_on_window_close_system);
         }
     }
 }
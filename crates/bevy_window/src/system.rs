@@ -1,26 +1,284 @@
-use crate::WindowCloseRequested;
+use crate::{
+    CloseWindow, CreateWindow, Window, WindowCloseRequested, WindowClosed, WindowCreated, WindowId,
+    Windows,
+};
 use bevy_app::{
     prelude::{EventReader, Events},
     AppExit,
 };
 use bevy_ecs::{Local, Res, ResMut};
 
+/// Determines how [WindowCloseRequested] events are turned into [CloseWindow] events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowClosePolicy {
+    /// A requested window is closed at the end of the frame it was requested in, unless a system
+    /// called [WindowCloseRequests::cancel] for it during that frame.
+    CloseUnlessCancelled,
+    /// A requested window stays open until a system calls [WindowCloseRequests::confirm]
+    /// (or [WindowCloseRequests::cancel]) for it. Use this to show "unsaved changes" prompts
+    /// that span multiple frames.
+    RequireConfirmation,
+}
+
+impl Default for WindowClosePolicy {
+    fn default() -> Self {
+        WindowClosePolicy::CloseUnlessCancelled
+    }
+}
+
+/// The set of windows the OS asked to close that have not been closed yet.
+#[derive(Debug, Default)]
+pub struct WindowCloseRequests {
+    pending: Vec<WindowId>,
+    confirmed: Vec<WindowId>,
+}
+
+impl WindowCloseRequests {
+    /// Keeps the window open and drops its pending close request.
+    pub fn cancel(&mut self, id: WindowId) {
+        self.pending.retain(|pending| *pending != id);
+        self.confirmed.retain(|confirmed| *confirmed != id);
+    }
+
+    /// Allows a pending close request to go through at the end of the current frame.
+    pub fn confirm(&mut self, id: WindowId) {
+        if self.is_pending(id) && !self.confirmed.contains(&id) {
+            self.confirmed.push(id);
+        }
+    }
+
+    pub fn is_pending(&self, id: WindowId) -> bool {
+        self.pending.contains(&id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &WindowId> {
+        self.pending.iter()
+    }
+}
+
 #[derive(Default)]
-pub struct ExitOnWindowCloseState {
+pub struct WindowCloseRequestState {
     event_reader: EventReader<WindowCloseRequested>,
 }
 
+/// Records new [WindowCloseRequested] events in [WindowCloseRequests] so that systems running
+/// in UPDATE can cancel or confirm them.
+pub fn collect_window_close_requests_system(
+    mut state: Local<WindowCloseRequestState>,
+    mut requests: ResMut<WindowCloseRequests>,
+    window_close_requested_events: Res<Events<WindowCloseRequested>>,
+) {
+    for event in state.event_reader.iter(&window_close_requested_events) {
+        if !requests.is_pending(event.id) {
+            requests.pending.push(event.id);
+        }
+    }
+}
+
+/// Sends [CloseWindow] for every close request that is allowed through by the current
+/// [WindowClosePolicy].
+pub fn resolve_window_close_requests_system(
+    policy: Res<WindowClosePolicy>,
+    mut requests: ResMut<WindowCloseRequests>,
+    mut close_window_events: ResMut<Events<CloseWindow>>,
+) {
+    let requests = &mut *requests;
+    let closing = match *policy {
+        WindowClosePolicy::CloseUnlessCancelled => std::mem::take(&mut requests.pending),
+        WindowClosePolicy::RequireConfirmation => {
+            let confirmed = &requests.confirmed;
+            requests.pending.retain(|id| !confirmed.contains(id));
+            confirmed.clone()
+        }
+    };
+    requests.confirmed.clear();
+
+    for id in closing {
+        close_window_events.send(CloseWindow { id });
+    }
+}
+
+#[derive(Default)]
+pub struct ExitOnWindowCloseState {
+    event_reader: EventReader<CloseWindow>,
+}
+
+/// Exits the app when the primary window is closed. Closing other windows doesn't exit the app.
 pub fn exit_on_window_close_system(
     mut state: Local<ExitOnWindowCloseState>,
     mut app_exit_events: ResMut<Events<AppExit>>,
-    window_close_requested_events: Res<Events<WindowCloseRequested>>,
+    close_window_events: Res<Events<CloseWindow>>,
 ) {
     if state
         .event_reader
-        .iter(&window_close_requested_events)
-        .next()
-        .is_some()
+        .iter(&close_window_events)
+        .any(|event| event.id.is_primary())
     {
         app_exit_events.send(AppExit);
     }
 }
+
+#[derive(Default)]
+pub struct OffscreenWindowState {
+    event_reader: EventReader<CreateWindow>,
+    close_window_event_reader: EventReader<CloseWindow>,
+}
+
+/// Creates [Window]s for [CreateWindow] events and removes them for [CloseWindow] events without a
+/// windowing backend, so apps can render offscreen where there is no display. Don't use this
+/// together with a backend like winit, which creates real windows for the same events.
+pub fn create_offscreen_windows_system(
+    mut state: Local<OffscreenWindowState>,
+    create_window_events: Res<Events<CreateWindow>>,
+    mut windows: ResMut<Windows>,
+    mut window_created_events: ResMut<Events<WindowCreated>>,
+    close_window_events: Res<Events<CloseWindow>>,
+    mut window_closed_events: ResMut<Events<WindowClosed>>,
+) {
+    for event in state.close_window_event_reader.iter(&close_window_events) {
+        if windows.remove(event.id).is_some() {
+            window_closed_events.send(WindowClosed { id: event.id });
+        }
+    }
+
+    for event in state.event_reader.iter(&create_window_events) {
+        let descriptor = &event.descriptor;
+        let scale_factor = descriptor.scale_factor_override.unwrap_or(1.0);
+        windows.add(Window::new(
+            event.id,
+            descriptor,
+            (descriptor.width as f64 * scale_factor).round() as u32,
+            (descriptor.height as f64 * scale_factor).round() as u32,
+            scale_factor,
+        ));
+        window_created_events.send(WindowCreated { id: event.id });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{IntoSystem, Resources, Schedule, World};
+
+    fn setup(policy: WindowClosePolicy) -> (World, Resources, Schedule) {
+        let world = World::default();
+        let mut resources = Resources::default();
+        resources.insert(policy);
+        resources.insert(WindowCloseRequests::default());
+        resources.insert(Events::<WindowCloseRequested>::default());
+        resources.insert(Events::<CloseWindow>::default());
+        resources.insert(Events::<AppExit>::default());
+
+        let mut schedule = Schedule::default();
+        schedule.add_stage("pre_update");
+        schedule.add_stage("post_update");
+        schedule.add_stage("last");
+        schedule.add_system_to_stage("pre_update", collect_window_close_requests_system.system());
+        schedule.add_system_to_stage("post_update", resolve_window_close_requests_system.system());
+        schedule.add_system_to_stage("last", exit_on_window_close_system.system());
+        (world, resources, schedule)
+    }
+
+    fn request_close(resources: &mut Resources, id: WindowId) {
+        resources
+            .get_mut::<Events<WindowCloseRequested>>()
+            .unwrap()
+            .send(WindowCloseRequested { id });
+    }
+
+    fn update(world: &mut World, resources: &mut Resources, schedule: &mut Schedule) {
+        schedule.initialize(world, resources);
+        schedule.run(world, resources);
+        resources
+            .get_mut::<Events<WindowCloseRequested>>()
+            .unwrap()
+            .update();
+        resources.get_mut::<Events<CloseWindow>>().unwrap().update();
+        resources.get_mut::<Events<AppExit>>().unwrap().update();
+    }
+
+    fn closed_windows(resources: &Resources) -> Vec<WindowId> {
+        let events = resources.get::<Events<CloseWindow>>().unwrap();
+        let mut reader = events.get_reader();
+        reader.iter(&events).map(|event| event.id).collect()
+    }
+
+    fn exited(resources: &Resources) -> bool {
+        let events = resources.get::<Events<AppExit>>().unwrap();
+        events.get_reader().iter(&events).next().is_some()
+    }
+
+    fn cancel_primary_window_close(mut requests: ResMut<WindowCloseRequests>) {
+        requests.cancel(WindowId::primary());
+    }
+
+    #[test]
+    fn cancelled_request_keeps_window_open() {
+        let (mut world, mut resources, mut schedule) =
+            setup(WindowClosePolicy::CloseUnlessCancelled);
+        let id = WindowId::primary();
+        request_close(&mut resources, id);
+        schedule.add_stage_after("pre_update", "update");
+        schedule.add_system_to_stage("update", cancel_primary_window_close.system());
+        update(&mut world, &mut resources, &mut schedule);
+
+        assert!(closed_windows(&resources).is_empty());
+        assert!(!resources
+            .get::<WindowCloseRequests>()
+            .unwrap()
+            .is_pending(id));
+        assert!(!exited(&resources));
+    }
+
+    #[test]
+    fn uncancelled_request_closes_window() {
+        let (mut world, mut resources, mut schedule) =
+            setup(WindowClosePolicy::CloseUnlessCancelled);
+        request_close(&mut resources, WindowId::primary());
+        update(&mut world, &mut resources, &mut schedule);
+
+        assert_eq!(closed_windows(&resources), vec![WindowId::primary()]);
+        assert!(exited(&resources));
+    }
+
+    #[test]
+    fn required_confirmation_keeps_window_open_until_confirmed() {
+        let (mut world, mut resources, mut schedule) =
+            setup(WindowClosePolicy::RequireConfirmation);
+        let id = WindowId::primary();
+        request_close(&mut resources, id);
+        update(&mut world, &mut resources, &mut schedule);
+        update(&mut world, &mut resources, &mut schedule);
+
+        assert!(closed_windows(&resources).is_empty());
+        assert!(resources
+            .get::<WindowCloseRequests>()
+            .unwrap()
+            .is_pending(id));
+
+        resources
+            .get_mut::<WindowCloseRequests>()
+            .unwrap()
+            .confirm(id);
+        update(&mut world, &mut resources, &mut schedule);
+
+        assert_eq!(closed_windows(&resources), vec![id]);
+        assert!(!resources
+            .get::<WindowCloseRequests>()
+            .unwrap()
+            .is_pending(id));
+        assert!(exited(&resources));
+    }
+
+    #[test]
+    fn closing_secondary_window_does_not_exit() {
+        let (mut world, mut resources, mut schedule) =
+            setup(WindowClosePolicy::CloseUnlessCancelled);
+        let id = WindowId::new();
+        request_close(&mut resources, id);
+        update(&mut world, &mut resources, &mut schedule);
+
+        assert_eq!(closed_windows(&resources), vec![id]);
+        assert!(!exited(&resources));
+    }
+}
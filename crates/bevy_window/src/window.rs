@@ -1,3 +1,4 @@
+use crate::CursorIcon;
 use uuid::Uuid;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -34,15 +35,22 @@ impl Default for WindowId {
 #[derive(Debug)]
 pub struct Window {
     id: WindowId,
-    width: u32,
-    height: u32,
+    requested_width: f32,
+    requested_height: f32,
+    physical_width: u32,
+    physical_height: u32,
+    scale_factor_override: Option<f64>,
+    backend_scale_factor: f64,
     title: String,
     vsync: bool,
     resizable: bool,
     decorations: bool,
     cursor_visible: bool,
     cursor_locked: bool,
+    cursor_icon: CursorIcon,
+    always_on_top: bool,
     mode: WindowMode,
+    transparent: bool,
     #[cfg(target_arch = "wasm32")]
     pub canvas: Option<String>,
     command_queue: Vec<WindowCommand>,
@@ -58,8 +66,11 @@ pub enum WindowCommand {
         title: String,
     },
     SetResolution {
-        width: u32,
-        height: u32,
+        logical_resolution: (f32, f32),
+        scale_factor: f64,
+    },
+    SetScaleFactor {
+        scale_factor: f64,
     },
     SetVsync {
         vsync: bool,
@@ -80,6 +91,23 @@ pub enum WindowCommand {
         x: i32,
         y: i32,
     },
+    SetCursorIcon {
+        icon: CursorIcon,
+    },
+    SetAlwaysOnTop {
+        always_on_top: bool,
+    },
+    SetMaximized {
+        maximized: bool,
+    },
+    SetMinimized {
+        minimized: bool,
+    },
+    SetPosition {
+        x: i32,
+        y: i32,
+    },
+    Center,
 }
 
 /// Defines the way a window is displayed
@@ -95,18 +123,31 @@ pub enum WindowMode {
 }
 
 impl Window {
-    pub fn new(id: WindowId, window_descriptor: &WindowDescriptor) -> Self {
+    pub fn new(
+        id: WindowId,
+        window_descriptor: &WindowDescriptor,
+        physical_width: u32,
+        physical_height: u32,
+        scale_factor: f64,
+    ) -> Self {
         Window {
             id,
-            height: window_descriptor.height,
-            width: window_descriptor.width,
+            requested_width: window_descriptor.width,
+            requested_height: window_descriptor.height,
+            physical_width,
+            physical_height,
+            scale_factor_override: window_descriptor.scale_factor_override,
+            backend_scale_factor: scale_factor,
             title: window_descriptor.title.clone(),
             vsync: window_descriptor.vsync,
             resizable: window_descriptor.resizable,
             decorations: window_descriptor.decorations,
             cursor_visible: window_descriptor.cursor_visible,
             cursor_locked: window_descriptor.cursor_locked,
+            cursor_icon: CursorIcon::Default,
+            always_on_top: window_descriptor.always_on_top,
             mode: window_descriptor.mode,
+            transparent: window_descriptor.transparent,
             #[cfg(target_arch = "wasm32")]
             canvas: window_descriptor.canvas.clone(),
             command_queue: Vec::new(),
@@ -118,27 +159,107 @@ impl Window {
         self.id
     }
 
+    /// The current logical width of the window's client area.
     #[inline]
-    pub fn width(&self) -> u32 {
-        self.width
+    pub fn width(&self) -> f32 {
+        (self.physical_width as f64 / self.scale_factor()) as f32
     }
 
+    /// The current logical height of the window's client area.
     #[inline]
-    pub fn height(&self) -> u32 {
-        self.height
+    pub fn height(&self) -> f32 {
+        (self.physical_height as f64 / self.scale_factor()) as f32
     }
 
-    pub fn set_resolution(&mut self, width: u32, height: u32) {
-        self.width = width;
-        self.height = height;
-        self.command_queue
-            .push(WindowCommand::SetResolution { width, height });
+    /// The requested window client area width in logical pixels from window
+    /// creation or the last call to [set_resolution](Window::set_resolution).
+    ///
+    /// This may differ from the actual width depending on OS size limits and
+    /// the scaling factor for high DPI monitors.
+    #[inline]
+    pub fn requested_width(&self) -> f32 {
+        self.requested_width
+    }
+
+    /// The requested window client area height in logical pixels from window
+    /// creation or the last call to [set_resolution](Window::set_resolution).
+    ///
+    /// This may differ from the actual height depending on OS size limits and
+    /// the scaling factor for high DPI monitors.
+    #[inline]
+    pub fn requested_height(&self) -> f32 {
+        self.requested_height
+    }
+
+    /// The window's client area width in physical pixels.
+    #[inline]
+    pub fn physical_width(&self) -> u32 {
+        self.physical_width
+    }
+
+    /// The window's client area height in physical pixels.
+    #[inline]
+    pub fn physical_height(&self) -> u32 {
+        self.physical_height
+    }
+
+    /// Request the OS to resize the window such that the client area matches the
+    /// specified width and height in logical pixels.
+    #[allow(clippy::float_cmp)]
+    pub fn set_resolution(&mut self, width: f32, height: f32) {
+        if self.requested_width == width && self.requested_height == height {
+            return;
+        }
+        self.requested_width = width;
+        self.requested_height = height;
+        self.command_queue.push(WindowCommand::SetResolution {
+            logical_resolution: (self.requested_width, self.requested_height),
+            scale_factor: self.scale_factor(),
+        });
+    }
+
+    /// Override the os-reported scaling factor. Pass `None` to go back to using the
+    /// scale factor reported by the backend.
+    pub fn set_scale_factor_override(&mut self, scale_factor: Option<f64>) {
+        self.scale_factor_override = scale_factor;
+        self.command_queue.push(WindowCommand::SetScaleFactor {
+            scale_factor: self.scale_factor(),
+        });
+        self.command_queue.push(WindowCommand::SetResolution {
+            logical_resolution: (self.requested_width, self.requested_height),
+            scale_factor: self.scale_factor(),
+        });
+    }
+
+    #[doc(hidden)]
+    pub fn update_scale_factor_from_backend(&mut self, scale_factor: f64) {
+        self.backend_scale_factor = scale_factor;
     }
 
     #[doc(hidden)]
-    pub fn update_resolution_from_backend(&mut self, width: u32, height: u32) {
-        self.width = width;
-        self.height = height;
+    pub fn update_actual_size_from_backend(&mut self, physical_width: u32, physical_height: u32) {
+        self.physical_width = physical_width;
+        self.physical_height = physical_height;
+    }
+
+    /// The ratio of physical pixels to logical pixels.
+    ///
+    /// `physical_pixels = logical_pixels * scale_factor`
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor_override
+            .unwrap_or(self.backend_scale_factor)
+    }
+
+    /// The window scale factor as reported by the window backend.
+    /// This value is unaffected by [scale_factor_override](Window::scale_factor_override).
+    #[inline]
+    pub fn backend_scale_factor(&self) -> f64 {
+        self.backend_scale_factor
+    }
+
+    #[inline]
+    pub fn scale_factor_override(&self) -> Option<f64> {
+        self.scale_factor_override
     }
 
     #[inline]
@@ -212,6 +333,54 @@ impl Window {
             .push(WindowCommand::SetCursorPosition { x, y });
     }
 
+    #[inline]
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.cursor_icon
+    }
+
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        self.cursor_icon = icon;
+        self.command_queue
+            .push(WindowCommand::SetCursorIcon { icon });
+    }
+
+    #[inline]
+    pub fn always_on_top(&self) -> bool {
+        self.always_on_top
+    }
+
+    /// Keeps the window above all other windows, e.g. for overlays and desktop widgets.
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.always_on_top = always_on_top;
+        self.command_queue
+            .push(WindowCommand::SetAlwaysOnTop { always_on_top });
+    }
+
+    /// Maximizes the window, or restores it to its previous size if `maximized` is false.
+    pub fn set_maximized(&mut self, maximized: bool) {
+        self.command_queue
+            .push(WindowCommand::SetMaximized { maximized });
+    }
+
+    /// Minimizes the window, or restores it if `minimized` is false.
+    ///
+    /// Not every platform supports restoring a minimized window programmatically.
+    pub fn set_minimized(&mut self, minimized: bool) {
+        self.command_queue
+            .push(WindowCommand::SetMinimized { minimized });
+    }
+
+    /// Moves the top-left corner of the window (including its decorations) to the given
+    /// position in physical desktop coordinates.
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        self.command_queue.push(WindowCommand::SetPosition { x, y });
+    }
+
+    /// Centers the window on the monitor it is currently on.
+    pub fn center(&mut self) {
+        self.command_queue.push(WindowCommand::Center);
+    }
+
     #[inline]
     pub fn mode(&self) -> WindowMode {
         self.mode
@@ -221,10 +390,17 @@ impl Window {
         self.mode = mode;
         self.command_queue.push(WindowCommand::SetWindowMode {
             mode,
-            resolution: (self.width, self.height),
+            resolution: (self.physical_width, self.physical_height),
         });
     }
 
+    /// Whether the window was created with a transparent framebuffer. This can only be set at
+    /// creation time through [WindowDescriptor::transparent].
+    #[inline]
+    pub fn transparent(&self) -> bool {
+        self.transparent
+    }
+
     #[inline]
     pub fn drain_commands(&mut self) -> impl Iterator<Item = WindowCommand> + '_ {
         self.command_queue.drain(..)
@@ -233,15 +409,25 @@ impl Window {
 
 #[derive(Debug, Clone)]
 pub struct WindowDescriptor {
-    pub width: u32,
-    pub height: u32,
+    pub width: f32,
+    pub height: f32,
+    /// Overrides the scale factor reported by the window backend. When set, logical sizes are
+    /// converted to physical pixels using this value instead of the monitor's DPI scale.
+    pub scale_factor_override: Option<f64>,
     pub title: String,
     pub vsync: bool,
     pub resizable: bool,
     pub decorations: bool,
     pub cursor_visible: bool,
     pub cursor_locked: bool,
+    pub always_on_top: bool,
     pub mode: WindowMode,
+    /// Asks the OS for a window with a transparent framebuffer.
+    ///
+    /// This is platform dependent, and currently a no-op for rendering: the wgpu 0.6 swap chain
+    /// can't request premultiplied or post-multiplied alpha, so the alpha of the `ClearColor` and
+    /// of drawn pixels is not used to composite the window with what is behind it.
+    pub transparent: bool,
     #[cfg(target_arch = "wasm32")]
     pub canvas: Option<String>,
 }
@@ -250,14 +436,17 @@ impl Default for WindowDescriptor {
     fn default() -> Self {
         WindowDescriptor {
             title: "bevy".to_string(),
-            width: 1280,
-            height: 720,
+            width: 1280.,
+            height: 720.,
+            scale_factor_override: None,
             vsync: true,
             resizable: true,
             decorations: true,
             cursor_locked: false,
             cursor_visible: true,
+            always_on_top: false,
             mode: WindowMode::Windowed,
+            transparent: false,
             #[cfg(target_arch = "wasm32")]
             canvas: None,
         }
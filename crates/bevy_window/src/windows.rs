@@ -11,6 +11,10 @@ impl Windows {
         self.windows.insert(window.id(), window);
     }
 
+    pub fn remove(&mut self, id: WindowId) -> Option<Window> {
+        self.windows.remove(&id)
+    }
+
     pub fn get(&self, id: WindowId) -> Option<&Window> {
         self.windows.get(&id)
     }
@@ -0,0 +1,146 @@
+use bevy_app::{prelude::*, Capabilities};
+use bevy_ecs::{Resources, World};
+use bevy_window::{Clipboard, ClipboardCommand, ClipboardText, CLIPBOARD_CAPABILITY};
+
+pub(crate) fn add_clipboard(app: &mut AppBuilder) {
+    if !app
+        .resources_mut()
+        .get_or_insert_with(Capabilities::default)
+        .is_enabled(CLIPBOARD_CAPABILITY)
+    {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    match arboard::Clipboard::new() {
+        Ok(clipboard) => {
+            app.add_thread_local_resource(clipboard);
+        }
+        Err(err) => {
+            bevy_utils::tracing::error!("Failed to open the clipboard. {}", err);
+            app.resources_mut()
+                .get_mut::<Capabilities>()
+                .unwrap()
+                .set_unavailable(CLIPBOARD_CAPABILITY);
+            return;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    app.add_thread_local_resource(web::WebClipboard::default());
+
+    app.resources_mut()
+        .get_or_insert_with(Clipboard::default)
+        .set_has_backend(true);
+    app.add_system_to_stage(stage::PRE_EVENT, clipboard_system);
+}
+
+/// Carries out the [Clipboard] commands queued since the last update
+#[cfg(not(target_arch = "wasm32"))]
+fn clipboard_system(_world: &mut World, resources: &mut Resources) {
+    use bevy_utils::tracing::error;
+
+    let mut backend = resources
+        .get_thread_local_mut::<arboard::Clipboard>()
+        .unwrap();
+    let mut clipboard = resources.get_mut::<Clipboard>().unwrap();
+    let mut clipboard_text_events = resources.get_mut::<Events<ClipboardText>>().unwrap();
+    for command in clipboard.drain_commands() {
+        match command {
+            ClipboardCommand::SetText { text } => {
+                if let Err(err) = backend.set_text(text) {
+                    error!("Failed to copy text to the clipboard. {}", err);
+                }
+            }
+            ClipboardCommand::RequestText => clipboard_text_events.send(ClipboardText {
+                text: backend.get_text().ok(),
+            }),
+        }
+    }
+}
+
+/// Carries out the [Clipboard] commands queued since the last update, and sends the text read
+/// by earlier requests once the browser has returned it
+#[cfg(target_arch = "wasm32")]
+fn clipboard_system(_world: &mut World, resources: &mut Resources) {
+    let backend = resources.get_thread_local::<web::WebClipboard>().unwrap();
+    let mut clipboard = resources.get_mut::<Clipboard>().unwrap();
+    for command in clipboard.drain_commands() {
+        match command {
+            ClipboardCommand::SetText { text } => backend.set_text(text),
+            ClipboardCommand::RequestText => backend.request_text(),
+        }
+    }
+
+    let mut clipboard_text_events = resources.get_mut::<Events<ClipboardText>>().unwrap();
+    for text in backend.received.borrow_mut().drain(..) {
+        clipboard_text_events.send(ClipboardText { text });
+    }
+}
+
+/// The async clipboard API. It is accessed dynamically because web-sys only exposes it as an
+/// unstable API.
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use bevy_utils::tracing::error;
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+    #[derive(Default)]
+    pub struct WebClipboard {
+        /// The results of text requests, in the order the browser returned them
+        pub received: Rc<RefCell<Vec<Option<String>>>>,
+    }
+
+    impl WebClipboard {
+        pub fn set_text(&self, text: String) {
+            let promise = match call_clipboard("writeText", &JsValue::from(text)) {
+                Some(promise) => promise,
+                None => return,
+            };
+            spawn_local(async move {
+                if let Err(err) = JsFuture::from(promise).await {
+                    error!("Failed to copy text to the clipboard. {:?}", err);
+                }
+            });
+        }
+
+        pub fn request_text(&self) {
+            let promise = match call_clipboard("readText", &JsValue::UNDEFINED) {
+                Some(promise) => promise,
+                None => {
+                    self.received.borrow_mut().push(None);
+                    return;
+                }
+            };
+            let received = self.received.clone();
+            spawn_local(async move {
+                // the browser may ask the user for permission first
+                let text = JsFuture::from(promise)
+                    .await
+                    .ok()
+                    .and_then(|text| text.as_string());
+                received.borrow_mut().push(text);
+            });
+        }
+    }
+
+    /// Calls a method of `navigator.clipboard`, which is only available in secure contexts
+    fn call_clipboard(method: &str, argument: &JsValue) -> Option<js_sys::Promise> {
+        let navigator = web_sys::window()?.navigator();
+        let clipboard = js_sys::Reflect::get(&navigator, &JsValue::from("clipboard")).ok()?;
+        if clipboard.is_undefined() {
+            error!("The clipboard is not available. Is the page served over https?");
+            return None;
+        }
+        let function = js_sys::Reflect::get(&clipboard, &JsValue::from(method))
+            .ok()?
+            .dyn_into::<js_sys::Function>()
+            .ok()?;
+        function
+            .call1(&clipboard, argument)
+            .ok()?
+            .dyn_into::<js_sys::Promise>()
+            .ok()
+    }
+}
@@ -1,3 +1,4 @@
+mod clipboard;
 mod converters;
 mod winit_config;
 mod winit_windows;
@@ -14,19 +15,31 @@ use bevy_ecs::{Resources, World};
 use bevy_math::Vec2;
 use bevy_utils::tracing::{error, trace};
 use bevy_window::{
-    CreateWindow, CursorMoved, ReceivedCharacter, Window, WindowCloseRequested, WindowCreated,
-    WindowResized, Windows,
+    AppLifecycle, CloseWindow, CreateWindow, CursorEntered, CursorLeft, CursorMoved,
+    ReceivedCharacter, WindowBackendScaleFactorChanged, WindowCloseRequested, WindowClosed,
+    WindowCreated, WindowResized, WindowScaleFactorChanged, Windows,
 };
 use winit::{
     event::{self, DeviceEvent, Event, WindowEvent},
     event_loop::{ControlFlow, EventLoop, EventLoopWindowTarget},
 };
 
+/// Runs Apps in a winit event loop and creates their windows with winit.
+///
+/// A [WinitConfig] resource inserted before this plugin is added takes precedence over
+/// `return_from_run`.
 #[derive(Default)]
-pub struct WinitPlugin;
+pub struct WinitPlugin {
+    /// Whether [App::run] should return once the event loop exits. See
+    /// [WinitConfig::return_from_run] for the caveats.
+    pub return_from_run: bool,
+}
 
 impl Plugin for WinitPlugin {
     fn build(&self, app: &mut AppBuilder) {
+        let return_from_run = self.return_from_run;
+        app.resources_mut()
+            .get_or_insert_with(|| WinitConfig { return_from_run });
         app
             // TODO: It would be great to provide a raw winit WindowEvent here, but the lifetime on it is
             // stopping us. there are plans to remove the lifetime: https://github.com/rust-windowing/winit/pull/1456
@@ -34,6 +47,7 @@ impl Plugin for WinitPlugin {
             .init_resource::<WinitWindows>()
             .set_runner(winit_runner)
             .add_system(change_window);
+        clipboard::add_clipboard(app);
     }
 }
 
@@ -71,9 +85,21 @@ fn change_window(_: &mut World, resources: &mut Resources) {
                     let window = winit_windows.get_window(id).unwrap();
                     window.set_title(&title);
                 }
-                bevy_window::WindowCommand::SetResolution { width, height } => {
+                bevy_window::WindowCommand::SetScaleFactor { scale_factor } => {
+                    let mut window_dpi_changed_events = resources
+                        .get_mut::<Events<WindowScaleFactorChanged>>()
+                        .unwrap();
+                    window_dpi_changed_events.send(WindowScaleFactorChanged { id, scale_factor });
+                }
+                bevy_window::WindowCommand::SetResolution {
+                    logical_resolution: (width, height),
+                    scale_factor,
+                } => {
                     let window = winit_windows.get_window(id).unwrap();
-                    window.set_inner_size(winit::dpi::PhysicalSize::new(width, height));
+                    window.set_inner_size(
+                        winit::dpi::LogicalSize::new(width, height)
+                            .to_physical::<f64>(scale_factor),
+                    );
                 }
                 bevy_window::WindowCommand::SetVsync { .. } => (),
                 bevy_window::WindowCommand::SetResizable { resizable } => {
@@ -100,6 +126,40 @@ fn change_window(_: &mut World, resources: &mut Resources) {
                         .set_cursor_position(winit::dpi::PhysicalPosition::new(x, y))
                         .unwrap_or_else(|e| error!("Unable to set cursor position: {}", e));
                 }
+                bevy_window::WindowCommand::SetCursorIcon { icon } => {
+                    let window = winit_windows.get_window(id).unwrap();
+                    window.set_cursor_icon(converters::convert_cursor_icon(icon));
+                }
+                bevy_window::WindowCommand::SetAlwaysOnTop { always_on_top } => {
+                    let window = winit_windows.get_window(id).unwrap();
+                    window.set_always_on_top(always_on_top);
+                }
+                bevy_window::WindowCommand::SetMaximized { maximized } => {
+                    let window = winit_windows.get_window(id).unwrap();
+                    window.set_maximized(maximized);
+                }
+                bevy_window::WindowCommand::SetMinimized { minimized } => {
+                    let window = winit_windows.get_window(id).unwrap();
+                    window.set_minimized(minimized);
+                }
+                bevy_window::WindowCommand::SetPosition { x, y } => {
+                    let window = winit_windows.get_window(id).unwrap();
+                    window.set_outer_position(winit::dpi::PhysicalPosition::new(x, y));
+                }
+                bevy_window::WindowCommand::Center => {
+                    let window = winit_windows.get_window(id).unwrap();
+                    if let Some(monitor) = window.current_monitor() {
+                        let monitor_position = monitor.position();
+                        let monitor_size = monitor.size();
+                        let window_size = window.outer_size();
+                        window.set_outer_position(winit::dpi::PhysicalPosition::new(
+                            monitor_position.x
+                                + (monitor_size.width as i32 - window_size.width as i32) / 2,
+                            monitor_position.y
+                                + (monitor_size.height as i32 - window_size.height as i32) / 2,
+                        ));
+                    }
+                }
             }
         }
     }
@@ -151,6 +211,7 @@ where
 pub fn winit_runner(mut app: App) {
     let mut event_loop = EventLoop::new();
     let mut create_window_event_reader = EventReader::<CreateWindow>::default();
+    let mut close_window_event_reader = EventReader::<CloseWindow>::default();
     let mut app_exit_event_reader = EventReader::<AppExit>::default();
 
     app.resources.insert_thread_local(event_loop.create_proxy());
@@ -172,10 +233,17 @@ pub fn winit_runner(mut app: App) {
         .get::<WinitConfig>()
         .map_or(false, |config| config.return_from_run);
 
+    // the app isn't updated while the OS has suspended it
+    let mut active = true;
+
     let event_handler = move |event: Event<()>,
                               event_loop: &EventLoopWindowTarget<()>,
                               control_flow: &mut ControlFlow| {
-        *control_flow = ControlFlow::Poll;
+        *control_flow = if active {
+            ControlFlow::Poll
+        } else {
+            ControlFlow::Wait
+        };
 
         if let Some(app_exit_events) = app.resources.get_mut::<Events<AppExit>>() {
             if app_exit_event_reader.latest(&app_exit_events).is_some() {
@@ -193,13 +261,13 @@ pub fn winit_runner(mut app: App) {
                 let mut windows = app.resources.get_mut::<Windows>().unwrap();
                 let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
                 let window = windows.get_mut(window_id).unwrap();
-                window.update_resolution_from_backend(size.width, size.height);
+                window.update_actual_size_from_backend(size.width, size.height);
 
                 let mut resize_events = app.resources.get_mut::<Events<WindowResized>>().unwrap();
                 resize_events.send(WindowResized {
                     id: window_id,
-                    height: window.height() as usize,
-                    width: window.width() as usize,
+                    width: window.width(),
+                    height: window.height(),
                 });
             }
             event::Event::WindowEvent {
@@ -225,16 +293,31 @@ pub fn winit_runner(mut app: App) {
                     let mut cursor_moved_events =
                         app.resources.get_mut::<Events<CursorMoved>>().unwrap();
                     let winit_windows = app.resources.get_mut::<WinitWindows>().unwrap();
+                    let windows = app.resources.get::<Windows>().unwrap();
                     let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
-                    let window = winit_windows.get_window(window_id).unwrap();
-                    let inner_size = window.inner_size();
+                    let window = windows.get(window_id).unwrap();
+                    let position = position.to_logical::<f32>(window.scale_factor());
                     // move origin to bottom left
-                    let y_position = inner_size.height as f32 - position.y as f32;
+                    let y_position = window.height() - position.y;
                     cursor_moved_events.send(CursorMoved {
                         id: window_id,
-                        position: Vec2::new(position.x as f32, y_position as f32),
+                        position: Vec2::new(position.x, y_position),
                     });
                 }
+                WindowEvent::CursorEntered { .. } => {
+                    let mut cursor_entered_events =
+                        app.resources.get_mut::<Events<CursorEntered>>().unwrap();
+                    let winit_windows = app.resources.get_mut::<WinitWindows>().unwrap();
+                    let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
+                    cursor_entered_events.send(CursorEntered { id: window_id });
+                }
+                WindowEvent::CursorLeft { .. } => {
+                    let mut cursor_left_events =
+                        app.resources.get_mut::<Events<CursorLeft>>().unwrap();
+                    let winit_windows = app.resources.get_mut::<WinitWindows>().unwrap();
+                    let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
+                    cursor_left_events.send(CursorLeft { id: window_id });
+                }
                 WindowEvent::MouseInput { state, button, .. } => {
                     let mut mouse_button_input_events =
                         app.resources.get_mut::<Events<MouseButtonInput>>().unwrap();
@@ -263,16 +346,19 @@ pub fn winit_runner(mut app: App) {
                         });
                     }
                 },
-                WindowEvent::Touch(mut touch) => {
+                WindowEvent::Touch(touch) => {
                     let mut touch_input_events =
                         app.resources.get_mut::<Events<TouchInput>>().unwrap();
-                    let windows = app.resources.get_mut::<Windows>().unwrap();
-                    // FIXME?: On Android window start is top while on PC/Linux/OSX on bottom
-                    if cfg!(target_os = "android") {
-                        let window_height = windows.get_primary().unwrap().height();
-                        touch.location.y = window_height as f64 - touch.location.y;
+                    let winit_windows = app.resources.get::<WinitWindows>().unwrap();
+                    let windows = app.resources.get::<Windows>().unwrap();
+                    let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
+                    let window = windows.get(window_id).unwrap();
+                    let mut location = touch.location.to_logical::<f32>(window.scale_factor());
+                    // FIXME?: On Android and iOS window start is top while on PC/Linux/OSX on bottom
+                    if cfg!(any(target_os = "android", target_os = "ios")) {
+                        location.y = window.height() - location.y;
                     }
-                    touch_input_events.send(converters::convert_touch_input(touch));
+                    touch_input_events.send(converters::convert_touch_input(touch, location));
                 }
                 WindowEvent::ReceivedCharacter(c) => {
                     let mut char_input_events = app
@@ -288,6 +374,68 @@ pub fn winit_runner(mut app: App) {
                         char: c,
                     })
                 }
+                WindowEvent::ScaleFactorChanged {
+                    scale_factor,
+                    new_inner_size,
+                } => {
+                    let winit_windows = app.resources.get::<WinitWindows>().unwrap();
+                    let mut windows = app.resources.get_mut::<Windows>().unwrap();
+                    let window_id = winit_windows.get_window_id(winit_window_id).unwrap();
+                    let window = windows.get_mut(window_id).unwrap();
+
+                    let mut backend_scale_factor_change_events = app
+                        .resources
+                        .get_mut::<Events<WindowBackendScaleFactorChanged>>()
+                        .unwrap();
+                    backend_scale_factor_change_events.send(WindowBackendScaleFactorChanged {
+                        id: window_id,
+                        scale_factor,
+                    });
+
+                    let prior_factor = window.scale_factor();
+                    let (prior_width, prior_height) = (window.width(), window.height());
+                    window.update_scale_factor_from_backend(scale_factor);
+                    let new_factor = window.scale_factor();
+                    if let Some(forced_factor) = window.scale_factor_override() {
+                        // the override wins over the OS-suggested size, so keep the requested
+                        // logical resolution at the forced scale factor
+                        *new_inner_size = winit::dpi::LogicalSize::new(
+                            window.requested_width(),
+                            window.requested_height(),
+                        )
+                        .to_physical::<u32>(forced_factor);
+                    }
+
+                    #[allow(clippy::float_cmp)]
+                    let scale_factor_changed = new_factor != prior_factor;
+                    if scale_factor_changed {
+                        let mut scale_factor_change_events = app
+                            .resources
+                            .get_mut::<Events<WindowScaleFactorChanged>>()
+                            .unwrap();
+                        scale_factor_change_events.send(WindowScaleFactorChanged {
+                            id: window_id,
+                            scale_factor: new_factor,
+                        });
+                    }
+
+                    window.update_actual_size_from_backend(
+                        new_inner_size.width,
+                        new_inner_size.height,
+                    );
+                    #[allow(clippy::float_cmp)]
+                    let size_changed =
+                        window.width() != prior_width || window.height() != prior_height;
+                    if size_changed {
+                        let mut resize_events =
+                            app.resources.get_mut::<Events<WindowResized>>().unwrap();
+                        resize_events.send(WindowResized {
+                            id: window_id,
+                            width: window.width(),
+                            height: window.height(),
+                        });
+                    }
+                }
                 _ => {}
             },
             event::Event::DeviceEvent { ref event, .. } => {
@@ -299,13 +447,37 @@ pub fn winit_runner(mut app: App) {
                     });
                 }
             }
-            event::Event::MainEventsCleared => {
-                handle_create_window_events(
-                    &mut app.resources,
-                    event_loop,
-                    &mut create_window_event_reader,
-                );
+            event::Event::Suspended => {
+                app.resources
+                    .get_mut::<Events<AppLifecycle>>()
+                    .unwrap()
+                    .send(AppLifecycle::Suspended);
+                // update once more so systems can react before the app stops updating
                 app.update();
+                active = false;
+            }
+            event::Event::Resumed => {
+                app.resources
+                    .get_mut::<Events<AppLifecycle>>()
+                    .unwrap()
+                    .send(AppLifecycle::Resumed);
+                active = true;
+            }
+            event::Event::MainEventsCleared => {
+                // the windows closed on exit may still be rendered to, so the app isn't updated
+                // once it is exiting
+                if active && *control_flow != ControlFlow::Exit {
+                    handle_close_window_events(&mut app.resources, &mut close_window_event_reader);
+                    handle_create_window_events(
+                        &mut app.resources,
+                        event_loop,
+                        &mut create_window_event_reader,
+                    );
+                    app.update();
+                }
+            }
+            event::Event::LoopDestroyed => {
+                app.shutdown();
             }
             _ => (),
         }
@@ -327,10 +499,30 @@ fn handle_create_window_events(
     let create_window_events = resources.get::<Events<CreateWindow>>().unwrap();
     let mut window_created_events = resources.get_mut::<Events<WindowCreated>>().unwrap();
     for create_window_event in create_window_event_reader.iter(&create_window_events) {
-        let window = Window::new(create_window_event.id, &create_window_event.descriptor);
-        winit_windows.create_window(event_loop, &window);
+        let window = winit_windows.create_window(
+            event_loop,
+            create_window_event.id,
+            &create_window_event.descriptor,
+        );
         let window_id = window.id();
         windows.add(window);
         window_created_events.send(WindowCreated { id: window_id });
     }
 }
+
+fn handle_close_window_events(
+    resources: &mut Resources,
+    close_window_event_reader: &mut EventReader<CloseWindow>,
+) {
+    let mut winit_windows = resources.get_mut::<WinitWindows>().unwrap();
+    let mut windows = resources.get_mut::<Windows>().unwrap();
+    let close_window_events = resources.get::<Events<CloseWindow>>().unwrap();
+    let mut window_closed_events = resources.get_mut::<Events<WindowClosed>>().unwrap();
+    for close_window_event in close_window_event_reader.iter(&close_window_events) {
+        let id = close_window_event.id;
+        winit_windows.remove_window(id);
+        if windows.remove(id).is_some() {
+            window_closed_events.send(WindowClosed { id });
+        }
+    }
+}
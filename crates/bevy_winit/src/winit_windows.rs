@@ -1,5 +1,5 @@
 use bevy_utils::HashMap;
-use bevy_window::{Window, WindowId, WindowMode};
+use bevy_window::{Window, WindowDescriptor, WindowId, WindowMode};
 
 #[derive(Debug, Default)]
 pub struct WinitWindows {
@@ -12,8 +12,9 @@ impl WinitWindows {
     pub fn create_window(
         &mut self,
         event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
-        window: &Window,
-    ) {
+        window_id: WindowId,
+        window_descriptor: &WindowDescriptor,
+    ) -> Window {
         #[cfg(target_os = "windows")]
         let mut winit_window_builder = {
             use winit::platform::windows::WindowBuilderExtWindows;
@@ -23,38 +24,53 @@ impl WinitWindows {
         #[cfg(not(target_os = "windows"))]
         let mut winit_window_builder = winit::window::WindowBuilder::new();
 
-        winit_window_builder = match window.mode() {
+        winit_window_builder = match window_descriptor.mode {
             WindowMode::BorderlessFullscreen => winit_window_builder.with_fullscreen(Some(
                 winit::window::Fullscreen::Borderless(event_loop.primary_monitor()),
             )),
             WindowMode::Fullscreen { use_size } => winit_window_builder.with_fullscreen(Some(
                 winit::window::Fullscreen::Exclusive(match use_size {
-                    true => get_fitting_videomode(
-                        &event_loop.primary_monitor().unwrap(),
-                        window.width(),
-                        window.height(),
-                    ),
+                    true => {
+                        let primary_monitor = event_loop.primary_monitor().unwrap();
+                        let (width, height) =
+                            physical_size(window_descriptor, primary_monitor.scale_factor());
+                        get_fitting_videomode(&primary_monitor, width, height)
+                    }
                     false => get_best_videomode(&event_loop.primary_monitor().unwrap()),
                 }),
             )),
-            _ => winit_window_builder
-                .with_inner_size(winit::dpi::PhysicalSize::new(
-                    window.width(),
-                    window.height(),
-                ))
-                .with_resizable(window.resizable())
-                .with_decorations(window.decorations()),
+            _ => {
+                let WindowDescriptor {
+                    width,
+                    height,
+                    scale_factor_override,
+                    ..
+                } = window_descriptor;
+                let logical_size = winit::dpi::LogicalSize::new(*width, *height);
+                let winit_window_builder = if let Some(scale_factor) = scale_factor_override {
+                    winit_window_builder
+                        .with_inner_size(logical_size.to_physical::<f64>(*scale_factor))
+                } else {
+                    winit_window_builder.with_inner_size(logical_size)
+                };
+                winit_window_builder
+                    .with_resizable(window_descriptor.resizable)
+                    .with_decorations(window_descriptor.decorations)
+            }
         };
 
         #[allow(unused_mut)]
-        let mut winit_window_builder = winit_window_builder.with_title(window.title());
+        let mut winit_window_builder = winit_window_builder
+            .with_title(&window_descriptor.title)
+            .with_transparent(window_descriptor.transparent)
+            .with_always_on_top(window_descriptor.always_on_top);
 
         #[cfg(target_arch = "wasm32")]
         {
             use wasm_bindgen::JsCast;
             use winit::platform::web::WindowBuilderExtWebSys;
 
-            if let Some(selector) = &window.canvas {
+            if let Some(selector) = &window_descriptor.canvas {
                 let window = web_sys::window().unwrap();
                 let document = window.document().unwrap();
                 let canvas = document
@@ -71,24 +87,22 @@ impl WinitWindows {
 
         let winit_window = winit_window_builder.build(&event_loop).unwrap();
 
-        match winit_window.set_cursor_grab(window.cursor_locked()) {
+        match winit_window.set_cursor_grab(window_descriptor.cursor_locked) {
             Ok(_) => {}
             Err(winit::error::ExternalError::NotSupported(_)) => {}
             Err(err) => Err(err).unwrap(),
         }
 
-        winit_window.set_cursor_visible(window.cursor_visible());
+        winit_window.set_cursor_visible(window_descriptor.cursor_visible);
 
-        self.window_id_to_winit
-            .insert(window.id(), winit_window.id());
-        self.winit_to_window_id
-            .insert(winit_window.id(), window.id());
+        self.window_id_to_winit.insert(window_id, winit_window.id());
+        self.winit_to_window_id.insert(winit_window.id(), window_id);
 
         #[cfg(target_arch = "wasm32")]
         {
             use winit::platform::web::WindowExtWebSys;
 
-            if window.canvas.is_none() {
+            if window_descriptor.canvas.is_none() {
                 let canvas = winit_window.canvas();
 
                 let window = web_sys::window().unwrap();
@@ -100,7 +114,16 @@ impl WinitWindows {
             }
         }
 
+        let inner_size = winit_window.inner_size();
+        let scale_factor = winit_window.scale_factor();
         self.windows.insert(winit_window.id(), winit_window);
+        Window::new(
+            window_id,
+            window_descriptor,
+            inner_size.width,
+            inner_size.height,
+            scale_factor,
+        )
     }
 
     pub fn get_window(&self, id: WindowId) -> Option<&winit::window::Window> {
@@ -112,7 +135,23 @@ impl WinitWindows {
     pub fn get_window_id(&self, id: winit::window::WindowId) -> Option<WindowId> {
         self.winit_to_window_id.get(&id).cloned()
     }
+
+    /// Removes the winit window of `id`. The native window is closed once it is dropped.
+    pub fn remove_window(&mut self, id: WindowId) -> Option<winit::window::Window> {
+        let winit_id = self.window_id_to_winit.remove(&id)?;
+        self.winit_to_window_id.remove(&winit_id);
+        self.windows.remove(&winit_id)
+    }
+}
+fn physical_size(window_descriptor: &WindowDescriptor, backend_scale_factor: f64) -> (u32, u32) {
+    let scale_factor = window_descriptor
+        .scale_factor_override
+        .unwrap_or(backend_scale_factor);
+    let size = winit::dpi::LogicalSize::new(window_descriptor.width, window_descriptor.height)
+        .to_physical::<u32>(scale_factor);
+    (size.width, size.height)
 }
+
 pub fn get_fitting_videomode(
     monitor: &winit::monitor::MonitorHandle,
     width: u32,
@@ -87,10 +87,12 @@ fn setup(
                 sprite: Sprite {
                     size: Vec2::new(1.0, 1.0) * SPRITE_SIZE,
                     resize_mode: SpriteResizeMode::Manual,
+                    ..Default::default()
                 },
                 material: materials.add(ColorMaterial {
                     color: COL_DESELECTED * col,
                     texture: Some(texture_handle.clone()),
+                    ..Default::default()
                 }),
                 ..Default::default()
             })
@@ -237,11 +239,11 @@ fn collision_system(
 
     let win = wins.get_primary().unwrap();
 
-    let ceiling = (win.height() / 2) as f32;
-    let ground = -((win.height() / 2) as f32);
+    let ceiling = win.height() / 2.;
+    let ground = -(win.height() / 2.);
 
-    let wall_left = -((win.width() / 2) as f32);
-    let wall_right = (win.width() / 2) as f32;
+    let wall_left = -(win.width() / 2.);
+    let wall_right = win.width() / 2.;
 
     for (mut v, mut t) in q.iter_mut() {
         let left = t.translation.x - SPRITE_SIZE / 2.0;
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+/// This example shows a low resolution sprite drifting slowly across the screen. The pixel perfect
+/// camera scales each texel by a whole number of pixels and snaps the sprite to the texel grid, so
+/// it moves without shimmering.
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(drift_system)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let texture_handle = asset_server.load("textures/rpg/mobs/slime-green.png");
+    commands
+        // always show at least 160x90 world units, scaled up by a whole number factor
+        .spawn(Camera2dBundle::pixel_perfect(160.0, 90.0))
+        .spawn(SpriteBundle {
+            material: materials.add(texture_handle.into()),
+            ..Default::default()
+        });
+}
+
+fn drift_system(time: Res<Time>, mut query: Query<&mut Transform, With<Sprite>>) {
+    for mut transform in query.iter_mut() {
+        transform.translation.x = (time.seconds_since_startup as f32 * 0.5).sin() * 60.0;
+    }
+}
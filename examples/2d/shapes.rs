@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+/// This example shows how to draw simple 2D shapes without any textures
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let shapes: Vec<(Mesh, Color)> = vec![
+        (shape::Circle::new(50.0).into(), Color::rgb(0.9, 0.3, 0.3)),
+        (
+            shape::Ring::new(30.0, 50.0).into(),
+            Color::rgb(0.3, 0.9, 0.3),
+        ),
+        (
+            shape::RegularPolygon::new(50.0, 6).into(),
+            Color::rgb(0.3, 0.3, 0.9),
+        ),
+        (
+            shape::RoundedRect::new(Vec2::new(100.0, 60.0), 15.0).into(),
+            Color::rgb(0.9, 0.9, 0.3),
+        ),
+    ];
+
+    commands.spawn(Camera2dBundle::default());
+    for (i, (mesh, color)) in shapes.into_iter().enumerate() {
+        let x = -225.0 + 150.0 * i as f32;
+        commands.spawn(SpriteBundle {
+            transform: Transform::from_translation(Vec3::new(x, 0.0, 0.0)),
+            ..SpriteBundle::from_mesh(meshes.add(mesh), materials.add(color.into()))
+        });
+    }
+}
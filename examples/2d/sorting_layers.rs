@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+/// This example shows how sorting layers control which sprites are drawn on top, independent of
+/// their z translation
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        // layers are listed back-to-front
+        .add_resource(SortingLayers::new(vec![
+            "Background",
+            SortingLayers::DEFAULT,
+            "Foreground",
+        ]))
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let texture_handle = asset_server.load("branding/icon.png");
+    commands.spawn(Camera2dBundle::default());
+
+    // the background is closest to the camera, but its layer is drawn first
+    commands
+        .spawn(SpriteBundle {
+            material: materials.add(Color::rgb(0.2, 0.2, 0.8).into()),
+            sprite: Sprite::new(Vec2::new(600.0, 400.0)),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.0, 10.0)),
+            ..Default::default()
+        })
+        .with(SortingLayer::new("Background", 0));
+
+    // characters on the default layer are ordered by their order in layer
+    let character_material = materials.add(texture_handle.into());
+    for (i, order) in [2, 1, 0].iter().enumerate() {
+        commands
+            .spawn(SpriteBundle {
+                material: character_material.clone(),
+                sprite: Sprite {
+                    color: Color::rgb(1.0, 1.0 - 0.3 * i as f32, 1.0 - 0.3 * i as f32),
+                    ..Sprite::new(Vec2::new(256.0, 256.0))
+                },
+                transform: Transform::from_translation(Vec3::new(
+                    -100.0 + 100.0 * i as f32,
+                    0.0,
+                    0.0,
+                )),
+                ..Default::default()
+            })
+            .with(SortingLayer::new(SortingLayers::DEFAULT, *order));
+    }
+
+    // the foreground is furthest from the camera, but its layer is drawn last
+    commands
+        .spawn(SpriteBundle {
+            material: materials.add(Color::rgba(0.1, 0.6, 0.1, 0.8).into()),
+            sprite: Sprite::new(Vec2::new(600.0, 80.0)),
+            transform: Transform::from_translation(Vec3::new(0.0, -160.0, -10.0)),
+            ..Default::default()
+        })
+        .with(SortingLayer::new("Foreground", 0));
+}
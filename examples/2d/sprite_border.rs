@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// This example resizes a text box sprite with 9-slice scaling. The corners of the texture keep
+/// their size while the edges and center stretch to fill the sprite.
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(resize_system)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let texture_handle = asset_server.load("textures/rpg/ui/generic-rpg-ui-text-box.png");
+    commands
+        .spawn(Camera2dBundle::default())
+        .spawn(SpriteBundle {
+            material: materials.add(texture_handle.into()),
+            sprite: Sprite::new(Vec2::new(112.0, 32.0)),
+            transform: Transform::from_scale(Vec3::splat(4.0)),
+            ..Default::default()
+        })
+        .with(SpriteBorder::all(8.0));
+}
+
+fn resize_system(time: Res<Time>, mut query: Query<&mut Sprite, With<SpriteBorder>>) {
+    let t = time.seconds_since_startup as f32;
+    for mut sprite in query.iter_mut() {
+        sprite.size = Vec2::new(112.0 + t.sin() * 80.0, 32.0 + t.cos() * 16.0);
+    }
+}
@@ -14,20 +14,22 @@ fn animate_sprite_system(
 ) {
     for (timer, mut sprite, texture_atlas_handle) in query.iter_mut() {
         if timer.is_finished() {
-            let texture_atlas = texture_atlases.get(texture_atlas_handle).unwrap();
-            sprite.index = ((sprite.index as usize + 1) % texture_atlas.textures.len()) as u32;
+            // the atlas is loaded asynchronously, so it might not be available yet. atlases that
+            // are packed from several textures also have no sprites until they are packed
+            if let Some(texture_atlas) = texture_atlases
+                .get(texture_atlas_handle)
+                .filter(|texture_atlas| texture_atlas.is_ready())
+            {
+                sprite.index = ((sprite.index as usize + 1) % texture_atlas.textures.len()) as u32;
+            }
         }
     }
 }
 
-fn setup(
-    commands: &mut Commands,
-    asset_server: Res<AssetServer>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-) {
-    let texture_handle = asset_server.load("textures/rpg/chars/gabe/gabe-idle-run.png");
-    let texture_atlas = TextureAtlas::from_grid(texture_handle, Vec2::new(24.0, 24.0), 7, 1);
-    let texture_atlas_handle = texture_atlases.add(texture_atlas);
+fn setup(commands: &mut Commands, asset_server: Res<AssetServer>) {
+    // the .atlas file describes how the sprite sheet texture is split into a 7x1 grid. this is
+    // equivalent to calling TextureAtlas::from_grid with the loaded texture
+    let texture_atlas_handle = asset_server.load("textures/rpg/chars/gabe/gabe-idle-run.atlas");
     commands
         .spawn(Camera2dBundle::default())
         .spawn(SpriteSheetBundle {
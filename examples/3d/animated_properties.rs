@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+/// This example animates the color of a light and of a material with an animation clip. Any
+/// field of a component or asset that derives `Properties` can be animated once its type is
+/// registered with `add_component_animation` or `add_asset_animation`.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_component_animation::<Light>()
+        .add_asset_animation::<StandardMaterial>()
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut clips: ResMut<Assets<AnimationClip>>,
+) {
+    let mut clip = AnimationClip::default();
+    // the light is a child of the animated entity, labeled "light"
+    clip.add_property_curve(
+        EntityPath::new(vec!["light"]),
+        PropertyCurve::new::<Light, Color>(
+            "color",
+            vec![0.0, 1.0, 2.0, 3.0],
+            vec![Color::WHITE, Color::ORANGE, Color::PURPLE, Color::WHITE],
+        ),
+    );
+    // the material is found through the animated entity's Handle<StandardMaterial>
+    clip.add_property_curve(
+        EntityPath::default(),
+        PropertyCurve::new::<StandardMaterial, Color>(
+            "albedo",
+            vec![0.0, 1.5, 3.0],
+            vec![
+                Color::rgb(0.8, 0.7, 0.6),
+                Color::rgb(0.3, 0.5, 0.8),
+                Color::rgb(0.8, 0.7, 0.6),
+            ],
+        ),
+    );
+    let mut player = AnimationPlayer::default();
+    player.play(clips.add(clip)).repeat();
+
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..Default::default()
+        })
+        .with(player)
+        .with_children(|parent| {
+            parent
+                .spawn(LightBundle {
+                    transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+                    ..Default::default()
+                })
+                .with(Labels::from(vec!["light"]));
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-3.0, 3.0, 5.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+/// This example animates the transforms of an entity and its child with animation clips.
+/// Press space to pause, up and down to change the playback speed, R to restart the clips and
+/// tab to cross-fade to the other clip.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(control_animation)
+        .add_system(print_animation_events)
+        .run();
+}
+
+struct Clips {
+    orbit: Handle<AnimationClip>,
+    hop: Handle<AnimationClip>,
+}
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut clips: ResMut<Assets<AnimationClip>>,
+) {
+    let mut clip = AnimationClip::default();
+    // the entity with the AnimationPlayer is animated by curves with an empty path
+    clip.add_curve(
+        EntityPath::default(),
+        VariableCurve::new(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0],
+            Keyframes::Translation(vec![
+                Vec3::new(-2.0, 0.0, 0.0),
+                Vec3::new(0.0, 0.0, 2.0),
+                Vec3::new(2.0, 0.0, 0.0),
+                Vec3::new(0.0, 0.0, -2.0),
+                Vec3::new(-2.0, 0.0, 0.0),
+            ]),
+        ),
+    );
+    // its children are found by their labels
+    clip.add_curve(
+        EntityPath::new(vec!["moon"]),
+        VariableCurve::new(
+            vec![0.0, 2.0, 4.0],
+            Keyframes::Rotation(vec![
+                Quat::identity(),
+                Quat::from_rotation_y(std::f32::consts::PI),
+                Quat::from_rotation_y(2.0 * std::f32::consts::PI),
+            ]),
+        ),
+    );
+    clip.add_curve(
+        EntityPath::new(vec!["moon"]),
+        VariableCurve::new(
+            vec![0.0, 2.0, 4.0],
+            Keyframes::Scale(vec![Vec3::splat(0.3), Vec3::splat(0.6), Vec3::splat(0.3)]),
+        ),
+    );
+    // events are sent when playback reaches their timestamp
+    clip.add_event(0.0, "left")
+        .add_event(1.0, "front")
+        .add_event(2.0, "right")
+        .add_event(3.0, "back");
+    let orbit = clips.add(clip);
+
+    let mut clip = AnimationClip::default();
+    clip.add_curve(
+        EntityPath::default(),
+        VariableCurve::new(
+            vec![0.0, 0.5, 1.0],
+            Keyframes::Translation(vec![Vec3::zero(), Vec3::new(0.0, 1.5, 0.0), Vec3::zero()]),
+        ),
+    );
+    let hop = clips.add(clip);
+
+    let mut player = AnimationPlayer::default();
+    player.play(orbit.clone()).repeat();
+    commands.insert_resource(Clips { orbit, hop });
+
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 1.0 }));
+    commands
+        .spawn(PbrBundle {
+            mesh: cube.clone(),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..Default::default()
+        })
+        .with(player)
+        .with_children(|parent| {
+            parent
+                .spawn(PbrBundle {
+                    mesh: cube,
+                    material: materials.add(Color::rgb(0.3, 0.5, 0.8).into()),
+                    transform: Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)),
+                    ..Default::default()
+                })
+                .with(Labels::from(vec!["moon"]));
+        })
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-4.0, 5.0, 8.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
+
+fn control_animation(
+    keyboard_input: Res<Input<KeyCode>>,
+    clips: Res<Clips>,
+    mut players: Query<&mut AnimationPlayer>,
+) {
+    for mut player in players.iter_mut() {
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            if player.is_paused() {
+                player.resume();
+            } else {
+                player.pause();
+            }
+        }
+        if keyboard_input.just_pressed(KeyCode::Up) {
+            let speed = player.speed() * 2.0;
+            player.set_speed(speed);
+        }
+        if keyboard_input.just_pressed(KeyCode::Down) {
+            let speed = player.speed() / 2.0;
+            player.set_speed(speed);
+        }
+        if keyboard_input.just_pressed(KeyCode::R) {
+            player.seek_to(0.0);
+        }
+        if keyboard_input.just_pressed(KeyCode::Tab) {
+            // blend smoothly from the current clip to the other one
+            let next = if player.clip() == Some(&clips.orbit) {
+                clips.hop.clone()
+            } else {
+                clips.orbit.clone()
+            };
+            player.cross_fade(next, 0.5);
+        }
+    }
+}
+
+fn print_animation_events(
+    mut event_reader: Local<EventReader<AnimationEvent>>,
+    events: Res<Events<AnimationEvent>>,
+) {
+    for event in event_reader.iter(&events) {
+        info!("reached {} (clip weight {})", event.name, event.weight);
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+/// This example lets an animation graph choose which clip a cube plays. Hold the right arrow to
+/// move, which switches from the idle state to the moving state, and press space to jump from
+/// any state. The jump returns to the idle state when its clip has finished.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(set_parameters)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut clips: ResMut<Assets<AnimationClip>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    let mut idle = AnimationClip::default();
+    idle.add_curve(
+        EntityPath::default(),
+        VariableCurve::new(
+            vec![0.0, 1.0, 2.0],
+            Keyframes::Scale(vec![Vec3::one(), Vec3::new(1.1, 0.9, 1.1), Vec3::one()]),
+        ),
+    );
+    let mut moving = AnimationClip::default();
+    moving.add_curve(
+        EntityPath::default(),
+        VariableCurve::new(
+            vec![0.0, 0.5, 1.0],
+            Keyframes::Rotation(vec![
+                Quat::from_rotation_z(0.2),
+                Quat::from_rotation_z(-0.2),
+                Quat::from_rotation_z(0.2),
+            ]),
+        ),
+    );
+    let mut jump = AnimationClip::default();
+    jump.add_curve(
+        EntityPath::default(),
+        VariableCurve::new(
+            vec![0.0, 0.4, 0.8],
+            Keyframes::Translation(vec![Vec3::zero(), Vec3::new(0.0, 2.0, 0.0), Vec3::zero()]),
+        ),
+    );
+
+    let mut graph = AnimationGraph::default();
+    graph
+        .add_state("idle", AnimationState::new(clips.add(idle)))
+        .add_state("moving", AnimationState::new(clips.add(moving)))
+        .add_state("jump", AnimationState::new(clips.add(jump)).once())
+        .add_transition("idle", "moving", Condition::Bool("moving".into()), 0.3)
+        .add_transition(
+            "moving",
+            "idle",
+            Condition::Not(Box::new(Condition::Bool("moving".into()))),
+            0.3,
+        )
+        .add_transition("jump", "idle", Condition::Finished, 0.2)
+        .add_transition_from_any("jump", Condition::Trigger("jump".into()), 0.1);
+
+    let mut player = AnimationPlayer::default();
+    player.play_graph(graphs.add(graph));
+
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..Default::default()
+        })
+        .with(player)
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-3.0, 3.0, 6.0))
+                .looking_at(Vec3::new(0.0, 1.0, 0.0), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
+
+/// Gameplay code only sets parameters, the graph decides when to change clips
+fn set_parameters(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut players: Query<&mut AnimationPlayer>,
+    mut previous_state: Local<Option<String>>,
+) {
+    for mut player in players.iter_mut() {
+        player.set_bool("moving", keyboard_input.pressed(KeyCode::Right));
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            player.set_trigger("jump");
+        }
+
+        let state = player.graph_state().map(|state| state.to_string());
+        if state != *previous_state {
+            if let Some(state) = &state {
+                info!("entered state {}", state);
+            }
+            *previous_state = state;
+        }
+    }
+}
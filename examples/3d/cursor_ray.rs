@@ -0,0 +1,68 @@
+use bevy::{prelude::*, window::CursorMoved};
+
+/// This example casts a ray from the camera through the cursor and moves a cube to the point
+/// where the ray hits the ground.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(follow_cursor)
+        .run();
+}
+
+struct Follower;
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Plane { size: 10.0 })),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..Default::default()
+        })
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 0.5 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..Default::default()
+        })
+        .with(Follower)
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-4.0, 6.0, 8.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
+
+fn follow_cursor(
+    mut cursor_moved_event_reader: Local<EventReader<CursorMoved>>,
+    cursor_moved_events: Res<Events<CursorMoved>>,
+    windows: Res<Windows>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut followers: Query<&mut Transform, With<Follower>>,
+) {
+    let cursor_position = match cursor_moved_event_reader.latest(&cursor_moved_events) {
+        Some(event) => event.position,
+        None => return,
+    };
+    for (camera, camera_transform) in cameras.iter() {
+        let ray = match camera.viewport_to_world(&windows, camera_transform, cursor_position) {
+            Some(ray) => ray,
+            None => continue,
+        };
+        // the ground is the plane through the origin facing up
+        if let Some(distance) = ray.intersect_plane(Vec3::zero(), Vec3::unit_y()) {
+            let hit = ray.get_point(distance);
+            for mut transform in followers.iter_mut() {
+                transform.translation = hit + Vec3::new(0.0, 0.25, 0.0);
+            }
+        }
+    }
+}
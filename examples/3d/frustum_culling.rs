@@ -0,0 +1,74 @@
+use bevy::{prelude::*, render::primitives::BoundingSphere};
+
+/// This example spins the camera over a grid of cubes. Cubes outside of the camera's view are
+/// culled, and the bounding spheres of the visible ones are drawn with gizmos.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(rotate_camera)
+        .add_system(show_visible)
+        .run();
+}
+
+struct Spinning;
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 0.5 }));
+    let material = materials.add(Color::rgb(0.8, 0.7, 0.6).into());
+    for x in -10..=10 {
+        for z in -10..=10 {
+            commands.spawn(PbrBundle {
+                mesh: cube.clone(),
+                material: material.clone(),
+                transform: Transform::from_translation(Vec3::new(x as f32, 0.0, z as f32)),
+                ..Default::default()
+            });
+        }
+    }
+    commands
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(0.0, 8.0, 0.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(0.0, 3.0, 0.0))
+                .looking_at(Vec3::new(0.0, 0.0, -5.0), Vec3::unit_y()),
+            ..Default::default()
+        })
+        .with(Spinning);
+}
+
+fn rotate_camera(time: Res<Time>, mut query: Query<&mut Transform, With<Spinning>>) {
+    for mut transform in query.iter_mut() {
+        transform.rotate(Quat::from_rotation_y(time.delta_seconds * 0.5));
+    }
+}
+
+fn show_visible(
+    mut gizmos: ResMut<Gizmos>,
+    mut last_count: Local<usize>,
+    query: Query<(&ComputedVisibility, &GlobalTransform, &BoundingSphere)>,
+) {
+    let mut count = 0;
+    for (visibility, transform, bounding_sphere) in query.iter() {
+        if !visibility.is_visible {
+            continue;
+        }
+        count += 1;
+        gizmos.sphere(
+            transform.translation + bounding_sphere.center,
+            bounding_sphere.radius,
+            Color::YELLOW,
+        );
+    }
+    if count != *last_count {
+        info!("{} cubes are visible", count);
+        *last_count = count;
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+/// This example draws debug gizmos around a rotating cube. Gizmos only last for one frame, so
+/// they are drawn again every frame.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(rotate)
+        .add_system(draw_gizmos)
+        .run();
+}
+
+struct Rotating;
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..Default::default()
+        })
+        .with(Rotating)
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-3.0, 3.0, 5.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
+
+fn rotate(time: Res<Time>, mut query: Query<&mut Transform, With<Rotating>>) {
+    for mut transform in query.iter_mut() {
+        transform.rotate(Quat::from_rotation_y(time.delta_seconds));
+    }
+}
+
+fn draw_gizmos(mut gizmos: ResMut<Gizmos>, query: Query<&GlobalTransform, With<Rotating>>) {
+    for transform in query.iter() {
+        // slightly larger than the cube, so the lines aren't hidden by its faces
+        let mut outline = *transform;
+        outline.scale *= 1.05;
+        gizmos
+            .cuboid(&outline, Color::YELLOW)
+            .axes(transform, 1.5)
+            .sphere(transform.translation, 1.0, Color::WHITE)
+            .ray(
+                transform.translation,
+                transform.rotation * Vec3::new(0.0, 0.0, -2.0),
+                Color::PINK,
+            );
+    }
+    gizmos.line(
+        Vec3::new(-2.0, -0.5, 0.0),
+        Vec3::new(2.0, -0.5, 0.0),
+        Color::GRAY,
+    );
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+/// This example highlights the shape under the cursor and logs clicks on it. The cube is picked
+/// by its bounding box and the sphere by its triangles.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(highlight_picked)
+        .run();
+}
+
+struct Highlight {
+    normal: Handle<StandardMaterial>,
+    hovered: Handle<StandardMaterial>,
+}
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let highlight = Highlight {
+        normal: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+        hovered: materials.add(Color::rgb(0.3, 0.5, 0.8).into()),
+    };
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: highlight.normal.clone(),
+            transform: Transform::from_translation(Vec3::new(-1.0, 0.0, 0.0)),
+            ..Default::default()
+        })
+        .with(Pickable::default())
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Icosphere {
+                radius: 0.6,
+                subdivisions: 3,
+            })),
+            material: highlight.normal.clone(),
+            transform: Transform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+            ..Default::default()
+        })
+        .with(Pickable {
+            mode: PickingMode::Triangles,
+        })
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(0.0, 2.0, 5.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+    commands.insert_resource(highlight);
+}
+
+fn highlight_picked(
+    mut pick_event_reader: Local<EventReader<PickEvent>>,
+    pick_events: Res<Events<PickEvent>>,
+    highlight: Res<Highlight>,
+    mut materials: Query<&mut Handle<StandardMaterial>>,
+) {
+    for event in pick_event_reader.iter(&pick_events) {
+        let material = match event.kind {
+            PickEventKind::HoverStarted => highlight.hovered.clone(),
+            PickEventKind::HoverEnded => highlight.normal.clone(),
+            PickEventKind::Clicked(button) => {
+                info!(
+                    "clicked {:?} with {:?} at {:?}, {} from the camera",
+                    event.hit.entity, button, event.hit.position, event.hit.distance
+                );
+                continue;
+            }
+        };
+        if let Ok(mut handle) = materials.get_mut(event.hit.entity) {
+            *handle = material;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+/// This example loads a glTF file with a skinned mesh and plays its animation, which bends the
+/// mesh by rotating one of its joints.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(play_animation)
+        .run();
+}
+
+fn setup(commands: &mut Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_scene(asset_server.load("models/SimpleSkin/SimpleSkin.gltf"))
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 5.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(0.0, 1.0, 5.0))
+                .looking_at(Vec3::new(0.0, 1.0, 0.0), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
+
+/// Starts the animation once the scene has been spawned. The clip animates the scene's nodes by
+/// their labels, so the player is added to the scene's root entity.
+fn play_animation(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    entity_labels: Res<EntityLabels>,
+    mut started: Local<bool>,
+) {
+    if *started {
+        return;
+    }
+    if let Some(root) = entity_labels
+        .get("Scene0")
+        .and_then(|entities| entities.first())
+    {
+        let mut player = AnimationPlayer::default();
+        player
+            .play(asset_server.load("models/SimpleSkin/SimpleSkin.gltf#Animation0"))
+            .repeat();
+        commands.insert_one(*root, player);
+        *started = true;
+    }
+}
@@ -1,12 +1,17 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, render::texture::TextureSuspendSettings};
 
 // the `bevy_main` proc_macro generates the required android boilerplate
 #[bevy_main]
 fn main() {
     App::build()
         .add_resource(Msaa { samples: 2 })
+        // free GPU memory while the app is in the background
+        .add_resource(TextureSuspendSettings {
+            release_on_suspend: true,
+        })
         .add_plugins(DefaultPlugins)
         .add_startup_system(setup)
+        .add_system(app_lifecycle)
         .run();
 }
 
@@ -43,3 +48,14 @@ fn setup(
             ..Default::default()
         });
 }
+
+/// The app stops updating while it is in the background. Its window surface and textures are
+/// recreated when it comes back to the foreground.
+fn app_lifecycle(
+    mut lifecycle_reader: Local<EventReader<AppLifecycle>>,
+    lifecycle_events: Res<Events<AppLifecycle>>,
+) {
+    for event in lifecycle_reader.iter(&lifecycle_events) {
+        info!("{:?}", event);
+    }
+}
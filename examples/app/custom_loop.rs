@@ -16,16 +16,24 @@ fn my_runner(mut app: App) {
         }
         app.update();
     }
+
+    // custom runners should run the shutdown systems once they stop updating the app
+    app.shutdown();
 }
 
 fn print_system(input: Res<Input>) {
     println!("You typed: {}", input.0);
 }
 
+fn shutdown_system() {
+    println!("Goodbye!");
+}
+
 fn main() {
     App::build()
         .add_resource(Input(String::new()))
         .set_runner(my_runner)
         .add_system(print_system)
+        .add_shutdown_system(shutdown_system)
         .run();
 }
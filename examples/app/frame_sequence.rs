@@ -0,0 +1,82 @@
+use bevy::{
+    prelude::*,
+    render::frame_sequence::{FrameSequenceCapture, FrameSequenceOutput, FrameSequencePlugin},
+};
+use std::path::PathBuf;
+
+/// This example illustrates recording a smooth frame sequence for a trailer, regardless of how fast
+/// the frames are rendered. Press R to start or stop recording to the `frames` directory.
+///
+/// To encode the frames into a video instead, pipe them to ffmpeg:
+/// `FrameSequenceOutput::Pipe(Command::new("ffmpeg").args(&["-f", "rawvideo", "-pix_fmt",
+/// "rgba", "-s", "1280x720", "-r", "60", "-i", "-", "trailer.mp4"]))`
+fn main() {
+    App::build()
+        .add_resource(WindowDescriptor {
+            width: 1280.0,
+            height: 720.0,
+            ..Default::default()
+        })
+        .add_resource(Msaa { samples: 4 })
+        .add_resource(FrameSequenceCapture::new(FrameSequenceOutput::Images {
+            directory: PathBuf::from("frames"),
+        }))
+        .add_plugins(DefaultPlugins)
+        .add_plugin(FrameSequencePlugin)
+        .add_startup_system(setup)
+        .add_system(record_system)
+        .add_system(rotate_system)
+        .run();
+}
+
+struct Rotates;
+
+fn record_system(keyboard_input: Res<Input<KeyCode>>, mut capture: ResMut<FrameSequenceCapture>) {
+    if !keyboard_input.just_pressed(KeyCode::R) {
+        return;
+    }
+    if capture.is_recording() {
+        capture.stop();
+        println!("recorded {} frames", capture.frames_captured());
+    } else {
+        capture.start().unwrap();
+        println!("recording");
+    }
+}
+
+fn rotate_system(time: Res<Time>, mut query: Query<&mut Transform, With<Rotates>>) {
+    // while recording, the time advances by exactly 1/60th of a second every frame
+    for mut transform in query.iter_mut() {
+        transform.rotate(Quat::from_rotation_y(time.delta_seconds));
+    }
+}
+
+/// set up a simple 3D scene
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Plane { size: 5.0 })),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..Default::default()
+        })
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+            ..Default::default()
+        })
+        .with(Rotates)
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-2.0, 2.5, 5.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
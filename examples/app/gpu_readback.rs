@@ -0,0 +1,54 @@
+use bevy::{
+    prelude::*,
+    render::{
+        render_graph::{GpuReadback, ReadbackComplete},
+        renderer::{BufferInfo, BufferUsage, RenderResourceContext},
+    },
+};
+
+/// This example reads the contents of a GPU buffer back to the CPU. The same works for textures,
+/// for example to get the results of a shader or a rendered image.
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(print_readback)
+        .run();
+}
+
+fn setup(
+    render_resource_context: Res<Box<dyn RenderResourceContext>>,
+    mut gpu_readback: ResMut<GpuReadback>,
+) {
+    let data = (0..16u32)
+        .map(|number| number * number)
+        .flat_map(|number| number.to_ne_bytes().to_vec())
+        .collect::<Vec<u8>>();
+    // readback sources need the COPY_SRC usage
+    let buffer = render_resource_context.create_buffer_with_data(
+        BufferInfo {
+            size: data.len(),
+            buffer_usage: BufferUsage::COPY_SRC,
+            ..Default::default()
+        },
+        &data,
+    );
+    gpu_readback.read_buffer(buffer, 0..data.len() as u64);
+}
+
+/// Prints the buffer's contents once they arrive and exits
+fn print_readback(
+    mut readback_reader: Local<EventReader<ReadbackComplete>>,
+    readback_events: Res<Events<ReadbackComplete>>,
+    mut app_exit_events: ResMut<Events<bevy::app::AppExit>>,
+) {
+    for readback in readback_reader.iter(&readback_events) {
+        let numbers = readback
+            .data
+            .chunks_exact(4)
+            .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect::<Vec<_>>();
+        println!("read back {:?}: {:?}", readback.id, numbers);
+        app_exit_events.send(bevy::app::AppExit);
+    }
+}
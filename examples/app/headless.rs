@@ -1,4 +1,4 @@
-use bevy::{app::ScheduleRunnerSettings, prelude::*, utils::Duration};
+use bevy::{app::ScheduleRunnerSettings, prelude::*};
 
 // This example only enables a minimal set of plugins required for bevy to run.
 // You can also completely remove rendering / windowing Plugin code from bevy
@@ -16,12 +16,11 @@ fn main() {
         .add_system(hello_world_system)
         .run();
 
-    // this app loops forever at 60 fps
+    // this app loops forever at 60 fps. HeadlessPlugins also includes asset loading, scenes and
+    // logging, but still doesn't need a window or a GPU
     App::build()
-        .add_resource(ScheduleRunnerSettings::run_loop(Duration::from_secs_f64(
-            1.0 / 60.0,
-        )))
-        .add_plugins(MinimalPlugins)
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(60.0))
+        .add_plugins(HeadlessPlugins)
         .add_system(counter)
         .run();
 }
@@ -3,16 +3,29 @@ use bevy::prelude::*;
 /// This example illustrates how to use logs in bevy
 fn main() {
     App::build()
-        // Uncomment this to override the default log settings:
-        // .add_resource(bevy::log::LogSettings {
-        //     level: bevy::log::Level::TRACE,
-        //     filter: "wgpu=warn,bevy_ecs=info".to_string(),
-        // })
+        // Replace this with the commented out code below to override the default log settings
         .add_plugins(DefaultPlugins)
+        // .add_plugins_with(DefaultPlugins, |group| {
+        //     group.set(bevy::log::LogPlugin {
+        //         level: bevy::log::Level::TRACE,
+        //         filter: "wgpu=warn,bevy_ecs=info".to_string(),
+        //     })
+        // })
+        .add_startup_system(setup_log_sink)
         .add_system(log_system)
         .run();
 }
 
+fn setup_log_sink(log_sinks: Res<bevy::log::LogSinks>) {
+    // log sinks receive every log that passes the filters, so games can show engine logs in an
+    // in-game console or write them to a file
+    log_sinks.add(|record: &bevy::log::LogRecord| {
+        if record.level == bevy::log::Level::ERROR {
+            eprintln!("[in-game console] {}: {}", record.target, record.message);
+        }
+    });
+}
+
 fn log_system() {
     // here is how you write new logs at each "log level" (in "least important" to "most important" order)
     trace!("very noisy");
@@ -22,7 +35,7 @@ fn log_system() {
     error!("something failed");
 
     // by default, trace and debug logs are ignored because they are "noisy"
-    // you can control what level is logged by adding the LogSettings resource
+    // you can control what level is logged by configuring the LogPlugin
     // alternatively you can set the log level via the RUST_LOG=LEVEL environment variable
     // ex: RUST_LOG=trace, RUST_LOG=info,bevy_ecs=warn
     // the format used here is super flexible. check out this documentation for more info:
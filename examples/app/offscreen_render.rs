@@ -0,0 +1,74 @@
+use bevy::{
+    app::ScheduleRunnerSettings,
+    prelude::*,
+    render::{
+        render_graph::{base::BaseRenderGraphConfig, FrameCapture},
+        RenderPlugin,
+    },
+    winit::WinitPlugin,
+};
+
+/// This example renders a scene without opening a window and reads the rendered frame back to the
+/// CPU, which is how rendering tests can run on CI machines without a display. Machines without a
+/// GPU can render with a software rasterizer, see `WgpuBackend` for how to select one.
+fn main() {
+    App::build()
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(60.0))
+        .add_plugins_with(DefaultPlugins, |group| {
+            group
+                .disable::<WinitPlugin>()
+                .set(RenderPlugin {
+                    base_render_graph_config: Some(BaseRenderGraphConfig {
+                        offscreen: true,
+                        ..Default::default()
+                    }),
+                })
+                .add(bevy::app::ScheduleRunnerPlugin::default())
+        })
+        .add_startup_system(setup)
+        .add_system(capture_frame)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..Default::default()
+        })
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-2.0, 2.5, 5.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
+
+/// Requests a capture once the scene has been rendered a few times and exits once it arrives
+fn capture_frame(
+    mut frames: Local<u32>,
+    mut frame_capture: ResMut<FrameCapture>,
+    mut app_exit_events: ResMut<Events<bevy::app::AppExit>>,
+) {
+    *frames += 1;
+    if *frames == 3 {
+        frame_capture.request();
+    }
+    if let Some(frame) = frame_capture.take_frame() {
+        println!(
+            "captured a {}x{} frame of {} bytes",
+            frame.size.width,
+            frame.size.height,
+            frame.data.len()
+        );
+        app_exit_events.send(bevy::app::AppExit);
+    }
+}
@@ -11,7 +11,11 @@ fn main() {
         // .add_plugins_with(HelloWorldPlugins, |group| {
         //     group
         //         .disable::<PrintWorldPlugin>()
-        //         .add_before::<PrintHelloPlugin, _>(bevy::diagnostic::PrintDiagnosticsPlugin::default())
+        //         .add_before::<PrintHelloPlugin, _>(bevy::diagnostic::LogDiagnosticsPlugin::default())
+        // })
+        // Plugins in a group can also be swapped out for your own, keeping their position:
+        // .add_plugins_with(HelloWorldPlugins, |group| {
+        //     group.replace::<PrintWorldPlugin, _>(PrintHelloPlugin)
         // })
         .run();
 }
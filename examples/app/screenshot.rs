@@ -0,0 +1,79 @@
+use bevy::{
+    prelude::*,
+    render::render_graph::{Screenshot, ScreenshotCaptured},
+    window::WindowId,
+};
+
+/// This example illustrates taking screenshots, saved as PNG files or kept as texture assets.
+/// Press space to save a screenshot, or C to capture one into a texture.
+fn main() {
+    App::build()
+        .add_resource(Msaa { samples: 4 })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(screenshot_system)
+        .add_system(print_captured_system)
+        .run();
+}
+
+fn screenshot_system(
+    mut count: Local<u32>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut screenshot: ResMut<Screenshot>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        let path = format!("screenshot-{}.png", *count);
+        println!("saving {}", path);
+        screenshot.save_to_file(WindowId::primary(), path);
+        *count += 1;
+    }
+    if keyboard_input.just_pressed(KeyCode::C) {
+        screenshot.capture(WindowId::primary());
+    }
+}
+
+fn print_captured_system(
+    mut event_reader: Local<EventReader<ScreenshotCaptured>>,
+    screenshot_events: Res<Events<ScreenshotCaptured>>,
+    textures: Res<Assets<Texture>>,
+) {
+    for event in event_reader.iter(&screenshot_events) {
+        // keep a clone of `event.texture` to keep the screenshot around
+        let texture = textures.get(&event.texture).unwrap();
+        println!(
+            "captured a {}x{} screenshot, {} bytes",
+            texture.size.width,
+            texture.size.height,
+            texture.data.len()
+        );
+    }
+}
+
+/// set up a simple 3D scene
+fn setup(
+    commands: &mut Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Plane { size: 5.0 })),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..Default::default()
+        })
+        .spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+            ..Default::default()
+        })
+        .spawn(LightBundle {
+            transform: Transform::from_translation(Vec3::new(4.0, 8.0, 4.0)),
+            ..Default::default()
+        })
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(-2.0, 2.5, 5.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
@@ -1,10 +1,29 @@
-use bevy::prelude::*;
+use bevy::{core::CorePlugin, prelude::*, tasks::prelude::*};
 
-/// This example illustrates how to customize the thread pool used internally (e.g. to only use a
-/// certain number of threads).
+/// This example illustrates how to customize the thread pools used internally (e.g. to only use a
+/// certain number of threads), and how systems can use those pools for their own work.
 fn main() {
     App::build()
-        .add_resource(DefaultTaskPoolOptions::with_num_threads(4))
-        .add_plugins(DefaultPlugins)
+        .add_plugins_with(DefaultPlugins, |group| {
+            group.set(CorePlugin {
+                task_pool_options: DefaultTaskPoolOptions::with_num_threads(4),
+            })
+        })
+        .add_startup_system(sum_numbers)
         .run();
 }
+
+/// The task pools are resources, so systems can spread their own work across the engine's threads
+/// instead of spawning threads themselves
+fn sum_numbers(compute_pool: Res<ComputeTaskPool>) {
+    let numbers = (1..=10_000u64).collect::<Vec<_>>();
+    let sum = numbers
+        .par_chunk_map(&compute_pool, 1_000, |chunk| chunk.iter().sum::<u64>())
+        .into_iter()
+        .sum::<u64>();
+    println!(
+        "the sum is {}, computed with {} threads",
+        sum,
+        compute_pool.thread_num()
+    );
+}
@@ -1,8 +1,11 @@
-use bevy::prelude::*;
+use bevy::{app::Capabilities, prelude::*};
 
 /// This example illustrates how to load and play an audio file
 fn main() {
     App::build()
+        // optional subsystems can be turned off from the command line, e.g. with `--disable-audio`.
+        // the app keeps running without sound when audio is disabled or there is no audio device
+        .add_resource(Capabilities::from_args(std::env::args()))
         .add_plugins(DefaultPlugins)
         .add_startup_system(setup)
         .run();
@@ -0,0 +1,21 @@
+use bevy::{
+    diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
+    prelude::*,
+};
+
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        // Adds frame time diagnostics
+        .add_plugin(FrameTimeDiagnosticsPlugin::default())
+        // Adds a system that logs diagnostics to the console
+        .add_plugin(LogDiagnosticsPlugin::default())
+        // Any plugin can register diagnostics
+        // Uncomment this to add some render resource diagnostics:
+        // .add_plugin(bevy::wgpu::diagnostic::WgpuResourceDiagnosticsPlugin::default())
+        // Uncomment this to measure how long each system takes to run:
+        // .add_plugin(bevy::diagnostic::SystemTimeDiagnosticsPlugin::default())
+        // Uncomment this to track the number of entities, archetypes and components:
+        // .add_plugin(bevy::diagnostic::EntityCountDiagnosticsPlugin::default())
+        .run();
+}
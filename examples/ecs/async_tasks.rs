@@ -0,0 +1,55 @@
+use bevy::{
+    app::{AppExit, ScheduleRunnerSettings},
+    prelude::*,
+    tasks::{AsyncComputeTaskPool, Task},
+};
+use std::time::Duration;
+
+/// This example shows how to run work that takes longer than a frame in the background. Tasks are
+/// spawned on the AsyncComputeTaskPool and stored as components, and a system checks every frame
+/// whether they are done.
+fn main() {
+    App::build()
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(60.0))
+        .add_plugins(MinimalPlugins)
+        .add_startup_system(spawn_tasks)
+        .add_system(handle_tasks)
+        .run();
+}
+
+const NUM_TASKS: u64 = 5;
+
+/// The result of a task
+struct Answer(u64);
+
+fn spawn_tasks(commands: &mut Commands, thread_pool: Res<AsyncComputeTaskPool>) {
+    for i in 1..=NUM_TASKS {
+        let task = thread_pool.spawn(async move {
+            // pretend this is expensive work, like pathfinding or generating a chunk of terrain
+            std::thread::sleep(Duration::from_millis(200 * i));
+            i * i
+        });
+        commands.spawn((task,));
+    }
+}
+
+/// Swaps finished tasks for their results, and exits once every task is done
+fn handle_tasks(
+    commands: &mut Commands,
+    mut app_exit_events: ResMut<Events<AppExit>>,
+    mut tasks: Query<(Entity, &mut Task<u64>)>,
+    answers: Query<&Answer>,
+) {
+    for (entity, mut task) in tasks.iter_mut() {
+        if let Some(answer) = task.check_ready() {
+            println!("{:?} finished with the answer {}", entity, answer);
+            commands.remove_one::<Task<u64>>(entity);
+            commands.insert_one(entity, Answer(answer));
+        }
+    }
+
+    if answers.iter().count() as u64 == NUM_TASKS {
+        println!("all tasks are done");
+        app_exit_events.send(AppExit);
+    }
+}
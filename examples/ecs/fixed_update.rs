@@ -0,0 +1,36 @@
+use bevy::{app::ScheduleRunnerSettings, prelude::*};
+
+/// This example shows how to run simulation logic at a fixed rate, independent of how often the app
+/// updates. Fixed update systems run zero or more times per frame, as many times as needed to keep
+/// up with the FixedTime clock.
+fn main() {
+    App::build()
+        // the app updates 10 times per second...
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(10.0))
+        // ...while the simulation steps 30 times per second
+        .add_resource(FixedTime::from_hz(30.0))
+        .add_plugins(MinimalPlugins)
+        .add_resource(Position(0.0))
+        .add_fixed_system(simulation_system)
+        .add_system(frame_system)
+        .run();
+}
+
+struct Position(f32);
+
+/// Moves at 1 unit per second. Fixed update systems use the fixed step as their delta time.
+fn simulation_system(fixed_time: Res<FixedTime>, mut position: ResMut<Position>) {
+    position.0 += fixed_time.step_seconds();
+}
+
+/// Runs once per frame. Both clocks can be read here: Time advances per frame and FixedTime
+/// advances per simulation step.
+fn frame_system(time: Res<Time>, fixed_time: Res<FixedTime>, position: Res<Position>) {
+    println!(
+        "frame time: {:.2}s, simulation time: {:.2}s ({} steps), position: {:.2}",
+        time.seconds_since_startup,
+        fixed_time.seconds_since_startup(),
+        fixed_time.steps(),
+        position.0
+    );
+}
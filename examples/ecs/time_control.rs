@@ -0,0 +1,43 @@
+use bevy::{app::ScheduleRunnerSettings, prelude::*};
+
+/// This example shows how to pause and slow down the Time resource. Systems that use the scaled
+/// delta time slow down and stop with it, while the raw delta time keeps running, e.g. for menus.
+fn main() {
+    App::build()
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(4.0))
+        .add_plugins(MinimalPlugins)
+        .add_resource(Position(0.0))
+        .add_system(control_system)
+        .add_system(movement_system)
+        .run();
+}
+
+struct Position(f32);
+
+/// Plays at normal speed for two real seconds, then in slow motion, then pauses for two seconds
+fn control_system(mut time: ResMut<Time>) {
+    let real_seconds = time.time_since_startup().as_secs_f32();
+    if real_seconds < 2.0 {
+        time.set_relative_speed(1.0);
+    } else if real_seconds < 4.0 {
+        time.set_relative_speed(0.25);
+    } else if real_seconds < 6.0 {
+        time.pause();
+    } else {
+        time.unpause();
+        time.set_relative_speed(1.0);
+    }
+}
+
+/// Moves at 1 unit per (scaled) second
+fn movement_system(time: Res<Time>, mut position: ResMut<Position>) {
+    position.0 += time.delta_seconds;
+    println!(
+        "real delta: {:.3}s, scaled delta: {:.3}s, paused: {}, speed: {:.2}, position: {:.2}",
+        time.raw_delta_seconds(),
+        time.delta_seconds,
+        time.is_paused(),
+        time.relative_speed(),
+        position.0
+    );
+}
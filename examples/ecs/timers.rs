@@ -0,0 +1,50 @@
+use bevy::{app::ScheduleRunnerSettings, prelude::*};
+
+/// This example shows how to use timers for repeating events and cooldowns, and stopwatches to
+/// measure how long something has been going on.
+fn main() {
+    App::build()
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(2.0))
+        .add_plugins(MinimalPlugins)
+        .add_resource(SpawnTimer(Timer::from_seconds(0.2, true)))
+        .add_startup_system(setup)
+        .add_system(spawn_system)
+        .add_system(cooldown_system)
+        .run();
+}
+
+/// Spawns five things per second, which is faster than the app updates
+struct SpawnTimer(Timer);
+
+struct Ability {
+    cooldown: Timer,
+    since_start: Stopwatch,
+}
+
+fn setup(commands: &mut Commands) {
+    commands.spawn((Ability {
+        cooldown: Timer::from_seconds(1.5, false),
+        since_start: Stopwatch::new(),
+    },));
+}
+
+fn spawn_system(time: Res<Time>, mut spawn_timer: ResMut<SpawnTimer>) {
+    // a repeating timer can finish several times in one tick
+    let spawned = spawn_timer.0.tick(time.delta_seconds).times_finished();
+    if spawned > 0 {
+        println!("spawned {} things", spawned);
+    }
+}
+
+fn cooldown_system(time: Res<Time>, mut query: Query<&mut Ability>) {
+    for mut ability in query.iter_mut() {
+        ability.since_start.tick(time.delta_seconds);
+        ability.cooldown.tick(time.delta_seconds);
+        if ability.cooldown.is_finished() {
+            println!("ability used after {:.1}s", ability.since_start.elapsed());
+            ability.cooldown.reset();
+        } else {
+            println!("ability ready in {:.1}s", ability.cooldown.remaining());
+        }
+    }
+}
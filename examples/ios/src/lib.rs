@@ -18,6 +18,7 @@ fn main() {
 /// set up a simple 3D scene
 fn setup(
     commands: &mut Commands,
+    asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
@@ -32,7 +33,11 @@ fn setup(
         // cube
         .spawn(PbrBundle {
             mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
-            material: materials.add(Color::rgb(0.5, 0.4, 0.3).into()),
+            // the assets folder is copied into the app bundle by the Xcode project
+            material: materials.add(StandardMaterial {
+                albedo_texture: Some(asset_server.load("branding/icon.png")),
+                ..Default::default()
+            }),
             transform: Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
             ..Default::default()
         })
@@ -0,0 +1,71 @@
+use bevy::{
+    ecs::EntityMap,
+    prelude::*,
+    scene::{apply_component_changes, ComponentChanges},
+};
+
+/// This example illustrates replicating the changes to components to another world, like a
+/// networked game would send them to its clients
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .register_component::<Health>()
+        .init_resource::<Remote>()
+        .add_startup_system(setup)
+        .add_system(damage_system)
+        .add_system(replicate_system)
+        .run();
+}
+
+#[derive(Properties, Default, Debug)]
+struct Health {
+    pub value: f32,
+}
+
+/// Stands in for the world of a client on the other end of a network connection
+#[derive(Default)]
+struct Remote {
+    world: World,
+    entity_map: EntityMap,
+}
+
+fn setup(commands: &mut Commands, mut component_changes: ResMut<ComponentChanges>) {
+    // only tracked components are recorded, everything else stays local
+    component_changes.track::<Health>();
+    commands.spawn((Health { value: 100.0 },));
+}
+
+fn damage_system(time: Res<Time>, mut query: Query<&mut Health>) {
+    // only the components that are actually mutated are sent
+    if time.seconds_since_startup as u64 % 2 != 0 {
+        return;
+    }
+    for mut health in query.iter_mut() {
+        health.value = (health.value - 10.0 * time.delta_seconds).max(0.0);
+    }
+}
+
+fn replicate_system(_world: &mut World, resources: &mut Resources) {
+    let changes = resources
+        .get_mut::<ComponentChanges>()
+        .unwrap()
+        .drain()
+        .collect::<Vec<_>>();
+    if changes.is_empty() {
+        return;
+    }
+
+    // the changes can be serialized with any serde format and sent over the network
+    let message = ron::ser::to_string(&changes).unwrap();
+    println!("sending {} bytes: {}", message.len(), message);
+
+    let mut remote = resources.get_mut::<Remote>().unwrap();
+    let Remote {
+        world: remote_world,
+        entity_map,
+    } = &mut *remote;
+    apply_component_changes(remote_world, resources, &changes, entity_map).unwrap();
+    for health in remote_world.query::<&Health>() {
+        println!("remote health: {}", health.value);
+    }
+}
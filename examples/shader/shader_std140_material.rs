@@ -0,0 +1,119 @@
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::shape,
+        pipeline::{PipelineCompiler, PipelineDescriptor, RenderPipeline, Std140},
+        render_graph::{base, AssetRenderResourcesNode, RenderGraph},
+        renderer::{RenderResource, RenderResources},
+        shader::{ShaderStage, ShaderStages},
+    },
+    type_registry::TypeUuid,
+};
+
+/// This example illustrates how to pass a struct with several fields to a shader as one uniform block
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_asset::<MyMaterial>()
+        .add_startup_system(setup)
+        .run();
+}
+
+#[derive(RenderResources, Default, TypeUuid)]
+#[uuid = "4ee9c361-1124-4113-890e-197d82b00123"]
+struct MyMaterial {
+    pub params: MaterialParams,
+}
+
+// Std140 inserts the padding GLSL expects between the fields. It also implements Bytes, so the
+// struct can be used as a RenderResource.
+#[derive(Std140, RenderResource, Default)]
+struct MaterialParams {
+    pub color: Color,
+    pub light_direction: Vec3,
+    pub ambient: f32,
+}
+
+const VERTEX_SHADER: &str = r#"
+#version 450
+layout(location = 0) in vec3 Vertex_Position;
+layout(location = 1) in vec3 Vertex_Normal;
+layout(location = 0) out vec3 v_Normal;
+layout(set = 0, binding = 0) uniform Camera {
+    mat4 ViewProj;
+};
+layout(set = 1, binding = 0) uniform Transform {
+    mat4 Model;
+};
+void main() {
+    v_Normal = mat3(Model) * Vertex_Normal;
+    gl_Position = ViewProj * Model * vec4(Vertex_Position, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"
+#version 450
+layout(location = 0) in vec3 v_Normal;
+layout(location = 0) out vec4 o_Target;
+layout(set = 1, binding = 1) uniform MyMaterial_params {
+    vec4 color;
+    vec3 light_direction;
+    float ambient;
+};
+void main() {
+    float light = max(dot(normalize(v_Normal), -light_direction), 0.0);
+    o_Target = vec4(color.rgb * (ambient + light), color.a);
+}
+"#;
+
+fn setup(
+    commands: &mut Commands,
+    mut pipelines: ResMut<Assets<PipelineDescriptor>>,
+    mut shaders: ResMut<Assets<Shader>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<MyMaterial>>,
+    mut render_graph: ResMut<RenderGraph>,
+    mut pipeline_compiler: ResMut<PipelineCompiler>,
+) {
+    let pipeline_handle = pipelines.add(PipelineDescriptor::default_config(ShaderStages {
+        vertex: shaders.add(Shader::from_glsl(ShaderStage::Vertex, VERTEX_SHADER)),
+        fragment: Some(shaders.add(Shader::from_glsl(ShaderStage::Fragment, FRAGMENT_SHADER))),
+    }));
+
+    // Compiling the pipeline panics if the shader's uniform block doesn't match MaterialParams
+    pipeline_compiler.register_uniform::<MaterialParams>("MyMaterial_params");
+
+    render_graph.add_system_node(
+        "my_material",
+        AssetRenderResourcesNode::<MyMaterial>::new(true),
+    );
+    render_graph
+        .add_node_edge("my_material", base::node::MAIN_PASS)
+        .unwrap();
+
+    let material = materials.add(MyMaterial {
+        params: MaterialParams {
+            color: Color::rgb(0.9, 0.4, 0.1),
+            light_direction: Vec3::new(-1.0, -2.0, 1.0).normalize(),
+            ambient: 0.2,
+        },
+    });
+
+    commands
+        .spawn(MeshBundle {
+            mesh: meshes.add(Mesh::from(shape::Icosphere {
+                radius: 1.5,
+                subdivisions: 4,
+            })),
+            render_pipelines: RenderPipelines::from_pipelines(vec![RenderPipeline::new(
+                pipeline_handle,
+            )]),
+            ..Default::default()
+        })
+        .with(material)
+        .spawn(Camera3dBundle {
+            transform: Transform::from_translation(Vec3::new(3.0, 5.0, -8.0))
+                .looking_at(Vec3::default(), Vec3::unit_y()),
+            ..Default::default()
+        });
+}
@@ -0,0 +1,56 @@
+use bevy::{prelude::*, window::CursorIcon};
+
+/// This example illustrates changing the cursor of a window at runtime, either to one of the
+/// standard OS cursors or to a custom image. Press 1-4 to switch between cursors.
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(cursor_system)
+        .run();
+}
+
+struct CursorMaterial(Handle<ColorMaterial>);
+
+fn setup(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    // custom cursor images are drawn by the UI, which needs a camera
+    commands
+        .spawn(UiCameraBundle::default())
+        .insert_resource(CursorMaterial(
+            materials.add(asset_server.load("branding/icon.png").into()),
+        ));
+}
+
+fn cursor_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    cursor_material: Res<CursorMaterial>,
+    mut windows: ResMut<Windows>,
+    mut cursor_images: ResMut<CursorImages>,
+) {
+    let window = windows.get_primary_mut().unwrap();
+    let icon = if keyboard_input.just_pressed(KeyCode::Key1) {
+        CursorIcon::Default
+    } else if keyboard_input.just_pressed(KeyCode::Key2) {
+        CursorIcon::Hand
+    } else if keyboard_input.just_pressed(KeyCode::Key3) {
+        CursorIcon::Crosshair
+    } else if keyboard_input.just_pressed(KeyCode::Key4) {
+        // the hotspot is the point of the image that "points", here the center of the icon
+        cursor_images.set(
+            window.id(),
+            CursorImage {
+                material: cursor_material.0.clone(),
+                hotspot: Vec2::new(128.0, 128.0),
+            },
+        );
+        return;
+    } else {
+        return;
+    };
+    cursor_images.remove(window.id());
+    window.set_cursor_icon(icon);
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+
+/// This example illustrates translating text into several languages and switching between them at
+/// runtime. Press space to count and L to switch the language.
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(count_system)
+        .add_system(switch_locale_system)
+        .run();
+}
+
+struct Counter(u32);
+
+fn setup(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    mut localization: ResMut<Localization>,
+) {
+    // messages missing from a language are shown in english
+    localization.set_fallback_locale(Some("en-US".to_string()));
+    localization.add_translations("en-US", asset_server.load("locales/en-US.ftl"));
+    localization.add_translations("de", asset_server.load("locales/de.ftl"));
+
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let text_bundle = |top: f32| TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: Rect {
+                top: Val::Px(top),
+                left: Val::Px(15.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        text: Text {
+            font: font.clone(),
+            style: TextStyle {
+                font_size: 40.0,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    commands
+        .spawn(UiCameraBundle::default())
+        // the text of these entities is filled in from the translations of the current locale
+        .spawn(text_bundle(15.0))
+        .with(LocalizedText::new("title"))
+        .spawn(text_bundle(75.0))
+        .with(LocalizedText::new("presses").with_arg("count", 0))
+        .with(Counter(0))
+        .spawn(text_bundle(135.0))
+        .with(LocalizedText::new("instructions"));
+}
+
+fn count_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut query: Query<(&mut Counter, &mut LocalizedText)>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+    for (mut counter, mut localized_text) in query.iter_mut() {
+        counter.0 += 1;
+        localized_text
+            .args
+            .insert("count".to_string(), counter.0.to_string());
+    }
+}
+
+fn switch_locale_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut localization: ResMut<Localization>,
+) {
+    if keyboard_input.just_pressed(KeyCode::L) {
+        let locale = if localization.locale() == "en-US" {
+            "de"
+        } else {
+            "en-US"
+        };
+        localization.set_locale(locale);
+    }
+}
@@ -2,14 +2,11 @@ use bevy::{
     app::{ScheduleRunnerPlugin, ScheduleRunnerSettings},
     log::LogPlugin,
     prelude::*,
-    utils::Duration,
 };
 
 fn main() {
     App::build()
-        .add_resource(ScheduleRunnerSettings::run_loop(Duration::from_secs_f64(
-            1.0 / 60.0,
-        )))
+        .add_resource(ScheduleRunnerSettings::run_loop_at_rate(60.0))
         .add_plugin(ScheduleRunnerPlugin::default())
         .add_plugin(LogPlugin::default())
         .add_startup_system(hello_world_system)
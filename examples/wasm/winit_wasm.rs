@@ -9,8 +9,8 @@ use bevy::{
 fn main() {
     App::build()
         .add_resource(WindowDescriptor {
-            width: 300,
-            height: 300,
+            width: 300.,
+            height: 300.,
             ..Default::default()
         })
         .add_plugins(DefaultPlugins)
@@ -52,6 +52,7 @@ struct TrackInputState {
     motion: EventReader<MouseMotion>,
     mousebtn: EventReader<MouseButtonInput>,
     scroll: EventReader<MouseWheel>,
+    gamepad: EventReader<GamepadEvent>,
 }
 
 fn track_input_events(
@@ -61,6 +62,7 @@ fn track_input_events(
     ev_motion: Res<Events<MouseMotion>>,
     ev_mousebtn: Res<Events<MouseButtonInput>>,
     ev_scroll: Res<Events<MouseWheel>>,
+    ev_gamepad: Res<Events<GamepadEvent>>,
 ) {
     // Keyboard input
     for ev in state.keys.iter(&ev_keys) {
@@ -97,4 +99,9 @@ fn track_input_events(
             ev.y, ev.x
         );
     }
+
+    // Gamepads, when the `bevy_gilrs` feature is enabled
+    for GamepadEvent(gamepad, event_type) in state.gamepad.iter(&ev_gamepad) {
+        info!("{:?}: {:?}", gamepad, event_type);
+    }
 }
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+/// This example illustrates copying text to and pasting text from the system clipboard.
+/// Type to add text, press Ctrl+C to copy it and Ctrl+V to paste.
+fn main() {
+    App::build()
+        .add_plugins(DefaultPlugins)
+        .init_resource::<Typed>()
+        .add_system(typing_system)
+        .add_system(copy_paste_system)
+        .add_system(paste_system)
+        .run();
+}
+
+#[derive(Default)]
+struct Typed(String);
+
+fn typing_system(
+    mut char_event_reader: Local<EventReader<ReceivedCharacter>>,
+    char_events: Res<Events<ReceivedCharacter>>,
+    mut typed: ResMut<Typed>,
+) {
+    for event in char_event_reader.iter(&char_events) {
+        if !event.char.is_control() {
+            typed.0.push(event.char);
+        }
+    }
+}
+
+fn copy_paste_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    typed: Res<Typed>,
+    mut clipboard: ResMut<Clipboard>,
+) {
+    let ctrl =
+        keyboard_input.pressed(KeyCode::LControl) || keyboard_input.pressed(KeyCode::RControl);
+    if ctrl && keyboard_input.just_pressed(KeyCode::C) {
+        println!("copied: {}", typed.0);
+        clipboard.set_text(typed.0.clone());
+    }
+    if ctrl && keyboard_input.just_pressed(KeyCode::V) {
+        clipboard.request_text();
+    }
+}
+
+fn paste_system(
+    mut clipboard_text_reader: Local<EventReader<ClipboardText>>,
+    clipboard_text_events: Res<Events<ClipboardText>>,
+    mut typed: ResMut<Typed>,
+) {
+    for event in clipboard_text_reader.iter(&clipboard_text_events) {
+        match &event.text {
+            Some(text) => {
+                typed.0.push_str(text);
+                println!("pasted: {}", typed.0);
+            }
+            None => println!("the clipboard doesn't contain text"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+/// This example illustrates how to customize the window scale factor. Press the up and down
+/// arrow keys to step the override, or press enter to toggle between the override and the
+/// scale factor reported by the OS.
+fn main() {
+    App::build()
+        .add_resource(WindowDescriptor {
+            width: 500.,
+            height: 300.,
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .add_system(toggle_override)
+        .add_system(change_scale_factor)
+        .run();
+}
+
+fn setup(commands: &mut Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(UiCameraBundle::default()).spawn(TextBundle {
+        style: Style {
+            align_self: AlignSelf::FlexEnd,
+            ..Default::default()
+        },
+        text: Text {
+            value: "Example text".to_string(),
+            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+            style: TextStyle {
+                font_size: 30.0,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+        },
+        ..Default::default()
+    });
+}
+
+/// This system toggles scale factor overrides when enter is pressed
+fn toggle_override(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
+    let window = windows.get_primary_mut().unwrap();
+    if input.just_pressed(KeyCode::Return) {
+        window.set_scale_factor_override(window.scale_factor_override().xor(Some(1.)));
+    }
+}
+
+/// This system changes the scale factor override when up or down is pressed
+fn change_scale_factor(input: Res<Input<KeyCode>>, mut windows: ResMut<Windows>) {
+    let window = windows.get_primary_mut().unwrap();
+    if input.just_pressed(KeyCode::Up) {
+        window.set_scale_factor_override(window.scale_factor_override().map(|n| n + 1.));
+    } else if input.just_pressed(KeyCode::Down) {
+        window.set_scale_factor_override(window.scale_factor_override().map(|n| (n - 1.).max(1.)));
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::{prelude::*, render::pass::ClearColor};
+
+/// This example shows how to create a borderless window that asks for a transparent background.
+/// See `WindowDescriptor::transparent`: the render backend doesn't composite the window with
+/// alpha yet, so whether anything behind the window shows through depends on the platform.
+fn main() {
+    App::build()
+        // ClearColor must have 0 alpha, otherwise some color will bleed through
+        .add_resource(ClearColor(Color::NONE))
+        .add_resource(WindowDescriptor {
+            transparent: true,
+            decorations: false,
+            ..Default::default()
+        })
+        .add_plugins(DefaultPlugins)
+        .add_startup_system(setup)
+        .run();
+}
+
+fn setup(
+    commands: &mut Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let texture_handle = asset_server.load("branding/icon.png");
+    commands
+        .spawn(Camera2dBundle::default())
+        .spawn(SpriteBundle {
+            material: materials.add(texture_handle.into()),
+            ..Default::default()
+        });
+}
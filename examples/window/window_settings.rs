@@ -1,17 +1,22 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, window::WindowPlugin};
 
 /// This example illustrates how to customize the default window settings
 fn main() {
     App::build()
-        .add_resource(WindowDescriptor {
-            title: "I am a window!".to_string(),
-            width: 500,
-            height: 300,
-            vsync: true,
-            resizable: false,
-            ..Default::default()
+        // plugins in a group can be configured by replacing them with a customized plugin
+        .add_plugins_with(DefaultPlugins, |group| {
+            group.set(WindowPlugin {
+                primary_window: Some(WindowDescriptor {
+                    title: "I am a window!".to_string(),
+                    width: 500.,
+                    height: 300.,
+                    vsync: true,
+                    resizable: false,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
         })
-        .add_plugins(DefaultPlugins)
         .add_system(change_title)
         .add_system(toggle_cursor)
         .run();
@@ -17,11 +17,14 @@ crates=(
     bevy_core
     bevy_diagnostic
     bevy_transform
+    bevy_animation
     bevy_window
     bevy_render
     bevy_input
     bevy_gilrs
     bevy_pbr
+    bevy_gizmos
+    bevy_picking
     bevy_gltf
     bevy_scene
     bevy_sprite